@@ -0,0 +1,747 @@
+//! Benchmarks of the `level_1` routines.
+//!
+//! Every routine runs on vectors of 10 to 10⁷ elements with increments 1 and 2, in f32, f64 and,
+//! where it has a complex version, Complex<f32> (c32) and Complex<f64> (c64). The mixed
+//! precision routines run on f32 vectors with f64 arithmetic. For the sparse routines the
+//! increment is the distance between consecutive indices. The groups are named
+//! `routine/type/incN` and each benchmark in them by the size.
+//!
+//! Arithmetic routines report their floating-point operations as the throughput, so criterion's
+//! Gelem/s is GFLOP/s. Routines that only move data report GB/s.
+//!
+//! Set `BENCH_REFERENCE` to time a plain loop computing the same result next to every routine,
+//! see `reference.rs`:
+//!
+//! ```text
+//! BENCH_REFERENCE=1 cargo bench --bench level_1 -- 'rotm_flag.*/f64/inc1'
+//! ```
+//!
+//! With increment 2 the largest complex vectors take 320 MB each. Pass a filter such as
+//! `'/(10|100|1000|10000)$'` to stay with the smaller sizes.
+
+use std::hint::black_box;
+use std::mem::size_of;
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use naive_blas::level_1::{
+    axpby, axpy, axpyi, copy, dot, dotc, doti, dotu, dsdot, gthr, nrm2, rot, rotg, roti, rotm,
+    rotmg, scal, sctr, swap,
+};
+use nd_slice::{NdSlice, NdSliceMut};
+use num_complex::Complex;
+use num_traits::{Float, Zero};
+
+mod reference;
+
+const SIZES: [usize; 7] = [10, 100, 1_000, 10_000, 100_000, 1_000_000, 10_000_000];
+const INCS: [usize; 2] = [1, 2];
+
+/// Element types, with a short name for the group names and some values to fill vectors with.
+trait Element: Copy + 'static {
+    const NAME: &'static str;
+
+    /// Returns a value between -1 and 1 that only depends on i.
+    fn at(i: usize) -> Self;
+}
+
+macro_rules! element {
+    ($t:ty, $name:expr, $complex:expr) => {
+        impl Element for $t {
+            const NAME: &'static str = $name;
+
+            fn at(i: usize) -> Self {
+                ((i % 13) as $t - 6.0) / 8.0
+            }
+        }
+
+        impl Element for Complex<$t> {
+            const NAME: &'static str = $complex;
+
+            fn at(i: usize) -> Self {
+                Complex::new(<$t>::at(i), <$t>::at(i + 5))
+            }
+        }
+    };
+}
+
+element!(f32, "f32", "c32");
+element!(f64, "f64", "c64");
+
+/// The vectors of one benchmark: x and y hold n elements inc apart, and indx holds the positions
+/// of those elements for the sparse routines, whose compressed vector is the start of x.
+struct Data<T> {
+    n: isize,
+    inc: isize,
+    x: Vec<T>,
+    y: Vec<T>,
+    indx: Vec<isize>,
+}
+
+impl<T: Element> Data<T> {
+    fn new(n: usize, inc: usize) -> Self {
+        let len = (n - 1) * inc + 1;
+
+        Self {
+            n: n as isize,
+            inc: inc as isize,
+            x: (0..len).map(T::at).collect(),
+            y: (0..len).map(|i| T::at(i + 7)).collect(),
+            indx: (0..n).map(|i| (i * inc) as isize).collect(),
+        }
+    }
+}
+
+fn vector<T>(x: &[T]) -> NdSlice<'_, T, 1> {
+    NdSlice::new_row_ordered(x, [x.len()]).unwrap_or_else(|_| unreachable!())
+}
+
+fn vector_mut<T>(x: &mut [T]) -> NdSliceMut<'_, T, 1> {
+    let n = x.len();
+    NdSliceMut::new_row_ordered(x, [n]).unwrap_or_else(|_| unreachable!())
+}
+
+fn reference_enabled() -> bool {
+    std::env::var_os("BENCH_REFERENCE").is_some()
+}
+
+/// Returns the throughput of a routine doing flops floating-point operations per element.
+fn flops(flops: u64) -> impl Fn(u64) -> Throughput {
+    move |n| Throughput::Elements(flops * n)
+}
+
+/// Returns the throughput of a routine moving bytes bytes per element.
+fn bytes(bytes: usize) -> impl Fn(u64) -> Throughput {
+    move |n| Throughput::BytesDecimal(bytes as u64 * n)
+}
+
+/// Benchmarks a routine, and the reference loop if enabled, for every increment and size.
+fn sweep<T: Element>(
+    c: &mut Criterion,
+    routine: &str,
+    throughput: impl Fn(u64) -> Throughput,
+    mut blas: impl FnMut(&mut Data<T>),
+    mut reference: impl FnMut(&mut Data<T>),
+) {
+    for &inc in &INCS {
+        let mut group = c.benchmark_group(format!("{}/{}/inc{}", routine, T::NAME, inc));
+
+        for &n in &SIZES {
+            group.throughput(throughput(n as u64));
+            group.bench_function(BenchmarkId::new("naive_blas", n), |b| {
+                let mut data = Data::new(n, inc);
+                b.iter(|| blas(&mut data))
+            });
+
+            if reference_enabled() {
+                group.bench_function(BenchmarkId::new("reference", n), |b| {
+                    let mut data = Data::new(n, inc);
+                    b.iter(|| reference(&mut data))
+                });
+            }
+        }
+
+        group.finish();
+    }
+}
+
+/// Benchmarks the routines on real vectors.
+fn real<T: Element + Float>(c: &mut Criterion) {
+    let (a, b) = (T::from(0.5).unwrap(), T::from(-1.0).unwrap());
+    let (cos, sin) = (T::from(0.6).unwrap(), T::from(0.8).unwrap());
+    let size = size_of::<T>();
+
+    sweep(
+        c,
+        "dot",
+        flops(2),
+        |d: &mut Data<T>| {
+            black_box(dot::dot::<T, T>(
+                &d.n,
+                &vector(&d.x),
+                &d.inc,
+                &vector(&d.y),
+                &d.inc,
+            ));
+        },
+        |d| {
+            black_box(reference::dot(&d.x, &d.y, d.inc as usize));
+        },
+    );
+
+    sweep(
+        c,
+        "nrm2",
+        flops(2),
+        |d: &mut Data<T>| {
+            black_box(nrm2::nrm2(&d.n, &mut vector(&d.x), &d.inc));
+        },
+        |d| {
+            black_box(reference::nrm2(&d.x, d.inc as usize));
+        },
+    );
+
+    sweep(
+        c,
+        "axpy",
+        flops(2),
+        |d: &mut Data<T>| {
+            axpy::axpy_real(
+                &d.n,
+                &a,
+                &vector(&d.x),
+                &d.inc,
+                &mut vector_mut(&mut d.y),
+                &d.inc,
+            )
+        },
+        |d| reference::axpy(a, &d.x, &mut d.y, d.inc as usize),
+    );
+
+    // b = -1 keeps y bounded however many times it is updated
+    sweep(
+        c,
+        "axpby",
+        flops(3),
+        |d: &mut Data<T>| {
+            axpby::axpby_real(
+                &d.n,
+                &a,
+                &vector(&d.x),
+                &d.inc,
+                &b,
+                &mut vector_mut(&mut d.y),
+                &d.inc,
+            )
+        },
+        |d| reference::axpby(a, &d.x, b, &mut d.y, d.inc as usize),
+    );
+
+    sweep(
+        c,
+        "scal",
+        flops(1),
+        |d: &mut Data<T>| scal::scal_real(&d.n, &b, &mut vector_mut(&mut d.x), &d.inc),
+        |d| reference::scal(b, &mut d.x, d.inc as usize, |x, a| x * a),
+    );
+
+    sweep(
+        c,
+        "copy",
+        bytes(2 * size),
+        |d: &mut Data<T>| {
+            copy::copy(
+                &d.n,
+                &vector(&d.x),
+                &d.inc,
+                &mut vector_mut(&mut d.y),
+                &d.inc,
+            )
+        },
+        |d| reference::copy(&d.x, &mut d.y, d.inc as usize),
+    );
+
+    sweep(
+        c,
+        "swap",
+        bytes(4 * size),
+        |d: &mut Data<T>| {
+            swap::swap(
+                &d.n,
+                &mut vector_mut(&mut d.x),
+                &d.inc,
+                &mut vector_mut(&mut d.y),
+                &d.inc,
+            )
+        },
+        |d| reference::swap(&mut d.x, &mut d.y, d.inc as usize),
+    );
+
+    sweep(
+        c,
+        "rot",
+        flops(6),
+        |d: &mut Data<T>| {
+            rot::rot(
+                &d.n,
+                &mut vector_mut(&mut d.x),
+                &d.inc,
+                &mut vector_mut(&mut d.y),
+                &d.inc,
+                &cos,
+                &sin,
+            )
+        },
+        |d| reference::rot(&mut d.x, &mut d.y, d.inc as usize, cos, sin),
+    );
+
+    // each flag has its own loop in rotm, while the reference always applies the full matrix;
+    // the matrices keep x and y bounded
+    let (zero, one) = (T::zero(), T::one());
+    let flags = [
+        ("rotm_flag-1", -one, [cos, -sin, sin, cos], 6),
+        ("rotm_flag0", zero, [one, zero, zero, one], 4),
+        ("rotm_flag1", one, [zero, -one, one, zero], 4),
+    ];
+    for &(routine, flag, h, ops) in &flags {
+        let [h11, h21, h12, h22] = h;
+        sweep(
+            c,
+            routine,
+            flops(ops),
+            |d: &mut Data<T>| {
+                rotm::rotm(
+                    &d.n,
+                    &mut vector_mut(&mut d.x),
+                    &d.inc,
+                    &mut vector_mut(&mut d.y),
+                    &d.inc,
+                    &mut vector_mut(&mut [flag, h11, h21, h12, h22]),
+                )
+            },
+            |d| reference::rotm(&mut d.x, &mut d.y, d.inc as usize, h),
+        );
+    }
+
+    sweep(
+        c,
+        "doti",
+        flops(2),
+        |d: &mut Data<T>| {
+            black_box(doti::doti(
+                &d.n,
+                &vector(&d.x),
+                &vector(&d.indx),
+                &0,
+                &vector(&d.y),
+            ));
+        },
+        |d| {
+            black_box(reference::doti(&d.x, &d.indx, &d.y));
+        },
+    );
+
+    sweep(
+        c,
+        "axpyi",
+        flops(2),
+        |d: &mut Data<T>| {
+            axpyi::axpyi_real(
+                &d.n,
+                &a,
+                &vector(&d.x),
+                &vector(&d.indx),
+                &0,
+                &mut vector_mut(&mut d.y),
+            )
+        },
+        |d| reference::axpyi(a, &d.x, &d.indx, &mut d.y),
+    );
+
+    sweep(
+        c,
+        "roti",
+        flops(6),
+        |d: &mut Data<T>| {
+            roti::roti(
+                &d.n,
+                &mut vector_mut(&mut d.x),
+                &vector(&d.indx),
+                &0,
+                &mut vector_mut(&mut d.y),
+                &cos,
+                &sin,
+            )
+        },
+        |d| reference::roti(&mut d.x, &d.indx, &mut d.y, cos, sin),
+    );
+
+    sparse_moves::<T>(c);
+
+    let mut group = c.benchmark_group("rotg");
+    group.bench_function(T::NAME, |b| {
+        b.iter(|| {
+            let (mut a, mut b) = (black_box(cos), black_box(sin));
+            let (mut c, mut s) = (T::zero(), T::zero());
+            rotg::rotg(&mut a, &mut b, &mut c, &mut s);
+            black_box((a, b, c, s))
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("rotmg");
+    group.bench_function(T::NAME, |b| {
+        b.iter(|| {
+            let (mut d1, mut d2) = (black_box(one), black_box(one));
+            let (mut x1, mut y1) = (black_box(cos), black_box(sin));
+            let mut param = [T::zero(); 5];
+            rotmg::rotmg(
+                &mut d1,
+                &mut d2,
+                &mut x1,
+                &mut y1,
+                &mut vector_mut(&mut param),
+            );
+            black_box((d1, d2, x1, param))
+        })
+    });
+    group.finish();
+}
+
+/// Benchmarks the routines on complex vectors.
+fn complex<T: Float>(c: &mut Criterion)
+where
+    Complex<T>: Element,
+{
+    let a = Complex::new(T::from(0.6).unwrap(), T::from(0.8).unwrap());
+    let b = Complex::new(-T::one(), T::zero());
+    let size = size_of::<Complex<T>>();
+
+    sweep(
+        c,
+        "dotc",
+        flops(8),
+        |d: &mut Data<Complex<T>>| {
+            black_box(dotc::dotc(
+                &d.n,
+                &mut vector(&d.x),
+                &d.inc,
+                &mut vector(&d.y),
+                &d.inc,
+            ));
+        },
+        |d| {
+            black_box(reference::dotc(&d.x, &d.y, d.inc as usize));
+        },
+    );
+
+    sweep(
+        c,
+        "dotu",
+        flops(8),
+        |d: &mut Data<Complex<T>>| {
+            black_box(dotu::dotu(
+                &d.n,
+                &mut vector(&d.x),
+                &d.inc,
+                &mut vector(&d.y),
+                &d.inc,
+            ));
+        },
+        |d| {
+            black_box(reference::dot(&d.x, &d.y, d.inc as usize));
+        },
+    );
+
+    sweep(
+        c,
+        "nrm2",
+        flops(4),
+        |d: &mut Data<Complex<T>>| {
+            black_box(nrm2::real_complex_nrm2(&d.n, vector(&d.x), &d.inc));
+        },
+        |d| {
+            black_box(reference::nrm2_complex(&d.x, d.inc as usize));
+        },
+    );
+
+    sweep(
+        c,
+        "axpy",
+        flops(8),
+        |d: &mut Data<Complex<T>>| {
+            axpy::axpy_complex(
+                &d.n,
+                &a,
+                &vector(&d.x),
+                &d.inc,
+                &mut vector_mut(&mut d.y),
+                &d.inc,
+            )
+        },
+        |d| reference::axpy(a, &d.x, &mut d.y, d.inc as usize),
+    );
+
+    sweep(
+        c,
+        "axpby",
+        flops(14),
+        |d: &mut Data<Complex<T>>| {
+            axpby::axpby_complex(
+                &d.n,
+                &a,
+                &vector(&d.x),
+                &d.inc,
+                &b,
+                &mut vector_mut(&mut d.y),
+                &d.inc,
+            )
+        },
+        |d| reference::axpby(a, &d.x, b, &mut d.y, d.inc as usize),
+    );
+
+    // |a| = 1 keeps x bounded however many times it is scaled
+    sweep(
+        c,
+        "scal",
+        flops(6),
+        |d: &mut Data<Complex<T>>| scal::scal_complex(d.n, a, vector_mut(&mut d.x), d.inc),
+        |d| reference::scal(a, &mut d.x, d.inc as usize, |x, a| x * a),
+    );
+
+    sweep(
+        c,
+        "scal_real",
+        flops(2),
+        |d: &mut Data<Complex<T>>| {
+            scal::scal_complex_real(d.n, -T::one(), vector_mut(&mut d.x), d.inc)
+        },
+        |d| reference::scal(-T::one(), &mut d.x, d.inc as usize, |x, a| x.scale(a)),
+    );
+
+    sweep(
+        c,
+        "copy",
+        bytes(2 * size),
+        |d: &mut Data<Complex<T>>| {
+            copy::copy(
+                &d.n,
+                &vector(&d.x),
+                &d.inc,
+                &mut vector_mut(&mut d.y),
+                &d.inc,
+            )
+        },
+        |d| reference::copy(&d.x, &mut d.y, d.inc as usize),
+    );
+
+    sweep(
+        c,
+        "swap",
+        bytes(4 * size),
+        |d: &mut Data<Complex<T>>| {
+            swap::swap(
+                &d.n,
+                &mut vector_mut(&mut d.x),
+                &d.inc,
+                &mut vector_mut(&mut d.y),
+                &d.inc,
+            )
+        },
+        |d| reference::swap(&mut d.x, &mut d.y, d.inc as usize),
+    );
+
+    sweep(
+        c,
+        "dotci",
+        flops(8),
+        |d: &mut Data<Complex<T>>| {
+            black_box(doti::dotci(
+                &d.n,
+                &vector(&d.x),
+                &vector(&d.indx),
+                &0,
+                &vector(&d.y),
+            ));
+        },
+        |d| {
+            black_box(reference::dotci(&d.x, &d.indx, &d.y));
+        },
+    );
+
+    sweep(
+        c,
+        "dotui",
+        flops(8),
+        |d: &mut Data<Complex<T>>| {
+            black_box(doti::dotui(
+                &d.n,
+                &vector(&d.x),
+                &vector(&d.indx),
+                &0,
+                &vector(&d.y),
+            ));
+        },
+        |d| {
+            black_box(reference::doti(&d.x, &d.indx, &d.y));
+        },
+    );
+
+    sweep(
+        c,
+        "axpyi",
+        flops(8),
+        |d: &mut Data<Complex<T>>| {
+            axpyi::axpyi_complex(
+                &d.n,
+                &a,
+                &vector(&d.x),
+                &vector(&d.indx),
+                &0,
+                &mut vector_mut(&mut d.y),
+            )
+        },
+        |d| reference::axpyi(a, &d.x, &d.indx, &mut d.y),
+    );
+
+    sparse_moves::<Complex<T>>(c);
+}
+
+/// Benchmarks the sparse routines that only move elements, which read or write an index and two
+/// elements per element, and gthrz a third.
+fn sparse_moves<T: Element + Zero>(c: &mut Criterion) {
+    let size = size_of::<T>();
+    let index = size_of::<isize>();
+
+    sweep(
+        c,
+        "gthr",
+        bytes(2 * size + index),
+        |d: &mut Data<T>| {
+            gthr::gthr(
+                &d.n,
+                &vector(&d.y),
+                &mut vector_mut(&mut d.x),
+                &vector(&d.indx),
+                &0,
+            )
+        },
+        |d| reference::gthr(&d.y, &mut d.x, &d.indx),
+    );
+
+    sweep(
+        c,
+        "gthrz",
+        bytes(3 * size + index),
+        |d: &mut Data<T>| {
+            gthr::gthrz(
+                &d.n,
+                &mut vector_mut(&mut d.y),
+                &mut vector_mut(&mut d.x),
+                &vector(&d.indx),
+                &0,
+            )
+        },
+        |d| reference::gthrz(&mut d.y, &mut d.x, &d.indx),
+    );
+
+    sweep(
+        c,
+        "sctr",
+        bytes(2 * size + index),
+        |d: &mut Data<T>| {
+            sctr::sctr(
+                &d.n,
+                &vector(&d.x),
+                &vector(&d.indx),
+                &0,
+                &mut vector_mut(&mut d.y),
+            )
+        },
+        |d| reference::sctr(&d.x, &d.indx, &mut d.y),
+    );
+}
+
+/// Benchmarks the routines that take f32 vectors and compute in f64.
+fn mixed(c: &mut Criterion) {
+    let a = 0.5f64;
+
+    sweep(
+        c,
+        "dsdot",
+        flops(2),
+        |d: &mut Data<f32>| {
+            black_box(dsdot::dsdot(
+                &d.n,
+                &vector(&d.x),
+                &d.inc,
+                &vector(&d.y),
+                &d.inc,
+            ));
+        },
+        |d| {
+            black_box(reference::dot_mixed::<f32, f64>(&d.x, &d.y, d.inc as usize));
+        },
+    );
+
+    sweep(
+        c,
+        "sdsdot",
+        flops(2),
+        |d: &mut Data<f32>| {
+            black_box(dot::sdsdot(
+                &d.n,
+                &0.5,
+                &vector(&d.x),
+                &d.inc,
+                &vector(&d.y),
+                &d.inc,
+            ));
+        },
+        |d| {
+            let dot = reference::dot_mixed::<f32, f64>(&d.x, &d.y, d.inc as usize);
+            black_box((0.5 + dot) as f32);
+        },
+    );
+
+    sweep(
+        c,
+        "nrm2_mixed",
+        flops(2),
+        |d: &mut Data<f32>| {
+            black_box(nrm2::nrm2_mixed::<f32, f64>(&d.n, &vector(&d.x), &d.inc));
+        },
+        |d| {
+            black_box(reference::nrm2_mixed::<f32, f64>(&d.x, d.inc as usize));
+        },
+    );
+
+    sweep(
+        c,
+        "axpy_mixed",
+        flops(2),
+        |d: &mut Data<f32>| {
+            axpy::axpy_mixed(
+                &d.n,
+                &a,
+                &vector(&d.x),
+                &d.inc,
+                &mut vector_mut(&mut d.y),
+                &d.inc,
+            )
+        },
+        |d| reference::axpy_mixed(a, &d.x, &mut d.y, d.inc as usize),
+    );
+
+    sweep(
+        c,
+        "scal_mixed",
+        flops(1),
+        |d: &mut Data<f32>| scal::scal_mixed(&d.n, &-1.0f64, &mut vector_mut(&mut d.x), &d.inc),
+        |d| {
+            reference::scal(-1.0f64, &mut d.x, d.inc as usize, |x, a| {
+                (f64::from(x) * a) as f32
+            })
+        },
+    );
+}
+
+fn all(c: &mut Criterion) {
+    real::<f32>(c);
+    real::<f64>(c);
+    complex::<f32>(c);
+    complex::<f64>(c);
+    mixed(c);
+}
+
+criterion_group! {
+    name = benches;
+    // hundreds of groups: fewer and shorter samples than criterion's defaults, which can still
+    // be raised from the command line
+    config = Criterion::default()
+        .sample_size(20)
+        .warm_up_time(Duration::from_millis(500))
+        .measurement_time(Duration::from_secs(2));
+    targets = all
+}
+criterion_main!(benches);
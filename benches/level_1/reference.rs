@@ -0,0 +1,160 @@
+//! Plain loops computing what the `level_1` routines compute, for comparison.
+//!
+//! Every loop steps through the vectors with the same increment and handles every case the same
+//! way: no special cases for unit increments, no unrolling, SIMD or threads, and no scaling in
+//! the norms.
+
+use num_complex::Complex;
+use num_traits::{Float, Num, Zero};
+
+pub fn dot<T: Num + Copy>(x: &[T], y: &[T], inc: usize) -> T {
+    let mut temp = T::zero();
+    for i in (0..x.len()).step_by(inc) {
+        temp = temp + x[i] * y[i];
+    }
+    temp
+}
+
+pub fn dotc<T: Float>(x: &[Complex<T>], y: &[Complex<T>], inc: usize) -> Complex<T> {
+    let mut temp = Complex::new(T::zero(), T::zero());
+    for i in (0..x.len()).step_by(inc) {
+        temp = temp + x[i].conj() * y[i];
+    }
+    temp
+}
+
+pub fn dot_mixed<T: Float, U: Float>(x: &[T], y: &[T], inc: usize) -> U {
+    let mut temp = U::zero();
+    for i in (0..x.len()).step_by(inc) {
+        temp = temp + U::from(x[i]).unwrap() * U::from(y[i]).unwrap();
+    }
+    temp
+}
+
+pub fn nrm2<T: Float>(x: &[T], inc: usize) -> T {
+    let mut ssq = T::zero();
+    for i in (0..x.len()).step_by(inc) {
+        ssq = ssq + x[i] * x[i];
+    }
+    ssq.sqrt()
+}
+
+pub fn nrm2_complex<T: Float>(x: &[Complex<T>], inc: usize) -> T {
+    let mut ssq = T::zero();
+    for i in (0..x.len()).step_by(inc) {
+        ssq = ssq + x[i].norm_sqr();
+    }
+    ssq.sqrt()
+}
+
+pub fn nrm2_mixed<T: Float, U: Float>(x: &[T], inc: usize) -> U {
+    let mut ssq = U::zero();
+    for i in (0..x.len()).step_by(inc) {
+        let xi = U::from(x[i]).unwrap();
+        ssq = ssq + xi * xi;
+    }
+    ssq.sqrt()
+}
+
+pub fn axpy<T: Num + Copy>(a: T, x: &[T], y: &mut [T], inc: usize) {
+    for i in (0..x.len()).step_by(inc) {
+        y[i] = y[i] + a * x[i];
+    }
+}
+
+pub fn axpy_mixed<T: Float, U: Float>(a: U, x: &[T], y: &mut [T], inc: usize) {
+    for i in (0..x.len()).step_by(inc) {
+        let yi = U::from(y[i]).unwrap() + a * U::from(x[i]).unwrap();
+        y[i] = T::from(yi).unwrap();
+    }
+}
+
+pub fn axpby<T: Num + Copy>(a: T, x: &[T], b: T, y: &mut [T], inc: usize) {
+    for i in (0..x.len()).step_by(inc) {
+        y[i] = a * x[i] + b * y[i];
+    }
+}
+
+pub fn scal<T: Num + Copy, A: Copy>(a: A, x: &mut [T], inc: usize, mul: impl Fn(T, A) -> T) {
+    for i in (0..x.len()).step_by(inc) {
+        x[i] = mul(x[i], a);
+    }
+}
+
+pub fn copy<T: Copy>(x: &[T], y: &mut [T], inc: usize) {
+    for i in (0..x.len()).step_by(inc) {
+        y[i] = x[i];
+    }
+}
+
+pub fn swap<T>(x: &mut [T], y: &mut [T], inc: usize) {
+    for i in (0..x.len()).step_by(inc) {
+        std::mem::swap(&mut x[i], &mut y[i]);
+    }
+}
+
+pub fn rot<T: Float>(x: &mut [T], y: &mut [T], inc: usize, c: T, s: T) {
+    for i in (0..x.len()).step_by(inc) {
+        let temp = c * x[i] + s * y[i];
+        y[i] = c * y[i] - s * x[i];
+        x[i] = temp;
+    }
+}
+
+/// Applies the full matrix H = [h11 h12; h21 h22] whatever the flag of the routine is.
+pub fn rotm<T: Float>(x: &mut [T], y: &mut [T], inc: usize, [h11, h21, h12, h22]: [T; 4]) {
+    for i in (0..x.len()).step_by(inc) {
+        let (w, z) = (x[i], y[i]);
+        x[i] = w * h11 + z * h12;
+        y[i] = w * h21 + z * h22;
+    }
+}
+
+pub fn doti<T: Num + Copy>(x: &[T], indx: &[isize], y: &[T]) -> T {
+    let mut temp = T::zero();
+    for (i, &j) in indx.iter().enumerate() {
+        temp = temp + x[i] * y[j as usize];
+    }
+    temp
+}
+
+pub fn dotci<T: Float>(x: &[Complex<T>], indx: &[isize], y: &[Complex<T>]) -> Complex<T> {
+    let mut temp = Complex::new(T::zero(), T::zero());
+    for (i, &j) in indx.iter().enumerate() {
+        temp = temp + x[i].conj() * y[j as usize];
+    }
+    temp
+}
+
+pub fn axpyi<T: Num + Copy>(a: T, x: &[T], indx: &[isize], y: &mut [T]) {
+    for (i, &j) in indx.iter().enumerate() {
+        y[j as usize] = y[j as usize] + a * x[i];
+    }
+}
+
+pub fn roti<T: Float>(x: &mut [T], indx: &[isize], y: &mut [T], c: T, s: T) {
+    for (i, &j) in indx.iter().enumerate() {
+        let temp = c * x[i] + s * y[j as usize];
+        y[j as usize] = c * y[j as usize] - s * x[i];
+        x[i] = temp;
+    }
+}
+
+pub fn gthr<T: Copy>(y: &[T], x: &mut [T], indx: &[isize]) {
+    for (i, &j) in indx.iter().enumerate() {
+        x[i] = y[j as usize];
+    }
+}
+
+pub fn gthrz<T: Copy + Zero>(y: &mut [T], x: &mut [T], indx: &[isize]) {
+    for (i, &j) in indx.iter().enumerate() {
+        x[i] = y[j as usize];
+        y[j as usize] = T::zero();
+    }
+}
+
+pub fn sctr<T: Copy>(x: &[T], indx: &[isize], y: &mut [T]) {
+    for (i, &j) in indx.iter().enumerate() {
+        y[j as usize] = x[i];
+    }
+}
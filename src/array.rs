@@ -0,0 +1,181 @@
+//! Routines for `ndarray` arrays, enabled by the `ndarray` feature.
+//!
+//! Views are used in place, with their ndarray strides, negative ones included, as the
+//! increments and leading dimensions. A view holds a slice over the memory from its lowest to its
+//! highest element, which may cover elements of other views, but only accesses its own. Only
+//! `gemm` copies, and only the matrices `level_3::gemm` cannot take: those with no unit stride,
+//! such as every other row and column of an array, or with a negative one.
+//!
+//! ```
+//! use ndarray::{array, s};
+//! use naive_blas::array;
+//!
+//! let x = array![1.0, 2.0, 3.0, 4.0];
+//! let mut y = array![1.0, 1.0];
+//!
+//! // y = 2 * [4, 2] + y
+//! array::axpy(2.0, &x.slice(s![..;-2]), &mut y);
+//! assert_eq!(y, array![9.0, 5.0]);
+//! ```
+
+use ndarray::{
+    ArrayBase, ArrayView1, ArrayView2, ArrayViewMut1, ArrayViewMut2, Data, DataMut, Ix1, Ix2,
+};
+use num_traits::Float;
+
+use crate::mat::{self, MatMut, MatRef};
+use crate::strided::{StridedVec, StridedVecMut};
+
+// SAFETY, for all of the conversions below: the elements of a view lie inside one allocation
+// and ndarray keeps them valid for 'a, and accessible only through the view for a mutable one.
+// The elements of a mutable view are distinct, so its strides are accepted.
+
+impl<'a, T> From<ArrayView1<'a, T>> for StridedVec<'a, T> {
+    fn from(x: ArrayView1<'a, T>) -> Self {
+        unsafe { StridedVec::from_raw_parts(x.as_ptr(), x.len(), x.strides()[0]) }
+    }
+}
+
+impl<'a, T> From<ArrayViewMut1<'a, T>> for StridedVecMut<'a, T> {
+    fn from(mut x: ArrayViewMut1<'a, T>) -> Self {
+        unsafe { StridedVecMut::from_raw_parts(x.as_mut_ptr(), x.len(), x.strides()[0]) }
+            .unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl<'a, T> From<ArrayView2<'a, T>> for MatRef<'a, T> {
+    fn from(a: ArrayView2<'a, T>) -> Self {
+        let ((rows, cols), (rs, cs)) = (a.dim(), (a.strides()[0], a.strides()[1]));
+        unsafe { MatRef::from_raw_parts(a.as_ptr(), rows, cols, rs, cs) }
+    }
+}
+
+impl<'a, T> From<ArrayViewMut2<'a, T>> for MatMut<'a, T> {
+    fn from(mut a: ArrayViewMut2<'a, T>) -> Self {
+        let ((rows, cols), (rs, cs)) = (a.dim(), (a.strides()[0], a.strides()[1]));
+        unsafe { MatMut::from_raw_parts(a.as_mut_ptr(), rows, cols, rs, cs) }
+            .unwrap_or_else(|_| unreachable!())
+    }
+}
+
+/// Computes the dot product of x and y.
+///
+/// # Panics
+///
+/// Panics if x and y have different lengths.
+pub fn dot<T, S1, S2>(x: &ArrayBase<S1, Ix1>, y: &ArrayBase<S2, Ix1>) -> T
+where
+    T: Float + 'static,
+    S1: Data<Elem = T>,
+    S2: Data<Elem = T>,
+{
+    StridedVec::from(x.view()).dot(&y.view().into())
+}
+
+/// Computes the Euclidean norm of x.
+pub fn nrm2<T: Float + 'static, S: Data<Elem = T>>(x: &ArrayBase<S, Ix1>) -> T {
+    StridedVec::from(x.view()).nrm2()
+}
+
+/// Computes y = a * x + y.
+///
+/// # Panics
+///
+/// Panics if x and y have different lengths.
+pub fn axpy<T, S1, S2>(a: T, x: &ArrayBase<S1, Ix1>, y: &mut ArrayBase<S2, Ix1>)
+where
+    T: Float + 'static,
+    S1: Data<Elem = T>,
+    S2: DataMut<Elem = T>,
+{
+    StridedVecMut::from(y.view_mut()).axpy(a, &x.view().into());
+}
+
+/// Computes y = a * x + b * y.
+///
+/// # Panics
+///
+/// Panics if x and y have different lengths.
+pub fn axpby<T, S1, S2>(a: T, x: &ArrayBase<S1, Ix1>, b: T, y: &mut ArrayBase<S2, Ix1>)
+where
+    T: Float + 'static,
+    S1: Data<Elem = T>,
+    S2: DataMut<Elem = T>,
+{
+    StridedVecMut::from(y.view_mut()).axpby(a, &x.view().into(), b);
+}
+
+/// Computes x = a * x.
+pub fn scal<T: Float + 'static, S: DataMut<Elem = T>>(a: T, x: &mut ArrayBase<S, Ix1>) {
+    StridedVecMut::from(x.view_mut()).scal(a);
+}
+
+/// Copies x into y.
+///
+/// # Panics
+///
+/// Panics if x and y have different lengths.
+pub fn copy<T, S1, S2>(x: &ArrayBase<S1, Ix1>, y: &mut ArrayBase<S2, Ix1>)
+where
+    T: Float + 'static,
+    S1: Data<Elem = T>,
+    S2: DataMut<Elem = T>,
+{
+    StridedVecMut::from(y.view_mut()).copy_from(&x.view().into());
+}
+
+/// Swaps the elements of x and y.
+///
+/// # Panics
+///
+/// Panics if x and y have different lengths.
+pub fn swap<T, S1, S2>(x: &mut ArrayBase<S1, Ix1>, y: &mut ArrayBase<S2, Ix1>)
+where
+    T: Float + 'static,
+    S1: DataMut<Elem = T>,
+    S2: DataMut<Elem = T>,
+{
+    StridedVecMut::from(x.view_mut()).swap(&mut y.view_mut().into());
+}
+
+/// Applies the plane rotation with cosine c and sine s to the points (x[i], y[i]).
+///
+/// # Panics
+///
+/// Panics if x and y have different lengths.
+pub fn rot<T, S1, S2>(x: &mut ArrayBase<S1, Ix1>, y: &mut ArrayBase<S2, Ix1>, c: T, s: T)
+where
+    T: Float + 'static,
+    S1: DataMut<Elem = T>,
+    S2: DataMut<Elem = T>,
+{
+    StridedVecMut::from(x.view_mut()).rot(&mut y.view_mut().into(), c, s);
+}
+
+/// Computes C = alpha * A * B + beta * C.
+///
+/// Pass `a.t()` or `b.t()` to multiply by a transpose, see `mat::gemm`.
+///
+/// # Panics
+///
+/// Panics if A is not m by k, B is not k by n or C is not m by n.
+pub fn gemm<T, S1, S2, S3>(
+    alpha: T,
+    a: &ArrayBase<S1, Ix2>,
+    b: &ArrayBase<S2, Ix2>,
+    beta: T,
+    c: &mut ArrayBase<S3, Ix2>,
+) where
+    T: Float + 'static,
+    S1: Data<Elem = T>,
+    S2: Data<Elem = T>,
+    S3: DataMut<Elem = T>,
+{
+    mat::gemm(
+        alpha,
+        a.view().into(),
+        b.view().into(),
+        beta,
+        c.view_mut().into(),
+    );
+}
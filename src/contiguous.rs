@@ -0,0 +1,67 @@
+//! Helpers for handing the unit-stride case of a 1-dimensional `NdSlice` to code that works on
+//! plain slices of a concrete element type.
+
+use core::any::TypeId;
+use core::ops::{Index, IndexMut};
+
+use nd_slice::{NdSlice, NdSliceMut};
+
+/// Views the first n elements of a 1-dimensional `NdSlice` or `NdSliceMut` as a slice.
+pub(crate) fn as_slice<T, S: Index<[usize; 1], Output = T> + ?Sized>(x: &S, n: usize) -> &[T] {
+    if n == 0 {
+        return &[];
+    }
+    // bounds check the last element the same way the scalar loop would
+    let _ = &x[[n - 1]];
+
+    // SAFETY: a 1-dimensional NdSlice addresses its elements directly, i.e. x[[i]] is the i-th
+    // element of the borrowed slice, so elements 0..n are contiguous and in bounds.
+    unsafe { core::slice::from_raw_parts(&x[[0]] as *const T, n) }
+}
+
+/// Views the first n elements of a 1-dimensional `NdSliceMut` as a mutable slice.
+pub(crate) fn as_mut_slice<T, S: IndexMut<[usize; 1], Output = T> + ?Sized>(
+    x: &mut S,
+    n: usize,
+) -> &mut [T] {
+    if n == 0 {
+        return &mut [];
+    }
+    let _ = &mut x[[n - 1]];
+
+    // SAFETY: see as_slice
+    unsafe { core::slice::from_raw_parts_mut(&mut x[[0]] as *mut T, n) }
+}
+
+pub(crate) fn cast<T: 'static, U: 'static>(x: &[T]) -> Option<&[U]> {
+    if TypeId::of::<T>() == TypeId::of::<U>() {
+        // SAFETY: T and U are the same type
+        Some(unsafe { &*(x as *const [T] as *const [U]) })
+    } else {
+        None
+    }
+}
+
+pub(crate) fn cast_mut<T: 'static, U: 'static>(x: &mut [T]) -> Option<&mut [U]> {
+    if TypeId::of::<T>() == TypeId::of::<U>() {
+        // SAFETY: T and U are the same type
+        Some(unsafe { &mut *(x as *mut [T] as *mut [U]) })
+    } else {
+        None
+    }
+}
+
+pub(crate) fn cast_scalar<T: Copy + 'static, U: Copy + 'static>(a: T) -> Option<U> {
+    cast::<T, U>(core::slice::from_ref(&a)).map(|a| a[0])
+}
+
+/// Wraps a slice in a 1-dimensional `NdSlice`.
+pub(crate) fn vector<T>(x: &[T]) -> NdSlice<'_, T, 1> {
+    NdSlice::new_row_ordered(x, [x.len()]).unwrap_or_else(|_| unreachable!())
+}
+
+/// Wraps a slice in a 1-dimensional `NdSliceMut`.
+pub(crate) fn vector_mut<T>(x: &mut [T]) -> NdSliceMut<'_, T, 1> {
+    let n = x.len();
+    NdSliceMut::new_row_ordered(x, [n]).unwrap_or_else(|_| unreachable!())
+}
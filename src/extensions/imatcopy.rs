@@ -0,0 +1,114 @@
+use nd_slice::NdSliceMut;
+use num_traits::Float;
+
+use crate::contiguous::{as_mut_slice, vector, vector_mut};
+use crate::extensions::omatcopy::omatcopy;
+
+/// Computes a scaled copy or transpose of a matrix in place.
+///
+/// AB = alpha * op(AB)
+///
+/// The matrix is read with leading dimension lda and written back with leading dimension ldb.
+///
+/// Input:
+///     ordering: 'C' if the matrix is stored in column-major order, 'R' for row-major
+///     trans: 'N' or 'R' for op(A) = A, 'T' or 'C' for op(A) = Aᵀ
+///     rows: number of rows of A
+///     cols: number of columns of A
+///     alpha: scalar
+///     ab: matrix
+///     lda: leading dimension of ab before the call
+///     ldb: leading dimension of ab after the call
+/// Output:
+///     ab: alpha * op(A)
+///
+/// Note: 'R' and 'C' conjugate for complex matrices and are the same as 'N' and 'T' here.
+/// Transposing a matrix that is not square, or with lda ≠ ldb, goes through a temporary copy,
+/// which is why imatcopy needs the alloc feature.
+#[allow(clippy::too_many_arguments)]
+pub fn imatcopy<T: Float>(
+    ordering: &char,
+    trans: &char,
+    rows: &isize,
+    cols: &isize,
+    alpha: &T,
+    ab: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    ldb: &isize,
+) {
+    let (nrows, ncols, alpha, nlda, nldb) = (*rows, *cols, *alpha, *lda, *ldb);
+
+    let notrans = "NnRr".contains(*trans);
+    if !(notrans || "TtCc".contains(*trans)) {
+        return;
+    }
+
+    // a row-major rows by cols matrix is a column-major cols by rows matrix
+    let (nrows, ncols) = match ordering {
+        'C' | 'c' => (nrows, ncols),
+        'R' | 'r' => (ncols, nrows),
+        _ => return,
+    };
+    let (brows, bcols) = if notrans {
+        (nrows, ncols)
+    } else {
+        (ncols, nrows)
+    };
+
+    if nrows < 0 || ncols < 0 || nlda < nrows.max(1) || nldb < brows.max(1) {
+        return;
+    }
+    if nrows == 0 || ncols == 0 {
+        return;
+    }
+    let (nrows, ncols, nlda, nldb) = (nrows as usize, ncols as usize, nlda as usize, nldb as usize);
+    let (brows, bcols) = (brows as usize, bcols as usize);
+
+    if notrans {
+        // moving columns towards the start of the buffer has to go front to back, and the other
+        // way round when they move towards the end
+        let scale = |ab: &mut NdSliceMut<'_, T, 1>, i: usize, j: usize| {
+            ab[[i + j * nldb]] = alpha * ab[[i + j * nlda]];
+        };
+
+        if nldb <= nlda {
+            for j in 0..ncols {
+                for i in 0..nrows {
+                    scale(ab, i, j);
+                }
+            }
+        } else {
+            for j in (0..ncols).rev() {
+                for i in (0..nrows).rev() {
+                    scale(ab, i, j);
+                }
+            }
+        }
+    } else if nrows == ncols && nlda == nldb {
+        for j in 0..ncols {
+            ab[[j + j * nlda]] = alpha * ab[[j + j * nlda]];
+
+            for i in j + 1..nrows {
+                let temp = ab[[i + j * nlda]];
+                ab[[i + j * nlda]] = alpha * ab[[j + i * nlda]];
+                ab[[j + i * nlda]] = alpha * temp;
+            }
+        }
+    } else {
+        let len = (nlda * (ncols - 1) + nrows).max(nldb * (bcols - 1) + brows);
+        let ab = as_mut_slice(ab, len);
+        let a = ab[..nlda * (ncols - 1) + nrows].to_vec();
+
+        omatcopy(
+            &'C',
+            trans,
+            &(nrows as isize),
+            &(ncols as isize),
+            &alpha,
+            &vector(&a),
+            lda,
+            &mut vector_mut(ab),
+            ldb,
+        );
+    }
+}
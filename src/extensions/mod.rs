@@ -0,0 +1,3 @@
+#[cfg(feature = "alloc")]
+pub mod imatcopy;
+pub mod omatcopy;
@@ -0,0 +1,62 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_traits::Float;
+
+/// Computes a scaled copy or transpose of a matrix into another matrix.
+///
+/// B = alpha * op(A)
+///
+/// Input:
+///     ordering: 'C' if the matrices are stored in column-major order, 'R' for row-major
+///     trans: 'N' or 'R' for op(A) = A, 'T' or 'C' for op(A) = Aᵀ
+///     rows: number of rows of A
+///     cols: number of columns of A
+///     alpha: scalar
+///     a: matrix
+///     lda: leading dimension of a
+///     b: matrix, rows by cols if trans = 'N' and cols by rows otherwise
+///     ldb: leading dimension of b
+/// Output:
+///     b: alpha * op(A)
+///
+/// Note: 'R' and 'C' conjugate for complex matrices and are the same as 'N' and 'T' here.
+#[allow(clippy::too_many_arguments)]
+pub fn omatcopy<T: Float>(
+    ordering: &char,
+    trans: &char,
+    rows: &isize,
+    cols: &isize,
+    alpha: &T,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) {
+    let (rows, cols, alpha, lda, ldb) = (*rows, *cols, *alpha, *lda, *ldb);
+
+    let notrans = "NnRr".contains(*trans);
+    if !(notrans || "TtCc".contains(*trans)) {
+        return;
+    }
+
+    // a row-major rows by cols matrix is a column-major cols by rows matrix
+    let (rows, cols) = match ordering {
+        'C' | 'c' => (rows, cols),
+        'R' | 'r' => (cols, rows),
+        _ => return,
+    };
+
+    if rows < 0 || cols < 0 || lda < rows.max(1) {
+        return;
+    }
+    if ldb < if notrans { rows } else { cols }.max(1) {
+        return;
+    }
+    let (rows, cols, lda, ldb) = (rows as usize, cols as usize, lda as usize, ldb as usize);
+
+    for j in 0..cols {
+        for i in 0..rows {
+            let ib = if notrans { i + j * ldb } else { j + i * ldb };
+            b[[ib]] = alpha * a[[i + j * lda]];
+        }
+    }
+}
@@ -0,0 +1,606 @@
+use alloc::vec;
+
+use nd_slice::NdSliceMut;
+use num_traits::{Float, NumCast, One, Zero};
+
+use crate::contiguous::as_mut_slice;
+use crate::level_1::rotg::rotg;
+
+use super::lasr::rotate;
+use super::{span, LapackError, Scalar};
+
+/// Maximum number of QR sweeps per singular value, on average.
+const MAXITR: usize = 6;
+
+/// Computes the singular value decomposition of a real bidiagonal matrix, using the implicit
+/// QR method with zero shifts where a shift would cost relative accuracy.
+///
+/// B = Q * diag(d) * Pᵀ
+///
+/// The singular values are found to high relative accuracy, chasing the bulge from the larger
+/// end of each unreduced block to the smaller one. The rotations come from `rotg` and are applied
+/// with `lasr`, giving U * Q, Pᵀ * VT and Qᵀ * C; the singular vectors of a matrix reduced by
+/// `gebrd` are found by starting with U = Q and VT = Pᴴ of the reduction.
+///
+/// Input:
+///     uplo: 'U' if B is upper bidiagonal, 'L' if it is lower bidiagonal
+///     n: order of B
+///     ncvt: number of columns of VT, may be zero
+///     nru: number of rows of U, may be zero
+///     ncc: number of columns of C, may be zero
+///     d: the diagonal of B, vector of n elements
+///     e: the off-diagonal of B, vector of n - 1 elements
+///     vt: matrix in column-major order, ldvt by ncvt, not referenced if ncvt = 0
+///     ldvt: leading dimension of vt, at least 1, and at least n if ncvt > 0
+///     u: matrix in column-major order, ldu by n, not referenced if nru = 0
+///     ldu: leading dimension of u, at least max(1, nru)
+///     c: matrix in column-major order, ldc by ncc, not referenced if ncc = 0
+///     ldc: leading dimension of c, at least 1, and at least n if ncc > 0
+/// Output:
+///     d: the singular values in descending order
+///     e: destroyed
+///     vt: Pᵀ * VT
+///     u: U * Q
+///     c: Qᵀ * C
+///     returns `LapackError::NotConverged(i)` if the iteration failed after 6 * n² sweeps of
+///     single elements, i elements of e being nonzero; d and e then hold a bidiagonal matrix
+///     orthogonally equivalent to B
+#[allow(clippy::too_many_arguments)]
+pub fn bdsqr<T: Scalar>(
+    uplo: &char,
+    n: &isize,
+    ncvt: &isize,
+    nru: &isize,
+    ncc: &isize,
+    d: &mut NdSliceMut<'_, T::Real, 1>,
+    e: &mut NdSliceMut<'_, T::Real, 1>,
+    vt: &mut NdSliceMut<'_, T, 1>,
+    ldvt: &isize,
+    u: &mut NdSliceMut<'_, T, 1>,
+    ldu: &isize,
+    c: &mut NdSliceMut<'_, T, 1>,
+    ldc: &isize,
+) -> Result<(), LapackError> {
+    let (n, ncvt, nru, ncc, ldvt, ldu, ldc) = (*n, *ncvt, *nru, *ncc, *ldvt, *ldu, *ldc);
+
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+
+    if !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if ncvt < 0 {
+        return Err(LapackError::Argument(3));
+    }
+    if nru < 0 {
+        return Err(LapackError::Argument(4));
+    }
+    if ncc < 0 {
+        return Err(LapackError::Argument(5));
+    }
+    if ldvt < 1 || (ncvt > 0 && ldvt < n) {
+        return Err(LapackError::Argument(9));
+    }
+    if ldu < nru.max(1) {
+        return Err(LapackError::Argument(11));
+    }
+    if ldc < 1 || (ncc > 0 && ldc < n) {
+        return Err(LapackError::Argument(13));
+    }
+    let (n, ncvt, nru, ncc) = (n as usize, ncvt as usize, nru as usize, ncc as usize);
+    let (ldvt, ldu, ldc) = (ldvt as usize, ldu as usize, ldc as usize);
+
+    if n == 0 {
+        return Ok(());
+    }
+
+    let d = as_mut_slice(d, n);
+    let e = as_mut_slice(e, n - 1);
+    let mut vectors = Vectors {
+        ncvt,
+        vt: as_mut_slice(vt, span(n, ncvt, ldvt)),
+        ldvt,
+        nru,
+        u: as_mut_slice(u, span(nru, n, ldu)),
+        ldu,
+        ncc,
+        c: as_mut_slice(c, span(n, ncc, ldc)),
+        ldc,
+    };
+
+    match diagonalize(!upper, n, d, e, &mut vectors) {
+        Some(i) => Err(LapackError::NotConverged(i)),
+        None => Ok(()),
+    }
+}
+
+/// The matrices the rotations of `bdsqr` are applied to: n by ncvt VT and n by ncc C from the
+/// left, nru by n U from the right.
+pub(super) struct Vectors<'a, T> {
+    pub(super) ncvt: usize,
+    pub(super) vt: &'a mut [T],
+    pub(super) ldvt: usize,
+    pub(super) nru: usize,
+    pub(super) u: &'a mut [T],
+    pub(super) ldu: usize,
+    pub(super) ncc: usize,
+    pub(super) c: &'a mut [T],
+    pub(super) ldc: usize,
+}
+
+impl<T: Scalar> Vectors<'_, T> {
+    /// Applies the rotations in the planes (k, k + 1) for k from ll to the len rows of VT from
+    /// row ll.
+    fn rotate_vt(&mut self, forward: bool, ll: usize, len: usize, c: &[T::Real], s: &[T::Real]) {
+        if self.ncvt > 0 {
+            let vt = &mut self.vt[ll..];
+            rotate(true, 'V', forward, len, self.ncvt, c, s, vt, self.ldvt);
+        }
+    }
+
+    /// Applies the rotations in the planes (k, k + 1) for k from ll to the len columns of U
+    /// and rows of C from ll.
+    fn rotate_uc(&mut self, forward: bool, ll: usize, len: usize, c: &[T::Real], s: &[T::Real]) {
+        if self.nru > 0 {
+            let u = &mut self.u[ll * self.ldu..];
+            rotate(false, 'V', forward, self.nru, len, c, s, u, self.ldu);
+        }
+        if self.ncc > 0 {
+            let cc = &mut self.c[ll..];
+            rotate(true, 'V', forward, len, self.ncc, c, s, cc, self.ldc);
+        }
+    }
+
+    /// Changes the sign of the singular vector i in VT.
+    fn negate(&mut self, i: usize) {
+        if self.ncvt > 0 {
+            T::scal(self.ncvt, -T::one(), &mut self.vt[i..], self.ldvt);
+        }
+    }
+
+    /// Swaps the singular vectors i and j.
+    fn swap(&mut self, i: usize, j: usize) {
+        for k in 0..self.ncvt {
+            self.vt.swap(i + k * self.ldvt, j + k * self.ldvt);
+        }
+        for k in 0..self.nru {
+            self.u.swap(k + i * self.ldu, k + j * self.ldu);
+        }
+        for k in 0..self.ncc {
+            self.c.swap(i + k * self.ldc, j + k * self.ldc);
+        }
+    }
+}
+
+/// Diagonalizes the bidiagonal matrix with diagonal d and off-diagonal e as `bdsqr` does,
+/// lower being true if it is lower bidiagonal. Returns the number of elements of e still
+/// nonzero if the iteration failed.
+pub(super) fn diagonalize<T: Scalar>(
+    lower: bool,
+    n: usize,
+    d: &mut [T::Real],
+    e: &mut [T::Real],
+    vectors: &mut Vectors<'_, T>,
+) -> Option<usize> {
+    let real = |x: usize| <T::Real as NumCast>::from(x).unwrap();
+    let (zero, one) = (T::Real::zero(), T::Real::one());
+
+    let eps = T::Real::epsilon() / real(2);
+    let unfl = T::Real::min_positive_value();
+
+    // the rotation (c, s) with [c s; -s c] * [f; g] = [r; 0], returning r
+    let rotation = |f: T::Real, g: T::Real, c: &mut T::Real, s: &mut T::Real| {
+        let (mut r, mut z) = (f, g);
+        rotg(&mut r, &mut z, c, s);
+        r
+    };
+
+    // the cosines and sines of the rotations of a sweep, from the right and from the left
+    let mut cr = vec![zero; n - 1];
+    let (mut sr, mut cl, mut sl) = (cr.clone(), cr.clone(), cr.clone());
+
+    // a lower bidiagonal matrix is made upper bidiagonal with rotations from the left
+    if lower {
+        for i in 0..n - 1 {
+            let (mut cs, mut sn) = (zero, zero);
+            d[i] = rotation(d[i], e[i], &mut cs, &mut sn);
+            e[i] = sn * d[i + 1];
+            d[i + 1] = cs * d[i + 1];
+            cl[i] = cs;
+            sl[i] = sn;
+        }
+        vectors.rotate_uc(true, 0, n, &cl, &sl);
+    }
+
+    // the tolerance for the relative accuracy of the singular values, and the threshold below
+    // which the off-diagonal elements are negligible
+    let tolmul = real(10).max(real(100).min(eps.powf(-one / real(8))));
+    let tol = tolmul * eps;
+
+    let mut sminoa = d[0].abs();
+    if sminoa != zero {
+        let mut mu = sminoa;
+        for i in 1..n {
+            mu = d[i].abs() * (mu / (mu + e[i - 1].abs()));
+            sminoa = sminoa.min(mu);
+            if sminoa == zero {
+                break;
+            }
+        }
+    }
+    sminoa = sminoa / real(n).sqrt();
+    let thresh = (tol * sminoa).max(real(MAXITR) * (real(n) * (real(n) * unfl)));
+
+    let maxit = MAXITR * n * n;
+    let mut iter = 0;
+    let mut oldblock = None;
+    let mut down = true;
+
+    // d[m] is the last element of the part not converged yet
+    let mut m = n - 1;
+    'sweep: while m > 0 {
+        if iter > maxit {
+            return Some(e.iter().filter(|&&ei| ei != zero).count());
+        }
+
+        // the block ll..=m ends where the off-diagonal element is negligible
+        let mut smax = d[m].abs();
+        let mut ll = 0;
+        for k in (0..m).rev() {
+            let (abss, abse) = (d[k].abs(), e[k].abs());
+            if abse <= thresh {
+                e[k] = zero;
+                if k == m - 1 {
+                    // d[m] has converged
+                    m -= 1;
+                    continue 'sweep;
+                }
+                ll = k + 1;
+                break;
+            }
+            smax = smax.max(abss).max(abse);
+        }
+
+        if ll == m - 1 {
+            // the 2 by 2 block is diagonalized directly
+            let (sigmn, sigmx, sinr, cosr, sinl, cosl) = lasv2(d[m - 1], e[m - 1], d[m]);
+            d[m - 1] = sigmx;
+            e[m - 1] = zero;
+            d[m] = sigmn;
+            vectors.rotate_vt(true, m - 1, 2, &[cosr], &[sinr]);
+            vectors.rotate_uc(true, m - 1, 2, &[cosl], &[sinl]);
+            m = m.saturating_sub(2);
+            continue;
+        }
+
+        // a new block is chased from its larger end to its smaller one
+        let new_block = match oldblock {
+            Some((oldll, oldm)) => ll > oldm || m < oldll,
+            None => true,
+        };
+        if new_block {
+            down = d[ll].abs() >= d[m].abs();
+        }
+
+        // convergence tests, first at the far end and then along the block, estimating the
+        // smallest singular value smin
+        let mut smin;
+        if down {
+            if e[m - 1].abs() <= tol * d[m].abs() {
+                e[m - 1] = zero;
+                continue;
+            }
+            let mut mu = d[ll].abs();
+            smin = mu;
+            for k in ll..m {
+                if e[k].abs() <= tol * mu {
+                    e[k] = zero;
+                    continue 'sweep;
+                }
+                mu = d[k + 1].abs() * (mu / (mu + e[k].abs()));
+                smin = smin.min(mu);
+            }
+        } else {
+            if e[ll].abs() <= tol * d[ll].abs() {
+                e[ll] = zero;
+                continue;
+            }
+            let mut mu = d[m].abs();
+            smin = mu;
+            for k in (ll..m).rev() {
+                if e[k].abs() <= tol * mu {
+                    e[k] = zero;
+                    continue 'sweep;
+                }
+                mu = d[k].abs() * (mu / (mu + e[k].abs()));
+                smin = smin.min(mu);
+            }
+        }
+        oldblock = Some((ll, m));
+
+        // the shift is the smaller singular value of the 2 by 2 block at the far end, unless it
+        // would ruin the relative accuracy or is negligible
+        let mut shift = zero;
+        if real(n) * tol * (smin / smax) > eps.max(tol / real(100)) {
+            let (sll, (ssmin, _)) = if down {
+                (d[ll].abs(), las2(d[m - 1], e[m - 1], d[m]))
+            } else {
+                (d[m].abs(), las2(d[ll], e[ll], d[ll + 1]))
+            };
+            shift = ssmin;
+            if sll > zero && (shift / sll).powi(2) < eps {
+                shift = zero;
+            }
+        }
+
+        iter += m - ll;
+        let len = m - ll + 1;
+        let sign = |x: T::Real| if x >= zero { one } else { -one };
+
+        if shift == zero && down {
+            // zero shift, chasing the bulge down
+            let (mut cs, mut sn, mut oldcs, mut oldsn) = (one, zero, one, zero);
+            for i in ll..m {
+                let r = rotation(d[i] * cs, e[i], &mut cs, &mut sn);
+                if i > ll {
+                    e[i - 1] = oldsn * r;
+                }
+                d[i] = rotation(oldcs * r, d[i + 1] * sn, &mut oldcs, &mut oldsn);
+                cr[i - ll] = cs;
+                sr[i - ll] = sn;
+                cl[i - ll] = oldcs;
+                sl[i - ll] = oldsn;
+            }
+            let h = d[m] * cs;
+            d[m] = h * oldcs;
+            e[m - 1] = h * oldsn;
+        } else if shift == zero {
+            // zero shift, chasing the bulge up
+            let (mut cs, mut sn, mut oldcs, mut oldsn) = (one, zero, one, zero);
+            for i in (ll + 1..=m).rev() {
+                let r = rotation(d[i] * cs, e[i - 1], &mut cs, &mut sn);
+                if i < m {
+                    e[i] = oldsn * r;
+                }
+                d[i] = rotation(oldcs * r, d[i - 1] * sn, &mut oldcs, &mut oldsn);
+                cl[i - ll - 1] = cs;
+                sl[i - ll - 1] = -sn;
+                cr[i - ll - 1] = oldcs;
+                sr[i - ll - 1] = -oldsn;
+            }
+            let h = d[ll] * cs;
+            d[ll] = h * oldcs;
+            e[ll] = h * oldsn;
+        } else if down {
+            // shifted, chasing the bulge down
+            let mut f = (d[ll].abs() - shift) * (sign(d[ll]) + shift / d[ll]);
+            let mut g = e[ll];
+            let (mut cosr, mut sinr, mut cosl, mut sinl) = (zero, zero, zero, zero);
+            for i in ll..m {
+                let r = rotation(f, g, &mut cosr, &mut sinr);
+                if i > ll {
+                    e[i - 1] = r;
+                }
+                f = cosr * d[i] + sinr * e[i];
+                e[i] = cosr * e[i] - sinr * d[i];
+                g = sinr * d[i + 1];
+                d[i + 1] = cosr * d[i + 1];
+                d[i] = rotation(f, g, &mut cosl, &mut sinl);
+                f = cosl * e[i] + sinl * d[i + 1];
+                d[i + 1] = cosl * d[i + 1] - sinl * e[i];
+                if i + 1 < m {
+                    g = sinl * e[i + 1];
+                    e[i + 1] = cosl * e[i + 1];
+                }
+                cr[i - ll] = cosr;
+                sr[i - ll] = sinr;
+                cl[i - ll] = cosl;
+                sl[i - ll] = sinl;
+            }
+            e[m - 1] = f;
+        } else {
+            // shifted, chasing the bulge up
+            let mut f = (d[m].abs() - shift) * (sign(d[m]) + shift / d[m]);
+            let mut g = e[m - 1];
+            let (mut cosr, mut sinr, mut cosl, mut sinl) = (zero, zero, zero, zero);
+            for i in (ll + 1..=m).rev() {
+                let r = rotation(f, g, &mut cosr, &mut sinr);
+                if i < m {
+                    e[i] = r;
+                }
+                f = cosr * d[i] + sinr * e[i - 1];
+                e[i - 1] = cosr * e[i - 1] - sinr * d[i];
+                g = sinr * d[i - 1];
+                d[i - 1] = cosr * d[i - 1];
+                d[i] = rotation(f, g, &mut cosl, &mut sinl);
+                f = cosl * e[i - 1] + sinl * d[i - 1];
+                d[i - 1] = cosl * d[i - 1] - sinl * e[i - 1];
+                if i > ll + 1 {
+                    g = sinl * e[i - 2];
+                    e[i - 2] = cosl * e[i - 2];
+                }
+                cl[i - ll - 1] = cosr;
+                sl[i - ll - 1] = -sinr;
+                cr[i - ll - 1] = cosl;
+                sr[i - ll - 1] = -sinl;
+            }
+            e[ll] = f;
+        }
+
+        // the rotations from the right apply to VT, those from the left to U and C, in the
+        // order they were generated
+        vectors.rotate_vt(down, ll, len, &cr, &sr);
+        vectors.rotate_uc(down, ll, len, &cl, &sl);
+
+        let far = if down { m - 1 } else { ll };
+        if e[far].abs() <= thresh {
+            e[far] = zero;
+        }
+    }
+
+    // the singular values are made positive and sorted in descending order, each vector being
+    // moved at most once
+    for (i, di) in d.iter_mut().enumerate() {
+        if *di < zero {
+            *di = -*di;
+            vectors.negate(i);
+        }
+    }
+    for i in 0..n - 1 {
+        let last = n - 1 - i;
+        let mut isub = 0;
+        for j in 1..=last {
+            if d[j] <= d[isub] {
+                isub = j;
+            }
+        }
+        if isub != last {
+            d.swap(isub, last);
+            vectors.swap(isub, last);
+        }
+    }
+
+    None
+}
+
+/// Returns the singular values (ssmin, ssmax) of the upper triangular matrix [f g; 0 h].
+fn las2<R: Float>(f: R, g: R, h: R) -> (R, R) {
+    let (zero, one) = (R::zero(), R::one());
+    let two = one + one;
+
+    let (fa, ga, ha) = (f.abs(), g.abs(), h.abs());
+    let (fhmn, fhmx) = (fa.min(ha), fa.max(ha));
+
+    if fhmn == zero {
+        let ssmax = if fhmx == zero {
+            ga
+        } else {
+            fhmx.max(ga) * (one + (fhmx.min(ga) / fhmx.max(ga)).powi(2)).sqrt()
+        };
+        (zero, ssmax)
+    } else if ga < fhmx {
+        let as_ = one + fhmn / fhmx;
+        let at = (fhmx - fhmn) / fhmx;
+        let au = (ga / fhmx).powi(2);
+        let c = two / ((as_ * as_ + au).sqrt() + (at * at + au).sqrt());
+        (fhmn * c, fhmx / c)
+    } else {
+        let au = fhmx / ga;
+        if au == zero {
+            // fhmx is negligible next to ga
+            ((fhmn * fhmx) / ga, ga)
+        } else {
+            let as_ = one + fhmn / fhmx;
+            let at = (fhmx - fhmn) / fhmx;
+            let c = one / ((one + (as_ * au).powi(2)).sqrt() + (one + (at * au).powi(2)).sqrt());
+            let ssmin = (fhmn * c) * au;
+            (ssmin + ssmin, ga / (c + c))
+        }
+    }
+}
+
+/// Returns the singular value decomposition of the upper triangular matrix [f g; 0 h],
+/// (ssmin, ssmax, snr, csr, snl, csl) with
+///
+/// [ csl snl] * [f g] * [csr -snr] = [ssmax     0]
+/// [-snl csl]   [0 h]   [snr  csr]   [    0 ssmin]
+///
+/// |ssmax| being the larger singular value and |ssmin| the smaller one.
+fn lasv2<R: Float>(f: R, g: R, h: R) -> (R, R, R, R, R, R) {
+    let (zero, one) = (R::zero(), R::one());
+    let (two, four) = (one + one, one + one + one + one);
+    let half = one / two;
+    let sign = |a: R, b: R| if b >= zero { a.abs() } else { -a.abs() };
+
+    let (mut ft, mut fa, mut ht, mut ha) = (f, f.abs(), h, h.abs());
+
+    // pmax is the position of the element of largest absolute value, 1 for f, 2 for g, 3 for h
+    let mut pmax = 1;
+    let swap = ha > fa;
+    if swap {
+        pmax = 3;
+        core::mem::swap(&mut ft, &mut ht);
+        core::mem::swap(&mut fa, &mut ha);
+    }
+
+    let (gt, ga) = (g, g.abs());
+    let (ssmin, ssmax, clt, crt, slt, srt);
+
+    if ga == zero {
+        // the matrix is diagonal
+        ssmin = ha;
+        ssmax = fa;
+        clt = one;
+        crt = one;
+        slt = zero;
+        srt = zero;
+    } else if ga > fa && fa / ga < R::epsilon() / two {
+        // ga is very large
+        pmax = 2;
+        ssmax = ga;
+        ssmin = if ha > one {
+            fa / (ga / ha)
+        } else {
+            (fa / ga) * ha
+        };
+        clt = one;
+        slt = ht / gt;
+        srt = one;
+        crt = ft / gt;
+    } else {
+        if ga > fa {
+            pmax = 2;
+        }
+
+        let dd = fa - ha;
+        let l = if dd == fa { one } else { dd / fa };
+        let m = gt / ft;
+        let t = two - l;
+        let (mm, tt) = (m * m, t * t);
+        let s = (tt + mm).sqrt();
+        let r = if l == zero {
+            m.abs()
+        } else {
+            (l * l + mm).sqrt()
+        };
+        let a = half * (s + r);
+
+        ssmin = ha / a;
+        ssmax = fa * a;
+
+        let t = if mm == zero {
+            // m is very tiny
+            if l == zero {
+                sign(two, ft) * sign(one, gt)
+            } else {
+                gt / sign(dd, ft) + m / t
+            }
+        } else {
+            (m / (s + t) + m / (r + l)) * (one + a)
+        };
+        let l = (t * t + four).sqrt();
+        crt = two / l;
+        srt = t / l;
+        clt = (crt + srt * m) / a;
+        slt = (ht / ft) * srt / a;
+    }
+
+    let (csl, snl, csr, snr) = if swap {
+        (srt, crt, slt, clt)
+    } else {
+        (clt, slt, crt, srt)
+    };
+
+    // the signs of ssmax and ssmin
+    let tsign = match pmax {
+        1 => sign(one, csr) * sign(one, csl) * sign(one, f),
+        2 => sign(one, snr) * sign(one, csl) * sign(one, g),
+        _ => sign(one, snr) * sign(one, snl) * sign(one, h),
+    };
+    let ssmax = sign(ssmax, tsign);
+    let ssmin = sign(ssmin, tsign * sign(one, f) * sign(one, h));
+
+    (ssmin, ssmax, snr, csr, snl, csl)
+}
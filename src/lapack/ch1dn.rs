@@ -0,0 +1,84 @@
+use alloc::vec;
+
+use nd_slice::{NdSlice, NdSliceMut};
+use num_traits::Float;
+
+use crate::contiguous::{as_mut_slice, as_slice};
+use crate::level_1::rotg::rotg;
+
+use super::{span, LapackError, Scalar};
+
+/// Downdates the triangular factor of a QR (or Cholesky) factorization after a row is removed
+/// from the matrix.
+///
+/// R1ᵀ * R1 = Rᵀ * R - x * xᵀ
+///
+/// With a = R⁻ᵀ * x, rotations from `rotg` reduce [a; sqrt(1 - ‖a‖²)] to the last unit vector,
+/// bottom to top, and the same rotations applied to [R; 0] give [R1; ±xᵀ]. If A = Q * R has
+/// the row xᵀ, then R1 is the triangular factor of A without it. The diagonal of R1 may have
+/// elements of either sign.
+///
+/// Input:
+///     n: order of R
+///     r: upper triangular matrix in column-major order, ldr by n, the strict lower triangle is
+///        not referenced
+///     ldr: leading dimension of r, at least max(1, n)
+///     x: vector of n elements
+/// Output:
+///     r: R1
+///     returns `LapackError::NotPositiveDefinite(i)` if the leading minor of order i + 1 of
+///     Rᵀ * R - x * xᵀ is not positive, R then being unchanged
+pub fn ch1dn<T: Float + Scalar<Real = T>>(
+    n: &isize,
+    r: &mut NdSliceMut<'_, T, 1>,
+    ldr: &isize,
+    x: &NdSlice<'_, T, 1>,
+) -> Result<(), LapackError> {
+    let (n, ldr) = (*n, *ldr);
+
+    if n < 0 {
+        return Err(LapackError::Argument(1));
+    }
+    if ldr < n.max(1) {
+        return Err(LapackError::Argument(3));
+    }
+    let (n, ldr) = (n as usize, ldr as usize);
+
+    if n == 0 {
+        return Ok(());
+    }
+
+    let r = as_mut_slice(r, span(n, n, ldr));
+
+    // a = R⁻ᵀ * x, whose first i + 1 elements give the leading minor of order i + 1
+    let mut a = as_slice(x, n).to_vec();
+    T::trsm('L', 'U', 'C', 'N', n, 1, T::one(), r, ldr, &mut a, n);
+
+    let mut ssq = T::zero();
+    for (i, &ai) in a.iter().enumerate() {
+        ssq = ssq + ai * ai;
+        if ssq >= T::one() || ssq.is_nan() {
+            return Err(LapackError::NotPositiveDefinite(i));
+        }
+    }
+
+    // rotations in the planes (i, n) of [a; alpha], bottom to top, reduce it to ±en
+    let (mut c, mut s) = (vec![T::zero(); n], vec![T::zero(); n]);
+    let mut alpha = (T::one() - ssq).sqrt();
+    for i in (0..n).rev() {
+        let mut ai = a[i];
+        rotg(&mut alpha, &mut ai, &mut c[i], &mut s[i]);
+    }
+
+    // apply them to each column of [R; 0]
+    for j in 0..n {
+        let mut xx = T::zero();
+        for i in (0..=j).rev() {
+            let rij = r[i + j * ldr];
+            r[i + j * ldr] = c[i] * rij - s[i] * xx;
+            xx = c[i] * xx + s[i] * rij;
+        }
+    }
+
+    Ok(())
+}
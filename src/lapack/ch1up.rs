@@ -0,0 +1,63 @@
+use alloc::vec;
+
+use nd_slice::{NdSlice, NdSliceMut};
+use num_traits::Float;
+
+use crate::contiguous::{as_mut_slice, as_slice};
+use crate::level_1::rotg::rotg;
+
+use super::{span, LapackError, Scalar};
+
+/// Updates the triangular factor of a QR (or Cholesky) factorization after a row is added to
+/// the matrix.
+///
+/// R1ᵀ * R1 = Rᵀ * R + x * xᵀ
+///
+/// x is rotated into the rows of R one at a time, with rotations from `rotg`. If A = Q * R,
+/// then R1 is the triangular factor of A with the row xᵀ appended. The diagonal of R1 may have
+/// elements of either sign.
+///
+/// Input:
+///     n: order of R
+///     r: upper triangular matrix in column-major order, ldr by n, the strict lower triangle is
+///        not referenced
+///     ldr: leading dimension of r, at least max(1, n)
+///     x: vector of n elements
+/// Output:
+///     r: R1
+pub fn ch1up<T: Float + Scalar<Real = T>>(
+    n: &isize,
+    r: &mut NdSliceMut<'_, T, 1>,
+    ldr: &isize,
+    x: &NdSlice<'_, T, 1>,
+) -> Result<(), LapackError> {
+    let (n, ldr) = (*n, *ldr);
+
+    if n < 0 {
+        return Err(LapackError::Argument(1));
+    }
+    if ldr < n.max(1) {
+        return Err(LapackError::Argument(3));
+    }
+    let (n, ldr) = (n as usize, ldr as usize);
+
+    let r = as_mut_slice(r, span(n, n, ldr));
+    let x = as_slice(x, n);
+
+    let (mut c, mut s) = (vec![T::zero(); n], vec![T::zero(); n]);
+
+    for j in 0..n {
+        // apply the previous rotations to column j of [R; xᵀ]
+        let mut xj = x[j];
+        for i in 0..j {
+            let rij = r[i + j * ldr];
+            r[i + j * ldr] = c[i] * rij + s[i] * xj;
+            xj = c[i] * xj - s[i] * rij;
+        }
+
+        // annihilate x[j] against R(j, j)
+        rotg(&mut r[j + j * ldr], &mut xj, &mut c[j], &mut s[j]);
+    }
+
+    Ok(())
+}
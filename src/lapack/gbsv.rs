@@ -0,0 +1,68 @@
+use nd_slice::NdSliceMut;
+
+use crate::contiguous::{as_slice, vector};
+
+use super::gbtrf::gbtrf;
+use super::gbtrs::gbtrs;
+use super::{span, LapackError, Scalar};
+
+/// Solves a system of linear equations with a general band matrix.
+///
+/// A * X = B
+///
+/// A is factored as P * L * U with `gbtrf` and the factors are used to solve the system with
+/// `gbtrs`.
+///
+/// Input:
+///     n: order of A
+///     kl: number of subdiagonals of A
+///     ku: number of superdiagonals of A
+///     nrhs: number of columns of B
+///     ab: band matrix in the band storage of `gbtrf`, ldab by n
+///     ldab: leading dimension of ab, at least 2 * kl + ku + 1
+///     ipiv: vector of n elements
+///     b: right-hand sides in column-major order, ldb by nrhs
+///     ldb: leading dimension of b, at least max(1, n)
+/// Output:
+///     ab: the factors L and U from `gbtrf`
+///     ipiv: the pivot indices from `gbtrf`
+///     b: the solution X, unless A is singular
+///     returns `LapackError::Singular(i)` if U(i, i) is exactly zero, B then being unchanged
+#[allow(clippy::too_many_arguments)]
+pub fn gbsv<T: Scalar>(
+    n: &isize,
+    kl: &isize,
+    ku: &isize,
+    nrhs: &isize,
+    ab: &mut NdSliceMut<'_, T, 1>,
+    ldab: &isize,
+    ipiv: &mut NdSliceMut<'_, isize, 1>,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) -> Result<(), LapackError> {
+    if *n < 0 {
+        return Err(LapackError::Argument(1));
+    }
+    if *kl < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if *ku < 0 {
+        return Err(LapackError::Argument(3));
+    }
+    if *nrhs < 0 {
+        return Err(LapackError::Argument(4));
+    }
+    if *ldab < 2 * *kl + *ku + 1 {
+        return Err(LapackError::Argument(6));
+    }
+    if *ldb < (*n).max(1) {
+        return Err(LapackError::Argument(9));
+    }
+
+    gbtrf(n, n, kl, ku, ab, ldab, ipiv)?;
+
+    let rows = (2 * *kl + *ku + 1) as usize;
+    let (len, k) = (span(rows, *n as usize, *ldab as usize), *n as usize);
+    let (ab, ipiv) = (vector(as_slice(ab, len)), vector(as_slice(ipiv, k)));
+    gbtrs(&'N', n, kl, ku, nrhs, &ab, ldab, &ipiv, b, ldb)
+}
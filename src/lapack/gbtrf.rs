@@ -0,0 +1,138 @@
+use alloc::vec::Vec;
+
+use nd_slice::NdSliceMut;
+
+use crate::contiguous::as_mut_slice;
+
+use super::{span, LapackError, Scalar};
+
+/// Computes the LU factorization of a general band matrix, using partial pivoting with row
+/// interchanges.
+///
+/// A = P * L * U
+///
+/// where L has a unit diagonal and kl subdiagonals and U is upper triangular with kl + ku
+/// superdiagonals, the interchanges making U wider than A. The matrix is factored one column
+/// at a time with iamax, scal and ger.
+///
+/// A is in band storage: A(i, j) is ab[kl + ku + i - j + j * ldab] for
+/// max(0, j - ku) <= i <= min(m - 1, j + kl), the first kl rows of ab being workspace for the
+/// fill-in of U.
+///
+/// Input:
+///     m: number of rows of A
+///     n: number of columns of A
+///     kl: number of subdiagonals of A
+///     ku: number of superdiagonals of A
+///     ab: band matrix in column-major order, ldab by n
+///     ldab: leading dimension of ab, at least 2 * kl + ku + 1
+///     ipiv: vector of min(m, n) elements
+/// Output:
+///     ab: U in its first kl + ku + 1 rows, the multipliers of L below them
+///     ipiv: pivot indices, row i was interchanged with row ipiv[i]
+///     returns `LapackError::Singular(i)` for the first i such that U(i, i) is exactly zero
+#[allow(clippy::too_many_arguments)]
+pub fn gbtrf<T: Scalar>(
+    m: &isize,
+    n: &isize,
+    kl: &isize,
+    ku: &isize,
+    ab: &mut NdSliceMut<'_, T, 1>,
+    ldab: &isize,
+    ipiv: &mut NdSliceMut<'_, isize, 1>,
+) -> Result<(), LapackError> {
+    let (m, n, kl, ku, ldab) = (*m, *n, *kl, *ku, *ldab);
+
+    if m < 0 {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if kl < 0 {
+        return Err(LapackError::Argument(3));
+    }
+    if ku < 0 {
+        return Err(LapackError::Argument(4));
+    }
+    if ldab < 2 * kl + ku + 1 {
+        return Err(LapackError::Argument(6));
+    }
+    let (m, n, kl, ku, ldab) = (
+        m as usize,
+        n as usize,
+        kl as usize,
+        ku as usize,
+        ldab as usize,
+    );
+
+    if m == 0 || n == 0 {
+        return Ok(());
+    }
+
+    let kv = ku + kl;
+    let ab = as_mut_slice(ab, span(kv + kl + 1, n, ldab));
+    let ipiv = as_mut_slice(ipiv, m.min(n));
+
+    // the fill-in elements of columns ku + 1..kv start as zero
+    for j in ku + 1..kv.min(n) {
+        for i in kv - j..kl {
+            ab[i + j * ldab] = T::zero();
+        }
+    }
+
+    let mut info = None;
+    // the last column affected by the interchanges so far
+    let mut ju = 0;
+    // the multipliers and row j right of the diagonal, which share their storage with A22
+    let (mut x, mut y) = (Vec::with_capacity(kl), Vec::with_capacity(kv));
+
+    for j in 0..m.min(n) {
+        // the fill-in elements of column j + kv start as zero
+        if j + kv < n {
+            for i in 0..kl {
+                ab[i + (j + kv) * ldab] = T::zero();
+            }
+        }
+
+        // find the pivot and test for singularity
+        let km = kl.min(m - 1 - j);
+        let jp = T::iamax(km + 1, &ab[kv + j * ldab..], 1);
+        ipiv[j] = (jp + j) as isize;
+
+        if ab[kv + jp + j * ldab] == T::zero() {
+            info = info.or(Some(j));
+            continue;
+        }
+        ju = ju.max((j + ku + jp).min(n - 1));
+
+        // interchange rows j and j + jp in columns j..=ju, which are ldab - 1 apart in ab
+        if jp != 0 {
+            for c in 0..=ju - j {
+                let k = kv + j * ldab + c * (ldab - 1);
+                ab.swap(k + jp, k);
+            }
+        }
+
+        if km > 0 {
+            let pivot = T::one() / ab[kv + j * ldab];
+            T::scal(km, pivot, &mut ab[kv + 1 + j * ldab..], 1);
+
+            // A22 = A22 - x * y
+            if ju > j {
+                x.clear();
+                x.extend_from_slice(&ab[kv + 1 + j * ldab..kv + 1 + km + j * ldab]);
+                y.clear();
+                y.extend((0..ju - j).map(|c| ab[kv - 1 + (j + 1) * ldab + c * (ldab - 1)]));
+
+                let a22 = &mut ab[kv + (j + 1) * ldab..];
+                T::ger(km, ju - j, -T::one(), &x, 1, &y, 1, a22, ldab - 1);
+            }
+        }
+    }
+
+    match info {
+        Some(i) => Err(LapackError::Singular(i)),
+        None => Ok(()),
+    }
+}
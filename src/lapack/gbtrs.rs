@@ -0,0 +1,144 @@
+use nd_slice::{NdSlice, NdSliceMut};
+
+use crate::contiguous::{as_mut_slice, as_slice};
+
+use super::{span, LapackError, Scalar};
+
+/// Solves a system of linear equations with a general band matrix, using its LU factorization
+/// computed by `gbtrf`.
+///
+/// op(A) * X = B
+///
+/// L is applied one column at a time, to each right-hand side in turn with axpy (gemm for
+/// op(A) = Aᵀ or Aᴴ), and U, a band matrix, is solved with `tbsv`.
+///
+/// Input:
+///     trans: 'N' for op(A) = A, 'T' for op(A) = Aᵀ, 'C' for op(A) = Aᴴ
+///     n: order of A
+///     kl: number of subdiagonals of A
+///     ku: number of superdiagonals of A
+///     nrhs: number of columns of B
+///     ab: the factors L and U from `gbtrf`, ldab by n
+///     ldab: leading dimension of ab, at least 2 * kl + ku + 1
+///     ipiv: the pivot indices from `gbtrf`
+///     b: right-hand sides in column-major order, ldb by nrhs
+///     ldb: leading dimension of b, at least max(1, n)
+/// Output:
+///     b: the solution X
+#[allow(clippy::too_many_arguments)]
+pub fn gbtrs<T: Scalar>(
+    trans: &char,
+    n: &isize,
+    kl: &isize,
+    ku: &isize,
+    nrhs: &isize,
+    ab: &NdSlice<'_, T, 1>,
+    ldab: &isize,
+    ipiv: &NdSlice<'_, isize, 1>,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) -> Result<(), LapackError> {
+    let (n, kl, ku, nrhs, ldab, ldb) = (*n, *kl, *ku, *nrhs, *ldab, *ldb);
+
+    let notrans = trans.eq_ignore_ascii_case(&'N');
+
+    if !(notrans || "TtCc".contains(*trans)) {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if kl < 0 {
+        return Err(LapackError::Argument(3));
+    }
+    if ku < 0 {
+        return Err(LapackError::Argument(4));
+    }
+    if nrhs < 0 {
+        return Err(LapackError::Argument(5));
+    }
+    if ldab < 2 * kl + ku + 1 {
+        return Err(LapackError::Argument(7));
+    }
+    if ldb < n.max(1) {
+        return Err(LapackError::Argument(10));
+    }
+    let (n, kl, ku, nrhs) = (n as usize, kl as usize, ku as usize, nrhs as usize);
+    let (ldab, ldb) = (ldab as usize, ldb as usize);
+
+    if n == 0 || nrhs == 0 {
+        return Ok(());
+    }
+
+    let kd = kl + ku;
+    let ab = as_slice(ab, span(kd + kl + 1, n, ldab));
+    let ipiv = as_slice(ipiv, n);
+    let b = as_mut_slice(b, span(n, nrhs, ldb));
+
+    let interchange = |b: &mut [T], j: usize| {
+        let p = ipiv[j] as usize;
+        if p != j {
+            for c in 0..nrhs {
+                b.swap(p + c * ldb, j + c * ldb);
+            }
+        }
+    };
+
+    if notrans {
+        // B = L⁻¹ * B, the interchanges applied as L is
+        if kl > 0 {
+            for j in 0..n - 1 {
+                let lm = kl.min(n - 1 - j);
+                interchange(b, j);
+
+                let l = &ab[kd + 1 + j * ldab..];
+                for col in b.chunks_mut(ldb) {
+                    let (bj, below) = col[j..].split_at_mut(1);
+                    T::axpy(lm, -bj[0], l, 1, below, 1);
+                }
+            }
+        }
+
+        // X = U⁻¹ * B
+        for c in 0..nrhs {
+            T::tbsv('U', 'N', 'N', n, kd, ab, ldab, &mut b[c * ldb..], 1);
+        }
+    } else {
+        // B = op(U)⁻¹ * B
+        for c in 0..nrhs {
+            T::tbsv('U', *trans, 'N', n, kd, ab, ldab, &mut b[c * ldb..], 1);
+        }
+
+        // X = op(L)⁻¹ * B, the interchanges undone in reverse order
+        if kl > 0 {
+            for j in (0..n - 1).rev() {
+                let lm = kl.min(n - 1 - j);
+                let l = &ab[kd + 1 + j * ldab..];
+
+                // row j less op(l) times the rows below it, l being column j of L, a
+                // right-hand side at a time as the rows share their columns
+                for col in b.chunks_mut(ldb) {
+                    let (bj, below) = col[j..].split_at_mut(1);
+                    T::gemm(
+                        *trans,
+                        'N',
+                        1,
+                        1,
+                        lm,
+                        -T::one(),
+                        l,
+                        ldab,
+                        below,
+                        lm,
+                        T::one(),
+                        bj,
+                        1,
+                    );
+                }
+                interchange(b, j);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,245 @@
+use alloc::{vec, vec::Vec};
+
+use nd_slice::NdSliceMut;
+
+use crate::contiguous::as_mut_slice;
+
+use super::larf::reflect;
+use super::larfg::generate;
+use super::{span, LapackError, Scalar};
+
+/// Reduces a general matrix to real bidiagonal form by a unitary transformation.
+///
+/// Qᴴ * A * P = B
+///
+/// where B is upper bidiagonal if m >= n and lower bidiagonal otherwise, Q = H(0) * H(1) * ...
+/// and P = G(0) * G(1) * ..., each H(i) = I - tauq[i] * v * vᴴ and G(i) = I - taup[i] * u * uᴴ
+/// being an elementary reflector. The columns and rows are reduced alternately, one at a time.
+///
+/// If m >= n, v[..i] = 0 and v[i] = 1, v[i + 1..] being stored in A(i + 1.., i), and
+/// u[..i + 1] = 0 and u[i + 1] = 1, conjugate(u[i + 2..]) being stored in A(i, i + 2..). If
+/// m < n, v[..i + 1] = 0 and v[i + 1] = 1, v[i + 2..] being stored in A(i + 2.., i), and
+/// u[..i] = 0 and u[i] = 1, conjugate(u[i + 1..]) being stored in A(i, i + 1..).
+///
+/// Input:
+///     m: number of rows of A
+///     n: number of columns of A
+///     a: matrix in column-major order, lda by n
+///     lda: leading dimension of a, at least max(1, m)
+///     d: vector of min(m, n) elements
+///     e: vector of min(m, n) - 1 elements
+///     tauq: vector of min(m, n) elements
+///     taup: vector of min(m, n) elements
+/// Output:
+///     a: B on its diagonal and first super-diagonal (m >= n) or sub-diagonal (m < n), the
+///     reflectors in the rest of it
+///     d: the diagonal of B
+///     e: the off-diagonal of B
+///     tauq: the scalar factors of the reflectors of Q
+///     taup: the scalar factors of the reflectors of P
+#[allow(clippy::too_many_arguments)]
+pub fn gebrd<T: Scalar>(
+    m: &isize,
+    n: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    d: &mut NdSliceMut<'_, T::Real, 1>,
+    e: &mut NdSliceMut<'_, T::Real, 1>,
+    tauq: &mut NdSliceMut<'_, T, 1>,
+    taup: &mut NdSliceMut<'_, T, 1>,
+) -> Result<(), LapackError> {
+    let (m, n, lda) = (*m, *n, *lda);
+
+    if m < 0 {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if lda < m.max(1) {
+        return Err(LapackError::Argument(4));
+    }
+    let (m, n, lda) = (m as usize, n as usize, lda as usize);
+    let k = m.min(n);
+
+    if k == 0 {
+        return Ok(());
+    }
+
+    let a = as_mut_slice(a, span(m, n, lda));
+    let d = as_mut_slice(d, k);
+    let e = as_mut_slice(e, k - 1);
+    let tauq = as_mut_slice(tauq, k);
+    let taup = as_mut_slice(taup, k);
+
+    bidiagonalize(m, n, a, lda, d, e, tauq, taup);
+
+    Ok(())
+}
+
+/// Reduces the m by n matrix a to bidiagonal form as `gebrd` does.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn bidiagonalize<T: Scalar>(
+    m: usize,
+    n: usize,
+    a: &mut [T],
+    lda: usize,
+    d: &mut [T::Real],
+    e: &mut [T::Real],
+    tauq: &mut [T],
+    taup: &mut [T],
+) {
+    let mut v = Vec::with_capacity(m.max(n));
+
+    // H annihilates A(i + 1.., j) below A(i, j), and Hᴴ is applied to the columns right of j;
+    // returns the new A(i, j)
+    let mut reduce_column = |a: &mut [T], i: usize, j: usize, tauq: &mut T| {
+        let len = m - i;
+        v.clear();
+        v.extend_from_slice(&a[i + j * lda..i + j * lda + len]);
+        let mut alpha = v[0];
+        *tauq = generate(len, &mut alpha, &mut v[1..], 1);
+        a[i + 1 + j * lda..i + j * lda + len].copy_from_slice(&v[1..]);
+
+        if j + 1 < n {
+            v[0] = T::one();
+            let c = &mut a[i + (j + 1) * lda..];
+            reflect(true, len, n - j - 1, &v, tauq.conj(), c, lda);
+        }
+        alpha.re()
+    };
+
+    // G annihilates A(i, j + 1..) right of A(i, j), and G is applied to the rows below i;
+    // returns the new A(i, j)
+    let mut u = Vec::with_capacity(n);
+    let mut reduce_row = |a: &mut [T], i: usize, j: usize, taup: &mut T| {
+        let len = n - j;
+        u.clear();
+        u.extend((0..len).map(|k| a[i + (j + k) * lda].conj()));
+        let mut alpha = u[0];
+        *taup = generate(len, &mut alpha, &mut u[1..], 1);
+        for k in 1..len {
+            a[i + (j + k) * lda] = u[k].conj();
+        }
+
+        u[0] = T::one();
+        let c = &mut a[i + 1 + j * lda..];
+        reflect(false, m - i - 1, len, &u, *taup, c, lda);
+        alpha.re()
+    };
+
+    if m >= n {
+        for i in 0..n {
+            d[i] = reduce_column(a, i, i, &mut tauq[i]);
+            a[i + i * lda] = T::from_real(d[i]);
+
+            if i + 1 < n {
+                e[i] = reduce_row(a, i, i + 1, &mut taup[i]);
+                a[i + (i + 1) * lda] = T::from_real(e[i]);
+            } else {
+                taup[i] = T::zero();
+            }
+        }
+    } else {
+        for i in 0..m {
+            d[i] = reduce_row(a, i, i, &mut taup[i]);
+            a[i + i * lda] = T::from_real(d[i]);
+
+            if i + 1 < m {
+                e[i] = reduce_column(a, i + 1, i, &mut tauq[i]);
+                a[i + 1 + i * lda] = T::from_real(e[i]);
+            } else {
+                tauq[i] = T::zero();
+            }
+        }
+    }
+}
+
+/// Forms the first ncols columns of Q of the reduction computed by `gebrd` in q, ldq by ncols,
+/// from the reflectors in the m by n matrix a and their scalar factors in tauq.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn form_q<T: Scalar>(
+    m: usize,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    tauq: &[T],
+    ncols: usize,
+    q: &mut [T],
+    ldq: usize,
+) {
+    // H(i) acts on the rows from i + shift
+    let (count, shift) = if m >= n { (n, 0) } else { (m - 1, 1) };
+    let vector = |i: usize, v: &mut Vec<T>| {
+        let first = i + shift;
+        v.push(T::one());
+        v.extend_from_slice(&a[first + 1 + i * lda..m + i * lda]);
+    };
+
+    accumulate(m, count, shift, vector, tauq, ncols, q, ldq);
+}
+
+/// Forms the first nrows rows of Pᴴ of the reduction computed by `gebrd` in pt, ldpt by n,
+/// from the reflectors in the m by n matrix a and their scalar factors in taup.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn form_pt<T: Scalar>(
+    m: usize,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    taup: &[T],
+    nrows: usize,
+    pt: &mut [T],
+    ldpt: usize,
+) {
+    // G(i) acts on the columns from i + shift
+    let (count, shift) = if m >= n { (n - 1, 1) } else { (m, 0) };
+    let vector = |i: usize, u: &mut Vec<T>| {
+        let first = i + shift;
+        u.push(T::one());
+        u.extend((first + 1..n).map(|k| a[i + k * lda].conj()));
+    };
+
+    // the first nrows columns of P, transposed and conjugated into pt
+    let mut p = vec![T::zero(); n * nrows];
+    accumulate(n, count, shift, vector, taup, nrows, &mut p, n);
+
+    for j in 0..n {
+        for i in 0..nrows {
+            pt[i + j * ldpt] = p[j + i * n].conj();
+        }
+    }
+}
+
+/// Sets the order by ncols matrix q to the first ncols columns of H(0) * ... * H(count - 1),
+/// H(i) = I - tau[i] * v * vᴴ acting on the indices from i + shift, with v from vector.
+#[allow(clippy::too_many_arguments)]
+fn accumulate<T: Scalar>(
+    order: usize,
+    count: usize,
+    shift: usize,
+    vector: impl Fn(usize, &mut Vec<T>),
+    tau: &[T],
+    ncols: usize,
+    q: &mut [T],
+    ldq: usize,
+) {
+    for j in 0..ncols {
+        for i in 0..order {
+            q[i + j * ldq] = if i == j { T::one() } else { T::zero() };
+        }
+    }
+
+    // H(i) leaves the columns before i + shift alone, as the reflectors after it do
+    let mut v = Vec::with_capacity(order);
+    for i in (0..count).rev() {
+        let first = i + shift;
+        if first >= ncols {
+            continue;
+        }
+        v.clear();
+        vector(i, &mut v);
+        let c = &mut q[first + first * ldq..];
+        reflect(true, order - first, ncols - first, &v, tau[i], c, ldq);
+    }
+}
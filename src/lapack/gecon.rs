@@ -0,0 +1,99 @@
+use alloc::vec;
+
+use nd_slice::NdSlice;
+use num_traits::{Float, One, Zero};
+
+use crate::contiguous::{as_slice, vector_mut};
+
+use super::lacn2::lacn2;
+use super::{span, LapackError, Scalar};
+
+/// Estimates the reciprocal of the condition number of a general matrix, in the 1-norm or the
+/// infinity-norm, using its LU factorization computed by `getrf`.
+///
+/// rcond = 1 / (‖ A ‖ * ‖ A⁻¹ ‖)
+///
+/// ‖ A⁻¹ ‖ is estimated with `lacn2`, the products with A⁻¹ being triangular solves with L and U.
+///
+/// Input:
+///     norm: '1' or 'O' for the 1-norm, 'I' for the infinity-norm
+///     n: order of A
+///     a: the factors L and U from `getrf`, lda by n
+///     lda: leading dimension of a, at least max(1, n)
+///     anorm: the norm of the original matrix A
+/// Output:
+///     rcond: the estimate, 0 if anorm is zero and 1 if n is zero
+pub fn gecon<T: Scalar>(
+    norm: &char,
+    n: &isize,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    anorm: &T::Real,
+    rcond: &mut T::Real,
+) -> Result<(), LapackError> {
+    let (n, lda, anorm) = (*n, *lda, *anorm);
+
+    let onenrm = *norm == '1' || norm.eq_ignore_ascii_case(&'O');
+
+    if !(onenrm || norm.eq_ignore_ascii_case(&'I')) {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if lda < n.max(1) {
+        return Err(LapackError::Argument(4));
+    }
+    if anorm < T::Real::zero() || anorm.is_nan() {
+        return Err(LapackError::Argument(5));
+    }
+    let (n, lda) = (n as usize, lda as usize);
+
+    *rcond = T::Real::zero();
+    if n == 0 {
+        *rcond = T::Real::one();
+        return Ok(());
+    }
+    if anorm == T::Real::zero() {
+        return Ok(());
+    }
+
+    let a = as_slice(a, span(n, n, lda));
+
+    // the product lacn2 asks for first gives ‖ A⁻¹ ‖₁, the other ‖ A⁻ᴴ ‖₁ = ‖ A⁻¹ ‖∞
+    let kase1 = if onenrm { 1 } else { 2 };
+
+    let (mut v, mut x) = (vec![T::zero(); n], vec![T::zero(); n]);
+    let mut isave = [0; 3];
+    let (mut ainvnm, mut kase) = (T::Real::zero(), 0);
+
+    loop {
+        lacn2(
+            &(n as isize),
+            &mut vector_mut(&mut v),
+            &mut vector_mut(&mut x),
+            &mut ainvnm,
+            &mut kase,
+            &mut vector_mut(&mut isave),
+        );
+        if kase == 0 {
+            break;
+        }
+
+        if kase == kase1 {
+            // x = U⁻¹ * L⁻¹ * x
+            T::trsm('L', 'L', 'N', 'U', n, 1, T::one(), a, lda, &mut x, n);
+            T::trsm('L', 'U', 'N', 'N', n, 1, T::one(), a, lda, &mut x, n);
+        } else {
+            // x = L⁻ᴴ * U⁻ᴴ * x
+            T::trsm('L', 'U', 'C', 'N', n, 1, T::one(), a, lda, &mut x, n);
+            T::trsm('L', 'L', 'C', 'U', n, 1, T::one(), a, lda, &mut x, n);
+        }
+    }
+
+    if ainvnm != T::Real::zero() {
+        *rcond = (T::Real::one() / ainvnm) / anorm;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,196 @@
+use alloc::vec;
+
+use nd_slice::NdSliceMut;
+
+use crate::contiguous::{as_mut_slice, vector, vector_mut};
+
+use super::geqrf::geqrf;
+use super::ormqr::ormqr;
+use super::{span, LapackError, Scalar};
+
+/// Solves overdetermined or underdetermined systems of linear equations with a matrix of full
+/// rank, using a QR factorization of the matrix or of its conjugate transpose.
+///
+/// op(A) * X = B
+///
+/// If op(A) has at least as many rows as columns, X is the least squares solution minimizing
+/// ‖ B - op(A) * X ‖. Otherwise X is the solution of minimum norm. A is m by n; for m < n its
+/// conjugate transpose Aᴴ = Q * R is factored in place of A = L * Q.
+///
+/// Input:
+///     trans: 'N' for op(A) = A, 'C' for op(A) = Aᴴ ('T' is accepted for real types)
+///     m: number of rows of A
+///     n: number of columns of A
+///     nrhs: number of columns of B and X
+///     a: matrix in column-major order, lda by n
+///     lda: leading dimension of a, at least max(1, m)
+///     b: right-hand sides in column-major order, ldb by nrhs, m rows if trans = 'N' and n rows
+///        otherwise
+///     ldb: leading dimension of b, at least max(1, m, n)
+/// Output:
+///     a: the QR factorization of A from `geqrf` if m >= n, the conjugate transpose of the QR
+///        factorization of Aᴴ otherwise
+///     b: X in its first n rows if trans = 'N' and m rows otherwise; for least squares
+///        problems, the squared norms of the remaining rows of each column sum to the residual
+///     returns `LapackError::Singular(i)` if R(i, i) is exactly zero, so A does not have full
+///     rank; B is then unchanged
+#[allow(clippy::too_many_arguments)]
+pub fn gels<T: Scalar>(
+    trans: &char,
+    m: &isize,
+    n: &isize,
+    nrhs: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) -> Result<(), LapackError> {
+    let (m, n, nrhs, lda, ldb) = (*m, *n, *nrhs, *lda, *ldb);
+
+    let notrans = trans.eq_ignore_ascii_case(&'N');
+
+    if !(notrans || "TtCc".contains(*trans)) {
+        return Err(LapackError::Argument(1));
+    }
+    if m < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(3));
+    }
+    if nrhs < 0 {
+        return Err(LapackError::Argument(4));
+    }
+    if lda < m.max(1) {
+        return Err(LapackError::Argument(6));
+    }
+    if ldb < m.max(n).max(1) {
+        return Err(LapackError::Argument(8));
+    }
+    let (m, n, nrhs, lda, ldb) = (
+        m as usize,
+        n as usize,
+        nrhs as usize,
+        lda as usize,
+        ldb as usize,
+    );
+
+    let a = as_mut_slice(a, span(m, n, lda));
+    let b = as_mut_slice(b, span(m.max(n), nrhs, ldb));
+
+    if m.min(n).min(nrhs) == 0 {
+        for j in 0..nrhs {
+            for i in 0..m.max(n) {
+                b[i + j * ldb] = T::zero();
+            }
+        }
+        return Ok(());
+    }
+
+    let mut tau = vec![T::zero(); m.min(n)];
+
+    if m >= n {
+        qr(m, n, a, lda, &mut tau)?;
+        solve(notrans, m, n, a, lda, &tau, nrhs, b, ldb)
+    } else {
+        // A = Rᴴ * Qᴴ, so A * X = B is the transposed problem for Aᴴ and the other way round
+        let mut ah = vec![T::zero(); n * m];
+        for j in 0..n {
+            for i in 0..m {
+                ah[j + i * n] = a[i + j * lda].conj();
+            }
+        }
+
+        let factored = qr(n, m, &mut ah, n, &mut tau);
+        for j in 0..n {
+            for i in 0..m {
+                a[i + j * lda] = ah[j + i * n].conj();
+            }
+        }
+        factored?;
+
+        solve(!notrans, n, m, &ah, n, &tau, nrhs, b, ldb)
+    }
+}
+
+/// Computes the QR factorization of the m by n matrix A, m >= n, checking that R has no zero on
+/// its diagonal.
+fn qr<T: Scalar>(
+    m: usize,
+    n: usize,
+    a: &mut [T],
+    lda: usize,
+    tau: &mut [T],
+) -> Result<(), LapackError> {
+    let (mi, ni, ldai) = (m as isize, n as isize, lda as isize);
+    geqrf(&mi, &ni, &mut vector_mut(a), &ldai, &mut vector_mut(tau))?;
+
+    match (0..n).find(|&i| a[i + i * lda] == T::zero()) {
+        Some(i) => Err(LapackError::Singular(i)),
+        None => Ok(()),
+    }
+}
+
+/// Solves Q * R * X = B in the least squares sense if least_squares is true, or finds the
+/// minimum norm solution of (Q * R)ᴴ * X = B otherwise, Q * R being the QR factorization of an
+/// m by n matrix with m >= n.
+#[allow(clippy::too_many_arguments)]
+fn solve<T: Scalar>(
+    least_squares: bool,
+    m: usize,
+    n: usize,
+    qr: &[T],
+    ldq: usize,
+    tau: &[T],
+    nrhs: usize,
+    b: &mut [T],
+    ldb: usize,
+) -> Result<(), LapackError> {
+    let (mi, ni, nrhsi, ldqi, ldbi) = (
+        m as isize,
+        n as isize,
+        nrhs as isize,
+        ldq as isize,
+        ldb as isize,
+    );
+    let (qr_nd, tau) = (vector(qr), vector(tau));
+
+    if least_squares {
+        // X = R⁻¹ * (Qᴴ * B)[0..n]
+        ormqr(
+            &'L',
+            &'C',
+            &mi,
+            &nrhsi,
+            &ni,
+            &qr_nd,
+            &ldqi,
+            &tau,
+            &mut vector_mut(b),
+            &ldbi,
+        )?;
+        T::trsm('L', 'U', 'N', 'N', n, nrhs, T::one(), qr, ldq, b, ldb);
+    } else {
+        // X = Q * [R⁻ᴴ * B; 0]
+        T::trsm('L', 'U', 'C', 'N', n, nrhs, T::one(), qr, ldq, b, ldb);
+        for j in 0..nrhs {
+            for i in n..m {
+                b[i + j * ldb] = T::zero();
+            }
+        }
+        ormqr(
+            &'L',
+            &'N',
+            &mi,
+            &nrhsi,
+            &ni,
+            &qr_nd,
+            &ldqi,
+            &tau,
+            &mut vector_mut(b),
+            &ldbi,
+        )?;
+    }
+
+    Ok(())
+}
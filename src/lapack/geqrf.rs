@@ -0,0 +1,123 @@
+use alloc::vec;
+
+use nd_slice::NdSliceMut;
+
+use crate::contiguous::as_mut_slice;
+
+use super::larf::reflect;
+use super::larfb::reflect_block;
+use super::larfg::generate;
+use super::larft::triangular_factor;
+use super::{span, LapackError, Scalar, NB};
+
+/// Computes the QR factorization of a general matrix.
+///
+/// A = Q * R
+///
+/// where Q = H(0) * H(1) * ... * H(k - 1), k = min(m, n), is a product of elementary reflectors
+/// H(i) = I - tau[i] * v * vᴴ, with v[0..i] = 0 and v[i] = 1, and R is upper triangular (upper
+/// trapezoidal if m < n) with a real diagonal. The matrix is factored NB columns at a time, the
+/// rest of the matrix being updated with `larfb`.
+///
+/// Input:
+///     m: number of rows of A
+///     n: number of columns of A
+///     a: matrix in column-major order, lda by n
+///     lda: leading dimension of a, at least max(1, m)
+///     tau: vector of min(m, n) elements, need not be set
+/// Output:
+///     a: R on and above the diagonal, v[i + 1..m] of H(i) below the diagonal in column i
+///     tau: the scalar factors of the reflectors
+pub fn geqrf<T: Scalar>(
+    m: &isize,
+    n: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    tau: &mut NdSliceMut<'_, T, 1>,
+) -> Result<(), LapackError> {
+    let (m, n, lda) = (*m, *n, *lda);
+
+    if m < 0 {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if lda < m.max(1) {
+        return Err(LapackError::Argument(4));
+    }
+    let (m, n, lda) = (m as usize, n as usize, lda as usize);
+    let k = m.min(n);
+
+    if k == 0 {
+        return Ok(());
+    }
+
+    let a = as_mut_slice(a, span(m, n, lda));
+    let tau = as_mut_slice(tau, k);
+
+    if NB >= k {
+        geqr2(m, n, a, lda, tau);
+        return Ok(());
+    }
+
+    let mut t = vec![T::zero(); NB * NB];
+
+    for i in (0..k).step_by(NB) {
+        let ib = NB.min(k - i);
+
+        // factor the diagonal and subdiagonal blocks
+        geqr2(m - i, ib, &mut a[i + i * lda..], lda, &mut tau[i..i + ib]);
+
+        if i + ib < n {
+            // apply Hᴴ = (H(i) * ... * H(i + ib - 1))ᴴ to the columns right of the block
+            let (left, right) = a.split_at_mut((i + ib) * lda);
+            let v = &left[i + i * lda..];
+            triangular_factor(m - i, ib, v, lda, &tau[i..i + ib], &mut t, ib);
+            reflect_block(
+                true,
+                false,
+                m - i,
+                n - i - ib,
+                ib,
+                v,
+                lda,
+                &t,
+                ib,
+                &mut right[i..],
+                lda,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Computes the QR factorization of an m by n matrix one column at a time.
+fn geqr2<T: Scalar>(m: usize, n: usize, a: &mut [T], lda: usize, tau: &mut [T]) {
+    for i in 0..m.min(n) {
+        // H(i) annihilates A(i + 1..m, i)
+        let (alpha, x) = a[i + i * lda..].split_at_mut(1);
+        tau[i] = generate(m - i, &mut alpha[0], x, 1);
+
+        if i + 1 < n {
+            // apply H(i)ᴴ to the columns on its right, with v[i] = 1 stored in place of R(i, i)
+            let (left, right) = a.split_at_mut((i + 1) * lda);
+            let aii = left[i + i * lda];
+            left[i + i * lda] = T::one();
+
+            let v = &left[i + i * lda..i * lda + m];
+            reflect(
+                true,
+                m - i,
+                n - i - 1,
+                v,
+                tau[i].conj(),
+                &mut right[i..],
+                lda,
+            );
+
+            left[i + i * lda] = aii;
+        }
+    }
+}
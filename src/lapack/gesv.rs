@@ -0,0 +1,57 @@
+use nd_slice::NdSliceMut;
+
+use crate::contiguous::{as_slice, vector};
+
+use super::getrf::getrf;
+use super::getrs::getrs;
+use super::{span, LapackError, Scalar};
+
+/// Solves a system of linear equations with a general matrix.
+///
+/// A * X = B
+///
+/// A is factored as P * L * U with `getrf` and the factors are used to solve the system with
+/// `getrs`.
+///
+/// Input:
+///     n: order of A
+///     nrhs: number of columns of B
+///     a: matrix in column-major order, lda by n
+///     lda: leading dimension of a, at least max(1, n)
+///     ipiv: vector of n elements
+///     b: right-hand sides in column-major order, ldb by nrhs
+///     ldb: leading dimension of b, at least max(1, n)
+/// Output:
+///     a: the factors L and U from `getrf`
+///     ipiv: the pivot indices from `getrf`
+///     b: the solution X, unless A is singular
+///     returns `LapackError::Singular(i)` if U(i, i) is exactly zero, B then being unchanged
+#[allow(clippy::too_many_arguments)]
+pub fn gesv<T: Scalar>(
+    n: &isize,
+    nrhs: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    ipiv: &mut NdSliceMut<'_, isize, 1>,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) -> Result<(), LapackError> {
+    if *n < 0 {
+        return Err(LapackError::Argument(1));
+    }
+    if *nrhs < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if *lda < (*n).max(1) {
+        return Err(LapackError::Argument(4));
+    }
+    if *ldb < (*n).max(1) {
+        return Err(LapackError::Argument(7));
+    }
+
+    getrf(n, n, a, lda, ipiv)?;
+
+    let (len, k) = (span(*n as usize, *n as usize, *lda as usize), *n as usize);
+    let (a, ipiv) = (vector(as_slice(a, len)), vector(as_slice(ipiv, k)));
+    getrs(&'N', n, nrhs, &a, lda, &ipiv, b, ldb)
+}
@@ -0,0 +1,176 @@
+use alloc::vec;
+
+use nd_slice::NdSliceMut;
+use num_traits::{Float, One, Zero};
+
+use crate::contiguous::as_mut_slice;
+
+use super::bdsqr::{diagonalize, Vectors};
+use super::gebrd::{bidiagonalize, form_pt, form_q};
+use super::{span, LapackError, Scalar};
+
+/// Computes the singular value decomposition of a general matrix.
+///
+/// A = U * Σ * Vᴴ
+///
+/// where U is m by m and V is n by n, both unitary, and Σ is m by n and zero apart from its
+/// diagonal, which holds the singular values in descending order. The first min(m, n) columns of
+/// U and V are the left and right singular vectors of A.
+///
+/// A is reduced to bidiagonal form with `gebrd`, which is then diagonalized with `bdsqr`, U and
+/// Vᴴ starting as the Q and Pᴴ of the reduction if they are wanted. A is scaled first if its
+/// elements are too large or too small.
+///
+/// Input:
+///     jobu: 'A' for all m columns of U, 'S' for its first min(m, n) columns, 'N' for none
+///     jobvt: 'A' for all n rows of Vᴴ, 'S' for its first min(m, n) rows, 'N' for none
+///     m: number of rows of A
+///     n: number of columns of A
+///     a: matrix in column-major order, lda by n
+///     lda: leading dimension of a, at least max(1, m)
+///     s: vector of min(m, n) elements
+///     u: matrix in column-major order, ldu by m if jobu = 'A' and ldu by min(m, n) if
+///     jobu = 'S', not referenced if jobu = 'N'
+///     ldu: leading dimension of u, at least 1, and at least m if jobu is not 'N'
+///     vt: matrix in column-major order, ldvt by n, not referenced if jobvt = 'N'
+///     ldvt: leading dimension of vt, at least 1, at least n if jobvt = 'A' and at least
+///     min(m, n) if jobvt = 'S'
+/// Output:
+///     a: destroyed
+///     s: the singular values in descending order
+///     u: the columns of U asked for by jobu
+///     vt: the rows of Vᴴ asked for by jobvt
+///     returns `LapackError::NotConverged(i)` if `bdsqr` failed to converge, i off-diagonal
+///     elements of an intermediate bidiagonal form being nonzero
+#[allow(clippy::too_many_arguments)]
+pub fn gesvd<T: Scalar>(
+    jobu: &char,
+    jobvt: &char,
+    m: &isize,
+    n: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    s: &mut NdSliceMut<'_, T::Real, 1>,
+    u: &mut NdSliceMut<'_, T, 1>,
+    ldu: &isize,
+    vt: &mut NdSliceMut<'_, T, 1>,
+    ldvt: &isize,
+) -> Result<(), LapackError> {
+    let (m, n, lda, ldu, ldvt) = (*m, *n, *lda, *ldu, *ldvt);
+
+    let wntua = jobu.eq_ignore_ascii_case(&'A');
+    let wntus = jobu.eq_ignore_ascii_case(&'S');
+    let wntva = jobvt.eq_ignore_ascii_case(&'A');
+    let wntvs = jobvt.eq_ignore_ascii_case(&'S');
+    let minmn = m.min(n);
+
+    if !(wntua || wntus || jobu.eq_ignore_ascii_case(&'N')) {
+        return Err(LapackError::Argument(1));
+    }
+    if !(wntva || wntvs || jobvt.eq_ignore_ascii_case(&'N')) {
+        return Err(LapackError::Argument(2));
+    }
+    if m < 0 {
+        return Err(LapackError::Argument(3));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(4));
+    }
+    if lda < m.max(1) {
+        return Err(LapackError::Argument(6));
+    }
+    if ldu < 1 || ((wntua || wntus) && ldu < m) {
+        return Err(LapackError::Argument(9));
+    }
+    if ldvt < 1 || (wntva && ldvt < n) || (wntvs && ldvt < minmn) {
+        return Err(LapackError::Argument(11));
+    }
+    let (m, n, lda, ldu, ldvt) = (
+        m as usize,
+        n as usize,
+        lda as usize,
+        ldu as usize,
+        ldvt as usize,
+    );
+    let minmn = minmn as usize;
+
+    if minmn == 0 {
+        return Ok(());
+    }
+
+    // the number of columns of U and of rows of Vᴴ computed
+    let ncu = if wntua {
+        m
+    } else if wntus {
+        minmn
+    } else {
+        0
+    };
+    let nrvt = if wntva {
+        n
+    } else if wntvs {
+        minmn
+    } else {
+        0
+    };
+
+    let a = as_mut_slice(a, span(m, n, lda));
+    let s = as_mut_slice(s, minmn);
+    let u = as_mut_slice(u, span(m, ncu, ldu));
+    let vt = as_mut_slice(vt, span(nrvt, n, ldvt));
+
+    // scales A so that its largest element lies between smlnum and bignum
+    let smlnum = T::Real::min_positive_value().sqrt() / T::Real::epsilon();
+    let bignum = T::Real::one() / smlnum;
+
+    let anrm = (0..n)
+        .flat_map(|j| (0..m).map(move |i| i + j * lda))
+        .fold(T::Real::zero(), |anrm, k| anrm.max(a[k].abs()));
+    let cto = if anrm > T::Real::zero() && anrm < smlnum {
+        Some(smlnum)
+    } else if anrm > bignum {
+        Some(bignum)
+    } else {
+        None
+    };
+    if let Some(cto) = cto {
+        for j in 0..n {
+            for i in 0..m {
+                a[i + j * lda] = a[i + j * lda] * T::from_real(cto / anrm);
+            }
+        }
+    }
+
+    let mut e = vec![T::Real::zero(); minmn - 1];
+    let (mut tauq, mut taup) = (vec![T::zero(); minmn], vec![T::zero(); minmn]);
+    bidiagonalize(m, n, a, lda, s, &mut e, &mut tauq, &mut taup);
+
+    if ncu > 0 {
+        form_q(m, n, a, lda, &tauq, ncu, u, ldu);
+    }
+    if nrvt > 0 {
+        form_pt(m, n, a, lda, &taup, nrvt, vt, ldvt);
+    }
+
+    let mut vectors = Vectors {
+        ncvt: if nrvt > 0 { n } else { 0 },
+        vt,
+        ldvt,
+        nru: if ncu > 0 { m } else { 0 },
+        u,
+        ldu,
+        ncc: 0,
+        c: &mut [],
+        ldc: 1,
+    };
+    let info = diagonalize(m < n, minmn, s, &mut e, &mut vectors);
+
+    if let Some(cto) = cto {
+        s.iter_mut().for_each(|si| *si = *si * (anrm / cto));
+    }
+
+    match info {
+        Some(i) => Err(LapackError::NotConverged(i)),
+        None => Ok(()),
+    }
+}
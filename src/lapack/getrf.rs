@@ -0,0 +1,184 @@
+use alloc::vec::Vec;
+
+use nd_slice::NdSliceMut;
+
+use crate::contiguous::as_mut_slice;
+
+use super::laswp::interchange;
+use super::{span, LapackError, Scalar, NB};
+
+/// Computes the LU factorization of a general matrix, using partial pivoting with row
+/// interchanges.
+///
+/// A = P * L * U
+///
+/// where P is a permutation matrix, L is lower triangular with a unit diagonal (lower
+/// trapezoidal if m > n) and U is upper triangular (upper trapezoidal if m < n). The matrix is
+/// factored NB columns at a time, the rest of the matrix being updated with trsm and gemm.
+///
+/// Input:
+///     m: number of rows of A
+///     n: number of columns of A
+///     a: matrix in column-major order, lda by n
+///     lda: leading dimension of a, at least max(1, m)
+///     ipiv: vector of min(m, n) elements
+/// Output:
+///     a: L below the diagonal, without its unit diagonal, and U on and above it
+///     ipiv: pivot indices, row i was interchanged with row ipiv[i]
+///     returns `LapackError::Singular(i)` for the first i such that U(i, i) is exactly zero
+pub fn getrf<T: Scalar>(
+    m: &isize,
+    n: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    ipiv: &mut NdSliceMut<'_, isize, 1>,
+) -> Result<(), LapackError> {
+    let (m, n, lda) = (*m, *n, *lda);
+
+    if m < 0 {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if lda < m.max(1) {
+        return Err(LapackError::Argument(4));
+    }
+    let (m, n, lda) = (m as usize, n as usize, lda as usize);
+    let mn = m.min(n);
+
+    if mn == 0 {
+        return Ok(());
+    }
+
+    let a = as_mut_slice(a, span(m, n, lda));
+    let ipiv = as_mut_slice(ipiv, mn);
+
+    if NB >= mn {
+        return match getf2(m, n, a, lda, ipiv) {
+            Some(i) => Err(LapackError::Singular(i)),
+            None => Ok(()),
+        };
+    }
+
+    let mut info = None;
+    let mut a12 = Vec::with_capacity(NB * (n - NB));
+
+    for j in (0..mn).step_by(NB) {
+        let jb = NB.min(mn - j);
+
+        // factor the diagonal and subdiagonal blocks
+        let singular = getf2(m - j, jb, &mut a[j + j * lda..], lda, &mut ipiv[j..j + jb]);
+        if let (None, Some(i)) = (info, singular) {
+            info = Some(i + j);
+        }
+
+        // the pivots are relative to row j, make them relative to the whole matrix
+        for p in &mut ipiv[j..j + jb] {
+            *p += j as isize;
+        }
+
+        // apply the interchanges to the columns on either side of the block
+        interchange(a, lda, j, j, j + jb, ipiv, 1);
+
+        if j + jb < n {
+            let (left, right) = a.split_at_mut((j + jb) * lda);
+            let nr = n - j - jb;
+            interchange(right, lda, nr, j, j + jb, ipiv, 1);
+
+            // A12 = L11⁻¹ * A12
+            T::trsm(
+                'L',
+                'L',
+                'N',
+                'U',
+                jb,
+                nr,
+                T::one(),
+                &left[j + j * lda..],
+                lda,
+                &mut right[j..],
+                lda,
+            );
+
+            // A22 = A22 - A21 * A12, with A12 copied out as A12 and A22 share their columns
+            if j + jb < m {
+                a12.clear();
+                for col in right.chunks(lda).take(nr) {
+                    a12.extend_from_slice(&col[j..j + jb]);
+                }
+                T::gemm(
+                    'N',
+                    'N',
+                    m - j - jb,
+                    nr,
+                    jb,
+                    -T::one(),
+                    &left[j + jb + j * lda..],
+                    lda,
+                    &a12,
+                    jb,
+                    T::one(),
+                    &mut right[j + jb..],
+                    lda,
+                );
+            }
+        }
+    }
+
+    match info {
+        Some(i) => Err(LapackError::Singular(i)),
+        None => Ok(()),
+    }
+}
+
+/// Computes the LU factorization of an m by n matrix one column at a time, returning the first
+/// i such that U(i, i) is exactly zero.
+fn getf2<T: Scalar>(
+    m: usize,
+    n: usize,
+    a: &mut [T],
+    lda: usize,
+    ipiv: &mut [isize],
+) -> Option<usize> {
+    let mut info = None;
+    let mut row = Vec::with_capacity(n);
+
+    for j in 0..m.min(n) {
+        // find the pivot and test for singularity
+        let p = j + T::iamax(m - j, &a[j + j * lda..], 1);
+        ipiv[j] = p as isize;
+
+        if a[p + j * lda] != T::zero() {
+            interchange(a, lda, n, j, j + 1, &ipiv[..j + 1], 1);
+
+            if j + 1 < m {
+                let pivot = T::one() / a[j + j * lda];
+                T::scal(m - j - 1, pivot, &mut a[j + 1 + j * lda..], 1);
+            }
+        } else if info.is_none() {
+            info = Some(j);
+        }
+
+        if j + 1 < m && j + 1 < n {
+            // A22 = A22 - x * row, x being column j below the diagonal and row being row j
+            // right of it, copied out as row and A22 share their columns
+            let (left, right) = a.split_at_mut((j + 1) * lda);
+            row.clear();
+            row.extend(right.chunks(lda).take(n - j - 1).map(|col| col[j]));
+            T::ger(
+                m - j - 1,
+                n - j - 1,
+                -T::one(),
+                &left[j + 1 + j * lda..],
+                1,
+                &row,
+                1,
+                &mut right[j + 1..],
+                lda,
+            );
+        }
+    }
+
+    info
+}
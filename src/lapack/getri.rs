@@ -0,0 +1,117 @@
+use alloc::vec;
+
+use nd_slice::{NdSlice, NdSliceMut};
+
+use crate::contiguous::{as_mut_slice, as_slice, vector_mut};
+
+use super::trtri::trtri;
+use super::{span, LapackError, Scalar, NB};
+
+/// Computes the inverse of a general matrix, using its LU factorization computed by `getrf`.
+///
+/// A⁻¹ = U⁻¹ * L⁻¹ * Pᵀ
+///
+/// U is inverted with `trtri`, then X * L = U⁻¹ is solved for X = A⁻¹ * P NB columns at a time
+/// with gemm and trsm, and the columns of X are interchanged back.
+///
+/// Input:
+///     n: order of A
+///     a: the factors L and U from `getrf`, lda by n
+///     lda: leading dimension of a, at least max(1, n)
+///     ipiv: the pivot indices from `getrf`
+/// Output:
+///     a: the inverse of A, unless A is singular
+///     returns `LapackError::Singular(i)` if U(i, i) is exactly zero, A then being unchanged
+pub fn getri<T: Scalar>(
+    n: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    ipiv: &NdSlice<'_, isize, 1>,
+) -> Result<(), LapackError> {
+    let (n, lda) = (*n, *lda);
+
+    if n < 0 {
+        return Err(LapackError::Argument(1));
+    }
+    if lda < n.max(1) {
+        return Err(LapackError::Argument(3));
+    }
+    let (n, lda) = (n as usize, lda as usize);
+
+    if n == 0 {
+        return Ok(());
+    }
+
+    let a = as_mut_slice(a, span(n, n, lda));
+    let ipiv = as_slice(ipiv, n);
+
+    // A = U⁻¹
+    trtri(
+        &'U',
+        &'N',
+        &(n as isize),
+        &mut vector_mut(a),
+        &(lda as isize),
+    )?;
+
+    // the columns of L being solved for, with leading dimension n
+    let mut work = vec![T::zero(); n * NB.min(n)];
+
+    for j in (0..n).step_by(NB).rev() {
+        let jb = NB.min(n - j);
+
+        // move L(:, j..j + jb) to work, leaving the columns of U⁻¹
+        for jj in j..j + jb {
+            for i in jj + 1..n {
+                work[i + (jj - j) * n] = a[i + jj * lda];
+                a[i + jj * lda] = T::zero();
+            }
+        }
+
+        // X(:, j..j + jb) = (A(:, j..j + jb) - X(:, j + jb..) * L21) * L11⁻¹
+        if j + jb < n {
+            let (a1, a2) = a.split_at_mut((j + jb) * lda);
+            let (l21, m) = (&work[j + jb..], n - j - jb);
+            T::gemm(
+                'N',
+                'N',
+                n,
+                jb,
+                m,
+                -T::one(),
+                a2,
+                lda,
+                l21,
+                n,
+                T::one(),
+                &mut a1[j * lda..],
+                lda,
+            );
+        }
+        T::trsm(
+            'R',
+            'L',
+            'N',
+            'U',
+            n,
+            jb,
+            T::one(),
+            &work[j..],
+            n,
+            &mut a[j * lda..],
+            lda,
+        );
+    }
+
+    // A⁻¹ = X * Pᵀ
+    for j in (0..n - 1).rev() {
+        let p = ipiv[j] as usize;
+        if p != j {
+            for i in 0..n {
+                a.swap(i + j * lda, i + p * lda);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use nd_slice::{NdSlice, NdSliceMut};
+
+use crate::contiguous::{as_mut_slice, as_slice};
+
+use super::laswp::interchange;
+use super::{span, LapackError, Scalar};
+
+/// Solves a system of linear equations with a general matrix, using its LU factorization
+/// computed by `getrf`.
+///
+/// op(A) * X = B
+///
+/// Input:
+///     trans: 'N' for op(A) = A, 'T' for op(A) = Aᵀ, 'C' for op(A) = Aᴴ
+///     n: order of A
+///     nrhs: number of columns of B
+///     a: the factors L and U from `getrf`, lda by n
+///     lda: leading dimension of a, at least max(1, n)
+///     ipiv: the pivot indices from `getrf`
+///     b: right-hand sides in column-major order, ldb by nrhs
+///     ldb: leading dimension of b, at least max(1, n)
+/// Output:
+///     b: the solution X
+#[allow(clippy::too_many_arguments)]
+pub fn getrs<T: Scalar>(
+    trans: &char,
+    n: &isize,
+    nrhs: &isize,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    ipiv: &NdSlice<'_, isize, 1>,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) -> Result<(), LapackError> {
+    let (n, nrhs, lda, ldb) = (*n, *nrhs, *lda, *ldb);
+
+    let notrans = trans.eq_ignore_ascii_case(&'N');
+
+    if !(notrans || "TtCc".contains(*trans)) {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if nrhs < 0 {
+        return Err(LapackError::Argument(3));
+    }
+    if lda < n.max(1) {
+        return Err(LapackError::Argument(5));
+    }
+    if ldb < n.max(1) {
+        return Err(LapackError::Argument(8));
+    }
+    let (n, nrhs, lda, ldb) = (n as usize, nrhs as usize, lda as usize, ldb as usize);
+
+    if n == 0 || nrhs == 0 {
+        return Ok(());
+    }
+
+    let a = as_slice(a, span(n, n, lda));
+    let ipiv = as_slice(ipiv, n);
+    let b = as_mut_slice(b, span(n, nrhs, ldb));
+
+    if notrans {
+        // B = P * B, then X = U⁻¹ * L⁻¹ * B
+        interchange(b, ldb, nrhs, 0, n, ipiv, 1);
+        T::trsm('L', 'L', 'N', 'U', n, nrhs, T::one(), a, lda, b, ldb);
+        T::trsm('L', 'U', 'N', 'N', n, nrhs, T::one(), a, lda, b, ldb);
+    } else {
+        // X = Pᵀ * op(L)⁻¹ * op(U)⁻¹ * B
+        T::trsm('L', 'U', *trans, 'N', n, nrhs, T::one(), a, lda, b, ldb);
+        T::trsm('L', 'L', *trans, 'U', n, nrhs, T::one(), a, lda, b, ldb);
+        interchange(b, ldb, nrhs, 0, n, ipiv, -1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use nd_slice::NdSliceMut;
+
+use crate::contiguous::as_mut_slice;
+
+use super::{span, LapackError, Scalar};
+
+/// Solves a system of linear equations with a general tridiagonal matrix, using Gaussian
+/// elimination with partial pivoting.
+///
+/// A * X = B
+///
+/// A = P * L * U is factored with row interchanges, L being unit lower bidiagonal and U upper
+/// triangular with two superdiagonals, and B is transformed along with A.
+///
+/// Input:
+///     n: order of A
+///     nrhs: number of columns of B
+///     dl: vector of n - 1 elements, the subdiagonal of A
+///     d: vector of n elements, the diagonal of A
+///     du: vector of n - 1 elements, the superdiagonal of A
+///     b: right-hand sides in column-major order, ldb by nrhs
+///     ldb: leading dimension of b, at least max(1, n)
+/// Output:
+///     dl: the second superdiagonal of U in its first n - 2 elements
+///     d: the diagonal of U
+///     du: the first superdiagonal of U
+///     b: the solution X
+///     returns `LapackError::Singular(i)` if U(i, i) is exactly zero, the factorization and B
+///     then being incomplete
+#[allow(clippy::too_many_arguments)]
+pub fn gtsv<T: Scalar>(
+    n: &isize,
+    nrhs: &isize,
+    dl: &mut NdSliceMut<'_, T, 1>,
+    d: &mut NdSliceMut<'_, T, 1>,
+    du: &mut NdSliceMut<'_, T, 1>,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) -> Result<(), LapackError> {
+    let (n, nrhs, ldb) = (*n, *nrhs, *ldb);
+
+    if n < 0 {
+        return Err(LapackError::Argument(1));
+    }
+    if nrhs < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if ldb < n.max(1) {
+        return Err(LapackError::Argument(7));
+    }
+    let (n, nrhs, ldb) = (n as usize, nrhs as usize, ldb as usize);
+
+    if n == 0 {
+        return Ok(());
+    }
+
+    let dl = as_mut_slice(dl, n - 1);
+    let d = as_mut_slice(d, n);
+    let du = as_mut_slice(du, n - 1);
+    let b = as_mut_slice(b, span(n, nrhs, ldb));
+
+    for i in 0..n - 1 {
+        if d[i].abs() >= dl[i].abs() {
+            // no row interchange, eliminate dl[i] with row i
+            if d[i] == T::zero() {
+                return Err(LapackError::Singular(i));
+            }
+
+            let fact = dl[i] / d[i];
+            d[i + 1] = d[i + 1] - fact * du[i];
+            for j in 0..nrhs {
+                b[i + 1 + j * ldb] = b[i + 1 + j * ldb] - fact * b[i + j * ldb];
+            }
+            if i + 2 < n {
+                dl[i] = T::zero();
+            }
+        } else {
+            // interchange rows i and i + 1, then eliminate d[i] with row i + 1
+            let fact = d[i] / dl[i];
+            d[i] = dl[i];
+            let temp = d[i + 1];
+            d[i + 1] = du[i] - fact * temp;
+            if i + 2 < n {
+                dl[i] = du[i + 1];
+                du[i + 1] = -fact * dl[i];
+            }
+            du[i] = temp;
+
+            for j in 0..nrhs {
+                let temp = b[i + j * ldb];
+                b[i + j * ldb] = b[i + 1 + j * ldb];
+                b[i + 1 + j * ldb] = temp - fact * b[i + 1 + j * ldb];
+            }
+        }
+    }
+
+    if d[n - 1] == T::zero() {
+        return Err(LapackError::Singular(n - 1));
+    }
+
+    // back substitution with U
+    for j in 0..nrhs {
+        let b = &mut b[j * ldb..];
+
+        b[n - 1] = b[n - 1] / d[n - 1];
+        if n > 1 {
+            b[n - 2] = (b[n - 2] - du[n - 2] * b[n - 1]) / d[n - 2];
+        }
+        for i in (0..n.saturating_sub(2)).rev() {
+            b[i] = (b[i] - du[i] * b[i + 1] - dl[i] * b[i + 2]) / d[i];
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use nd_slice::NdSliceMut;
+
+use super::sytrf::factor_checked;
+use super::{LapackError, Scalar};
+
+/// Computes the factorization of a Hermitian matrix, using the Bunch-Kaufman diagonal pivoting
+/// method.
+///
+/// A = U * D * Uᴴ or A = L * D * Lᴴ
+///
+/// where U (L) is a product of permutation and unit upper (lower) triangular matrices and D is
+/// Hermitian and block diagonal with 1 by 1 and 2 by 2 blocks. The rest of the triangle is
+/// updated with herk after a 1 by 1 block. For real types this is `sytrf`.
+///
+/// Input:
+///     uplo: 'U' if the upper triangle of A is stored, 'L' if the lower triangle is
+///     n: order of A
+///     a: Hermitian matrix in column-major order, lda by n, the other triangle is not referenced
+///     and the imaginary parts of the diagonal are assumed to be zero
+///     lda: leading dimension of a, at least max(1, n)
+///     ipiv: vector of n elements
+/// Output:
+///     a: D and the multipliers of U or L in the stored triangle
+///     ipiv: the interchanges and the block structure of D, as for `sytrf`
+///     returns `LapackError::Singular(i)` for the first i such that D(i, i) is exactly zero
+pub fn hetrf<T: Scalar>(
+    uplo: &char,
+    n: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    ipiv: &mut NdSliceMut<'_, isize, 1>,
+) -> Result<(), LapackError> {
+    factor_checked(true, uplo, n, a, lda, ipiv)
+}
@@ -0,0 +1,34 @@
+use nd_slice::{NdSlice, NdSliceMut};
+
+use super::sytrs::solve_checked;
+use super::{LapackError, Scalar};
+
+/// Solves a system of linear equations with a Hermitian matrix, using its factorization
+/// computed by `hetrf`. For real types this is `sytrs`.
+///
+/// A * X = B
+///
+/// Input:
+///     uplo: 'U' if A = U * D * Uᴴ, 'L' if A = L * D * Lᴴ
+///     n: order of A
+///     nrhs: number of columns of B
+///     a: D and the multipliers from `hetrf`, lda by n
+///     lda: leading dimension of a, at least max(1, n)
+///     ipiv: the interchanges and the block structure of D from `hetrf`
+///     b: right-hand sides in column-major order, ldb by nrhs
+///     ldb: leading dimension of b, at least max(1, n)
+/// Output:
+///     b: the solution X
+#[allow(clippy::too_many_arguments)]
+pub fn hetrs<T: Scalar>(
+    uplo: &char,
+    n: &isize,
+    nrhs: &isize,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    ipiv: &NdSlice<'_, isize, 1>,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) -> Result<(), LapackError> {
+    solve_checked(true, uplo, n, nrhs, a, lda, ipiv, b, ldb)
+}
@@ -0,0 +1,159 @@
+use nd_slice::NdSliceMut;
+use num_traits::{Float, NumCast, One};
+
+use crate::contiguous::as_mut_slice;
+
+use super::Scalar;
+
+/// Maximum number of iterations of the power method.
+const ITMAX: isize = 5;
+
+/// Estimates the 1-norm of a square matrix, using Hager's method as refined by Higham and
+/// reverse communication for the matrix-vector products.
+///
+/// est ≈ ‖ A ‖₁
+///
+/// Start with kase = 0, then call `lacn2` again after each product it asks for, until it
+/// returns with kase = 0:
+///     kase = 1: overwrite x by A * x
+///     kase = 2: overwrite x by Aᴴ * x, Aᵀ for real types
+/// A is usually an inverse, applied with a factorization, as in `gecon`. The 1-norms of the
+/// vectors are computed with asum, as sums of |Re| + |Im| for complex types.
+///
+/// Input:
+///     n: order of A
+///     v: vector of n elements, workspace
+///     x: vector of n elements, set by the caller to the product asked for
+///     est: the estimate from the previous call, not set on the first one
+///     kase: 0 on the first call, the value returned by the previous call otherwise
+///     isave: vector of 3 elements, the state between calls
+/// Output:
+///     v: on the final return, v = A * w with est = ‖ v ‖₁ / ‖ w ‖₁, w not being returned
+///     x: the vector to be multiplied, unless kase = 0
+///     est: the estimate, a lower bound of ‖ A ‖₁ up to the measure of the norms
+///     kase: the product to compute, or 0 when the estimate is final
+///     isave: the state for the next call
+pub fn lacn2<T: Scalar>(
+    n: &isize,
+    v: &mut NdSliceMut<'_, T, 1>,
+    x: &mut NdSliceMut<'_, T, 1>,
+    est: &mut T::Real,
+    kase: &mut isize,
+    isave: &mut NdSliceMut<'_, isize, 1>,
+) {
+    let n = *n;
+
+    if n < 1 {
+        *kase = 0;
+        return;
+    }
+    let n = n as usize;
+
+    let v = as_mut_slice(v, n);
+    let x = as_mut_slice(x, n);
+    let isave = as_mut_slice(isave, 3);
+
+    let real = |k: usize| <T::Real as NumCast>::from(k).unwrap();
+    let safmin = T::Real::min_positive_value();
+
+    // x = sign(x), elements too small to have a sign becoming 1
+    let sign = |x: &mut [T]| {
+        for xi in x.iter_mut() {
+            let absxi = xi.abs();
+            *xi = if absxi > safmin {
+                *xi / T::from_real(absxi)
+            } else {
+                T::one()
+            };
+        }
+    };
+
+    // x = e_j, asking for column j of A
+    let unit = |x: &mut [T], j: usize, kase: &mut isize, isave: &mut [isize]| {
+        x.iter_mut().for_each(|xi| *xi = T::zero());
+        x[j] = T::one();
+        *kase = 1;
+        isave[0] = 3;
+    };
+
+    // x = (-1)^i * (1 + i / (n - 1)), a vector which catches what the power method can miss
+    let alternating = |x: &mut [T], kase: &mut isize, isave: &mut [isize]| {
+        let mut altsgn = T::one();
+        for (i, xi) in x.iter_mut().enumerate() {
+            let ratio = real(i) / real((n - 1).max(1));
+            *xi = altsgn * T::from_real(T::Real::one() + ratio);
+            altsgn = -altsgn;
+        }
+        *kase = 1;
+        isave[0] = 5;
+    };
+
+    if *kase == 0 {
+        x.iter_mut()
+            .for_each(|xi| *xi = T::from_real(T::Real::one() / real(n)));
+        *kase = 1;
+        isave[0] = 1;
+        return;
+    }
+
+    match isave[0] {
+        // x = A * x with x = (1 / n, ..., 1 / n)
+        1 => {
+            if n == 1 {
+                v[0] = x[0];
+                *est = v[0].abs();
+                *kase = 0;
+                return;
+            }
+            *est = T::asum(n, x, 1);
+
+            sign(x);
+            *kase = 2;
+            isave[0] = 2;
+        }
+        // x = Aᴴ * sign(A * x)
+        2 => {
+            isave[1] = T::iamax(n, x, 1) as isize;
+            isave[2] = 2;
+            unit(x, isave[1] as usize, kase, isave);
+        }
+        // x = A * e_j
+        3 => {
+            v.copy_from_slice(x);
+            let estold = *est;
+            *est = T::asum(n, v, 1);
+
+            if *est <= estold {
+                alternating(x, kase, isave);
+                return;
+            }
+
+            sign(x);
+            *kase = 2;
+            isave[0] = 4;
+        }
+        // x = Aᴴ * sign(A * e_j)
+        4 => {
+            let jlast = isave[1] as usize;
+            isave[1] = T::iamax(n, x, 1) as isize;
+            let j = isave[1] as usize;
+
+            if x[jlast].abs() != x[j].abs() && isave[2] < ITMAX {
+                isave[2] += 1;
+                unit(x, j, kase, isave);
+            } else {
+                alternating(x, kase, isave);
+            }
+        }
+        // x = A * x with the alternating vector
+        _ => {
+            let temp = T::asum(n, x, 1) / real(3 * n);
+            let temp = temp + temp;
+            if temp > *est {
+                v.copy_from_slice(x);
+                *est = temp;
+            }
+            *kase = 0;
+        }
+    }
+}
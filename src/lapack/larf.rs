@@ -0,0 +1,87 @@
+use alloc::{vec, vec::Vec};
+
+use nd_slice::{NdSlice, NdSliceMut};
+
+use crate::contiguous::{as_mut_slice, as_slice};
+
+use super::{span, Scalar};
+
+/// Applies an elementary reflector H = I - tau * v * vᴴ to a matrix from the left or the right.
+///
+/// C = H * C or C = C * H
+///
+/// Hᴴ is applied by passing conjugate(tau) instead of tau.
+///
+/// Input:
+///     side: 'L' for C = H * C, 'R' for C = C * H
+///     m: number of rows of C
+///     n: number of columns of C
+///     v: vector of m elements if side = 'L' and n elements otherwise
+///     incv: increment for v, not zero
+///     tau: scalar, H is the identity if tau is zero
+///     c: matrix in column-major order, ldc by n
+///     ldc: leading dimension of c, at least max(1, m)
+/// Output:
+///     c: modified c
+#[allow(clippy::too_many_arguments)]
+pub fn larf<T: Scalar>(
+    side: &char,
+    m: &isize,
+    n: &isize,
+    v: &NdSlice<'_, T, 1>,
+    incv: &isize,
+    tau: &T,
+    c: &mut NdSliceMut<'_, T, 1>,
+    ldc: &isize,
+) {
+    let (m, n, incv, ldc) = (*m, *n, *incv, *ldc);
+
+    let left = side.eq_ignore_ascii_case(&'L');
+
+    if !(left || side.eq_ignore_ascii_case(&'R')) {
+        return;
+    }
+    if m <= 0 || n <= 0 || incv == 0 || ldc < m {
+        return;
+    }
+    let (m, n, ldc) = (m as usize, n as usize, ldc as usize);
+    let (len, inc) = (if left { m } else { n }, incv.unsigned_abs());
+
+    // v with unit increment, its elements last to first if incv is negative
+    let v = as_slice(v, (len - 1) * inc + 1);
+    let v: Vec<T> = if incv > 0 {
+        v.iter().step_by(inc).copied().collect()
+    } else {
+        v.iter().step_by(inc).rev().copied().collect()
+    };
+
+    reflect(left, m, n, &v, *tau, as_mut_slice(c, span(m, n, ldc)), ldc);
+}
+
+/// Applies H = I - tau * v * vᴴ to an m by n matrix, v having a unit increment.
+pub(super) fn reflect<T: Scalar>(
+    left: bool,
+    m: usize,
+    n: usize,
+    v: &[T],
+    tau: T,
+    c: &mut [T],
+    ldc: usize,
+) {
+    if tau == T::zero() || m == 0 || n == 0 {
+        return;
+    }
+    let (zero, one) = (T::zero(), T::one());
+
+    if left {
+        // w = Cᴴ * v, then C = C - tau * v * wᴴ
+        let mut w = vec![zero; n];
+        T::gemm('C', 'N', n, 1, m, one, c, ldc, v, m, zero, &mut w, n);
+        T::gemm('N', 'C', m, n, 1, -tau, v, m, &w, n, one, c, ldc);
+    } else {
+        // w = C * v, then C = C - tau * w * vᴴ
+        let mut w = vec![zero; m];
+        T::gemm('N', 'N', m, 1, n, one, c, ldc, v, n, zero, &mut w, m);
+        T::gemm('N', 'C', m, n, 1, -tau, &w, m, v, n, one, c, ldc);
+    }
+}
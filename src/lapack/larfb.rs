@@ -0,0 +1,203 @@
+use alloc::vec;
+
+use nd_slice::{NdSlice, NdSliceMut};
+
+use crate::contiguous::{as_mut_slice, as_slice};
+
+use super::{span, Scalar};
+
+/// Applies a block reflector H = I - V * T * Vᴴ, or its conjugate transpose, to a matrix from
+/// the left or the right.
+///
+/// C = op(H) * C or C = C * op(H)
+///
+/// Only the forward, columnwise variant is provided: V is unit lower trapezoidal, its diagonal
+/// and upper triangle are not referenced, and T is the upper triangular factor from `larft`.
+///
+/// Input:
+///     side: 'L' for C = op(H) * C, 'R' for C = C * op(H)
+///     trans: 'N' for op(H) = H, 'C' for op(H) = Hᴴ ('T' is accepted for real types)
+///     m: number of rows of C
+///     n: number of columns of C
+///     k: order of T, the number of elementary reflectors
+///     v: matrix in column-major order, ldv by k
+///     ldv: leading dimension of v, at least max(1, m) if side = 'L' and max(1, n) otherwise
+///     t: upper triangular matrix in column-major order, ldt by k
+///     ldt: leading dimension of t, at least k
+///     c: matrix in column-major order, ldc by n
+///     ldc: leading dimension of c, at least max(1, m)
+/// Output:
+///     c: modified c
+#[allow(clippy::too_many_arguments)]
+pub fn larfb<T: Scalar>(
+    side: &char,
+    trans: &char,
+    m: &isize,
+    n: &isize,
+    k: &isize,
+    v: &NdSlice<'_, T, 1>,
+    ldv: &isize,
+    t: &NdSlice<'_, T, 1>,
+    ldt: &isize,
+    c: &mut NdSliceMut<'_, T, 1>,
+    ldc: &isize,
+) {
+    let (m, n, k, ldv, ldt, ldc) = (*m, *n, *k, *ldv, *ldt, *ldc);
+
+    let left = side.eq_ignore_ascii_case(&'L');
+    let notrans = trans.eq_ignore_ascii_case(&'N');
+    let nq = if left { m } else { n };
+
+    if !(left || side.eq_ignore_ascii_case(&'R')) || !(notrans || "TtCc".contains(*trans)) {
+        return;
+    }
+    if m <= 0 || n <= 0 || k <= 0 || k > nq || ldv < nq || ldt < k || ldc < m {
+        return;
+    }
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (nq, ldv, ldt, ldc) = (nq as usize, ldv as usize, ldt as usize, ldc as usize);
+
+    reflect_block(
+        left,
+        notrans,
+        m,
+        n,
+        k,
+        as_slice(v, span(nq, k, ldv)),
+        ldv,
+        as_slice(t, span(k, k, ldt)),
+        ldt,
+        as_mut_slice(c, span(m, n, ldc)),
+        ldc,
+    );
+}
+
+/// Applies H or Hᴴ as `larfb` does.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn reflect_block<T: Scalar>(
+    left: bool,
+    notrans: bool,
+    m: usize,
+    n: usize,
+    k: usize,
+    v: &[T],
+    ldv: usize,
+    t: &[T],
+    ldt: usize,
+    c: &mut [T],
+    ldc: usize,
+) {
+    if m == 0 || n == 0 || k == 0 {
+        return;
+    }
+    let (zero, one) = (T::zero(), T::one());
+
+    if left {
+        // W = Cᴴ * V = C1ᴴ * V1 + C2ᴴ * V2, C1 being the first k rows of C
+        let mut w = vec![zero; n * k];
+        for j in 0..k {
+            for i in 0..n {
+                w[i + j * n] = c[j + i * ldc].conj();
+            }
+        }
+        T::trmm('R', 'L', 'N', 'U', n, k, one, v, ldv, &mut w, n);
+        if m > k {
+            T::gemm(
+                'C',
+                'N',
+                n,
+                k,
+                m - k,
+                one,
+                &c[k..],
+                ldc,
+                &v[k..],
+                ldv,
+                one,
+                &mut w,
+                n,
+            );
+        }
+
+        // W = W * Tᴴ for H, W * T for Hᴴ, then C = C - V * Wᴴ
+        let transt = if notrans { 'C' } else { 'N' };
+        T::trmm('R', 'U', transt, 'N', n, k, one, t, ldt, &mut w, n);
+        if m > k {
+            T::gemm(
+                'N',
+                'C',
+                m - k,
+                n,
+                k,
+                -one,
+                &v[k..],
+                ldv,
+                &w,
+                n,
+                one,
+                &mut c[k..],
+                ldc,
+            );
+        }
+        T::trmm('R', 'L', 'C', 'U', n, k, one, v, ldv, &mut w, n);
+
+        for j in 0..k {
+            for i in 0..n {
+                c[j + i * ldc] = c[j + i * ldc] - w[i + j * n].conj();
+            }
+        }
+    } else {
+        // W = C * V = C1 * V1 + C2 * V2, C1 being the first k columns of C
+        let mut w = vec![zero; m * k];
+        for j in 0..k {
+            w[j * m..(j + 1) * m].copy_from_slice(&c[j * ldc..j * ldc + m]);
+        }
+        T::trmm('R', 'L', 'N', 'U', m, k, one, v, ldv, &mut w, m);
+        if n > k {
+            T::gemm(
+                'N',
+                'N',
+                m,
+                k,
+                n - k,
+                one,
+                &c[k * ldc..],
+                ldc,
+                &v[k..],
+                ldv,
+                one,
+                &mut w,
+                m,
+            );
+        }
+
+        // W = W * op(T), then C = C - W * Vᴴ
+        let trans = if notrans { 'N' } else { 'C' };
+        T::trmm('R', 'U', trans, 'N', m, k, one, t, ldt, &mut w, m);
+        if n > k {
+            let c2 = &mut c[k * ldc..];
+            T::gemm(
+                'N',
+                'C',
+                m,
+                n - k,
+                k,
+                -one,
+                &w,
+                m,
+                &v[k..],
+                ldv,
+                one,
+                c2,
+                ldc,
+            );
+        }
+        T::trmm('R', 'L', 'C', 'U', m, k, one, v, ldv, &mut w, m);
+
+        for j in 0..k {
+            for i in 0..m {
+                c[i + j * ldc] = c[i + j * ldc] - w[i + j * m];
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use nd_slice::NdSliceMut;
+use num_traits::{Float, One, Zero};
+
+use crate::contiguous::as_mut_slice;
+
+use super::Scalar;
+
+/// Generates an elementary reflector H such that
+///
+/// Hᴴ * [alpha] = [beta]
+///      [  x  ]   [  0 ]
+///
+/// where beta is real and H = I - tau * [1] * [1 vᴴ]
+///                                      [v]
+///
+/// If x is zero and alpha is real, tau is zero and H is the identity. Otherwise
+/// 1 <= re(tau) <= 2 and |tau - 1| <= 1.
+///
+/// Input:
+///     n: order of H
+///     alpha: scalar
+///     x: vector of n - 1 elements
+///     incx: increment for x, positive
+///     tau: scalar, need not be set
+/// Output:
+///     alpha: beta
+///     x: v
+///     tau: scalar
+pub fn larfg<T: Scalar>(
+    n: &isize,
+    alpha: &mut T,
+    x: &mut NdSliceMut<'_, T, 1>,
+    incx: &isize,
+    tau: &mut T,
+) {
+    let (n, incx) = (*n, *incx);
+
+    if n <= 0 || incx <= 0 {
+        *tau = T::zero();
+        return;
+    }
+    let (n, incx) = (n as usize, incx as usize);
+
+    let len = if n > 1 { (n - 2) * incx + 1 } else { 0 };
+    *tau = generate(n, alpha, as_mut_slice(x, len), incx);
+}
+
+/// Generates the reflector of `larfg`, returning tau.
+pub(super) fn generate<T: Scalar>(n: usize, alpha: &mut T, x: &mut [T], incx: usize) -> T {
+    if n == 0 {
+        return T::zero();
+    }
+
+    let mut xnorm = T::nrm2(n - 1, x, incx);
+    if xnorm == T::Real::zero() && *alpha == T::from_real(alpha.re()) {
+        return T::zero();
+    }
+
+    // beta = -sign(‖(alpha, x)‖, re(alpha))
+    let norm = |alpha: T, xnorm: T::Real| -alpha.abs().hypot(xnorm) * alpha.re().signum();
+    let mut beta = norm(*alpha, xnorm);
+
+    let safmin = T::Real::min_positive_value() / T::Real::epsilon();
+    let mut knt = 0;
+
+    if beta.abs() < safmin {
+        // beta may be inaccurate, scale x and recompute it
+        let rsafmn = T::Real::one() / safmin;
+        loop {
+            knt += 1;
+            T::scal(n - 1, T::from_real(rsafmn), x, incx);
+            beta = beta * rsafmn;
+            *alpha = *alpha * T::from_real(rsafmn);
+
+            if beta.abs() >= safmin || knt == 20 {
+                break;
+            }
+        }
+
+        xnorm = T::nrm2(n - 1, x, incx);
+        beta = norm(*alpha, xnorm);
+    }
+
+    let tau = (T::from_real(beta) - *alpha) / T::from_real(beta);
+    T::scal(n - 1, T::one() / (*alpha - T::from_real(beta)), x, incx);
+
+    for _ in 0..knt {
+        beta = beta * safmin;
+    }
+    *alpha = T::from_real(beta);
+
+    tau
+}
@@ -0,0 +1,103 @@
+use nd_slice::{NdSlice, NdSliceMut};
+
+use crate::contiguous::{as_mut_slice, as_slice};
+
+use super::{span, Scalar};
+
+/// Forms the triangular factor T of a block reflector of order n made of k elementary
+/// reflectors.
+///
+/// H = H(0) * H(1) * ... * H(k - 1) = I - V * T * Vᴴ
+///
+/// Only the forward, columnwise variant is provided: column i of V holds the vector of H(i),
+/// as returned by `geqrf`. V is unit lower trapezoidal, its diagonal and upper triangle are not
+/// referenced.
+///
+/// Input:
+///     n: order of H
+///     k: number of elementary reflectors, at most n
+///     v: matrix in column-major order, ldv by k
+///     ldv: leading dimension of v, at least max(1, n)
+///     tau: vector of k elements, the scalar factors of the reflectors
+///     t: matrix in column-major order, ldt by k, need not be set
+///     ldt: leading dimension of t, at least k
+/// Output:
+///     t: the upper triangular factor T, its strict lower triangle is not referenced
+#[allow(clippy::too_many_arguments)]
+pub fn larft<T: Scalar>(
+    n: &isize,
+    k: &isize,
+    v: &NdSlice<'_, T, 1>,
+    ldv: &isize,
+    tau: &NdSlice<'_, T, 1>,
+    t: &mut NdSliceMut<'_, T, 1>,
+    ldt: &isize,
+) {
+    let (n, k, ldv, ldt) = (*n, *k, *ldv, *ldt);
+
+    if n < 0 || k <= 0 || k > n || ldv < n.max(1) || ldt < k {
+        return;
+    }
+    let (n, k, ldv, ldt) = (n as usize, k as usize, ldv as usize, ldt as usize);
+
+    triangular_factor(
+        n,
+        k,
+        as_slice(v, span(n, k, ldv)),
+        ldv,
+        as_slice(tau, k),
+        as_mut_slice(t, span(k, k, ldt)),
+        ldt,
+    );
+}
+
+/// Forms T as `larft` does.
+pub(super) fn triangular_factor<T: Scalar>(
+    n: usize,
+    k: usize,
+    v: &[T],
+    ldv: usize,
+    tau: &[T],
+    t: &mut [T],
+    ldt: usize,
+) {
+    for i in 0..k {
+        if tau[i] == T::zero() {
+            // H(i) is the identity
+            for j in 0..=i {
+                t[j + i * ldt] = T::zero();
+            }
+            continue;
+        }
+
+        // T(0..i, i) = -tau(i) * V(i.., 0..i)ᴴ * V(i.., i), V(i, i) being 1
+        for j in 0..i {
+            t[j + i * ldt] = -tau[i] * v[i + j * ldv].conj();
+        }
+        if i > 0 && i + 1 < n {
+            let (v2, vi) = (&v[i + 1..], &v[i + 1 + i * ldv..]);
+            T::gemm(
+                'C',
+                'N',
+                i,
+                1,
+                n - i - 1,
+                -tau[i],
+                v2,
+                ldv,
+                vi,
+                ldv,
+                T::one(),
+                &mut t[i * ldt..],
+                ldt,
+            );
+        }
+
+        // T(0..i, i) = T(0..i, 0..i) * T(0..i, i)
+        if i > 0 {
+            let (left, right) = t.split_at_mut(i * ldt);
+            T::trmm('L', 'U', 'N', 'N', i, 1, T::one(), left, ldt, right, ldt);
+        }
+        t[i + i * ldt] = tau[i];
+    }
+}
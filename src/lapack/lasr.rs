@@ -0,0 +1,126 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_traits::{One, Zero};
+
+use crate::contiguous::{as_mut_slice, as_slice};
+
+use super::{span, Scalar};
+
+/// Applies a sequence of plane rotations to a general matrix from the left or the right.
+///
+/// A = P * A or A = A * Pᵀ
+///
+/// where P = P(z - 2) * ... * P(1) * P(0) if direct = 'F' and P = P(0) * P(1) * ... * P(z - 2)
+/// if direct = 'B', z being m if side = 'L' and n otherwise. P(k) rotates the plane of two rows
+/// (columns if side = 'R') chosen by pivot, (x, y) becoming
+///
+/// [ c[k] s[k]] * [x]
+/// [-s[k] c[k]]   [y]
+///
+/// as with `rot`. The cosines and sines are real, also for complex matrices.
+///
+/// Input:
+///     side: 'L' for A = P * A, 'R' for A = A * Pᵀ
+///     pivot: 'V' for the plane (k, k + 1), 'T' for (0, k + 1), 'B' for (k, z - 1)
+///     direct: 'F' to apply P(0) first, 'B' to apply P(z - 2) first
+///     m: number of rows of A
+///     n: number of columns of A
+///     c: vector of z - 1 cosines
+///     s: vector of z - 1 sines
+///     a: matrix in column-major order, lda by n
+///     lda: leading dimension of a, at least max(1, m)
+/// Output:
+///     a: the rotated matrix
+#[allow(clippy::too_many_arguments)]
+pub fn lasr<T: Scalar>(
+    side: &char,
+    pivot: &char,
+    direct: &char,
+    m: &isize,
+    n: &isize,
+    c: &NdSlice<'_, T::Real, 1>,
+    s: &NdSlice<'_, T::Real, 1>,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+) {
+    let (m, n, lda) = (*m, *n, *lda);
+
+    let left = side.eq_ignore_ascii_case(&'L');
+    let forward = direct.eq_ignore_ascii_case(&'F');
+    let pivot = pivot.to_ascii_uppercase();
+
+    if !(left || side.eq_ignore_ascii_case(&'R')) || !"VTB".contains(pivot) {
+        return;
+    }
+    if !(forward || direct.eq_ignore_ascii_case(&'B')) {
+        return;
+    }
+    if m <= 0 || n <= 0 || lda < m {
+        return;
+    }
+    let (m, n, lda) = (m as usize, n as usize, lda as usize);
+
+    let k = if left { m } else { n } - 1;
+    let (c, s) = (as_slice(c, k), as_slice(s, k));
+
+    rotate(
+        left,
+        pivot,
+        forward,
+        m,
+        n,
+        c,
+        s,
+        as_mut_slice(a, span(m, n, lda)),
+        lda,
+    );
+}
+
+/// Applies the rotations as `lasr` does, for an upper case pivot.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn rotate<T: Scalar>(
+    left: bool,
+    pivot: char,
+    forward: bool,
+    m: usize,
+    n: usize,
+    c: &[T::Real],
+    s: &[T::Real],
+    a: &mut [T],
+    lda: usize,
+) {
+    let (z, len) = if left { (m, n) } else { (n, m) };
+    if z < 2 {
+        return;
+    }
+
+    let mut apply = |k: usize| {
+        if c[k] == T::Real::one() && s[k] == T::Real::zero() {
+            return;
+        }
+        let (ck, sk) = (T::from_real(c[k]), T::from_real(s[k]));
+
+        let (p, q) = match pivot {
+            'V' => (k, k + 1),
+            'T' => (0, k + 1),
+            _ => (k, z - 1),
+        };
+
+        for i in 0..len {
+            let (ip, iq) = if left {
+                (p + i * lda, q + i * lda)
+            } else {
+                (i + p * lda, i + q * lda)
+            };
+
+            let (x, y) = (a[ip], a[iq]);
+            a[ip] = ck * x + sk * y;
+            a[iq] = ck * y - sk * x;
+        }
+    };
+
+    if forward {
+        (0..z - 1).for_each(&mut apply);
+    } else {
+        (0..z - 1).rev().for_each(&mut apply);
+    }
+}
@@ -0,0 +1,74 @@
+use nd_slice::{NdSlice, NdSliceMut};
+
+use crate::contiguous::{as_mut_slice, as_slice};
+
+use super::span;
+
+/// Performs a series of row interchanges on a general matrix.
+///
+/// For each row i from k1 to k2 - 1, in that order if incx > 0 and in reverse order if incx < 0,
+/// row i of A is interchanged with row ipiv[k1 + (i - k1) * |incx|].
+///
+/// Input:
+///     n: number of columns of A
+///     a: matrix in column-major order, lda by n
+///     lda: leading dimension of a
+///     k1: first row to interchange
+///     k2: one past the last row to interchange
+///     ipiv: row indices, counting from 0
+///     incx: increment for ipiv, nothing is done if it is zero
+/// Output:
+///     a: the permuted matrix
+pub fn laswp<T: Copy>(
+    n: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    k1: &isize,
+    k2: &isize,
+    ipiv: &NdSlice<'_, isize, 1>,
+    incx: &isize,
+) {
+    let (n, lda, k1, k2, incx) = (*n, *lda, *k1, *k2, *incx);
+
+    if n <= 0 || lda <= 0 || k1 < 0 || k2 <= k1 || incx == 0 {
+        return;
+    }
+    let (n, lda, k1, k2) = (n as usize, lda as usize, k1 as usize, k2 as usize);
+
+    let ipiv = as_slice(ipiv, k1 + (k2 - 1 - k1) * incx.unsigned_abs() + 1);
+    let rows = (0..k2)
+        .chain(ipiv.iter().map(|&p| p as usize))
+        .max()
+        .unwrap_or(0);
+    let a = as_mut_slice(a, span(rows + 1, n, lda));
+
+    interchange(a, lda, n, k1, k2, ipiv, incx);
+}
+
+/// laswp on a matrix already viewed as a slice.
+pub(super) fn interchange<T>(
+    a: &mut [T],
+    lda: usize,
+    n: usize,
+    k1: usize,
+    k2: usize,
+    ipiv: &[isize],
+    incx: isize,
+) {
+    let step = incx.unsigned_abs();
+
+    let mut swap = |i: usize| {
+        let p = ipiv[k1 + (i - k1) * step] as usize;
+        if p != i {
+            for j in 0..n {
+                a.swap(i + j * lda, p + j * lda);
+            }
+        }
+    };
+
+    if incx > 0 {
+        (k1..k2).for_each(&mut swap);
+    } else {
+        (k1..k2).rev().for_each(&mut swap);
+    }
+}
@@ -0,0 +1,724 @@
+//! LAPACK routines for solving systems of linear equations, least squares problems, symmetric
+//! eigenvalue problems and singular value decompositions, built on the BLAS routines of this
+//! crate and enabled by the `alloc` feature.
+//!
+//! The routines follow the netlib interfaces: matrices are stored in column-major order with a
+//! leading dimension, and the element type is any of f32, f64, Complex<f32> and Complex<f64>.
+//! Unlike netlib, row indices count from 0, pivot indices included, and the `info` argument is
+//! replaced by a `LapackError`.
+//!
+//! ```
+//! use naive_blas::lapack::gesv::gesv;
+//! use nd_slice::NdSliceMut;
+//!
+//! // [2 1] x = [3]
+//! // [4 5]     [9]
+//! let mut a = [2.0, 4.0, 1.0, 5.0];
+//! let mut b = [3.0, 9.0];
+//! let mut ipiv = [0; 2];
+//!
+//! gesv(
+//!     &2,
+//!     &1,
+//!     &mut NdSliceMut::new_row_ordered(&mut a, [4]).unwrap(),
+//!     &2,
+//!     &mut NdSliceMut::new_row_ordered(&mut ipiv, [2]).unwrap(),
+//!     &mut NdSliceMut::new_row_ordered(&mut b, [2]).unwrap(),
+//!     &2,
+//! )
+//! .unwrap();
+//! assert_eq!(b, [1.0, 1.0]);
+//! ```
+
+use core::fmt;
+use core::ops::Neg;
+
+use num_complex::Complex;
+use num_traits::{Float, Num};
+
+use crate::contiguous::{vector, vector_mut};
+use crate::level_1::{asum, axpy, dot, dotc, iamax, nrm2, scal};
+use crate::level_2::{ger, symv, syr2, tbsv};
+use crate::level_3::{gemm, herk, syrk, trmm, trsm};
+
+pub mod bdsqr;
+pub mod ch1dn;
+pub mod ch1up;
+pub mod gbsv;
+pub mod gbtrf;
+pub mod gbtrs;
+pub mod gebrd;
+pub mod gecon;
+pub mod gels;
+pub mod geqrf;
+pub mod gesv;
+pub mod gesvd;
+pub mod getrf;
+pub mod getri;
+pub mod getrs;
+pub mod gtsv;
+pub mod hetrf;
+pub mod hetrs;
+pub mod lacn2;
+pub mod larf;
+pub mod larfb;
+pub mod larfg;
+pub mod larft;
+pub mod lasr;
+pub mod laswp;
+pub mod orgqr;
+pub mod ormqr;
+pub mod pocon;
+pub mod posv;
+pub mod potrf;
+pub mod potrs;
+pub mod ptsv;
+pub mod qr1up;
+pub mod steqr;
+pub mod syev;
+pub mod sysv;
+pub mod sytrd;
+pub mod sytrf;
+pub mod sytrs;
+pub mod trtri;
+
+/// Number of columns the blocked routines factor at a time.
+const NB: usize = 32;
+
+/// Error returned by the LAPACK routines, in place of netlib's `info`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LapackError {
+    /// the argument at this position, counting from 1, has an illegal value (info = -i)
+    Argument(usize),
+    /// the diagonal element i of the triangular factor (U of an LU or R of a QR factorization)
+    /// or of the block diagonal factor D of an LDLᵀ factorization is exactly zero, so the matrix
+    /// is singular; the factorization itself is complete (info = i + 1)
+    Singular(usize),
+    /// the leading minor of order i + 1 is not positive, so the matrix is not positive definite
+    /// and its factorization could not be completed (info = i + 1)
+    NotPositiveDefinite(usize),
+    /// the iteration failed to converge, i off-diagonal elements of the tridiagonal or
+    /// bidiagonal matrix it was working on being still nonzero (info = i)
+    NotConverged(usize),
+}
+
+impl fmt::Display for LapackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LapackError::Argument(i) => write!(f, "argument {} has an illegal value", i),
+            LapackError::Singular(i) => {
+                write!(f, "diagonal element {} of the factor is exactly zero", i)
+            }
+            LapackError::NotPositiveDefinite(i) => {
+                write!(f, "the leading minor of order {} is not positive", i + 1)
+            }
+            LapackError::NotConverged(i) => {
+                write!(f, "{} off-diagonal elements did not converge to zero", i)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LapackError {}
+
+/// Element types of the LAPACK routines: f32, f64 and their complex counterparts.
+///
+/// The hidden methods forward to the matching real or complex BLAS routines, with the same
+/// arguments as plain values and slices.
+pub trait Scalar: Copy + Num + Neg<Output = Self> + 'static {
+    /// The type of the real part, Self for real types.
+    type Real: Float;
+
+    /// Returns the complex conjugate, or the value itself for real types.
+    fn conj(self) -> Self;
+
+    /// Returns the real part.
+    fn re(self) -> Self::Real;
+
+    /// Returns the real number r as a Self.
+    fn from_real(r: Self::Real) -> Self;
+
+    /// Returns the absolute value, the modulus for complex types.
+    fn abs(self) -> Self::Real;
+
+    #[doc(hidden)]
+    fn iamax(n: usize, x: &[Self], incx: usize) -> usize;
+
+    #[doc(hidden)]
+    fn nrm2(n: usize, x: &[Self], incx: usize) -> Self::Real;
+
+    /// Σ |x[i]|, |Re x[i]| + |Im x[i]| for complex types
+    #[doc(hidden)]
+    fn asum(n: usize, x: &[Self], incx: usize) -> Self::Real;
+
+    #[doc(hidden)]
+    fn scal(n: usize, a: Self, x: &mut [Self], incx: usize);
+
+    #[doc(hidden)]
+    fn axpy(n: usize, a: Self, x: &[Self], incx: usize, y: &mut [Self], incy: usize);
+
+    /// Σ conjugate(x[i]) * y[i]
+    #[doc(hidden)]
+    fn dotc(n: usize, x: &[Self], incx: usize, y: &[Self], incy: usize) -> Self;
+
+    /// A = alpha * x * yᵀ + A, without conjugating y
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    fn ger(
+        m: usize,
+        n: usize,
+        alpha: Self,
+        x: &[Self],
+        incx: usize,
+        y: &[Self],
+        incy: usize,
+        a: &mut [Self],
+        lda: usize,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    fn tbsv(
+        uplo: char,
+        trans: char,
+        diag: char,
+        n: usize,
+        k: usize,
+        a: &[Self],
+        lda: usize,
+        x: &mut [Self],
+        incx: usize,
+    );
+
+    /// y = alpha * A * x + beta * y with A Hermitian, symv for real types
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    fn hemv(
+        uplo: char,
+        n: usize,
+        alpha: Self,
+        a: &[Self],
+        lda: usize,
+        x: &[Self],
+        incx: usize,
+        beta: Self,
+        y: &mut [Self],
+        incy: usize,
+    );
+
+    /// A = alpha * x * yᴴ + conjugate(alpha) * y * xᴴ + A with A Hermitian, syr2 for real types
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    fn her2(
+        uplo: char,
+        n: usize,
+        alpha: Self,
+        x: &[Self],
+        incx: usize,
+        y: &[Self],
+        incy: usize,
+        a: &mut [Self],
+        lda: usize,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    fn gemm(
+        transa: char,
+        transb: char,
+        m: usize,
+        n: usize,
+        k: usize,
+        alpha: Self,
+        a: &[Self],
+        lda: usize,
+        b: &[Self],
+        ldb: usize,
+        beta: Self,
+        c: &mut [Self],
+        ldc: usize,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    fn trmm(
+        side: char,
+        uplo: char,
+        transa: char,
+        diag: char,
+        m: usize,
+        n: usize,
+        alpha: Self,
+        a: &[Self],
+        lda: usize,
+        b: &mut [Self],
+        ldb: usize,
+    );
+
+    /// C = alpha * A * Aᴴ + beta * C or C = alpha * Aᴴ * A + beta * C, syrk for real types
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    fn herk(
+        uplo: char,
+        trans: char,
+        n: usize,
+        k: usize,
+        alpha: Self::Real,
+        a: &[Self],
+        lda: usize,
+        beta: Self::Real,
+        c: &mut [Self],
+        ldc: usize,
+    );
+
+    /// C = alpha * A * Aᵀ + beta * C or C = alpha * Aᵀ * A + beta * C, without conjugating A
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    fn syrk(
+        uplo: char,
+        trans: char,
+        n: usize,
+        k: usize,
+        alpha: Self,
+        a: &[Self],
+        lda: usize,
+        beta: Self,
+        c: &mut [Self],
+        ldc: usize,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    fn trsm(
+        side: char,
+        uplo: char,
+        transa: char,
+        diag: char,
+        m: usize,
+        n: usize,
+        alpha: Self,
+        a: &[Self],
+        lda: usize,
+        b: &mut [Self],
+        ldb: usize,
+    );
+}
+
+macro_rules! scalar {
+    (
+        $t:ty,
+        $real:ty,
+        $conj:expr,
+        $re:expr,
+        $from_real:expr,
+        $abs:expr,
+        $iamax:path,
+        $nrm2:expr,
+        $asum:path,
+        $scal:expr,
+        $axpy:path,
+        $dotc:expr,
+        $ger:path,
+        $tbsv:path,
+        $hemv:path,
+        $her2:path,
+        $gemm:path,
+        $herk:path,
+        $syrk:path,
+        $trmm:path,
+        $trsm:path
+    ) => {
+        impl Scalar for $t {
+            type Real = $real;
+
+            fn conj(self) -> Self {
+                ($conj)(self)
+            }
+
+            fn re(self) -> $real {
+                ($re)(self)
+            }
+
+            fn from_real(r: $real) -> Self {
+                ($from_real)(r)
+            }
+
+            fn abs(self) -> $real {
+                ($abs)(self)
+            }
+
+            fn iamax(n: usize, x: &[Self], incx: usize) -> usize {
+                $iamax(&(n as isize), &vector(x), &(incx as isize))
+            }
+
+            fn nrm2(n: usize, x: &[Self], incx: usize) -> $real {
+                ($nrm2)(n as isize, vector(x), incx as isize)
+            }
+
+            fn asum(n: usize, x: &[Self], incx: usize) -> $real {
+                $asum(&(n as isize), &vector(x), &(incx as isize))
+            }
+
+            fn scal(n: usize, a: Self, x: &mut [Self], incx: usize) {
+                ($scal)(n as isize, a, vector_mut(x), incx as isize);
+            }
+
+            fn axpy(n: usize, a: Self, x: &[Self], incx: usize, y: &mut [Self], incy: usize) {
+                $axpy(
+                    &(n as isize),
+                    &a,
+                    &vector(x),
+                    &(incx as isize),
+                    &mut vector_mut(y),
+                    &(incy as isize),
+                );
+            }
+
+            fn dotc(n: usize, x: &[Self], incx: usize, y: &[Self], incy: usize) -> Self {
+                let (n, incx, incy) = (n as isize, incx as isize, incy as isize);
+                ($dotc)(n, vector(x), incx, vector(y), incy)
+            }
+
+            fn ger(
+                m: usize,
+                n: usize,
+                alpha: Self,
+                x: &[Self],
+                incx: usize,
+                y: &[Self],
+                incy: usize,
+                a: &mut [Self],
+                lda: usize,
+            ) {
+                $ger(
+                    &(m as isize),
+                    &(n as isize),
+                    &alpha,
+                    &vector(x),
+                    &(incx as isize),
+                    &vector(y),
+                    &(incy as isize),
+                    &mut vector_mut(a),
+                    &(lda as isize),
+                );
+            }
+
+            fn tbsv(
+                uplo: char,
+                trans: char,
+                diag: char,
+                n: usize,
+                k: usize,
+                a: &[Self],
+                lda: usize,
+                x: &mut [Self],
+                incx: usize,
+            ) {
+                $tbsv(
+                    &uplo,
+                    &trans,
+                    &diag,
+                    &(n as isize),
+                    &(k as isize),
+                    &vector(a),
+                    &(lda as isize),
+                    &mut vector_mut(x),
+                    &(incx as isize),
+                );
+            }
+
+            fn hemv(
+                uplo: char,
+                n: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                x: &[Self],
+                incx: usize,
+                beta: Self,
+                y: &mut [Self],
+                incy: usize,
+            ) {
+                $hemv(
+                    &uplo,
+                    &(n as isize),
+                    &alpha,
+                    &vector(a),
+                    &(lda as isize),
+                    &vector(x),
+                    &(incx as isize),
+                    &beta,
+                    &mut vector_mut(y),
+                    &(incy as isize),
+                );
+            }
+
+            fn her2(
+                uplo: char,
+                n: usize,
+                alpha: Self,
+                x: &[Self],
+                incx: usize,
+                y: &[Self],
+                incy: usize,
+                a: &mut [Self],
+                lda: usize,
+            ) {
+                $her2(
+                    &uplo,
+                    &(n as isize),
+                    &alpha,
+                    &vector(x),
+                    &(incx as isize),
+                    &vector(y),
+                    &(incy as isize),
+                    &mut vector_mut(a),
+                    &(lda as isize),
+                );
+            }
+
+            fn gemm(
+                transa: char,
+                transb: char,
+                m: usize,
+                n: usize,
+                k: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                b: &[Self],
+                ldb: usize,
+                beta: Self,
+                c: &mut [Self],
+                ldc: usize,
+            ) {
+                $gemm(
+                    &transa,
+                    &transb,
+                    &(m as isize),
+                    &(n as isize),
+                    &(k as isize),
+                    &alpha,
+                    &vector(a),
+                    &(lda as isize),
+                    &vector(b),
+                    &(ldb as isize),
+                    &beta,
+                    &mut vector_mut(c),
+                    &(ldc as isize),
+                );
+            }
+
+            fn herk(
+                uplo: char,
+                trans: char,
+                n: usize,
+                k: usize,
+                alpha: $real,
+                a: &[Self],
+                lda: usize,
+                beta: $real,
+                c: &mut [Self],
+                ldc: usize,
+            ) {
+                $herk(
+                    &uplo,
+                    &trans,
+                    &(n as isize),
+                    &(k as isize),
+                    &alpha,
+                    &vector(a),
+                    &(lda as isize),
+                    &beta,
+                    &mut vector_mut(c),
+                    &(ldc as isize),
+                );
+            }
+
+            fn trmm(
+                side: char,
+                uplo: char,
+                transa: char,
+                diag: char,
+                m: usize,
+                n: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                b: &mut [Self],
+                ldb: usize,
+            ) {
+                $trmm(
+                    &side,
+                    &uplo,
+                    &transa,
+                    &diag,
+                    &(m as isize),
+                    &(n as isize),
+                    &alpha,
+                    &vector(a),
+                    &(lda as isize),
+                    &mut vector_mut(b),
+                    &(ldb as isize),
+                );
+            }
+
+            fn syrk(
+                uplo: char,
+                trans: char,
+                n: usize,
+                k: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                beta: Self,
+                c: &mut [Self],
+                ldc: usize,
+            ) {
+                $syrk(
+                    &uplo,
+                    &trans,
+                    &(n as isize),
+                    &(k as isize),
+                    &alpha,
+                    &vector(a),
+                    &(lda as isize),
+                    &beta,
+                    &mut vector_mut(c),
+                    &(ldc as isize),
+                );
+            }
+
+            fn trsm(
+                side: char,
+                uplo: char,
+                transa: char,
+                diag: char,
+                m: usize,
+                n: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                b: &mut [Self],
+                ldb: usize,
+            ) {
+                $trsm(
+                    &side,
+                    &uplo,
+                    &transa,
+                    &diag,
+                    &(m as isize),
+                    &(n as isize),
+                    &alpha,
+                    &vector(a),
+                    &(lda as isize),
+                    &mut vector_mut(b),
+                    &(ldb as isize),
+                );
+            }
+        }
+    };
+}
+
+scalar!(
+    f32,
+    f32,
+    |x| x,
+    |x| x,
+    |r| r,
+    Float::abs,
+    iamax::iamax_real,
+    |n, mut x, incx| nrm2::nrm2(&n, &mut x, &incx),
+    asum::asum_real,
+    |n, a, mut x, incx| scal::scal_real(&n, &a, &mut x, &incx),
+    axpy::axpy_real,
+    |n, x, incx, y, incy| dot::dot(&n, &x, &incx, &y, &incy),
+    ger::ger_real,
+    tbsv::tbsv_real,
+    symv::symv_real,
+    syr2::syr2_real,
+    gemm::gemm::<f32, f32>,
+    syrk::syrk_real,
+    syrk::syrk_real,
+    trmm::trmm_real,
+    trsm::trsm_real
+);
+scalar!(
+    f64,
+    f64,
+    |x| x,
+    |x| x,
+    |r| r,
+    Float::abs,
+    iamax::iamax_real,
+    |n, mut x, incx| nrm2::nrm2(&n, &mut x, &incx),
+    asum::asum_real,
+    |n, a, mut x, incx| scal::scal_real(&n, &a, &mut x, &incx),
+    axpy::axpy_real,
+    |n, x, incx, y, incy| dot::dot(&n, &x, &incx, &y, &incy),
+    ger::ger_real,
+    tbsv::tbsv_real,
+    symv::symv_real,
+    syr2::syr2_real,
+    gemm::gemm::<f64, f64>,
+    syrk::syrk_real,
+    syrk::syrk_real,
+    trmm::trmm_real,
+    trsm::trsm_real
+);
+scalar!(
+    Complex<f32>,
+    f32,
+    |x: Self| Complex::conj(&x),
+    |x: Self| x.re,
+    Complex::from,
+    |x: Self| x.norm(),
+    iamax::iamax_complex,
+    |n, x, incx| nrm2::real_complex_nrm2(&n, x, &incx),
+    asum::asum_complex,
+    scal::scal_complex,
+    axpy::axpy_complex,
+    |n, mut x, incx, mut y, incy| dotc::dotc(&n, &mut x, &incx, &mut y, &incy),
+    ger::geru_complex,
+    tbsv::tbsv_complex,
+    symv::hemv_complex,
+    syr2::her2_complex,
+    gemm::gemm_complex,
+    herk::herk_complex,
+    syrk::syrk_complex,
+    trmm::trmm_complex,
+    trsm::trsm_complex
+);
+scalar!(
+    Complex<f64>,
+    f64,
+    |x: Self| Complex::conj(&x),
+    |x: Self| x.re,
+    Complex::from,
+    |x: Self| x.norm(),
+    iamax::iamax_complex,
+    |n, x, incx| nrm2::real_complex_nrm2(&n, x, &incx),
+    asum::asum_complex,
+    scal::scal_complex,
+    axpy::axpy_complex,
+    |n, mut x, incx, mut y, incy| dotc::dotc(&n, &mut x, &incx, &mut y, &incy),
+    ger::geru_complex,
+    tbsv::tbsv_complex,
+    symv::hemv_complex,
+    syr2::her2_complex,
+    gemm::gemm_complex,
+    herk::herk_complex,
+    syrk::syrk_complex,
+    trmm::trmm_complex,
+    trsm::trsm_complex
+);
+
+/// Returns the number of elements an m by n matrix with leading dimension ld spans.
+fn span(m: usize, n: usize, ld: usize) -> usize {
+    if m == 0 || n == 0 {
+        0
+    } else {
+        ld * (n - 1) + m
+    }
+}
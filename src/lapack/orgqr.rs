@@ -0,0 +1,142 @@
+use alloc::vec;
+
+use nd_slice::{NdSlice, NdSliceMut};
+
+use crate::contiguous::{as_mut_slice, as_slice};
+
+use super::larf::reflect;
+use super::larfb::reflect_block;
+use super::larft::triangular_factor;
+use super::{span, LapackError, Scalar, NB};
+
+/// `orgqr` under the name netlib gives it for complex types.
+pub use self::orgqr as ungqr;
+
+/// Generates the m by n matrix Q with orthonormal columns defined by the elementary reflectors
+/// of a QR factorization computed by `geqrf`.
+///
+/// Q = H(0) * H(1) * ... * H(k - 1), its first n columns
+///
+/// Q is unitary for complex types; the reflectors are applied NB at a time with `larfb`.
+///
+/// Input:
+///     m: number of rows of Q
+///     n: number of columns of Q, at most m
+///     k: number of elementary reflectors, at most n
+///     a: the reflectors from `geqrf` in their first k columns, lda by n
+///     lda: leading dimension of a, at least max(1, m)
+///     tau: the scalar factors of the reflectors from `geqrf`, vector of k elements
+/// Output:
+///     a: Q
+pub fn orgqr<T: Scalar>(
+    m: &isize,
+    n: &isize,
+    k: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    tau: &NdSlice<'_, T, 1>,
+) -> Result<(), LapackError> {
+    let (m, n, k, lda) = (*m, *n, *k, *lda);
+
+    if m < 0 {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 || n > m {
+        return Err(LapackError::Argument(2));
+    }
+    if k < 0 || k > n {
+        return Err(LapackError::Argument(3));
+    }
+    if lda < m.max(1) {
+        return Err(LapackError::Argument(5));
+    }
+    let (m, n, k, lda) = (m as usize, n as usize, k as usize, lda as usize);
+
+    if n == 0 {
+        return Ok(());
+    }
+
+    let a = as_mut_slice(a, span(m, n, lda));
+    let tau = as_slice(tau, k);
+
+    if NB >= k {
+        org2r(m, n, k, a, lda, tau);
+        return Ok(());
+    }
+
+    // the columns right of the reflectors start as those of the identity
+    for j in k..n {
+        for i in 0..k {
+            a[i + j * lda] = T::zero();
+        }
+    }
+    if k < n {
+        org2r(m - k, n - k, 0, &mut a[k + k * lda..], lda, &[]);
+    }
+
+    let mut t = vec![T::zero(); NB * NB];
+
+    for i in (0..k).step_by(NB).rev() {
+        let ib = NB.min(k - i);
+
+        if i + ib < n {
+            // apply H(i) * ... * H(i + ib - 1) to the columns right of the block
+            let (left, right) = a.split_at_mut((i + ib) * lda);
+            let v = &left[i + i * lda..];
+            triangular_factor(m - i, ib, v, lda, &tau[i..i + ib], &mut t, ib);
+            reflect_block(
+                true,
+                true,
+                m - i,
+                n - i - ib,
+                ib,
+                v,
+                lda,
+                &t,
+                ib,
+                &mut right[i..],
+                lda,
+            );
+        }
+
+        // the block's own rows i..m, the rows above it being zero
+        org2r(m - i, ib, ib, &mut a[i + i * lda..], lda, &tau[i..i + ib]);
+        for j in i..i + ib {
+            for l in 0..i {
+                a[l + j * lda] = T::zero();
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Generates Q from k reflectors one column at a time.
+fn org2r<T: Scalar>(m: usize, n: usize, k: usize, a: &mut [T], lda: usize, tau: &[T]) {
+    // columns k..n of the identity
+    for j in k..n {
+        for l in 0..m {
+            a[l + j * lda] = T::zero();
+        }
+        a[j + j * lda] = T::one();
+    }
+
+    for i in (0..k).rev() {
+        // apply H(i) to the columns on its right
+        if i + 1 < n {
+            a[i + i * lda] = T::one();
+            let (left, right) = a.split_at_mut((i + 1) * lda);
+            let v = &left[i + i * lda..i * lda + m];
+            reflect(true, m - i, n - i - 1, v, tau[i], &mut right[i..], lda);
+        }
+
+        // column i of H(i)
+        if i + 1 < m {
+            T::scal(m - i - 1, -tau[i], &mut a[i + 1 + i * lda..], 1);
+        }
+        a[i + i * lda] = T::one() - tau[i];
+        for l in 0..i {
+            a[l + i * lda] = T::zero();
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use alloc::vec;
+
+use nd_slice::{NdSlice, NdSliceMut};
+
+use crate::contiguous::{as_mut_slice, as_slice};
+
+use super::larfb::reflect_block;
+use super::larft::triangular_factor;
+use super::{span, LapackError, Scalar, NB};
+
+/// `ormqr` under the name netlib gives it for complex types.
+pub use self::ormqr as unmqr;
+
+/// Multiplies a matrix by the orthogonal (unitary for complex types) matrix Q of a QR
+/// factorization computed by `geqrf`.
+///
+/// C = op(Q) * C or C = C * op(Q)
+///
+/// where Q = H(0) * H(1) * ... * H(k - 1) is of order m if side = 'L' and n otherwise. The
+/// reflectors are applied NB at a time with `larfb`.
+///
+/// Input:
+///     side: 'L' for C = op(Q) * C, 'R' for C = C * op(Q)
+///     trans: 'N' for op(Q) = Q, 'C' for op(Q) = Qᴴ ('T' is accepted for real types)
+///     m: number of rows of C
+///     n: number of columns of C
+///     k: number of elementary reflectors, at most m if side = 'L' and at most n otherwise
+///     a: the reflectors from `geqrf` in their first k columns, lda by k
+///     lda: leading dimension of a, at least max(1, m) if side = 'L' and max(1, n) otherwise
+///     tau: the scalar factors of the reflectors from `geqrf`, vector of k elements
+///     c: matrix in column-major order, ldc by n
+///     ldc: leading dimension of c, at least max(1, m)
+/// Output:
+///     c: modified c
+#[allow(clippy::too_many_arguments)]
+pub fn ormqr<T: Scalar>(
+    side: &char,
+    trans: &char,
+    m: &isize,
+    n: &isize,
+    k: &isize,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    tau: &NdSlice<'_, T, 1>,
+    c: &mut NdSliceMut<'_, T, 1>,
+    ldc: &isize,
+) -> Result<(), LapackError> {
+    let (m, n, k, lda, ldc) = (*m, *n, *k, *lda, *ldc);
+
+    let left = side.eq_ignore_ascii_case(&'L');
+    let notrans = trans.eq_ignore_ascii_case(&'N');
+    let nq = if left { m } else { n };
+
+    if !(left || side.eq_ignore_ascii_case(&'R')) {
+        return Err(LapackError::Argument(1));
+    }
+    if !(notrans || "TtCc".contains(*trans)) {
+        return Err(LapackError::Argument(2));
+    }
+    if m < 0 {
+        return Err(LapackError::Argument(3));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(4));
+    }
+    if k < 0 || k > nq {
+        return Err(LapackError::Argument(5));
+    }
+    if lda < nq.max(1) {
+        return Err(LapackError::Argument(7));
+    }
+    if ldc < m.max(1) {
+        return Err(LapackError::Argument(10));
+    }
+    let (m, n, k, lda, ldc) = (
+        m as usize,
+        n as usize,
+        k as usize,
+        lda as usize,
+        ldc as usize,
+    );
+    let nq = nq as usize;
+
+    if m == 0 || n == 0 || k == 0 {
+        return Ok(());
+    }
+
+    let a = as_slice(a, span(nq, k, lda));
+    let tau = as_slice(tau, k);
+    let c = as_mut_slice(c, span(m, n, ldc));
+
+    let mut t = vec![T::zero(); NB * NB];
+
+    // Q * C and C * Qᴴ apply H(k - 1) first, Qᴴ * C and C * Q apply H(0) first
+    let mut apply = |i: usize| {
+        let ib = NB.min(k - i);
+        let v = &a[i + i * lda..];
+        triangular_factor(nq - i, ib, v, lda, &tau[i..i + ib], &mut t, ib);
+
+        // the block of reflectors affects rows (columns if side = 'R') i..nq of C
+        let (mi, ni, ci) = if left {
+            (m - i, n, &mut c[i..])
+        } else {
+            (m, n - i, &mut c[i * ldc..])
+        };
+        reflect_block(left, notrans, mi, ni, ib, v, lda, &t, ib, ci, ldc);
+    };
+
+    if left != notrans {
+        (0..k).step_by(NB).for_each(&mut apply);
+    } else {
+        (0..k).step_by(NB).rev().for_each(&mut apply);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use alloc::vec;
+
+use nd_slice::NdSlice;
+use num_traits::{Float, One, Zero};
+
+use crate::contiguous::{as_slice, vector_mut};
+
+use super::lacn2::lacn2;
+use super::{span, LapackError, Scalar};
+
+/// Estimates the reciprocal of the condition number, in the 1-norm, of a Hermitian positive
+/// definite matrix, symmetric for real types, using its Cholesky factorization computed by
+/// `potrf`.
+///
+/// rcond = 1 / (‖ A ‖₁ * ‖ A⁻¹ ‖₁)
+///
+/// ‖ A⁻¹ ‖₁ is estimated with `lacn2`, the products with A⁻¹ being triangular solves with the
+/// factor.
+///
+/// Input:
+///     uplo: 'U' if A = Uᴴ * U, 'L' if A = L * Lᴴ
+///     n: order of A
+///     a: the factor U or L from `potrf`, lda by n
+///     lda: leading dimension of a, at least max(1, n)
+///     anorm: the 1-norm, equal to the infinity-norm, of the original matrix A
+/// Output:
+///     rcond: the estimate, 0 if anorm is zero and 1 if n is zero
+pub fn pocon<T: Scalar>(
+    uplo: &char,
+    n: &isize,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    anorm: &T::Real,
+    rcond: &mut T::Real,
+) -> Result<(), LapackError> {
+    let (n, lda, anorm) = (*n, *lda, *anorm);
+
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+
+    if !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if lda < n.max(1) {
+        return Err(LapackError::Argument(4));
+    }
+    if anorm < T::Real::zero() || anorm.is_nan() {
+        return Err(LapackError::Argument(5));
+    }
+    let (n, lda) = (n as usize, lda as usize);
+
+    *rcond = T::Real::zero();
+    if n == 0 {
+        *rcond = T::Real::one();
+        return Ok(());
+    }
+    if anorm == T::Real::zero() {
+        return Ok(());
+    }
+
+    let a = as_slice(a, span(n, n, lda));
+
+    let (mut v, mut x) = (vec![T::zero(); n], vec![T::zero(); n]);
+    let mut isave = [0; 3];
+    let (mut ainvnm, mut kase) = (T::Real::zero(), 0);
+
+    loop {
+        lacn2(
+            &(n as isize),
+            &mut vector_mut(&mut v),
+            &mut vector_mut(&mut x),
+            &mut ainvnm,
+            &mut kase,
+            &mut vector_mut(&mut isave),
+        );
+        if kase == 0 {
+            break;
+        }
+
+        // x = A⁻¹ * x, A⁻¹ being Hermitian whichever product is asked for
+        if upper {
+            T::trsm('L', 'U', 'C', 'N', n, 1, T::one(), a, lda, &mut x, n);
+            T::trsm('L', 'U', 'N', 'N', n, 1, T::one(), a, lda, &mut x, n);
+        } else {
+            T::trsm('L', 'L', 'N', 'N', n, 1, T::one(), a, lda, &mut x, n);
+            T::trsm('L', 'L', 'C', 'N', n, 1, T::one(), a, lda, &mut x, n);
+        }
+    }
+
+    if ainvnm != T::Real::zero() {
+        *rcond = (T::Real::one() / ainvnm) / anorm;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use nd_slice::NdSliceMut;
+
+use crate::contiguous::{as_slice, vector};
+
+use super::potrf::potrf;
+use super::potrs::potrs;
+use super::{span, LapackError, Scalar};
+
+/// Solves a system of linear equations with a Hermitian positive definite matrix, symmetric for
+/// real types.
+///
+/// A * X = B
+///
+/// A is factored as Uᴴ * U or L * Lᴴ with `potrf` and the factor is used to solve the system
+/// with `potrs`.
+///
+/// Input:
+///     uplo: 'U' if the upper triangle of A is stored, 'L' if the lower triangle is
+///     n: order of A
+///     nrhs: number of columns of B
+///     a: Hermitian matrix in column-major order, lda by n, the other triangle is not referenced
+///     lda: leading dimension of a, at least max(1, n)
+///     b: right-hand sides in column-major order, ldb by nrhs
+///     ldb: leading dimension of b, at least max(1, n)
+/// Output:
+///     a: the factor U or L from `potrf`
+///     b: the solution X, unless A is not positive definite
+///     returns `LapackError::NotPositiveDefinite(i)` if the leading minor of order i + 1 is not
+///     positive, B then being unchanged
+#[allow(clippy::too_many_arguments)]
+pub fn posv<T: Scalar>(
+    uplo: &char,
+    n: &isize,
+    nrhs: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) -> Result<(), LapackError> {
+    if !"UuLl".contains(*uplo) {
+        return Err(LapackError::Argument(1));
+    }
+    if *n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if *nrhs < 0 {
+        return Err(LapackError::Argument(3));
+    }
+    if *lda < (*n).max(1) {
+        return Err(LapackError::Argument(5));
+    }
+    if *ldb < (*n).max(1) {
+        return Err(LapackError::Argument(7));
+    }
+
+    potrf(uplo, n, a, lda)?;
+
+    let a = vector(as_slice(a, span(*n as usize, *n as usize, *lda as usize)));
+    potrs(uplo, n, nrhs, &a, lda, b, ldb)
+}
@@ -0,0 +1,218 @@
+use alloc::vec::Vec;
+
+use nd_slice::NdSliceMut;
+use num_traits::{Float, One, Zero};
+
+use crate::contiguous::as_mut_slice;
+
+use super::{span, LapackError, Scalar, NB};
+
+/// Computes the Cholesky factorization of a Hermitian positive definite matrix, symmetric for
+/// real types.
+///
+/// A = Uᴴ * U or A = L * Lᴴ
+///
+/// where U is upper triangular and L is lower triangular. The matrix is factored NB columns at a
+/// time, the rest of the triangle being updated with herk (syrk for real types), gemm and trsm.
+///
+/// Input:
+///     uplo: 'U' if the upper triangle of A is stored, 'L' if the lower triangle is
+///     n: order of A
+///     a: Hermitian matrix in column-major order, lda by n, the other triangle is not referenced
+///     lda: leading dimension of a, at least max(1, n)
+/// Output:
+///     a: U or L in the stored triangle
+///     returns `LapackError::NotPositiveDefinite(i)` if the leading minor of order i + 1 is not
+///     positive, the factorization being incomplete
+pub fn potrf<T: Scalar>(
+    uplo: &char,
+    n: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+) -> Result<(), LapackError> {
+    let (n, lda) = (*n, *lda);
+
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+
+    if !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if lda < n.max(1) {
+        return Err(LapackError::Argument(4));
+    }
+    let (n, lda) = (n as usize, lda as usize);
+
+    if n == 0 {
+        return Ok(());
+    }
+
+    let a = as_mut_slice(a, span(n, n, lda));
+    let not_positive = |i: usize| Err(LapackError::NotPositiveDefinite(i));
+
+    if NB >= n {
+        return match potf2(upper, n, a, lda) {
+            Some(i) => not_positive(i),
+            None => Ok(()),
+        };
+    }
+
+    let (one, minus_one) = (T::one(), -T::one());
+    let real_one = T::Real::one();
+    let (mut a01, mut a12) = (Vec::new(), Vec::new());
+
+    for j in (0..n).step_by(NB) {
+        let jb = NB.min(n - j);
+        let nr = n - j - jb;
+
+        if upper {
+            // A01 and A12 are copied out, as they share their columns with A11 and A02
+            if j > 0 {
+                a01.clear();
+                for col in a[j * lda..].chunks(lda).take(jb) {
+                    a01.extend_from_slice(&col[..j]);
+                }
+
+                // A11 = A11 - A01ᴴ * A01, A01 being above the diagonal block in its columns
+                let a11 = &mut a[j + j * lda..];
+                T::herk('U', 'C', jb, j, -real_one, &a01, j, real_one, a11, lda);
+            }
+
+            if let Some(i) = potf2(upper, jb, &mut a[j + j * lda..], lda) {
+                return not_positive(i + j);
+            }
+
+            if nr > 0 {
+                a12.clear();
+                for col in a[(j + jb) * lda..].chunks(lda) {
+                    a12.extend_from_slice(&col[j..j + jb]);
+                }
+
+                // A12 = A12 - A01ᴴ * A02
+                if j > 0 {
+                    let a02 = &a[(j + jb) * lda..];
+                    T::gemm(
+                        'C', 'N', jb, nr, j, minus_one, &a01, j, a02, lda, one, &mut a12, jb,
+                    );
+                }
+
+                // A12 = U11⁻ᴴ * A12
+                let u11 = &a[j + j * lda..];
+                T::trsm('L', 'U', 'C', 'N', jb, nr, one, u11, lda, &mut a12, jb);
+
+                for (col, x) in a[(j + jb) * lda..].chunks_mut(lda).zip(a12.chunks(jb)) {
+                    col[j..j + jb].copy_from_slice(x);
+                }
+            }
+        } else {
+            let (left, right) = a.split_at_mut(j * lda);
+
+            if j > 0 {
+                // A11 = A11 - A10 * A10ᴴ, A10 being left of the diagonal block in its rows
+                let (a10, a11) = (&left[j..], &mut right[j..]);
+                T::herk('L', 'N', jb, j, -real_one, a10, lda, real_one, a11, lda);
+            }
+
+            if let Some(i) = potf2(upper, jb, &mut right[j..], lda) {
+                return not_positive(i + j);
+            }
+
+            if nr > 0 {
+                // A21 = A21 - A20 * A10ᴴ
+                if j > 0 {
+                    let (a20, a10, a21) = (&left[j + jb..], &left[j..], &mut right[j + jb..]);
+                    T::gemm(
+                        'N', 'C', nr, jb, j, minus_one, a20, lda, a10, lda, one, a21, lda,
+                    );
+                }
+
+                // A21 = A21 * L11⁻ᴴ, a column at a time as L11 and A21 share their columns:
+                // column c of A21 less the columns of the result before it times row c of L11,
+                // over L(c, c)
+                for c in j..j + jb {
+                    let (left, right) = a.split_at_mut(c * lda);
+                    let (l, col) = right[c..].split_at_mut(jb - (c - j));
+                    if c > j {
+                        let (x, lc) = (&left[j + jb + j * lda..], &left[c + j * lda..]);
+                        T::gemm(
+                            'N',
+                            'C',
+                            nr,
+                            1,
+                            c - j,
+                            minus_one,
+                            x,
+                            lda,
+                            lc,
+                            lda,
+                            one,
+                            col,
+                            nr,
+                        );
+                    }
+                    T::scal(nr, T::from_real(l[0].re().recip()), col, 1);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Computes the Cholesky factorization of an n by n matrix one column (or row) at a time,
+/// returning the first i such that the leading minor of order i + 1 is not positive.
+///
+/// A(i, i) is then left as the value whose square root would have been taken.
+fn potf2<T: Scalar>(upper: bool, n: usize, a: &mut [T], lda: usize) -> Option<usize> {
+    let (one, minus_one) = (T::one(), -T::one());
+
+    for j in 0..n {
+        // the diagonal element, minus the squares of the computed part of its row (or column)
+        let ajj = if upper {
+            let u = &a[j * lda..];
+            a[j + j * lda].re() - T::dotc(j, u, 1, u, 1).re()
+        } else {
+            let l = &a[j..];
+            a[j + j * lda].re() - T::dotc(j, l, lda, l, lda).re()
+        };
+
+        if ajj <= T::Real::zero() || ajj.is_nan() {
+            a[j + j * lda] = T::from_real(ajj);
+            return Some(j);
+        }
+        let ajj = ajj.sqrt();
+        a[j + j * lda] = T::from_real(ajj);
+
+        if j + 1 == n {
+            break;
+        }
+        let nr = n - j - 1;
+        let scale = T::from_real(ajj.recip());
+
+        if upper {
+            // row j of U right of the diagonal, whose columns hold the rows above it
+            if j > 0 {
+                for c in j + 1..n {
+                    let (left, right) = a.split_at_mut(c * lda);
+                    let (u02, row) = right.split_at_mut(j);
+                    row[0] = row[0] - T::dotc(j, &left[j * lda..], 1, u02, 1);
+                }
+            }
+            T::scal(nr, scale, &mut a[j + (j + 1) * lda..], lda);
+        } else {
+            // column j of L below the diagonal
+            let (left, right) = a.split_at_mut(j * lda);
+            if j > 0 {
+                let (a20, l, col) = (&left[j + 1..], &left[j..], &mut right[j + 1..]);
+                T::gemm(
+                    'N', 'C', nr, 1, j, minus_one, a20, lda, l, lda, one, col, lda,
+                );
+            }
+            T::scal(nr, scale, &mut right[j + 1..], 1);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,71 @@
+use nd_slice::{NdSlice, NdSliceMut};
+
+use crate::contiguous::{as_mut_slice, as_slice};
+
+use super::{span, LapackError, Scalar};
+
+/// Solves a system of linear equations with a Hermitian positive definite matrix, using its
+/// Cholesky factorization computed by `potrf`.
+///
+/// A * X = B
+///
+/// Input:
+///     uplo: 'U' if a holds U from A = Uᴴ * U, 'L' if it holds L from A = L * Lᴴ
+///     n: order of A
+///     nrhs: number of columns of B
+///     a: the factor U or L from `potrf`, lda by n
+///     lda: leading dimension of a, at least max(1, n)
+///     b: right-hand sides in column-major order, ldb by nrhs
+///     ldb: leading dimension of b, at least max(1, n)
+/// Output:
+///     b: the solution X
+#[allow(clippy::too_many_arguments)]
+pub fn potrs<T: Scalar>(
+    uplo: &char,
+    n: &isize,
+    nrhs: &isize,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) -> Result<(), LapackError> {
+    let (n, nrhs, lda, ldb) = (*n, *nrhs, *lda, *ldb);
+
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+
+    if !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if nrhs < 0 {
+        return Err(LapackError::Argument(3));
+    }
+    if lda < n.max(1) {
+        return Err(LapackError::Argument(5));
+    }
+    if ldb < n.max(1) {
+        return Err(LapackError::Argument(7));
+    }
+    let (n, nrhs, lda, ldb) = (n as usize, nrhs as usize, lda as usize, ldb as usize);
+
+    if n == 0 || nrhs == 0 {
+        return Ok(());
+    }
+
+    let a = as_slice(a, span(n, n, lda));
+    let b = as_mut_slice(b, span(n, nrhs, ldb));
+
+    if upper {
+        // X = U⁻¹ * U⁻ᴴ * B
+        T::trsm('L', 'U', 'C', 'N', n, nrhs, T::one(), a, lda, b, ldb);
+        T::trsm('L', 'U', 'N', 'N', n, nrhs, T::one(), a, lda, b, ldb);
+    } else {
+        // X = L⁻ᴴ * L⁻¹ * B
+        T::trsm('L', 'L', 'N', 'N', n, nrhs, T::one(), a, lda, b, ldb);
+        T::trsm('L', 'L', 'C', 'N', n, nrhs, T::one(), a, lda, b, ldb);
+    }
+
+    Ok(())
+}
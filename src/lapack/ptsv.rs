@@ -0,0 +1,99 @@
+use nd_slice::NdSliceMut;
+use num_traits::Zero;
+
+use crate::contiguous::as_mut_slice;
+
+use super::{span, LapackError, Scalar};
+
+/// Solves a system of linear equations with a Hermitian positive definite tridiagonal matrix,
+/// symmetric for real types.
+///
+/// A * X = B
+///
+/// A is factored as L * D * Lᴴ, L being unit lower bidiagonal and D diagonal, and the factors
+/// are used to solve the system.
+///
+/// Input:
+///     n: order of A
+///     nrhs: number of columns of B
+///     d: vector of n real elements, the diagonal of A
+///     e: vector of n - 1 elements, the subdiagonal of A
+///     b: right-hand sides in column-major order, ldb by nrhs
+///     ldb: leading dimension of b, at least max(1, n)
+/// Output:
+///     d: the diagonal of D
+///     e: the subdiagonal of L
+///     b: the solution X, unless A is not positive definite
+///     returns `LapackError::NotPositiveDefinite(i)` if the leading minor of order i + 1 is not
+///     positive, the factorization being incomplete and B unchanged
+pub fn ptsv<T: Scalar>(
+    n: &isize,
+    nrhs: &isize,
+    d: &mut NdSliceMut<'_, T::Real, 1>,
+    e: &mut NdSliceMut<'_, T, 1>,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) -> Result<(), LapackError> {
+    let (n, nrhs, ldb) = (*n, *nrhs, *ldb);
+
+    if n < 0 {
+        return Err(LapackError::Argument(1));
+    }
+    if nrhs < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if ldb < n.max(1) {
+        return Err(LapackError::Argument(6));
+    }
+    let (n, nrhs, ldb) = (n as usize, nrhs as usize, ldb as usize);
+
+    if n == 0 {
+        return Ok(());
+    }
+
+    let d = as_mut_slice(d, n);
+    let e = as_mut_slice(e, n - 1);
+
+    pttrf(n, d, e)?;
+
+    let b = as_mut_slice(b, span(n, nrhs, ldb));
+    for j in 0..nrhs {
+        pttrs(n, d, e, &mut b[j * ldb..]);
+    }
+
+    Ok(())
+}
+
+/// Factors A as L * D * Lᴴ, returning the first i such that the leading minor of order i + 1 is
+/// not positive.
+fn pttrf<T: Scalar>(n: usize, d: &mut [T::Real], e: &mut [T]) -> Result<(), LapackError> {
+    for i in 0..n - 1 {
+        if d[i] <= T::Real::zero() {
+            return Err(LapackError::NotPositiveDefinite(i));
+        }
+
+        let ei = e[i];
+        e[i] = ei / T::from_real(d[i]);
+        d[i + 1] = d[i + 1] - (e[i].conj() * ei).re();
+    }
+
+    if d[n - 1] <= T::Real::zero() {
+        return Err(LapackError::NotPositiveDefinite(n - 1));
+    }
+
+    Ok(())
+}
+
+/// Solves L * D * Lᴴ * x = b for one right-hand side.
+fn pttrs<T: Scalar>(n: usize, d: &[T::Real], e: &[T], b: &mut [T]) {
+    // L * y = b
+    for i in 1..n {
+        b[i] = b[i] - b[i - 1] * e[i - 1];
+    }
+
+    // D * Lᴴ * x = y
+    b[n - 1] = b[n - 1] / T::from_real(d[n - 1]);
+    for i in (0..n - 1).rev() {
+        b[i] = b[i] / T::from_real(d[i]) - b[i + 1] * e[i].conj();
+    }
+}
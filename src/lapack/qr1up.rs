@@ -0,0 +1,114 @@
+use alloc::vec;
+
+use nd_slice::{NdSlice, NdSliceMut};
+use num_traits::Float;
+
+use crate::contiguous::{as_mut_slice, as_slice};
+use crate::level_1::rotg::rotg;
+
+use super::lasr::rotate;
+use super::{span, LapackError, Scalar};
+
+/// Updates a QR factorization after a rank-one change of the matrix.
+///
+/// Q1 * R1 = Q * R + u * vᵀ
+///
+/// Rotations from `rotg` first reduce w = Qᵀ * u to a multiple of its first element, which
+/// turns R into an upper Hessenberg matrix H, then reduce H + w[0] * e0 * vᵀ back to upper
+/// triangular form. Both sequences are applied to Q with `lasr`. The diagonal of R1 may have
+/// elements of either sign.
+///
+/// Input:
+///     m: number of rows of Q and R
+///     n: number of columns of R
+///     q: orthogonal matrix in column-major order, ldq by m
+///     ldq: leading dimension of q, at least max(1, m)
+///     r: upper trapezoidal matrix in column-major order, ldr by n, zero below the diagonal
+///     ldr: leading dimension of r, at least max(1, m)
+///     u: vector of m elements
+///     v: vector of n elements
+/// Output:
+///     q: Q1
+///     r: R1
+#[allow(clippy::too_many_arguments)]
+pub fn qr1up<T: Float + Scalar<Real = T>>(
+    m: &isize,
+    n: &isize,
+    q: &mut NdSliceMut<'_, T, 1>,
+    ldq: &isize,
+    r: &mut NdSliceMut<'_, T, 1>,
+    ldr: &isize,
+    u: &NdSlice<'_, T, 1>,
+    v: &NdSlice<'_, T, 1>,
+) -> Result<(), LapackError> {
+    let (m, n, ldq, ldr) = (*m, *n, *ldq, *ldr);
+
+    if m < 0 {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if ldq < m.max(1) {
+        return Err(LapackError::Argument(4));
+    }
+    if ldr < m.max(1) {
+        return Err(LapackError::Argument(6));
+    }
+    let (m, n, ldq, ldr) = (m as usize, n as usize, ldq as usize, ldr as usize);
+
+    if m == 0 {
+        return Ok(());
+    }
+
+    let q = as_mut_slice(q, span(m, m, ldq));
+    let r = as_mut_slice(r, span(m, n, ldr));
+    let (u, v) = (as_slice(u, m), as_slice(v, n));
+
+    let (zero, one) = (T::zero(), T::one());
+    let (mut c, mut s) = (vec![zero; m - 1], vec![zero; m - 1]);
+
+    // w = Qᵀ * u, then rotations in the planes (k, k + 1), bottom to top, reduce it to w[0] * e0
+    let mut w = vec![zero; m];
+    T::gemm('C', 'N', m, 1, m, one, q, ldq, u, m, zero, &mut w, m);
+
+    for k in (0..m - 1).rev() {
+        let (mut a, mut b) = (w[k], w[k + 1]);
+        rotg(&mut a, &mut b, &mut c[k], &mut s[k]);
+        w[k] = a;
+    }
+    rotate::<T>(true, 'V', false, m, n, &c, &s, r, ldr);
+    rotate::<T>(false, 'V', false, m, m, &c, &s, q, ldq);
+
+    // H = H + w[0] * e0 * vᵀ
+    for (j, &vj) in v.iter().enumerate() {
+        r[j * ldr] = r[j * ldr] + w[0] * vj;
+    }
+
+    // rotations in the planes (k, k + 1), top to bottom, annihilate the subdiagonal of H
+    let kmax = (m - 1).min(n);
+    for k in 0..kmax {
+        let (mut a, mut b) = (r[k + k * ldr], r[k + 1 + k * ldr]);
+        rotg(&mut a, &mut b, &mut c[k], &mut s[k]);
+        r[k + k * ldr] = a;
+        r[k + 1 + k * ldr] = zero;
+
+        if k + 1 < n {
+            let rk = &mut r[k + (k + 1) * ldr..];
+            rotate::<T>(true, 'V', true, 2, n - k - 1, &c[k..=k], &s[k..=k], rk, ldr);
+        }
+    }
+    rotate::<T>(
+        false,
+        'V',
+        true,
+        m,
+        kmax + 1,
+        &c[..kmax],
+        &s[..kmax],
+        q,
+        ldq,
+    );
+
+    Ok(())
+}
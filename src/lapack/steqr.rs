@@ -0,0 +1,462 @@
+use alloc::vec;
+
+use nd_slice::NdSliceMut;
+use num_traits::{Float, One, Zero};
+
+use crate::contiguous::as_mut_slice;
+use crate::level_1::rotg::rotg;
+
+use super::lasr::rotate;
+use super::{span, LapackError, Scalar};
+
+/// Maximum number of iterations per eigenvalue, on average.
+const MAXIT: usize = 30;
+
+/// Computes the eigenvalues and, optionally, the eigenvectors of a real symmetric tridiagonal
+/// matrix, using the implicit QL or QR method.
+///
+/// T = Z * diag(d) * Zᵀ
+///
+/// The matrix is split where its off-diagonal elements are negligible, and each block is
+/// diagonalized with QL iterations, or QR iterations if its first diagonal element is the larger,
+/// using Wilkinson shifts. The rotations come from `rotg` and are applied to Z as `rot` would,
+/// with `lasr`. The eigenvectors of a full matrix reduced by `sytrd` are found by starting with
+/// Z = Q.
+///
+/// Input:
+///     compz: 'N' for eigenvalues only, 'V' to multiply Z by the eigenvectors of T, 'I' to set Z
+///     to the eigenvectors of T
+///     n: order of T
+///     d: the diagonal of T, vector of n elements
+///     e: the off-diagonal of T, vector of n - 1 elements
+///     z: matrix in column-major order, ldz by n, not referenced if compz = 'N', Q if
+///     compz = 'V', set if compz = 'I'
+///     ldz: leading dimension of z, at least 1, and at least n if compz is not 'N'
+/// Output:
+///     d: the eigenvalues in ascending order
+///     e: destroyed
+///     z: Q times the orthonormal eigenvectors of T if compz = 'V', the eigenvectors if
+///     compz = 'I', column j belonging to d[j]
+///     returns `LapackError::NotConverged(i)` if the iteration failed after 30 * n steps, i
+///     elements of e being nonzero; d and e then hold a tridiagonal matrix orthogonally similar
+///     to T, and Z the matching transformation
+pub fn steqr<T: Scalar>(
+    compz: &char,
+    n: &isize,
+    d: &mut NdSliceMut<'_, T::Real, 1>,
+    e: &mut NdSliceMut<'_, T::Real, 1>,
+    z: &mut NdSliceMut<'_, T, 1>,
+    ldz: &isize,
+) -> Result<(), LapackError> {
+    let (n, ldz) = (*n, *ldz);
+
+    let identity = compz.eq_ignore_ascii_case(&'I');
+    let vectors = identity || compz.eq_ignore_ascii_case(&'V');
+
+    if !(vectors || compz.eq_ignore_ascii_case(&'N')) {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if ldz < 1 || (vectors && ldz < n) {
+        return Err(LapackError::Argument(6));
+    }
+    let (n, ldz) = (n as usize, ldz as usize);
+
+    if n == 0 {
+        return Ok(());
+    }
+
+    let d = as_mut_slice(d, n);
+    let e = as_mut_slice(e, n - 1);
+    let z = as_mut_slice(z, if vectors { span(n, n, ldz) } else { 0 });
+
+    if identity {
+        for j in 0..n {
+            for i in 0..n {
+                z[i + j * ldz] = if i == j { T::one() } else { T::zero() };
+            }
+        }
+    }
+
+    match diagonalize(vectors, n, d, e, z, ldz) {
+        Some(i) => Err(LapackError::NotConverged(i)),
+        None => Ok(()),
+    }
+}
+
+/// Diagonalizes the tridiagonal matrix with diagonal d and off-diagonal e as `steqr` does,
+/// multiplying the n by n matrix z by the rotations if vectors is true. Returns the number of
+/// elements of e still nonzero if the iteration failed.
+pub(super) fn diagonalize<T: Scalar>(
+    vectors: bool,
+    n: usize,
+    d: &mut [T::Real],
+    e: &mut [T::Real],
+    z: &mut [T],
+    ldz: usize,
+) -> Option<usize> {
+    let (zero, one) = (T::Real::zero(), T::Real::one());
+    let two = one + one;
+    let three = two + one;
+
+    let eps = T::Real::epsilon() / two;
+    let eps2 = eps * eps;
+    let safmin = T::Real::min_positive_value();
+    let ssfmax = (one / safmin).sqrt() / three;
+    let ssfmin = safmin.sqrt() / eps2;
+
+    // x = x * (cto / cfrom)
+    let scale = |x: &mut [T::Real], cfrom: T::Real, cto: T::Real| {
+        x.iter_mut().for_each(|xi| *xi = *xi * (cto / cfrom));
+    };
+
+    // the eigenvalues of [a b; b c], the one of larger absolute value first, and the rotation
+    // (cs, sn) of the eigenvector of the first one
+    let eigen2 = |a: T::Real, b: T::Real, c: T::Real| {
+        let (sm, df) = (a + c, a - c);
+        let (adf, tb) = (df.abs(), b + b);
+        let ab = tb.abs();
+        let (acmx, acmn) = if a.abs() > c.abs() { (a, c) } else { (c, a) };
+
+        let rt = if adf > ab {
+            adf * (one + (ab / adf).powi(2)).sqrt()
+        } else if adf < ab {
+            ab * (one + (adf / ab).powi(2)).sqrt()
+        } else {
+            ab * two.sqrt()
+        };
+
+        let (rt1, rt2, sgn1) = if sm < zero {
+            let rt1 = (sm - rt) / two;
+            (rt1, (acmx / rt1) * acmn - (b / rt1) * b, -one)
+        } else if sm > zero {
+            let rt1 = (sm + rt) / two;
+            (rt1, (acmx / rt1) * acmn - (b / rt1) * b, one)
+        } else {
+            (rt / two, -rt / two, one)
+        };
+
+        let (cs, sgn2) = if df >= zero {
+            (df + rt, one)
+        } else {
+            (df - rt, -one)
+        };
+
+        let (mut cs1, mut sn1) = if cs.abs() > ab {
+            let ct = -tb / cs;
+            let sn1 = one / (one + ct * ct).sqrt();
+            (ct * sn1, sn1)
+        } else if ab == zero {
+            (one, zero)
+        } else {
+            let tn = -cs / tb;
+            let cs1 = one / (one + tn * tn).sqrt();
+            (cs1, tn * cs1)
+        };
+
+        if sgn1 == sgn2 {
+            let tn = cs1;
+            cs1 = -sn1;
+            sn1 = tn;
+        }
+
+        (rt1, rt2, cs1, sn1)
+    };
+
+    // the rotation (c, s) with [c s; -s c] * [f; g] = [r; 0], returning r
+    let rotation = |f: T::Real, g: T::Real, c: &mut T::Real, s: &mut T::Real| {
+        let (mut r, mut z) = (f, g);
+        rotg(&mut r, &mut z, c, s);
+        r
+    };
+
+    // the cosines and sines of the rotations of a sweep, applied to z at its end
+    let (mut work_c, mut work_s) = (vec![zero; n - 1], vec![zero; n - 1]);
+
+    let nmaxit = n * MAXIT;
+    let mut jtot = 0;
+
+    let mut l1 = 0;
+    while l1 < n {
+        if l1 > 0 {
+            e[l1 - 1] = zero;
+        }
+
+        // the block l1..=m ends where the off-diagonal element is negligible
+        let mut m = n - 1;
+        for k in l1..n - 1 {
+            let tst = e[k].abs();
+            if tst == zero {
+                m = k;
+                break;
+            }
+            if tst <= (d[k].abs().sqrt() * d[k + 1].abs().sqrt()) * eps {
+                e[k] = zero;
+                m = k;
+                break;
+            }
+        }
+
+        let (mut l, mut lend) = (l1, m);
+        let (lsv, lendsv) = (l, lend);
+        l1 = m + 1;
+        if lend == l {
+            continue;
+        }
+
+        // scales the block if its elements are too large or too small
+        let anorm = d[l..=lend]
+            .iter()
+            .chain(e[l..lend].iter())
+            .fold(zero, |anorm, x| anorm.max(x.abs()));
+        if anorm == zero {
+            continue;
+        }
+        let scaled = if anorm > ssfmax {
+            Some(ssfmax)
+        } else if anorm < ssfmin {
+            Some(ssfmin)
+        } else {
+            None
+        };
+        if let Some(cto) = scaled {
+            scale(&mut d[l..=lend], anorm, cto);
+            scale(&mut e[l..lend], anorm, cto);
+        }
+
+        // QR iteration if the first diagonal element is the larger, QL iteration otherwise
+        if d[lend].abs() < d[l].abs() {
+            lend = lsv;
+            l = lendsv;
+        }
+
+        if lend > l {
+            // QL iteration, finding the eigenvalues from the first one
+            loop {
+                let mut m = lend;
+                for k in l..lend {
+                    let tst = e[k].abs().powi(2);
+                    if tst <= (eps2 * d[k].abs()) * d[k + 1].abs() + safmin {
+                        m = k;
+                        break;
+                    }
+                }
+                if m < lend {
+                    e[m] = zero;
+                }
+
+                if m == l {
+                    // d[l] is an eigenvalue
+                    l += 1;
+                } else if m == l + 1 {
+                    // the 2 by 2 block is diagonalized directly
+                    let (rt1, rt2, c, s) = eigen2(d[l], e[l], d[l + 1]);
+                    if vectors {
+                        work_c[l] = c;
+                        work_s[l] = s;
+                        rotate(
+                            false,
+                            'V',
+                            false,
+                            n,
+                            2,
+                            &work_c[l..],
+                            &work_s[l..],
+                            &mut z[l * ldz..],
+                            ldz,
+                        );
+                    }
+                    d[l] = rt1;
+                    d[l + 1] = rt2;
+                    e[l] = zero;
+                    l += 2;
+                } else {
+                    if jtot == nmaxit {
+                        break;
+                    }
+                    jtot += 1;
+
+                    // Wilkinson shift
+                    let p = d[l];
+                    let g = (d[l + 1] - p) / (two * e[l]);
+                    let r = g.hypot(one);
+                    let mut g = d[m] - p + e[l] / (g + if g >= zero { r } else { -r });
+
+                    let (mut s, mut c, mut p) = (one, one, zero);
+                    for i in (l..m).rev() {
+                        let f = s * e[i];
+                        let b = c * e[i];
+                        let r = rotation(g, f, &mut c, &mut s);
+                        if i != m - 1 {
+                            e[i + 1] = r;
+                        }
+                        g = d[i + 1] - p;
+                        let r = (d[i] - g) * s + two * c * b;
+                        p = s * r;
+                        d[i + 1] = g + p;
+                        g = c * r - b;
+
+                        if vectors {
+                            work_c[i] = c;
+                            work_s[i] = -s;
+                        }
+                    }
+
+                    if vectors {
+                        let mm = m - l + 1;
+                        rotate(
+                            false,
+                            'V',
+                            false,
+                            n,
+                            mm,
+                            &work_c[l..],
+                            &work_s[l..],
+                            &mut z[l * ldz..],
+                            ldz,
+                        );
+                    }
+
+                    d[l] = d[l] - p;
+                    e[l] = g;
+                    continue;
+                }
+
+                if l > lend {
+                    break;
+                }
+            }
+        } else {
+            // QR iteration, finding the eigenvalues from the last one
+            loop {
+                let mut m = lend;
+                for k in (lend + 1..=l).rev() {
+                    let tst = e[k - 1].abs().powi(2);
+                    if tst <= (eps2 * d[k].abs()) * d[k - 1].abs() + safmin {
+                        m = k;
+                        break;
+                    }
+                }
+                if m > lend {
+                    e[m - 1] = zero;
+                }
+
+                if m == l {
+                    // d[l] is an eigenvalue
+                    if l == lend {
+                        break;
+                    }
+                    l -= 1;
+                } else if m + 1 == l {
+                    // the 2 by 2 block is diagonalized directly
+                    let (rt1, rt2, c, s) = eigen2(d[l - 1], e[l - 1], d[l]);
+                    if vectors {
+                        work_c[m] = c;
+                        work_s[m] = s;
+                        rotate(
+                            false,
+                            'V',
+                            true,
+                            n,
+                            2,
+                            &work_c[m..],
+                            &work_s[m..],
+                            &mut z[m * ldz..],
+                            ldz,
+                        );
+                    }
+                    d[l - 1] = rt1;
+                    d[l] = rt2;
+                    e[l - 1] = zero;
+                    if l < lend + 2 {
+                        break;
+                    }
+                    l -= 2;
+                } else {
+                    if jtot == nmaxit {
+                        break;
+                    }
+                    jtot += 1;
+
+                    // Wilkinson shift
+                    let p = d[l];
+                    let g = (d[l - 1] - p) / (two * e[l - 1]);
+                    let r = g.hypot(one);
+                    let mut g = d[m] - p + e[l - 1] / (g + if g >= zero { r } else { -r });
+
+                    let (mut s, mut c, mut p) = (one, one, zero);
+                    for i in m..l {
+                        let f = s * e[i];
+                        let b = c * e[i];
+                        let r = rotation(g, f, &mut c, &mut s);
+                        if i != m {
+                            e[i - 1] = r;
+                        }
+                        g = d[i] - p;
+                        let r = (d[i + 1] - g) * s + two * c * b;
+                        p = s * r;
+                        d[i] = g + p;
+                        g = c * r - b;
+
+                        if vectors {
+                            work_c[i] = c;
+                            work_s[i] = s;
+                        }
+                    }
+
+                    if vectors {
+                        let mm = l - m + 1;
+                        rotate(
+                            false,
+                            'V',
+                            true,
+                            n,
+                            mm,
+                            &work_c[m..],
+                            &work_s[m..],
+                            &mut z[m * ldz..],
+                            ldz,
+                        );
+                    }
+
+                    d[l] = d[l] - p;
+                    e[l - 1] = g;
+                }
+            }
+        }
+
+        if let Some(cto) = scaled {
+            scale(&mut d[lsv..=lendsv], cto, anorm);
+            scale(&mut e[lsv..lendsv], cto, anorm);
+        }
+
+        if jtot == nmaxit {
+            let nonzero = e.iter().filter(|&&ei| ei != zero).count();
+            if nonzero > 0 {
+                return Some(nonzero);
+            }
+        }
+    }
+
+    // selection sort, which swaps the fewest eigenvectors
+    for i in 0..n - 1 {
+        let mut k = i;
+        for j in i + 1..n {
+            if d[j] < d[k] {
+                k = j;
+            }
+        }
+        if k != i {
+            d.swap(i, k);
+            if vectors {
+                for r in 0..n {
+                    z.swap(r + i * ldz, r + k * ldz);
+                }
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,119 @@
+use alloc::vec;
+
+use nd_slice::NdSliceMut;
+use num_traits::{Float, One, Zero};
+
+use crate::contiguous::as_mut_slice;
+
+use super::steqr::diagonalize;
+use super::sytrd::{form_q, tridiagonalize};
+use super::{span, LapackError, Scalar};
+
+/// `syev` under the name netlib gives it for complex types.
+pub use self::syev as heev;
+
+/// Computes the eigenvalues and, optionally, the eigenvectors of a Hermitian matrix, symmetric
+/// for real types.
+///
+/// A = Z * diag(w) * Zᴴ
+///
+/// A is reduced to tridiagonal form with `sytrd`, which is then diagonalized with `steqr`, Z
+/// starting as the Q of the reduction if the eigenvectors are wanted. A is scaled first if its
+/// elements are too large or too small.
+///
+/// Input:
+///     jobz: 'N' for eigenvalues only, 'V' for eigenvalues and eigenvectors
+///     uplo: 'U' if the upper triangle of A is stored, 'L' if the lower triangle is
+///     n: order of A
+///     a: Hermitian matrix in column-major order, lda by n, the other triangle is not referenced
+///     lda: leading dimension of a, at least max(1, n)
+///     w: vector of n elements
+/// Output:
+///     a: the orthonormal eigenvectors Z if jobz = 'V', column j belonging to w[j], destroyed
+///     otherwise
+///     w: the eigenvalues in ascending order
+///     returns `LapackError::NotConverged(i)` if `steqr` failed to converge, i off-diagonal
+///     elements of an intermediate tridiagonal form being nonzero
+pub fn syev<T: Scalar>(
+    jobz: &char,
+    uplo: &char,
+    n: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    w: &mut NdSliceMut<'_, T::Real, 1>,
+) -> Result<(), LapackError> {
+    let (n, lda) = (*n, *lda);
+
+    let wantz = jobz.eq_ignore_ascii_case(&'V');
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+
+    if !(wantz || jobz.eq_ignore_ascii_case(&'N')) {
+        return Err(LapackError::Argument(1));
+    }
+    if !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return Err(LapackError::Argument(2));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(3));
+    }
+    if lda < n.max(1) {
+        return Err(LapackError::Argument(5));
+    }
+    let (n, lda) = (n as usize, lda as usize);
+
+    if n == 0 {
+        return Ok(());
+    }
+
+    let a = as_mut_slice(a, span(n, n, lda));
+    let w = as_mut_slice(w, n);
+
+    // the stored triangle of A, column by column
+    let rows = |j: usize| if upper { 0..j + 1 } else { j..n };
+
+    // scales A so that its largest element lies between rmin and rmax
+    let smlnum = T::Real::min_positive_value() / T::Real::epsilon();
+    let (rmin, rmax) = (smlnum.sqrt(), (T::Real::one() / smlnum).sqrt());
+
+    let anrm = (0..n)
+        .flat_map(|j| rows(j).map(move |i| i + j * lda))
+        .fold(T::Real::zero(), |anrm, k| anrm.max(a[k].abs()));
+    let sigma = if anrm > T::Real::zero() && anrm < rmin {
+        Some(rmin / anrm)
+    } else if anrm > rmax {
+        Some(rmax / anrm)
+    } else {
+        None
+    };
+    if let Some(sigma) = sigma {
+        for j in 0..n {
+            for i in rows(j) {
+                a[i + j * lda] = a[i + j * lda] * T::from_real(sigma);
+            }
+        }
+    }
+
+    let mut e = vec![T::Real::zero(); n - 1];
+    let mut tau = vec![T::zero(); n - 1];
+    tridiagonalize(upper, n, a, lda, w, &mut e, &mut tau);
+
+    let info = if wantz {
+        let mut q = vec![T::zero(); n * n];
+        form_q(upper, n, a, lda, &tau, &mut q, n);
+        for j in 0..n {
+            a[j * lda..j * lda + n].copy_from_slice(&q[j * n..(j + 1) * n]);
+        }
+        diagonalize(true, n, w, &mut e, a, lda)
+    } else {
+        diagonalize::<T>(false, n, w, &mut e, &mut [], 1)
+    };
+
+    if let Some(sigma) = sigma {
+        w.iter_mut().for_each(|wi| *wi = *wi / sigma);
+    }
+
+    match info {
+        Some(i) => Err(LapackError::NotConverged(i)),
+        None => Ok(()),
+    }
+}
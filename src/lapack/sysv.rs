@@ -0,0 +1,63 @@
+use nd_slice::NdSliceMut;
+
+use crate::contiguous::{as_slice, vector};
+
+use super::sytrf::sytrf;
+use super::sytrs::sytrs;
+use super::{span, LapackError, Scalar};
+
+/// Solves a system of linear equations with a symmetric matrix.
+///
+/// A * X = B
+///
+/// A is factored as U * D * Uᵀ or L * D * Lᵀ with `sytrf` and the factorization is used to
+/// solve the system with `sytrs`.
+///
+/// Input:
+///     uplo: 'U' if the upper triangle of A is stored, 'L' if the lower triangle is
+///     n: order of A
+///     nrhs: number of columns of B
+///     a: symmetric matrix in column-major order, lda by n, the other triangle is not referenced
+///     lda: leading dimension of a, at least max(1, n)
+///     ipiv: vector of n elements
+///     b: right-hand sides in column-major order, ldb by nrhs
+///     ldb: leading dimension of b, at least max(1, n)
+/// Output:
+///     a: D and the multipliers from `sytrf`
+///     ipiv: the interchanges and the block structure of D from `sytrf`
+///     b: the solution X, unless A is singular
+///     returns `LapackError::Singular(i)` if D(i, i) is exactly zero, B then being unchanged
+#[allow(clippy::too_many_arguments)]
+pub fn sysv<T: Scalar>(
+    uplo: &char,
+    n: &isize,
+    nrhs: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    ipiv: &mut NdSliceMut<'_, isize, 1>,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) -> Result<(), LapackError> {
+    if !"UuLl".contains(*uplo) {
+        return Err(LapackError::Argument(1));
+    }
+    if *n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if *nrhs < 0 {
+        return Err(LapackError::Argument(3));
+    }
+    if *lda < (*n).max(1) {
+        return Err(LapackError::Argument(5));
+    }
+    if *ldb < (*n).max(1) {
+        return Err(LapackError::Argument(8));
+    }
+
+    sytrf(uplo, n, a, lda, ipiv)?;
+
+    let k = *n as usize;
+    let a = vector(as_slice(a, span(k, k, *lda as usize)));
+    let ipiv = vector(as_slice(ipiv, k));
+    sytrs(uplo, n, nrhs, &a, lda, &ipiv, b, ldb)
+}
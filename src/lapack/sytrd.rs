@@ -0,0 +1,200 @@
+use alloc::vec::Vec;
+
+use nd_slice::NdSliceMut;
+use num_traits::NumCast;
+
+use crate::contiguous::as_mut_slice;
+
+use super::larf::reflect;
+use super::larfg::generate;
+use super::{span, LapackError, Scalar};
+
+/// `sytrd` under the name netlib gives it for complex types.
+pub use self::sytrd as hetrd;
+
+/// Reduces a Hermitian matrix, symmetric for real types, to real symmetric tridiagonal form by
+/// a unitary similarity transformation.
+///
+/// Qᴴ * A * Q = T
+///
+/// where Q = H(n - 2) * ... * H(1) * H(0) if uplo = 'U' and Q = H(0) * H(1) * ... * H(n - 2)
+/// if uplo = 'L', each H(i) = I - tau[i] * v * vᴴ being an elementary reflector. The matrix is
+/// reduced one column at a time, the rest of the triangle being updated with hemv and her2 (symv
+/// and syr2 for real types).
+///
+/// If uplo = 'U', v[i + 1..] = 0 and v[i] = 1, v[..i] being stored in A(0..i, i + 1). If
+/// uplo = 'L', v[..i + 1] = 0 and v[i + 1] = 1, v[i + 2..] being stored in A(i + 2.., i).
+///
+/// Input:
+///     uplo: 'U' if the upper triangle of A is stored, 'L' if the lower triangle is
+///     n: order of A
+///     a: Hermitian matrix in column-major order, lda by n, the other triangle is not referenced
+///     lda: leading dimension of a, at least max(1, n)
+///     d: vector of n elements
+///     e: vector of n - 1 elements
+///     tau: vector of n - 1 elements
+/// Output:
+///     a: T on its diagonal and first off-diagonal of the stored triangle, the reflectors
+///     in the rest of it
+///     d: the diagonal of T
+///     e: the off-diagonal of T
+///     tau: the scalar factors of the reflectors
+pub fn sytrd<T: Scalar>(
+    uplo: &char,
+    n: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    d: &mut NdSliceMut<'_, T::Real, 1>,
+    e: &mut NdSliceMut<'_, T::Real, 1>,
+    tau: &mut NdSliceMut<'_, T, 1>,
+) -> Result<(), LapackError> {
+    let (n, lda) = (*n, *lda);
+
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+
+    if !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if lda < n.max(1) {
+        return Err(LapackError::Argument(4));
+    }
+    let (n, lda) = (n as usize, lda as usize);
+
+    if n == 0 {
+        return Ok(());
+    }
+
+    let a = as_mut_slice(a, span(n, n, lda));
+    let d = as_mut_slice(d, n);
+    let e = as_mut_slice(e, n - 1);
+    let tau = as_mut_slice(tau, n - 1);
+
+    tridiagonalize(upper, n, a, lda, d, e, tau);
+
+    Ok(())
+}
+
+/// Reduces the n by n matrix a to tridiagonal form as `sytrd` does.
+pub(super) fn tridiagonalize<T: Scalar>(
+    upper: bool,
+    n: usize,
+    a: &mut [T],
+    lda: usize,
+    d: &mut [T::Real],
+    e: &mut [T::Real],
+    tau: &mut [T],
+) {
+    let uplo = if upper { 'U' } else { 'L' };
+    let real = |x: T| T::from_real(x.re());
+    let half = <T::Real as NumCast>::from(0.5).unwrap();
+
+    // applies H = I - taui * v * vᴴ of order m from both sides as the rank-2 update
+    // A = A - v * wᴴ - w * vᴴ, with w = x - ½ * taui * (xᴴ * v) * v and x = taui * A * v computed
+    // in the workspace w
+    let update = |m: usize, a: &mut [T], v: &[T], taui: T, w: &mut [T]| {
+        T::hemv(uplo, m, taui, a, lda, v, 1, T::zero(), w, 1);
+        let alpha = -T::from_real(half) * taui * T::dotc(m, w, 1, v, 1);
+        T::axpy(m, alpha, v, 1, w, 1);
+        T::her2(uplo, m, -T::one(), v, 1, w, 1, a, lda);
+    };
+
+    if upper {
+        a[(n - 1) + (n - 1) * lda] = real(a[(n - 1) + (n - 1) * lda]);
+
+        for i in (0..n - 1).rev() {
+            // H(i) annihilates A(0..i, i + 1)
+            let (a11, v) = a.split_at_mut((i + 1) * lda);
+            let mut alpha = v[i];
+            let taui = generate(i + 1, &mut alpha, &mut v[..i], 1);
+            e[i] = alpha.re();
+
+            if taui != T::zero() {
+                v[i] = T::one();
+                update(i + 1, a11, &v[..=i], taui, &mut tau[..=i]);
+            } else {
+                a11[i + i * lda] = real(a11[i + i * lda]);
+            }
+
+            v[i] = T::from_real(e[i]);
+            d[i + 1] = v[i + 1].re();
+            tau[i] = taui;
+        }
+        d[0] = a[0].re();
+    } else {
+        a[0] = real(a[0]);
+
+        for i in 0..n - 1 {
+            // H(i) annihilates A(i + 2.., i)
+            let m = n - i - 1;
+            let (v, a22) = a.split_at_mut((i + 1) * lda);
+            let (aii, v) = v[i * lda..].split_at_mut(i + 1);
+            let mut alpha = v[0];
+            let taui = generate(m, &mut alpha, &mut v[1..m], 1);
+            e[i] = alpha.re();
+
+            let a22 = &mut a22[i + 1..];
+            if taui != T::zero() {
+                v[0] = T::one();
+                update(m, a22, &v[..m], taui, &mut tau[i..]);
+            } else {
+                a22[0] = real(a22[0]);
+            }
+
+            v[0] = T::from_real(e[i]);
+            d[i] = aii[i].re();
+            tau[i] = taui;
+        }
+        d[n - 1] = a[(n - 1) + (n - 1) * lda].re();
+    }
+}
+
+/// Forms the n by n matrix Q of the reduction computed by `sytrd` in q, from the reflectors in
+/// a and their scalar factors in tau.
+pub(super) fn form_q<T: Scalar>(
+    upper: bool,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    tau: &[T],
+    q: &mut [T],
+    ldq: usize,
+) {
+    for j in 0..n {
+        for i in 0..n {
+            q[i + j * ldq] = if i == j { T::one() } else { T::zero() };
+        }
+    }
+
+    let mut v = Vec::with_capacity(n);
+
+    if upper {
+        // Q = H(n - 2) * ... * H(0) * I, H(i) only acting on the leading i + 1 rows and columns
+        for i in 0..n - 1 {
+            v.clear();
+            v.extend_from_slice(&a[(i + 1) * lda..(i + 1) * lda + i]);
+            v.push(T::one());
+            reflect(true, i + 1, i + 1, &v, tau[i], q, ldq);
+        }
+    } else {
+        // Q = H(0) * ... * H(n - 2) * I, H(i) only acting on the trailing n - i - 1 rows and
+        // columns
+        for i in (0..n - 1).rev() {
+            let m = n - i - 1;
+            v.clear();
+            v.push(T::one());
+            v.extend_from_slice(&a[i + 2 + i * lda..i + 1 + m + i * lda]);
+            reflect(
+                true,
+                m,
+                m,
+                &v,
+                tau[i],
+                &mut q[(i + 1) + (i + 1) * ldq..],
+                ldq,
+            );
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use nd_slice::NdSliceMut;
+use num_traits::{Float, NumCast, One, Zero};
+
+use crate::contiguous::as_mut_slice;
+
+use super::{span, LapackError, Scalar};
+
+/// Computes the factorization of a symmetric matrix, using the Bunch-Kaufman diagonal pivoting
+/// method.
+///
+/// A = U * D * Uᵀ or A = L * D * Lᵀ
+///
+/// where U (L) is a product of permutation and unit upper (lower) triangular matrices and D is
+/// symmetric and block diagonal with 1 by 1 and 2 by 2 blocks. The matrix is factored one
+/// column, or two for a 2 by 2 block, at a time, the rest of the triangle being updated with
+/// syrk after a 1 by 1 block. Complex matrices are symmetric, not Hermitian, see `hetrf`.
+///
+/// Input:
+///     uplo: 'U' if the upper triangle of A is stored, 'L' if the lower triangle is
+///     n: order of A
+///     a: symmetric matrix in column-major order, lda by n, the other triangle is not referenced
+///     lda: leading dimension of a, at least max(1, n)
+///     ipiv: vector of n elements
+/// Output:
+///     a: D and the multipliers of U or L in the stored triangle
+///     ipiv: the interchanges and the block structure of D. If ipiv[k] >= 0, D(k, k) is a 1 by 1
+///     block and rows and columns k and ipiv[k] were interchanged. If ipiv[k] = ipiv[k - 1] < 0
+///     with uplo = 'U', or ipiv[k] = ipiv[k + 1] < 0 with uplo = 'L', rows and columns k - 1 and
+///     !ipiv[k] = -ipiv[k] - 1, or k + 1 and !ipiv[k], were interchanged and D(k - 1..=k,
+///     k - 1..=k), or D(k..=k + 1, k..=k + 1), is a 2 by 2 block.
+///     returns `LapackError::Singular(i)` for the first i such that D(i, i) is exactly zero
+pub fn sytrf<T: Scalar>(
+    uplo: &char,
+    n: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    ipiv: &mut NdSliceMut<'_, isize, 1>,
+) -> Result<(), LapackError> {
+    factor_checked(false, uplo, n, a, lda, ipiv)
+}
+
+/// Checks the arguments of `sytrf` or `hetrf` and factors A.
+pub(super) fn factor_checked<T: Scalar>(
+    hermitian: bool,
+    uplo: &char,
+    n: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    ipiv: &mut NdSliceMut<'_, isize, 1>,
+) -> Result<(), LapackError> {
+    let (n, lda) = (*n, *lda);
+
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+
+    if !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if lda < n.max(1) {
+        return Err(LapackError::Argument(4));
+    }
+    let (n, lda) = (n as usize, lda as usize);
+
+    if n == 0 {
+        return Ok(());
+    }
+
+    let a = as_mut_slice(a, span(n, n, lda));
+    let ipiv = as_mut_slice(ipiv, n);
+
+    match factor(hermitian, upper, n, a, lda, ipiv) {
+        Some(i) => Err(LapackError::Singular(i)),
+        None => Ok(()),
+    }
+}
+
+/// Factors A as U * D * Uᵀ or L * D * Lᵀ, or U * D * Uᴴ or L * D * Lᴴ if hermitian is true,
+/// returning the first i such that D(i, i) is exactly zero.
+fn factor<T: Scalar>(
+    hermitian: bool,
+    upper: bool,
+    n: usize,
+    a: &mut [T],
+    lda: usize,
+    ipiv: &mut [isize],
+) -> Option<usize> {
+    let conj = |x: T| if hermitian { x.conj() } else { x };
+    // the diagonal of a Hermitian matrix is real
+    let real = |x: T| if hermitian { T::from_real(x.re()) } else { x };
+
+    let (zero, one) = (T::Real::zero(), T::Real::one());
+    // the growth bound of the Bunch-Kaufman pivoting
+    let cast = |x: u8| <T::Real as NumCast>::from(x).unwrap();
+    let alpha = (one + cast(17).sqrt()) / cast(8);
+
+    let mut info = None;
+
+    // a = a - x * d⁻¹ * op(x) in the triangle of the k by k matrix a, x being the first k
+    // elements of b
+    let rank_one = |uplo: char, k: usize, a: &mut [T], b: &[T], d: T| {
+        if hermitian {
+            T::herk(uplo, 'N', k, 1, -(one / d.re()), b, lda, one, a, lda);
+        } else {
+            T::syrk(uplo, 'N', k, 1, -(T::one() / d), b, lda, T::one(), a, lda);
+        }
+    };
+
+    if upper {
+        // factor columns n - 1 down to 0, k being the last column left
+        let mut end = n;
+        while end > 0 {
+            let k = end - 1;
+            let mut kstep = 1;
+
+            a[k + k * lda] = real(a[k + k * lda]);
+            let absakk = a[k + k * lda].abs();
+
+            // the largest off-diagonal element in column k
+            let (imax, colmax) = if k > 0 {
+                let imax = T::iamax(k, &a[k * lda..], 1);
+                (imax, a[imax + k * lda].abs())
+            } else {
+                (0, zero)
+            };
+
+            let kp = if absakk.max(colmax) == zero || absakk.is_nan() {
+                // column k is zero or contains a NaN, it is left as it is
+                info = info.or(Some(k));
+                k
+            } else {
+                let kp = if absakk >= alpha * colmax {
+                    k
+                } else {
+                    // the largest off-diagonal element in row and column imax
+                    let jmax = imax + 1 + T::iamax(k - imax, &a[imax + (imax + 1) * lda..], lda);
+                    let mut rowmax = a[imax + jmax * lda].abs();
+                    if imax > 0 {
+                        let jmax = T::iamax(imax, &a[imax * lda..], 1);
+                        rowmax = rowmax.max(a[jmax + imax * lda].abs());
+                    }
+
+                    if absakk >= alpha * colmax * (colmax / rowmax) {
+                        k
+                    } else if real(a[imax + imax * lda]).abs() >= alpha * rowmax {
+                        imax
+                    } else {
+                        kstep = 2;
+                        imax
+                    }
+                };
+
+                // interchange rows and columns kk and kp in the leading k + 1 columns
+                let kk = k + 1 - kstep;
+                if kp != kk {
+                    for i in 0..kp {
+                        a.swap(i + kk * lda, i + kp * lda);
+                    }
+                    for j in kp + 1..kk {
+                        let temp = conj(a[j + kk * lda]);
+                        a[j + kk * lda] = conj(a[kp + j * lda]);
+                        a[kp + j * lda] = temp;
+                    }
+                    a[kp + kk * lda] = conj(a[kp + kk * lda]);
+                    let temp = real(a[kk + kk * lda]);
+                    a[kk + kk * lda] = real(a[kp + kp * lda]);
+                    a[kp + kp * lda] = temp;
+                    if kstep == 2 {
+                        a.swap(k - 1 + k * lda, kp + k * lda);
+                    }
+                }
+
+                if kstep == 1 {
+                    // A(0..k, 0..k) = A(0..k, 0..k) - x * D(k, k)⁻¹ * op(x), x = A(0..k, k)
+                    if k > 0 {
+                        let d = a[k + k * lda];
+                        let (a11, b) = a.split_at_mut(k * lda);
+                        rank_one('U', k, a11, b, d);
+                        T::scal(k, T::one() / real(d), &mut a[k * lda..], 1);
+                    }
+                } else if k > 1 {
+                    // A(0..k - 1, 0..k - 1) = A(0..k - 1, 0..k - 1) - W * D⁻¹ * op(W), the
+                    // columns of W being A(0..k - 1, k - 1) and A(0..k - 1, k)
+                    let d12 = a[k - 1 + k * lda];
+                    let s = if hermitian {
+                        T::from_real(d12.abs())
+                    } else {
+                        d12
+                    };
+                    let e = d12 / s;
+                    let d11 = a[k + k * lda] / s;
+                    let d22 = a[k - 1 + (k - 1) * lda] / s;
+                    let d = T::one() / (d11 * d22 - T::one()) / s;
+
+                    for j in (0..k - 1).rev() {
+                        let wkm1 = d * (d11 * a[j + (k - 1) * lda] - conj(e) * a[j + k * lda]);
+                        let wk = d * (d22 * a[j + k * lda] - e * a[j + (k - 1) * lda]);
+                        for i in (0..=j).rev() {
+                            a[i + j * lda] = a[i + j * lda]
+                                - a[i + k * lda] * conj(wk)
+                                - a[i + (k - 1) * lda] * conj(wkm1);
+                        }
+                        a[j + k * lda] = wk;
+                        a[j + (k - 1) * lda] = wkm1;
+                        a[j + j * lda] = real(a[j + j * lda]);
+                    }
+                }
+
+                kp
+            };
+
+            if kstep == 1 {
+                ipiv[k] = kp as isize;
+            } else {
+                ipiv[k] = !(kp as isize);
+                ipiv[k - 1] = !(kp as isize);
+            }
+            end -= kstep;
+        }
+    } else {
+        // factor columns 0 up to n - 1, k being the first column left
+        let mut k = 0;
+        while k < n {
+            let mut kstep = 1;
+
+            a[k + k * lda] = real(a[k + k * lda]);
+            let absakk = a[k + k * lda].abs();
+
+            // the largest off-diagonal element in column k
+            let (imax, colmax) = if k + 1 < n {
+                let imax = k + 1 + T::iamax(n - k - 1, &a[k + 1 + k * lda..], 1);
+                (imax, a[imax + k * lda].abs())
+            } else {
+                (0, zero)
+            };
+
+            let kp = if absakk.max(colmax) == zero || absakk.is_nan() {
+                // column k is zero or contains a NaN, it is left as it is
+                info = info.or(Some(k));
+                k
+            } else {
+                let kp = if absakk >= alpha * colmax {
+                    k
+                } else {
+                    // the largest off-diagonal element in row and column imax
+                    let jmax = k + T::iamax(imax - k, &a[imax + k * lda..], lda);
+                    let mut rowmax = a[imax + jmax * lda].abs();
+                    if imax + 1 < n {
+                        let jmax =
+                            imax + 1 + T::iamax(n - imax - 1, &a[imax + 1 + imax * lda..], 1);
+                        rowmax = rowmax.max(a[jmax + imax * lda].abs());
+                    }
+
+                    if absakk >= alpha * colmax * (colmax / rowmax) {
+                        k
+                    } else if real(a[imax + imax * lda]).abs() >= alpha * rowmax {
+                        imax
+                    } else {
+                        kstep = 2;
+                        imax
+                    }
+                };
+
+                // interchange rows and columns kk and kp in the trailing n - k columns
+                let kk = k + kstep - 1;
+                if kp != kk {
+                    for i in kp + 1..n {
+                        a.swap(i + kk * lda, i + kp * lda);
+                    }
+                    for j in kk + 1..kp {
+                        let temp = conj(a[j + kk * lda]);
+                        a[j + kk * lda] = conj(a[kp + j * lda]);
+                        a[kp + j * lda] = temp;
+                    }
+                    a[kp + kk * lda] = conj(a[kp + kk * lda]);
+                    let temp = real(a[kk + kk * lda]);
+                    a[kk + kk * lda] = real(a[kp + kp * lda]);
+                    a[kp + kp * lda] = temp;
+                    if kstep == 2 {
+                        a.swap(k + 1 + k * lda, kp + k * lda);
+                    }
+                }
+
+                if kstep == 1 {
+                    // A22 = A22 - x * D(k, k)⁻¹ * op(x), x = A(k + 1..n, k)
+                    if k + 1 < n {
+                        let d = a[k + k * lda];
+                        let (b, a22) = a.split_at_mut((k + 1) * lda);
+                        rank_one('L', n - k - 1, &mut a22[k + 1..], &b[k + 1 + k * lda..], d);
+                        T::scal(n - k - 1, T::one() / real(d), &mut a[k + 1 + k * lda..], 1);
+                    }
+                } else if k + 2 < n {
+                    // A22 = A22 - W * D⁻¹ * op(W), the columns of W being A(k + 2..n, k) and
+                    // A(k + 2..n, k + 1)
+                    let d21 = a[k + 1 + k * lda];
+                    let s = if hermitian {
+                        T::from_real(d21.abs())
+                    } else {
+                        d21
+                    };
+                    let e = d21 / s;
+                    let d11 = a[k + 1 + (k + 1) * lda] / s;
+                    let d22 = a[k + k * lda] / s;
+                    let d = T::one() / (d11 * d22 - T::one()) / s;
+
+                    for j in k + 2..n {
+                        let wk = d * (d11 * a[j + k * lda] - e * a[j + (k + 1) * lda]);
+                        let wkp1 = d * (d22 * a[j + (k + 1) * lda] - conj(e) * a[j + k * lda]);
+                        for i in j..n {
+                            a[i + j * lda] = a[i + j * lda]
+                                - a[i + k * lda] * conj(wk)
+                                - a[i + (k + 1) * lda] * conj(wkp1);
+                        }
+                        a[j + k * lda] = wk;
+                        a[j + (k + 1) * lda] = wkp1;
+                        a[j + j * lda] = real(a[j + j * lda]);
+                    }
+                }
+
+                kp
+            };
+
+            if kstep == 1 {
+                ipiv[k] = kp as isize;
+            } else {
+                ipiv[k] = !(kp as isize);
+                ipiv[k + 1] = !(kp as isize);
+            }
+            k += kstep;
+        }
+    }
+
+    info
+}
@@ -0,0 +1,255 @@
+use nd_slice::{NdSlice, NdSliceMut};
+
+use crate::contiguous::{as_mut_slice, as_slice};
+
+use super::{span, LapackError, Scalar};
+
+/// Solves a system of linear equations with a symmetric matrix, using its factorization
+/// computed by `sytrf`.
+///
+/// A * X = B
+///
+/// The interchanges and the multipliers are applied to B one block of D and one right-hand side
+/// at a time with axpy, and with gemm when solving with Uᵀ or Lᵀ.
+///
+/// Input:
+///     uplo: 'U' if A = U * D * Uᵀ, 'L' if A = L * D * Lᵀ
+///     n: order of A
+///     nrhs: number of columns of B
+///     a: D and the multipliers from `sytrf`, lda by n
+///     lda: leading dimension of a, at least max(1, n)
+///     ipiv: the interchanges and the block structure of D from `sytrf`
+///     b: right-hand sides in column-major order, ldb by nrhs
+///     ldb: leading dimension of b, at least max(1, n)
+/// Output:
+///     b: the solution X
+#[allow(clippy::too_many_arguments)]
+pub fn sytrs<T: Scalar>(
+    uplo: &char,
+    n: &isize,
+    nrhs: &isize,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    ipiv: &NdSlice<'_, isize, 1>,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) -> Result<(), LapackError> {
+    solve_checked(false, uplo, n, nrhs, a, lda, ipiv, b, ldb)
+}
+
+/// Checks the arguments of `sytrs` or `hetrs` and solves the system.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn solve_checked<T: Scalar>(
+    hermitian: bool,
+    uplo: &char,
+    n: &isize,
+    nrhs: &isize,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    ipiv: &NdSlice<'_, isize, 1>,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) -> Result<(), LapackError> {
+    let (n, nrhs, lda, ldb) = (*n, *nrhs, *lda, *ldb);
+
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+
+    if !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return Err(LapackError::Argument(1));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(2));
+    }
+    if nrhs < 0 {
+        return Err(LapackError::Argument(3));
+    }
+    if lda < n.max(1) {
+        return Err(LapackError::Argument(5));
+    }
+    if ldb < n.max(1) {
+        return Err(LapackError::Argument(8));
+    }
+    let (n, nrhs, lda, ldb) = (n as usize, nrhs as usize, lda as usize, ldb as usize);
+
+    if n == 0 || nrhs == 0 {
+        return Ok(());
+    }
+
+    let a = as_slice(a, span(n, n, lda));
+    let ipiv = as_slice(ipiv, n);
+    let b = as_mut_slice(b, span(n, nrhs, ldb));
+
+    solve(hermitian, upper, n, nrhs, a, lda, ipiv, b, ldb);
+
+    Ok(())
+}
+
+/// Solves A * X = B with A = U * D * op(U) or A = L * D * op(L), op being the conjugate
+/// transpose if hermitian is true and the transpose otherwise.
+#[allow(clippy::too_many_arguments)]
+fn solve<T: Scalar>(
+    hermitian: bool,
+    upper: bool,
+    n: usize,
+    nrhs: usize,
+    a: &[T],
+    lda: usize,
+    ipiv: &[isize],
+    b: &mut [T],
+    ldb: usize,
+) {
+    let conj = |x: T| if hermitian { x.conj() } else { x };
+    let real = |x: T| if hermitian { T::from_real(x.re()) } else { x };
+    let op = if hermitian { 'C' } else { 'T' };
+
+    let interchange = |b: &mut [T], i: usize, j: usize| {
+        if i != j {
+            for c in 0..nrhs {
+                b.swap(i + c * ldb, j + c * ldb);
+            }
+        }
+    };
+
+    // B = D⁻¹ * B for the 2 by 2 block D(k..=k + 1, k..=k + 1), d21 = D(k + 1, k)
+    let solve_block = |b: &mut [T], k: usize, d11: T, d21: T, d22: T| {
+        let d11 = d11 / conj(d21);
+        let d22 = d22 / d21;
+        let denom = d11 * d22 - T::one();
+        for c in 0..nrhs {
+            let bk = b[k + c * ldb] / conj(d21);
+            let bkp1 = b[k + 1 + c * ldb] / d21;
+            b[k + c * ldb] = (d22 * bk - bkp1) / denom;
+            b[k + 1 + c * ldb] = (d11 * bkp1 - bk) / denom;
+        }
+    };
+
+    // B(k, :) = B(k, :) - op(x) * B(i..i + m, :), x being the m elements of A from index j and
+    // the rows i..i + m not including k, a column at a time as the rows share their columns
+    let update_row = |b: &mut [T], k: usize, i: usize, m: usize, j: usize| {
+        if m == 0 {
+            return;
+        }
+        for col in b.chunks_mut(ldb) {
+            let (row, rows) = if i > k {
+                let (head, tail) = col.split_at_mut(i);
+                (&mut head[k..=k], &tail[..m])
+            } else {
+                let (head, tail) = col.split_at_mut(k);
+                (&mut tail[..1], &head[i..])
+            };
+            T::gemm(
+                op,
+                'N',
+                1,
+                1,
+                m,
+                -T::one(),
+                &a[j..],
+                lda,
+                rows,
+                m,
+                T::one(),
+                row,
+                1,
+            );
+        }
+    };
+
+    if upper {
+        // B = D⁻¹ * U⁻¹ * B, working from the last block of D back to the first
+        let mut end = n;
+        while end > 0 {
+            let k = end - 1;
+            if ipiv[k] >= 0 {
+                interchange(b, k, ipiv[k] as usize);
+
+                for col in b.chunks_mut(ldb) {
+                    let (above, row) = col.split_at_mut(k);
+                    T::axpy(k, -row[0], &a[k * lda..], 1, above, 1);
+                }
+                T::scal(nrhs, T::one() / real(a[k + k * lda]), &mut b[k..], ldb);
+                end -= 1;
+            } else {
+                interchange(b, k - 1, !ipiv[k] as usize);
+
+                for col in b.chunks_mut(ldb) {
+                    let (above, rows) = col.split_at_mut(k - 1);
+                    T::axpy(k - 1, -rows[1], &a[k * lda..], 1, above, 1);
+                    T::axpy(k - 1, -rows[0], &a[(k - 1) * lda..], 1, above, 1);
+                }
+
+                let d21 = conj(a[k - 1 + k * lda]);
+                let (d11, d22) = (a[k - 1 + (k - 1) * lda], a[k + k * lda]);
+                solve_block(b, k - 1, d11, d21, d22);
+                end -= 2;
+            }
+        }
+
+        // X = op(U)⁻¹ * B, working from the first block of D to the last
+        let mut k = 0;
+        while k < n {
+            if ipiv[k] >= 0 {
+                update_row(b, k, 0, k, k * lda);
+                interchange(b, k, ipiv[k] as usize);
+                k += 1;
+            } else {
+                update_row(b, k, 0, k, k * lda);
+                update_row(b, k + 1, 0, k, (k + 1) * lda);
+                interchange(b, k, !ipiv[k] as usize);
+                k += 2;
+            }
+        }
+    } else {
+        // B = D⁻¹ * L⁻¹ * B, working from the first block of D to the last
+        let mut k = 0;
+        while k < n {
+            if ipiv[k] >= 0 {
+                interchange(b, k, ipiv[k] as usize);
+
+                if k + 1 < n {
+                    let m = n - k - 1;
+                    for col in b.chunks_mut(ldb) {
+                        let (rows, below) = col.split_at_mut(k + 1);
+                        T::axpy(m, -rows[k], &a[k + 1 + k * lda..], 1, below, 1);
+                    }
+                }
+                T::scal(nrhs, T::one() / real(a[k + k * lda]), &mut b[k..], ldb);
+                k += 1;
+            } else {
+                interchange(b, k + 1, !ipiv[k] as usize);
+
+                if k + 2 < n {
+                    let m = n - k - 2;
+                    let (l1, l2) = (&a[k + 2 + k * lda..], &a[k + 2 + (k + 1) * lda..]);
+                    for col in b.chunks_mut(ldb) {
+                        let (rows, below) = col.split_at_mut(k + 2);
+                        T::axpy(m, -rows[k], l1, 1, below, 1);
+                        T::axpy(m, -rows[k + 1], l2, 1, below, 1);
+                    }
+                }
+
+                let d21 = a[k + 1 + k * lda];
+                let (d11, d22) = (a[k + k * lda], a[k + 1 + (k + 1) * lda]);
+                solve_block(b, k, d11, d21, d22);
+                k += 2;
+            }
+        }
+
+        // X = op(L)⁻¹ * B, working from the last block of D back to the first
+        let mut end = n;
+        while end > 0 {
+            let k = end - 1;
+            let m = n - k - 1;
+            if ipiv[k] >= 0 {
+                update_row(b, k, k + 1, m, k + 1 + k * lda);
+                interchange(b, k, ipiv[k] as usize);
+                end -= 1;
+            } else {
+                update_row(b, k, k + 1, m, k + 1 + k * lda);
+                update_row(b, k - 1, k + 1, m, k + 1 + (k - 1) * lda);
+                interchange(b, k, !ipiv[k] as usize);
+                end -= 2;
+            }
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use nd_slice::NdSliceMut;
+
+use crate::contiguous::as_mut_slice;
+
+use super::{span, LapackError, Scalar, NB};
+
+/// Computes the inverse of a triangular matrix.
+///
+/// A = A⁻¹
+///
+/// The matrix is inverted NB columns at a time, the off-diagonal blocks being updated with trmm
+/// and then solved with the diagonal block a column at a time with gemm.
+///
+/// Input:
+///     uplo: 'U' if A is upper triangular, 'L' if it is lower triangular
+///     diag: 'U' if A has a unit diagonal, 'N' otherwise
+///     n: order of A
+///     a: triangular matrix in column-major order, lda by n, the other triangle is not
+///     referenced, nor is the diagonal if diag = 'U'
+///     lda: leading dimension of a, at least max(1, n)
+/// Output:
+///     a: the inverse of A in the same triangle
+///     returns `LapackError::Singular(i)` if A(i, i) is exactly zero, A then being unchanged
+pub fn trtri<T: Scalar>(
+    uplo: &char,
+    diag: &char,
+    n: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+) -> Result<(), LapackError> {
+    let (n, lda) = (*n, *lda);
+
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+    let nounit = diag.eq_ignore_ascii_case(&'N');
+
+    if !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return Err(LapackError::Argument(1));
+    }
+    if !(nounit || diag.eq_ignore_ascii_case(&'U')) {
+        return Err(LapackError::Argument(2));
+    }
+    if n < 0 {
+        return Err(LapackError::Argument(3));
+    }
+    if lda < n.max(1) {
+        return Err(LapackError::Argument(5));
+    }
+    let (n, lda) = (n as usize, lda as usize);
+
+    if n == 0 {
+        return Ok(());
+    }
+
+    let a = as_mut_slice(a, span(n, n, lda));
+
+    if nounit {
+        if let Some(i) = (0..n).find(|&i| a[i + i * lda] == T::zero()) {
+            return Err(LapackError::Singular(i));
+        }
+    }
+
+    let diag = *diag;
+
+    if upper {
+        for j in (0..n).step_by(NB) {
+            let jb = NB.min(n - j);
+
+            // A12 = -A11⁻¹ * A12 * A22⁻¹, A11 being inverted already
+            if j > 0 {
+                let (a11, a12) = a.split_at_mut(j * lda);
+                T::trmm('L', 'U', 'N', diag, j, jb, T::one(), a11, lda, a12, lda);
+
+                // column c of the result times A22(c, c) is minus column c of A11⁻¹ * A12 less
+                // the columns of the result before it times A22(j..c, c), a column at a time as
+                // A12 and A22 share their columns
+                for c in j..j + jb {
+                    let (left, right) = a.split_at_mut(c * lda);
+                    let (x, a22) = right.split_at_mut(j);
+                    T::gemm(
+                        'N',
+                        'N',
+                        j,
+                        1,
+                        c - j,
+                        T::one(),
+                        &left[j * lda..],
+                        lda,
+                        a22,
+                        (c - j).max(1),
+                        T::one(),
+                        x,
+                        j,
+                    );
+                    let scale = if nounit {
+                        -T::one() / a22[c - j]
+                    } else {
+                        -T::one()
+                    };
+                    T::scal(j, scale, x, 1);
+                }
+            }
+
+            trti2(upper, nounit, jb, &mut a[j + j * lda..], lda);
+        }
+    } else {
+        for j in (0..n).step_by(NB).rev() {
+            let jb = NB.min(n - j);
+
+            // A21 = -A22⁻¹ * A21 * A11⁻¹, A22 being inverted already
+            if j + jb < n {
+                let m = n - j - jb;
+                let (left, a22) = a.split_at_mut((j + jb) * lda);
+                let a21 = &mut left[j + jb + j * lda..];
+                T::trmm(
+                    'L',
+                    'L',
+                    'N',
+                    diag,
+                    m,
+                    jb,
+                    T::one(),
+                    &a22[j + jb..],
+                    lda,
+                    a21,
+                    lda,
+                );
+
+                // column c of the result times A11(c, c) is minus column c of A22⁻¹ * A21 less
+                // the columns of the result after it times A11(c + 1..j + jb, c), a column at a
+                // time as A21 and A11 share their columns
+                for c in (j..j + jb).rev() {
+                    let (left, right) = a.split_at_mut((c + 1) * lda);
+                    let (a11, x) = left[c * lda..].split_at_mut(j + jb);
+                    let k = j + jb - c - 1;
+                    T::gemm(
+                        'N',
+                        'N',
+                        m,
+                        1,
+                        k,
+                        T::one(),
+                        &right[j + jb..],
+                        lda,
+                        &a11[c + 1..],
+                        k.max(1),
+                        T::one(),
+                        x,
+                        m,
+                    );
+                    let scale = if nounit {
+                        -T::one() / a11[c]
+                    } else {
+                        -T::one()
+                    };
+                    T::scal(m, scale, x, 1);
+                }
+            }
+
+            trti2(upper, nounit, jb, &mut a[j + j * lda..], lda);
+        }
+    }
+
+    Ok(())
+}
+
+/// Inverts the n by n triangular matrix a one column at a time, its diagonal being nonzero.
+fn trti2<T: Scalar>(upper: bool, nounit: bool, n: usize, a: &mut [T], lda: usize) {
+    let diag = if nounit { 'N' } else { 'U' };
+
+    // inverts A(j, j) and returns -A(j, j)⁻¹, by which the rest of column j is scaled
+    let invert_diagonal = |a: &mut [T], j: usize| {
+        if nounit {
+            a[j + j * lda] = T::one() / a[j + j * lda];
+            -a[j + j * lda]
+        } else {
+            -T::one()
+        }
+    };
+
+    if upper {
+        for j in 0..n {
+            let ajj = invert_diagonal(a, j);
+
+            if j > 0 {
+                let (a11, x) = a.split_at_mut(j * lda);
+                T::trmm('L', 'U', 'N', diag, j, 1, T::one(), a11, lda, x, lda);
+                T::scal(j, ajj, x, 1);
+            }
+        }
+    } else {
+        for j in (0..n).rev() {
+            let ajj = invert_diagonal(a, j);
+
+            if j + 1 < n {
+                let m = n - j - 1;
+                let (x, a22) = a.split_at_mut((j + 1) * lda);
+                let (x, a22) = (&mut x[j + 1 + j * lda..], &a22[j + 1..]);
+                T::trmm('L', 'L', 'N', diag, m, 1, T::one(), a22, lda, x, m);
+                T::scal(m, ajj, x, 1);
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use nd_slice::NdSlice;
+use num_complex::Complex;
+use num_traits::Float;
+
+/// Computes the sum of the absolute values of the elements of a vector.
+///
+/// res = Σ |x[i]|
+///
+/// Input:
+///     n: number of elements in x
+///     x: vector
+///     incx: increment for x
+/// Output:
+///     returns the sum, or 0 if n < 1 or incx <= 0
+pub fn asum_real<T: Float>(n: &isize, x: &NdSlice<'_, T, 1>, incx: &isize) -> T {
+    let (n, incx) = (*n, *incx);
+
+    if n < 1 || incx <= 0 {
+        return T::zero();
+    }
+    let (n, incx) = (n as usize, incx as usize);
+
+    let mut temp = T::zero();
+    for i in (0..n * incx).step_by(incx) {
+        temp = temp + x[[i]].abs();
+    }
+
+    temp
+}
+
+/// Computes the sum of the absolute values of the real and imaginary parts of the elements of a
+/// vector.
+///
+/// res = Σ |Re x[i]| + |Im x[i]|
+///
+/// Input:
+///     n: number of elements in x
+///     x: vector
+///     incx: increment for x
+/// Output:
+///     returns the sum, or 0 if n < 1 or incx <= 0
+pub fn asum_complex<T: Float>(n: &isize, x: &NdSlice<'_, Complex<T>, 1>, incx: &isize) -> T {
+    let (n, incx) = (*n, *incx);
+
+    if n < 1 || incx <= 0 {
+        return T::zero();
+    }
+    let (n, incx) = (n as usize, incx as usize);
+
+    let mut temp = T::zero();
+    for i in (0..n * incx).step_by(incx) {
+        temp = temp + x[[i]].re.abs() + x[[i]].im.abs();
+    }
+
+    temp
+}
@@ -0,0 +1,143 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_complex::Complex;
+use num_traits::Float;
+
+use crate::level_1::axpy::{axpy_complex, axpy_real};
+
+/// Computes the sum of two scaled vectors.
+///
+/// y = ax + by
+///
+/// Input:
+///     n: number of elements in x and y
+///     a: scalar to multiply x with
+///     x: vector
+///     incx: increment for x
+///     b: scalar to multiply y with, y need not be set if b is zero
+///     y: vector
+///     incy: increment for y
+/// Output:
+///     y: modified y
+pub fn axpby_real<T: Float + 'static>(
+    n: &isize,
+    a: &T,
+    x: &NdSlice<'_, T, 1>,
+    incx: &isize,
+    b: &T,
+    y: &mut NdSliceMut<'_, T, 1>,
+    incy: &isize,
+) {
+    if *b == num_traits::one() {
+        axpy_real(n, a, x, incx, y, incy);
+        return;
+    }
+
+    let (n, a, b, incx, incy) = (*n, *a, *b, *incx, *incy);
+    let zero = T::zero();
+
+    if n < 0 {
+        return;
+    }
+    let n = n as usize;
+
+    if incx == 1 && incy == 1 {
+        for i in 0..n {
+            y[[i]] = if b == zero {
+                a * x[[i]]
+            } else {
+                a * x[[i]] + b * y[[i]]
+            };
+        }
+    } else {
+        let mut ix = if incx < 0 {
+            (1 - (n as isize)) * incx
+        } else {
+            0
+        };
+        let mut iy = if incy < 0 {
+            (1 - (n as isize)) * incy
+        } else {
+            0
+        };
+
+        for _ in 0..n {
+            y[[iy as usize]] = if b == zero {
+                a * x[[ix as usize]]
+            } else {
+                a * x[[ix as usize]] + b * y[[iy as usize]]
+            };
+
+            ix += incx;
+            iy += incy;
+        }
+    }
+}
+
+/// Computes the sum of two scaled vectors.
+///
+/// y = ax + by
+///
+/// Input:
+///     n: number of elements in x and y
+///     a: scalar to multiply x with
+///     x: vector
+///     incx: increment for x
+///     b: scalar to multiply y with, y need not be set if b is zero
+///     y: vector
+///     incy: increment for y
+/// Output:
+///     y: modified y
+pub fn axpby_complex<T: Float>(
+    n: &isize,
+    a: &Complex<T>,
+    x: &NdSlice<'_, Complex<T>, 1>,
+    incx: &isize,
+    b: &Complex<T>,
+    y: &mut NdSliceMut<'_, Complex<T>, 1>,
+    incy: &isize,
+) {
+    if *b == Complex::new(num_traits::one(), num_traits::zero()) {
+        axpy_complex(n, a, x, incx, y, incy);
+        return;
+    }
+
+    let (n, a, b, incx, incy) = (*n, *a, *b, *incx, *incy);
+    let zero = Complex::new(T::zero(), T::zero());
+
+    if n < 0 {
+        return;
+    }
+    let n = n as usize;
+
+    if incx == 1 && incy == 1 {
+        for i in 0..n {
+            y[[i]] = if b == zero {
+                a * x[[i]]
+            } else {
+                a * x[[i]] + b * y[[i]]
+            };
+        }
+    } else {
+        let mut ix = if incx < 0 {
+            (1 - (n as isize)) * incx
+        } else {
+            0
+        };
+        let mut iy = if incy < 0 {
+            (1 - (n as isize)) * incy
+        } else {
+            0
+        };
+
+        for _ in 0..n {
+            y[[iy as usize]] = if b == zero {
+                a * x[[ix as usize]]
+            } else {
+                a * x[[ix as usize]] + b * y[[iy as usize]]
+            };
+
+            ix += incx;
+            iy += incy;
+        }
+    }
+}
@@ -1,7 +1,11 @@
-use nd_slice::NdSliceMut;
+use nd_slice::{NdSlice, NdSliceMut};
 use num_complex::Complex;
 use num_traits::Float;
 
+use crate::simd;
+#[cfg(feature = "rayon")]
+use crate::threading;
+
 /// Computes a vector-scalar product and adds the result to the vector.
 ///
 /// y = ax + y
@@ -15,10 +19,10 @@ use num_traits::Float;
 ///     incy: increment for y
 /// Output:
 ///     y: modified y
-pub fn axpy_real<T: Float>(
+pub fn axpy_real<T: Float + 'static>(
     n: &isize,
     a: &T,
-    x: &mut NdSliceMut<'_, T, 1>,
+    x: &NdSlice<'_, T, 1>,
     incx: &isize,
     y: &mut NdSliceMut<'_, T, 1>,
     incy: &isize,
@@ -35,6 +39,15 @@ pub fn axpy_real<T: Float>(
     }
 
     if incx == 1 && incy == 1 {
+        #[cfg(feature = "rayon")]
+        if threading::axpy(n, a, x, y) {
+            return;
+        }
+
+        if simd::axpy(n, a, x, y) {
+            return;
+        }
+
         for i in 0..n {
             y[[i]] = y[[i]] + a * x[[i]];
         }
@@ -75,7 +88,7 @@ pub fn axpy_real<T: Float>(
 pub fn axpy_complex<T: Float>(
     n: &isize,
     a: &Complex<T>,
-    x: &mut NdSliceMut<'_, Complex<T>, 1>,
+    x: &NdSlice<'_, Complex<T>, 1>,
     incx: &isize,
     y: &mut NdSliceMut<'_, Complex<T>, 1>,
     incy: &isize,
@@ -114,3 +127,64 @@ pub fn axpy_complex<T: Float>(
         }
     }
 }
+
+/// Computes a vector-scalar product and adds the result to the vector, in a wider type.
+///
+/// y = ax + y
+///
+/// Each element is computed in U and rounded back to T, e.g. `axpy_mixed::<f16, f32>`.
+///
+/// Input:
+///     n: number of elements in x and y
+///     a: scalar to multiply with
+///     x: vector
+///     incx: increment for x
+///     y: vector
+///     incy: increment for y
+/// Output:
+///     y: modified y
+pub fn axpy_mixed<T: Float, U: Float>(
+    n: &isize,
+    a: &U,
+    x: &NdSlice<'_, T, 1>,
+    incx: &isize,
+    y: &mut NdSliceMut<'_, T, 1>,
+    incy: &isize,
+) {
+    let (n, a, incx, incy) = (*n, *a, *incx, *incy);
+
+    if n < 0 {
+        return;
+    }
+    let n = n as usize;
+
+    if a == num_traits::zero() {
+        return;
+    }
+
+    let axpy = |x: T, y: T| T::from(U::from(y).unwrap() + a * U::from(x).unwrap()).unwrap();
+
+    if incx == 1 && incy == 1 {
+        for i in 0..n {
+            y[[i]] = axpy(x[[i]], y[[i]]);
+        }
+    } else {
+        let mut ix = if incx < 0 {
+            (1 - (n as isize)) * incx
+        } else {
+            0
+        };
+        let mut iy = if incy < 0 {
+            (1 - (n as isize)) * incy
+        } else {
+            0
+        };
+
+        for _ in 0..n {
+            y[[iy as usize]] = axpy(x[[ix as usize]], y[[iy as usize]]);
+
+            ix += incx;
+            iy += incy;
+        }
+    }
+}
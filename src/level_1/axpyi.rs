@@ -0,0 +1,89 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_complex::Complex;
+use num_traits::Float;
+
+use crate::level_1::index_base;
+
+/// Adds a scalar multiple of a sparse vector to a dense vector.
+///
+/// y[indx[i]] = a * x[i] + y[indx[i]]
+///
+/// Input:
+///     nz: number of elements in x and indx
+///     a: scalar to multiply with
+///     x: nonzero elements of the sparse vector
+///     indx: indices of the elements of x in the dense vector
+///     base: 0 or 1, the index of the first element of the dense vector
+///     y: dense vector
+/// Output:
+///     y: modified y
+///
+/// # Panics
+///
+/// Panics if base is not 0 or 1, an index is below base or outside y, or x has fewer than nz
+/// elements. The indices are checked before anything is modified.
+pub fn axpyi_real<T: Float>(
+    nz: &isize,
+    a: &T,
+    x: &NdSlice<'_, T, 1>,
+    indx: &NdSlice<'_, isize, 1>,
+    base: &isize,
+    y: &mut NdSliceMut<'_, T, 1>,
+) {
+    let (nz, a, base) = (*nz, *a, *base);
+    if nz <= 0 || a == num_traits::zero() {
+        return;
+    }
+    let nz = nz as usize;
+
+    let len = index_base::bound(nz, indx, base);
+    // bounds check both vectors before modifying y
+    let _ = (&y[[len - 1]], &x[[nz - 1]]);
+
+    for i in 0..nz {
+        let iy = (indx[[i]] - base) as usize;
+        y[[iy]] = y[[iy]] + a * x[[i]];
+    }
+}
+
+/// Adds a scalar multiple of a sparse vector to a dense vector.
+///
+/// y[indx[i]] = a * x[i] + y[indx[i]]
+///
+/// Input:
+///     nz: number of elements in x and indx
+///     a: scalar to multiply with
+///     x: nonzero elements of the sparse vector
+///     indx: indices of the elements of x in the dense vector
+///     base: 0 or 1, the index of the first element of the dense vector
+///     y: dense vector
+/// Output:
+///     y: modified y
+///
+/// # Panics
+///
+/// Panics if base is not 0 or 1, an index is below base or outside y, or x has fewer than nz
+/// elements. The indices are checked before anything is modified.
+pub fn axpyi_complex<T: Float>(
+    nz: &isize,
+    a: &Complex<T>,
+    x: &NdSlice<'_, Complex<T>, 1>,
+    indx: &NdSlice<'_, isize, 1>,
+    base: &isize,
+    y: &mut NdSliceMut<'_, Complex<T>, 1>,
+) {
+    let (nz, base) = (*nz, *base);
+    if nz <= 0 || a.re.abs() + a.im.abs() == num_traits::zero() {
+        return;
+    }
+    let nz = nz as usize;
+
+    let len = index_base::bound(nz, indx, base);
+    // bounds check both vectors before modifying y
+    let _ = (&y[[len - 1]], &x[[nz - 1]]);
+
+    for i in 0..nz {
+        let iy = (indx[[i]] - base) as usize;
+        y[[iy]] = y[[iy]] + a * x[[i]];
+    }
+}
@@ -1,4 +1,8 @@
-use nd_slice::NdSliceMut;
+use nd_slice::{NdSlice, NdSliceMut};
+
+use crate::simd;
+#[cfg(feature = "rayon")]
+use crate::threading;
 
 /// Copies one vector to another.
 ///
@@ -9,12 +13,12 @@ use nd_slice::NdSliceMut;
 ///     x: array
 ///     incx: increment for x
 ///     y: array
-///     incy: increment for x
+///     incy: increment for y
 /// Output:
 ///     y: copies x
-pub fn copy<T: Copy /* both Float and Complex<T> implement Copy */>(
+pub fn copy<T: Copy + 'static /* both Float and Complex<T> implement Copy */>(
     n: &isize,
-    x: &mut NdSliceMut<'_, T, 1>,
+    x: &NdSlice<'_, T, 1>,
     incx: &isize,
     y: &mut NdSliceMut<'_, T, 1>,
     incy: &isize,
@@ -26,6 +30,15 @@ pub fn copy<T: Copy /* both Float and Complex<T> implement Copy */>(
     let n = n as usize;
 
     if incx == 1 && incy == 1 {
+        #[cfg(feature = "rayon")]
+        if threading::copy(n, x, y) {
+            return;
+        }
+
+        if simd::copy(n, x, y) {
+            return;
+        }
+
         for i in 0..n {
             y[[i]] = x[[i]];
         }
@@ -42,7 +55,7 @@ pub fn copy<T: Copy /* both Float and Complex<T> implement Copy */>(
         };
 
         for _ in 0..n {
-            y[[ix as usize]] = x[[iy as usize]];
+            y[[iy as usize]] = x[[ix as usize]];
 
             ix += incx;
             iy += incy;
@@ -1,6 +1,12 @@
+use core::any::TypeId;
+
 use nd_slice::NdSlice;
 use num_traits::Float;
 
+use crate::simd;
+#[cfg(feature = "rayon")]
+use crate::threading;
+
 /// Computes vector-vector dot product
 ///
 /// res = Σ x[i] * y[i]
@@ -14,8 +20,10 @@ use num_traits::Float;
 /// Output:
 ///     returns dot product of x and y
 ///
+/// The products are accumulated in U, e.g. `dot::<f16, f32>` for half precision vectors.
+///
 /// Note: dot has s, d and ds variants; sds is not implemented yet.
-pub fn dot<T: Float, U: Float>(
+pub fn dot<T: Float + 'static, U: Float + 'static>(
     n: &isize,
     x: &NdSlice<'_, T, 1>,
     incx: &isize,
@@ -32,6 +40,18 @@ pub fn dot<T: Float, U: Float>(
         let mut temp = num_traits::zero();
 
         if incx == 1 && incy == 1 {
+            #[cfg(feature = "rayon")]
+            if let Some(res) = threading::dot::<T, U, _, _>(n, x, y) {
+                return res;
+            }
+
+            // the kernels accumulate in T, so they can only be used when no widening is asked for
+            if TypeId::of::<T>() == TypeId::of::<U>() {
+                if let Some(res) = simd::dot(n, x, y) {
+                    return U::from(res).unwrap();
+                }
+            }
+
             for i in 0..n {
                 temp = temp + U::from(x[[i]]).unwrap() * U::from(y[[i]]).unwrap();
             }
@@ -94,7 +114,7 @@ pub fn sdsdot(
 
         if incx == 1 && incy == 1 {
             for i in 0..n {
-                temp = temp + (x[[i]] as f64 * y[[i]] as f64) as f32;
+                temp += (x[[i]] as f64 * y[[i]] as f64) as f32;
             }
         } else {
             let mut ix = if incx < 0 {
@@ -109,7 +129,7 @@ pub fn sdsdot(
             };
 
             for _ in 0..n {
-                temp = temp + (x[[ix as usize]] as f64 * y[[iy as usize]] as f64) as f32;
+                temp += (x[[ix as usize]] as f64 * y[[iy as usize]] as f64) as f32;
 
                 ix += incx;
                 iy += incy;
@@ -0,0 +1,128 @@
+use nd_slice::NdSlice;
+use num_complex::Complex;
+use num_traits::Float;
+
+use crate::level_1::index_base;
+
+/// Computes the dot product of a sparse vector with a dense vector.
+///
+/// res = Σ x[i] * y[indx[i]]
+///
+/// Input:
+///     nz: number of elements in x and indx
+///     x: nonzero elements of the sparse vector
+///     indx: indices of the elements of x in the dense vector
+///     base: 0 or 1, the index of the first element of the dense vector
+///     y: dense vector
+/// Output:
+///     returns dot product of x and y
+///
+/// # Panics
+///
+/// Panics if base is not 0 or 1, an index is below base or outside y, or x has fewer than nz
+/// elements.
+pub fn doti<T: Float>(
+    nz: &isize,
+    x: &NdSlice<'_, T, 1>,
+    indx: &NdSlice<'_, isize, 1>,
+    base: &isize,
+    y: &NdSlice<'_, T, 1>,
+) -> T {
+    let (nz, base) = (*nz, *base);
+
+    let mut temp = num_traits::zero();
+    if nz <= 0 {
+        return temp;
+    }
+    let nz = nz as usize;
+
+    index_base::bound(nz, indx, base);
+
+    for i in 0..nz {
+        temp = temp + x[[i]] * y[[(indx[[i]] - base) as usize]];
+    }
+
+    temp
+}
+
+/// Computes the dot product of a conjugated sparse vector with a dense vector.
+///
+/// res = Σ conjugate(x[i]) * y[indx[i]]
+///
+/// Input:
+///     nz: number of elements in x and indx
+///     x: nonzero elements of the sparse vector
+///     indx: indices of the elements of x in the dense vector
+///     base: 0 or 1, the index of the first element of the dense vector
+///     y: dense vector
+/// Output:
+///     returns dot product of x and y
+///
+/// # Panics
+///
+/// Panics if base is not 0 or 1, an index is below base or outside y, or x has fewer than nz
+/// elements.
+pub fn dotci<T: Float>(
+    nz: &isize,
+    x: &NdSlice<'_, Complex<T>, 1>,
+    indx: &NdSlice<'_, isize, 1>,
+    base: &isize,
+    y: &NdSlice<'_, Complex<T>, 1>,
+) -> Complex<T> {
+    let (nz, base) = (*nz, *base);
+
+    let mut temp = Complex::new(num_traits::zero(), num_traits::zero());
+    if nz <= 0 {
+        return temp;
+    }
+    let nz = nz as usize;
+
+    index_base::bound(nz, indx, base);
+
+    for i in 0..nz {
+        temp = temp + x[[i]].conj() * y[[(indx[[i]] - base) as usize]];
+    }
+
+    temp
+}
+
+/// Computes the dot product of a complex sparse vector with a dense vector.
+///
+/// res = Σ x[i] * y[indx[i]]
+///
+/// Input:
+///     nz: number of elements in x and indx
+///     x: nonzero elements of the sparse vector
+///     indx: indices of the elements of x in the dense vector
+///     base: 0 or 1, the index of the first element of the dense vector
+///     y: dense vector
+/// Output:
+///     returns dot product of x and y
+///
+/// # Panics
+///
+/// Panics if base is not 0 or 1, an index is below base or outside y, or x has fewer than nz
+/// elements.
+pub fn dotui<T: Float>(
+    nz: &isize,
+    x: &NdSlice<'_, Complex<T>, 1>,
+    indx: &NdSlice<'_, isize, 1>,
+    base: &isize,
+    y: &NdSlice<'_, Complex<T>, 1>,
+) -> Complex<T> {
+    let (nz, base) = (*nz, *base);
+
+    let mut temp = Complex::new(num_traits::zero(), num_traits::zero());
+    if nz <= 0 {
+        return temp;
+    }
+    let nz = nz as usize;
+
+    index_base::bound(nz, indx, base);
+
+    for i in 0..nz {
+        temp = temp + x[[i]] * y[[(indx[[i]] - base) as usize]];
+    }
+
+    temp
+}
@@ -1,9 +1,10 @@
 use nd_slice::NdSlice;
-use num_traits::Float;
 
-/// Computes vector-vector dot product using double precision.
+/// Computes vector-vector dot product of single precision vectors in double precision.
+/// Each element is cast to a double precision float before it is multiplied and the products
+/// are accumulated and returned in double precision.
 ///
-/// res = Σ x[i] + y[i]
+/// res = Σ x[i] * y[i]
 ///
 /// Input:
 ///     n: number of elements in x and y
@@ -13,27 +14,25 @@ use num_traits::Float;
 ///     incy: increment for y
 /// Output:
 ///     returns dot product of x and y
-///
-/// Note: dot has s, d and ds variants; sds is not implemented yet.
-pub fn sdot<T: Float, U: Float>(
+pub fn dsdot(
     n: &isize,
-    x: &NdSlice<'_, T, 1>,
+    x: &NdSlice<'_, f32, 1>,
     incx: &isize,
-    y: &NdSlice<'_, T, 1>,
+    y: &NdSlice<'_, f32, 1>,
     incy: &isize,
-) -> U {
+) -> f64 {
     let (n, incx, incy) = (*n, *incx, *incy);
 
-    if n < 0 {
-        num_traits::zero()
+    if n <= 0 {
+        0.0
     } else {
         let n = n as usize;
 
-        let mut temp: f64 = num_traits::zero();
+        let mut temp = 0.0;
 
         if incx == 1 && incy == 1 {
             for i in 0..n {
-                temp = temp + x[[i]] * y[[i]];
+                temp += x[[i]] as f64 * y[[i]] as f64;
             }
         } else {
             let mut ix = if incx < 0 {
@@ -48,7 +47,7 @@ pub fn sdot<T: Float, U: Float>(
             };
 
             for _ in 0..n {
-                temp = temp + x[[ix as usize]] as f64 * f64::from(y[[iy as usize]]).unwrap();
+                temp += x[[ix as usize]] as f64 * y[[iy as usize]] as f64;
 
                 ix += incx;
                 iy += incy;
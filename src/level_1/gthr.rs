@@ -0,0 +1,87 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_traits::Zero;
+
+use crate::level_1::index_base;
+
+/// Gathers the elements of a dense vector into a sparse vector.
+///
+/// x[i] = y[indx[i]]
+///
+/// Input:
+///     nz: number of elements in x and indx
+///     y: dense vector
+///     x: nonzero elements of the sparse vector
+///     indx: indices of the elements of x in the dense vector
+///     base: 0 or 1, the index of the first element of the dense vector
+/// Output:
+///     x: modified x
+///
+/// # Panics
+///
+/// Panics if base is not 0 or 1, an index is below base or outside y, or x has fewer than nz
+/// elements. The indices are checked before anything is modified.
+pub fn gthr<T: Copy>(
+    nz: &isize,
+    y: &NdSlice<'_, T, 1>,
+    x: &mut NdSliceMut<'_, T, 1>,
+    indx: &NdSlice<'_, isize, 1>,
+    base: &isize,
+) {
+    let (nz, base) = (*nz, *base);
+    if nz <= 0 {
+        return;
+    }
+    let nz = nz as usize;
+
+    let len = index_base::bound(nz, indx, base);
+    // bounds check both vectors before modifying x
+    let _ = (&y[[len - 1]], &x[[nz - 1]]);
+
+    for i in 0..nz {
+        x[[i]] = y[[(indx[[i]] - base) as usize]];
+    }
+}
+
+/// Gathers the elements of a dense vector into a sparse vector and zeroes them in the dense
+/// vector.
+///
+/// x[i] = y[indx[i]]
+/// y[indx[i]] = 0
+///
+/// Input:
+///     nz: number of elements in x and indx
+///     y: dense vector
+///     x: nonzero elements of the sparse vector
+///     indx: indices of the elements of x in the dense vector
+///     base: 0 or 1, the index of the first element of the dense vector
+/// Output:
+///     y: modified y
+///     x: modified x
+///
+/// # Panics
+///
+/// Panics if base is not 0 or 1, an index is below base or outside y, or x has fewer than nz
+/// elements. The indices are checked before anything is modified.
+pub fn gthrz<T: Copy + Zero>(
+    nz: &isize,
+    y: &mut NdSliceMut<'_, T, 1>,
+    x: &mut NdSliceMut<'_, T, 1>,
+    indx: &NdSlice<'_, isize, 1>,
+    base: &isize,
+) {
+    let (nz, base) = (*nz, *base);
+    if nz <= 0 {
+        return;
+    }
+    let nz = nz as usize;
+
+    let len = index_base::bound(nz, indx, base);
+    // bounds check both vectors before modifying either
+    let _ = (&y[[len - 1]], &x[[nz - 1]]);
+
+    for i in 0..nz {
+        let iy = (indx[[i]] - base) as usize;
+        x[[i]] = y[[iy]];
+        y[[iy]] = T::zero();
+    }
+}
@@ -0,0 +1,66 @@
+use nd_slice::NdSlice;
+use num_complex::Complex;
+use num_traits::Float;
+
+/// Finds the index of the element with the largest absolute value.
+///
+/// Input:
+///     n: number of elements in x
+///     x: vector
+///     incx: increment for x
+/// Output:
+///     returns the position i of the first element x[i] with the largest absolute value,
+///     counting from 0, or 0 if n < 1 or incx <= 0
+pub fn iamax_real<T: Float>(n: &isize, x: &NdSlice<'_, T, 1>, incx: &isize) -> usize {
+    let (n, incx) = (*n, *incx);
+
+    if n < 1 || incx <= 0 {
+        return 0;
+    }
+    let (n, incx) = (n as usize, incx as usize);
+
+    let mut index = 0;
+    let mut max = x[[0]].abs();
+    for i in 1..n {
+        let temp = x[[i * incx]].abs();
+        if temp > max {
+            index = i;
+            max = temp;
+        }
+    }
+
+    index
+}
+
+/// Finds the index of the element with the largest sum of the absolute values of its real and
+/// imaginary parts.
+///
+/// Input:
+///     n: number of elements in x
+///     x: vector
+///     incx: increment for x
+/// Output:
+///     returns the position i of the first element x[i] with the largest |Re x[i]| + |Im x[i]|,
+///     counting from 0, or 0 if n < 1 or incx <= 0
+pub fn iamax_complex<T: Float>(n: &isize, x: &NdSlice<'_, Complex<T>, 1>, incx: &isize) -> usize {
+    let (n, incx) = (*n, *incx);
+
+    if n < 1 || incx <= 0 {
+        return 0;
+    }
+    let (n, incx) = (n as usize, incx as usize);
+
+    let abs1 = |z: Complex<T>| z.re.abs() + z.im.abs();
+
+    let mut index = 0;
+    let mut max = abs1(x[[0]]);
+    for i in 1..n {
+        let temp = abs1(x[[i * incx]]);
+        if temp > max {
+            index = i;
+            max = temp;
+        }
+    }
+
+    index
+}
@@ -0,0 +1,28 @@
+use nd_slice::NdSlice;
+
+/// Validates the indices of a sparse vector.
+///
+/// Returns one more than the largest zero-based index, i.e. the length the dense vector needs,
+/// so that the sparse routines can bounds check it before they modify anything.
+///
+/// # Panics
+///
+/// Panics if base is not 0 or 1 or an index is below base.
+pub(crate) fn bound(nz: usize, indx: &NdSlice<'_, isize, 1>, base: isize) -> usize {
+    assert!(base == 0 || base == 1, "index base {} is not 0 or 1", base);
+
+    let mut len = 0;
+    for i in 0..nz {
+        let index = indx[[i]];
+        assert!(
+            index >= base,
+            "index {} at position {} is below the base {}",
+            index,
+            i,
+            base
+        );
+        len = len.max((index - base) as usize + 1);
+    }
+
+    len
+}
@@ -1,12 +1,31 @@
+//! Level 1 BLAS: vector-vector operations.
+//!
+//! The routines with SIMD or multi-threaded unit-stride paths (`axpy_real`, `axpby_real`, `dot`,
+//! `scal_real`, `copy`, `swap`, `rot`, `rotm`, `nrm2`) require their element type to be `'static`,
+//! as they recognise `f32` and `f64` with `TypeId`. Numeric element types are plain values, so
+//! this rules out nothing in practice, whereas dispatching on a sealed trait instead would rule
+//! out the `Float` types of other crates.
+
+pub mod asum;
+pub mod axpby;
 pub mod axpy;
+pub mod axpyi;
 pub mod copy;
 pub mod dot;
 pub mod dotc;
+pub mod doti;
 pub mod dotu;
+pub mod dsdot;
+pub mod gthr;
+pub mod iamax;
 pub mod nrm2;
 pub mod rot;
 pub mod rotg;
+pub mod roti;
 pub mod rotm;
 pub mod rotmg;
 pub mod scal;
+pub mod sctr;
 pub mod swap;
+
+mod index_base;
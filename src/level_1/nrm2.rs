@@ -2,6 +2,10 @@ use nd_slice::NdSlice;
 use num_complex::Complex;
 use num_traits::Float;
 
+use crate::simd;
+#[cfg(feature = "rayon")]
+use crate::threading;
+
 /// Computes the Euclidean norm of a vector.
 ///
 /// res = ‖ x ‖
@@ -12,7 +16,7 @@ use num_traits::Float;
 ///     incx: increment for x
 /// Output:
 ///     euclidean norm of the vector
-pub fn nrm2<F: Float>(n: &isize, x: &mut NdSlice<'_, F, 1>, incx: &isize) -> F {
+pub fn nrm2<F: Float + 'static>(n: &isize, x: &mut NdSlice<'_, F, 1>, incx: &isize) -> F {
     let (n, incx) = (*n, *incx);
 
     let (zero, one): (F, F) = (num_traits::zero(), num_traits::one());
@@ -20,12 +24,24 @@ pub fn nrm2<F: Float>(n: &isize, x: &mut NdSlice<'_, F, 1>, incx: &isize) -> F {
     if n < 1 || incx < 1 {
         zero
     } else if n == 1 {
-        x[[0]]
+        x[[0]].abs()
     } else {
         let (n, incx) = (n as usize, incx as usize);
+
+        if incx == 1 {
+            #[cfg(feature = "rayon")]
+            if let Some(res) = threading::nrm2(n, x) {
+                return res;
+            }
+
+            if let Some(res) = simd::nrm2(n, x) {
+                return res;
+            }
+        }
+
         let (mut scale, mut ssq) = (zero, one);
 
-        for ix in (0..(n - 1) * incx).step_by(incx) {
+        for ix in (0..n * incx).step_by(incx) {
             if x[[ix]] != zero {
                 let absxi = x[[ix]].abs();
 
@@ -53,7 +69,7 @@ pub fn real_complex_nrm2<F: Float>(n: &isize, x: NdSlice<'_, Complex<F>, 1>, inc
         let (n, incx) = (n as usize, incx as usize);
         let (mut scale, mut ssq) = (zero, one);
 
-        for ix in (0..(n - 1) * incx).step_by(incx) {
+        for ix in (0..n * incx).step_by(incx) {
             if x[[ix]].re != zero {
                 let abs_xi_re = x[[ix]].re.abs();
 
@@ -80,3 +96,45 @@ pub fn real_complex_nrm2<F: Float>(n: &isize, x: NdSlice<'_, Complex<F>, 1>, inc
         scale * ssq.sqrt()
     }
 }
+
+/// Computes the Euclidean norm of a vector, accumulating in a wider type.
+///
+/// res = ‖ x ‖
+///
+/// e.g. `nrm2_mixed::<f16, f32>` returns the norm of a half precision vector in single precision.
+///
+/// Input:
+///     n: number of elements in x
+///     x: vector
+///     incx: increment for x
+/// Output:
+///     euclidean norm of the vector
+pub fn nrm2_mixed<T: Float, U: Float>(n: &isize, x: &NdSlice<'_, T, 1>, incx: &isize) -> U {
+    let (n, incx) = (*n, *incx);
+
+    let (zero, one): (U, U) = (num_traits::zero(), num_traits::one());
+
+    if n < 1 || incx < 1 {
+        zero
+    } else {
+        let (n, incx) = (n as usize, incx as usize);
+        let (mut scale, mut ssq) = (zero, one);
+
+        for ix in (0..n * incx).step_by(incx) {
+            let xi = U::from(x[[ix]]).unwrap();
+
+            if xi != zero {
+                let absxi = xi.abs();
+
+                if scale < absxi {
+                    ssq = one + ssq * (scale / absxi).powi(2);
+                    scale = absxi;
+                } else {
+                    ssq = ssq + (absxi / scale).powi(2);
+                }
+            }
+        }
+
+        scale * ssq.sqrt()
+    }
+}
@@ -1,11 +1,15 @@
 use nd_slice::NdSliceMut;
-use num_traits::{Float, NumCast};
+use num_traits::Float;
+
+use crate::simd;
+#[cfg(feature = "rayon")]
+use crate::threading;
 
 /// Performs rotation of points in a plane.
 ///
 /// Given two vectors x and y, each element is replaced as:
 ///     x[i] = c*x[i] + s*y[i]
-///     y[i] = c*y[i] + s*x[i]
+///     y[i] = c*y[i] - s*x[i]
 ///
 /// Input:
 ///     n: number of elements in each of the vectors
@@ -20,17 +24,16 @@ use num_traits::{Float, NumCast};
 ///     y: modified y
 ///
 /// Note: Intel MKL also defines rotg for c and z too.
-pub fn rot<T: Float>(
+pub fn rot<T: Float + 'static>(
     n: &isize,
     x: &mut NdSliceMut<'_, T, 1>,
     incx: &isize,
     y: &mut NdSliceMut<'_, T, 1>,
     incy: &isize,
-    c: &isize,
-    s: &isize,
+    c: &T,
+    s: &T,
 ) {
-    let (n, incx, incy) = (*n, *incx, *incy);
-    let (c, s): (T, T) = (NumCast::from(*c).unwrap(), NumCast::from(*s).unwrap());
+    let (n, incx, incy, c, s) = (*n, *incx, *incy, *c, *s);
 
     if n < 0 {
         return;
@@ -38,6 +41,15 @@ pub fn rot<T: Float>(
     let n = n as usize;
 
     if incx == 1 && incy == 1 {
+        #[cfg(feature = "rayon")]
+        if threading::rot(n, c, s, x, y) {
+            return;
+        }
+
+        if simd::rot(n, c, s, x, y) {
+            return;
+        }
+
         for i in 0..n {
             let temp = c * x[[i]] + s * y[[i]];
             y[[i]] = c * y[[i]] - s * x[[i]];
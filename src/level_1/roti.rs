@@ -0,0 +1,53 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_traits::Float;
+
+use crate::level_1::index_base;
+
+/// Performs rotation of points in a plane, for a sparse and a dense vector.
+///
+/// Given a sparse vector x and a dense vector y, each element is replaced as:
+///     x[i] = c*x[i] + s*y[indx[i]]
+///     y[indx[i]] = c*y[indx[i]] - s*x[i]
+///
+/// Input:
+///     nz: number of elements in x and indx
+///     x: nonzero elements of the sparse vector
+///     indx: indices of the elements of x in the dense vector
+///     base: 0 or 1, the index of the first element of the dense vector
+///     y: dense vector
+///     c: scalar
+///     s: scalar
+/// Output:
+///     x: modified x
+///     y: modified y
+///
+/// # Panics
+///
+/// Panics if base is not 0 or 1, an index is below base or outside y, or x has fewer than nz
+/// elements. The indices are checked before anything is modified.
+pub fn roti<T: Float>(
+    nz: &isize,
+    x: &mut NdSliceMut<'_, T, 1>,
+    indx: &NdSlice<'_, isize, 1>,
+    base: &isize,
+    y: &mut NdSliceMut<'_, T, 1>,
+    c: &T,
+    s: &T,
+) {
+    let (nz, base, c, s) = (*nz, *base, *c, *s);
+    if nz <= 0 {
+        return;
+    }
+    let nz = nz as usize;
+
+    let len = index_base::bound(nz, indx, base);
+    // bounds check both vectors before modifying either
+    let _ = (&y[[len - 1]], &x[[nz - 1]]);
+
+    for i in 0..nz {
+        let iy = (indx[[i]] - base) as usize;
+        let temp = c * x[[i]] + s * y[[iy]];
+        y[[iy]] = c * y[[iy]] - s * x[[i]];
+        x[[i]] = temp;
+    }
+}
@@ -1,6 +1,9 @@
 use nd_slice::NdSliceMut;
 use num_traits::Float;
 
+#[cfg(feature = "rayon")]
+use crate::threading;
+
 /// Performs modified Givens rotation of points in the plane.
 ///
 /// Given two vectors x and y, replace their elements as:
@@ -27,7 +30,7 @@ use num_traits::Float;
 /// Output:
 ///     x: modified x
 ///     y: modified y
-pub fn rotm<T: Float>(
+pub fn rotm<T: Float + 'static>(
     n: &isize,
     x: &mut NdSliceMut<'_, T, 1>,
     incx: &isize,
@@ -48,6 +51,14 @@ pub fn rotm<T: Float>(
     let n = n as usize;
 
     if incx == incy && incx > 0 {
+        #[cfg(feature = "rayon")]
+        if incx == 1 {
+            let param = [param[[0]], param[[1]], param[[2]], param[[3]], param[[4]]];
+            if threading::rotm(n, x, y, param) {
+                return;
+            }
+        }
+
         let incx = incx as usize;
         let nsteps = n * incx;
 
@@ -2,6 +2,10 @@ use nd_slice::NdSliceMut;
 use num_complex::Complex;
 use num_traits::Float;
 
+use crate::simd;
+#[cfg(feature = "rayon")]
+use crate::threading;
+
 /// Computes the product of a vector by a scalar.
 ///
 /// x = a * x
@@ -13,16 +17,25 @@ use num_traits::Float;
 ///     incx: increment for x
 /// Output:
 ///     x: modified x
-pub fn scal_real<T: Float>(n: &isize, a: &T, x: &mut NdSliceMut<'_, T, 1>, incx: &isize) {
+pub fn scal_real<T: Float + 'static>(n: &isize, a: &T, x: &mut NdSliceMut<'_, T, 1>, incx: &isize) {
     let (n, a, incx) = (*n, *a, *incx);
 
-    if n < 0 || incx < 0 {
+    if n <= 0 || incx <= 0 {
         return;
     }
     let (n, incx) = (n as usize, incx as usize);
 
     // both branches are actually the same. Netlib does some weird optimization in for incx == 1
     if incx == 1 {
+        #[cfg(feature = "rayon")]
+        if threading::scal(n, a, x) {
+            return;
+        }
+
+        if simd::scal(n, a, x) {
+            return;
+        }
+
         for i in 0..n {
             x[[i]] = x[[i]] * a;
         }
@@ -49,7 +62,7 @@ pub fn scal_complex<T: Float>(
     mut x: NdSliceMut<'_, Complex<T>, 1>,
     incx: isize,
 ) {
-    if n < 0 || incx < 0 {
+    if n <= 0 || incx <= 0 {
         return;
     }
     let (n, incx) = (n as usize, incx as usize);
@@ -82,7 +95,7 @@ pub fn scal_complex_real<T: Float>(
     mut x: NdSliceMut<'_, Complex<T>, 1>,
     incx: isize,
 ) {
-    if n < 0 || incx < 0 {
+    if n <= 0 || incx <= 0 {
         return;
     }
     let (n, incx) = (n as usize, incx as usize);
@@ -99,3 +112,35 @@ pub fn scal_complex_real<T: Float>(
         }
     }
 }
+
+/// Computes the product of a vector by a scalar, in a wider type.
+///
+/// x = a * x
+///
+/// Each element is computed in U and rounded back to T, e.g. `scal_mixed::<f16, f32>`.
+///
+/// Input:
+///     n: number of elements in the vector
+///     a: scalar
+///     x: vector
+///     incx: increment for x
+/// Output:
+///     x: modified x
+pub fn scal_mixed<T: Float, U: Float>(
+    n: &isize,
+    a: &U,
+    x: &mut NdSliceMut<'_, T, 1>,
+    incx: &isize,
+) {
+    let (n, a, incx) = (*n, *a, *incx);
+
+    if n <= 0 || incx <= 0 {
+        return;
+    }
+    let (n, incx) = (n as usize, incx as usize);
+
+    let nincx = n * incx;
+    for i in (0..nincx).step_by(incx) {
+        x[[i]] = T::from(U::from(x[[i]]).unwrap() * a).unwrap();
+    }
+}
@@ -0,0 +1,42 @@
+use nd_slice::{NdSlice, NdSliceMut};
+
+use crate::level_1::index_base;
+
+/// Scatters the elements of a sparse vector into a dense vector.
+///
+/// y[indx[i]] = x[i]
+///
+/// Input:
+///     nz: number of elements in x and indx
+///     x: nonzero elements of the sparse vector
+///     indx: indices of the elements of x in the dense vector
+///     base: 0 or 1, the index of the first element of the dense vector
+///     y: dense vector
+/// Output:
+///     y: modified y
+///
+/// # Panics
+///
+/// Panics if base is not 0 or 1, an index is below base or outside y, or x has fewer than nz
+/// elements. The indices are checked before anything is modified.
+pub fn sctr<T: Copy>(
+    nz: &isize,
+    x: &NdSlice<'_, T, 1>,
+    indx: &NdSlice<'_, isize, 1>,
+    base: &isize,
+    y: &mut NdSliceMut<'_, T, 1>,
+) {
+    let (nz, base) = (*nz, *base);
+    if nz <= 0 {
+        return;
+    }
+    let nz = nz as usize;
+
+    let len = index_base::bound(nz, indx, base);
+    // bounds check both vectors before modifying y
+    let _ = (&y[[len - 1]], &x[[nz - 1]]);
+
+    for i in 0..nz {
+        y[[(indx[[i]] - base) as usize]] = x[[i]];
+    }
+}
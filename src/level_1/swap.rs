@@ -1,5 +1,9 @@
 use nd_slice::NdSliceMut;
 
+use crate::simd;
+#[cfg(feature = "rayon")]
+use crate::threading;
+
 /// Given two vectors x and y, swap their elements.
 ///
 /// Input:
@@ -11,7 +15,7 @@ use nd_slice::NdSliceMut;
 /// Output:
 ///     x: modified x
 ///     y: modified y
-pub fn swap<T>(
+pub fn swap<T: 'static>(
     n: &isize,
     x: &mut NdSliceMut<'_, T, 1>,
     incx: &isize,
@@ -24,8 +28,17 @@ pub fn swap<T>(
     let n = *n as usize;
 
     if incx == &1 && incy == &1 {
+        #[cfg(feature = "rayon")]
+        if threading::swap(n, x, y) {
+            return;
+        }
+
+        if simd::swap(n, x, y) {
+            return;
+        }
+
         for i in 0..n {
-            std::mem::swap(&mut x[[i]], &mut y[[i]]);
+            core::mem::swap(&mut x[[i]], &mut y[[i]]);
         }
     } else {
         let mut ix = if incx < &0 {
@@ -40,7 +53,7 @@ pub fn swap<T>(
         };
 
         for _ in 0..n {
-            std::mem::swap(&mut x[[(ix as usize)]], &mut y[[(iy as usize)]]);
+            core::mem::swap(&mut x[[(ix as usize)]], &mut y[[(iy as usize)]]);
 
             ix += incx;
             iy += incy;
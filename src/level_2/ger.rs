@@ -0,0 +1,138 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_complex::Complex;
+use num_traits::{Float, Num};
+
+/// Performs a rank-1 update of a general matrix.
+///
+/// A = alpha * x * yᵀ + A
+///
+/// Input:
+///     m: number of rows of A
+///     n: number of columns of A
+///     alpha: scalar
+///     x: vector of m elements
+///     incx: increment for x, must not be zero
+///     y: vector of n elements
+///     incy: increment for y, must not be zero
+///     a: matrix in column-major order, lda by n
+///     lda: leading dimension of a
+/// Output:
+///     a: modified a
+#[allow(clippy::too_many_arguments)]
+pub fn ger_real<T: Float>(
+    m: &isize,
+    n: &isize,
+    alpha: &T,
+    x: &NdSlice<'_, T, 1>,
+    incx: &isize,
+    y: &NdSlice<'_, T, 1>,
+    incy: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+) {
+    ger(m, n, alpha, x, incx, y, incy, a, lda, |y| y);
+}
+
+/// Performs a rank-1 update of a general matrix.
+///
+/// A = alpha * x * yᵀ + A
+///
+/// Input:
+///     m: number of rows of A
+///     n: number of columns of A
+///     alpha: scalar
+///     x: vector of m elements
+///     incx: increment for x, must not be zero
+///     y: vector of n elements
+///     incy: increment for y, must not be zero
+///     a: matrix in column-major order, lda by n
+///     lda: leading dimension of a
+/// Output:
+///     a: modified a
+#[allow(clippy::too_many_arguments)]
+pub fn geru_complex<T: Float>(
+    m: &isize,
+    n: &isize,
+    alpha: &Complex<T>,
+    x: &NdSlice<'_, Complex<T>, 1>,
+    incx: &isize,
+    y: &NdSlice<'_, Complex<T>, 1>,
+    incy: &isize,
+    a: &mut NdSliceMut<'_, Complex<T>, 1>,
+    lda: &isize,
+) {
+    ger(m, n, alpha, x, incx, y, incy, a, lda, |y| y);
+}
+
+/// Performs a rank-1 update of a general matrix with the conjugate of y.
+///
+/// A = alpha * x * yᴴ + A
+///
+/// Input:
+///     m: number of rows of A
+///     n: number of columns of A
+///     alpha: scalar
+///     x: vector of m elements
+///     incx: increment for x, must not be zero
+///     y: vector of n elements
+///     incy: increment for y, must not be zero
+///     a: matrix in column-major order, lda by n
+///     lda: leading dimension of a
+/// Output:
+///     a: modified a
+#[allow(clippy::too_many_arguments)]
+pub fn gerc_complex<T: Float>(
+    m: &isize,
+    n: &isize,
+    alpha: &Complex<T>,
+    x: &NdSlice<'_, Complex<T>, 1>,
+    incx: &isize,
+    y: &NdSlice<'_, Complex<T>, 1>,
+    incy: &isize,
+    a: &mut NdSliceMut<'_, Complex<T>, 1>,
+    lda: &isize,
+) {
+    ger(m, n, alpha, x, incx, y, incy, a, lda, |y| y.conj());
+}
+
+/// All versions of ger, conj being applied to the elements of y.
+#[allow(clippy::too_many_arguments)]
+fn ger<T: Num + Copy>(
+    m: &isize,
+    n: &isize,
+    alpha: &T,
+    x: &NdSlice<'_, T, 1>,
+    incx: &isize,
+    y: &NdSlice<'_, T, 1>,
+    incy: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    conj: impl Fn(T) -> T,
+) {
+    let (m, n, alpha, incx, incy, lda) = (*m, *n, *alpha, *incx, *incy, *lda);
+
+    if m < 0 || n < 0 || incx == 0 || incy == 0 || lda < m.max(1) {
+        return;
+    }
+    if m == 0 || n == 0 || alpha == T::zero() {
+        return;
+    }
+
+    // the first elements of x and y are the last ones in memory if their increments are negative
+    let kx = if incx < 0 { (1 - m) * incx } else { 0 };
+    let ky = if incy < 0 { (1 - n) * incy } else { 0 };
+    let ix = |i: usize| (kx + i as isize * incx) as usize;
+    let iy = |j: usize| (ky + j as isize * incy) as usize;
+
+    let (m, n, lda) = (m as usize, n as usize, lda as usize);
+
+    for j in 0..n {
+        let yj = y[[iy(j)]];
+        if yj != T::zero() {
+            let temp = alpha * conj(yj);
+            for i in 0..m {
+                a[[i + j * lda]] = a[[i + j * lda]] + x[[ix(i)]] * temp;
+            }
+        }
+    }
+}
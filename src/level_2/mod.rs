@@ -0,0 +1,5 @@
+pub mod ger;
+pub mod symv;
+pub mod syr2;
+pub mod tbmv;
+pub mod tbsv;
@@ -0,0 +1,149 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_complex::Complex;
+use num_traits::{Float, Num};
+
+/// Computes a matrix-vector product with a symmetric matrix.
+///
+/// y = alpha * A * x + beta * y
+///
+/// Input:
+///     uplo: 'U' if the upper triangle of A is stored, 'L' if the lower triangle is
+///     n: order of A
+///     alpha: scalar
+///     a: symmetric matrix in column-major order, lda by n, the other triangle is not referenced
+///     lda: leading dimension of a, at least max(1, n)
+///     x: vector of n elements
+///     incx: increment for x, must not be zero
+///     beta: scalar, y need not be set if beta is zero
+///     y: vector of n elements
+///     incy: increment for y, must not be zero
+/// Output:
+///     y: modified y
+#[allow(clippy::too_many_arguments)]
+pub fn symv_real<T: Float>(
+    uplo: &char,
+    n: &isize,
+    alpha: &T,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    x: &NdSlice<'_, T, 1>,
+    incx: &isize,
+    beta: &T,
+    y: &mut NdSliceMut<'_, T, 1>,
+    incy: &isize,
+) {
+    symv(uplo, n, alpha, a, lda, x, incx, beta, y, incy, |a| a, |a| a);
+}
+
+/// Computes a matrix-vector product with a Hermitian matrix.
+///
+/// y = alpha * A * x + beta * y
+///
+/// Input:
+///     uplo: 'U' if the upper triangle of A is stored, 'L' if the lower triangle is
+///     n: order of A
+///     alpha: scalar
+///     a: Hermitian matrix in column-major order, lda by n, the other triangle is not referenced
+///     and the imaginary parts of the diagonal are assumed to be zero
+///     lda: leading dimension of a, at least max(1, n)
+///     x: vector of n elements
+///     incx: increment for x, must not be zero
+///     beta: scalar, y need not be set if beta is zero
+///     y: vector of n elements
+///     incy: increment for y, must not be zero
+/// Output:
+///     y: modified y
+#[allow(clippy::too_many_arguments)]
+pub fn hemv_complex<T: Float>(
+    uplo: &char,
+    n: &isize,
+    alpha: &Complex<T>,
+    a: &NdSlice<'_, Complex<T>, 1>,
+    lda: &isize,
+    x: &NdSlice<'_, Complex<T>, 1>,
+    incx: &isize,
+    beta: &Complex<T>,
+    y: &mut NdSliceMut<'_, Complex<T>, 1>,
+    incy: &isize,
+) {
+    symv(
+        uplo,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+        |a| a.conj(),
+        |a| Complex::from(a.re),
+    );
+}
+
+/// Both versions of symv, conj being applied to the elements of A mirrored from the stored
+/// triangle and diag to those on the diagonal.
+#[allow(clippy::too_many_arguments)]
+fn symv<T: Num + Copy>(
+    uplo: &char,
+    n: &isize,
+    alpha: &T,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    x: &NdSlice<'_, T, 1>,
+    incx: &isize,
+    beta: &T,
+    y: &mut NdSliceMut<'_, T, 1>,
+    incy: &isize,
+    conj: impl Fn(T) -> T,
+    diag: impl Fn(T) -> T,
+) {
+    let (n, alpha, lda, incx, beta, incy) = (*n, *alpha, *lda, *incx, *beta, *incy);
+
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+
+    if !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return;
+    }
+    if n < 0 || lda < n.max(1) || incx == 0 || incy == 0 {
+        return;
+    }
+    if n == 0 || (alpha == T::zero() && beta == T::one()) {
+        return;
+    }
+
+    // the first elements of x and y are the last ones in memory if their increments are negative
+    let kx = if incx < 0 { (1 - n) * incx } else { 0 };
+    let ky = if incy < 0 { (1 - n) * incy } else { 0 };
+    let ix = |i: usize| (kx + i as isize * incx) as usize;
+    let iy = |i: usize| (ky + i as isize * incy) as usize;
+
+    let (n, lda) = (n as usize, lda as usize);
+
+    if beta != T::one() {
+        for i in 0..n {
+            y[[iy(i)]] = if beta == T::zero() {
+                T::zero()
+            } else {
+                beta * y[[iy(i)]]
+            };
+        }
+    }
+    if alpha == T::zero() {
+        return;
+    }
+
+    // column j of the stored triangle adds alpha * x[j] times itself to y, and its mirror adds
+    // the product of its row with x to y[j]
+    for j in 0..n {
+        let rows = if upper { 0..j } else { j + 1..n };
+        let temp1 = alpha * x[[ix(j)]];
+        let mut temp2 = T::zero();
+        for i in rows {
+            y[[iy(i)]] = y[[iy(i)]] + temp1 * a[[i + j * lda]];
+            temp2 = temp2 + conj(a[[i + j * lda]]) * x[[ix(i)]];
+        }
+        y[[iy(j)]] = y[[iy(j)]] + temp1 * diag(a[[j + j * lda]]) + alpha * temp2;
+    }
+}
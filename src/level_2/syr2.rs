@@ -0,0 +1,128 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_complex::Complex;
+use num_traits::{Float, Num};
+
+/// Performs a rank-2 update of a symmetric matrix.
+///
+/// A = alpha * x * yᵀ + alpha * y * xᵀ + A
+///
+/// Input:
+///     uplo: 'U' if the upper triangle of A is stored, 'L' if the lower triangle is
+///     n: order of A
+///     alpha: scalar
+///     x: vector of n elements
+///     incx: increment for x, must not be zero
+///     y: vector of n elements
+///     incy: increment for y, must not be zero
+///     a: symmetric matrix in column-major order, lda by n, the other triangle is not referenced
+///     lda: leading dimension of a, at least max(1, n)
+/// Output:
+///     a: modified a, in the stored triangle
+#[allow(clippy::too_many_arguments)]
+pub fn syr2_real<T: Float>(
+    uplo: &char,
+    n: &isize,
+    alpha: &T,
+    x: &NdSlice<'_, T, 1>,
+    incx: &isize,
+    y: &NdSlice<'_, T, 1>,
+    incy: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+) {
+    syr2(uplo, n, alpha, x, incx, y, incy, a, lda, |a| a, |a| a);
+}
+
+/// Performs a rank-2 update of a Hermitian matrix.
+///
+/// A = alpha * x * yᴴ + conjugate(alpha) * y * xᴴ + A
+///
+/// Input:
+///     uplo: 'U' if the upper triangle of A is stored, 'L' if the lower triangle is
+///     n: order of A
+///     alpha: scalar
+///     x: vector of n elements
+///     incx: increment for x, must not be zero
+///     y: vector of n elements
+///     incy: increment for y, must not be zero
+///     a: Hermitian matrix in column-major order, lda by n, the other triangle is not referenced
+///     lda: leading dimension of a, at least max(1, n)
+/// Output:
+///     a: modified a, in the stored triangle, the imaginary parts of the diagonal being set to
+///     zero
+#[allow(clippy::too_many_arguments)]
+pub fn her2_complex<T: Float>(
+    uplo: &char,
+    n: &isize,
+    alpha: &Complex<T>,
+    x: &NdSlice<'_, Complex<T>, 1>,
+    incx: &isize,
+    y: &NdSlice<'_, Complex<T>, 1>,
+    incy: &isize,
+    a: &mut NdSliceMut<'_, Complex<T>, 1>,
+    lda: &isize,
+) {
+    syr2(
+        uplo,
+        n,
+        alpha,
+        x,
+        incx,
+        y,
+        incy,
+        a,
+        lda,
+        |a| a.conj(),
+        |a| Complex::from(a.re),
+    );
+}
+
+/// Both versions of syr2, conj being applied to the elements of x and y in the transposed
+/// factors and to alpha in the second term, and diag to the updated diagonal.
+#[allow(clippy::too_many_arguments)]
+fn syr2<T: Num + Copy>(
+    uplo: &char,
+    n: &isize,
+    alpha: &T,
+    x: &NdSlice<'_, T, 1>,
+    incx: &isize,
+    y: &NdSlice<'_, T, 1>,
+    incy: &isize,
+    a: &mut NdSliceMut<'_, T, 1>,
+    lda: &isize,
+    conj: impl Fn(T) -> T,
+    diag: impl Fn(T) -> T,
+) {
+    let (n, alpha, incx, incy, lda) = (*n, *alpha, *incx, *incy, *lda);
+
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+
+    if !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return;
+    }
+    if n < 0 || incx == 0 || incy == 0 || lda < n.max(1) {
+        return;
+    }
+    if n == 0 || alpha == T::zero() {
+        return;
+    }
+
+    // the first elements of x and y are the last ones in memory if their increments are negative
+    let kx = if incx < 0 { (1 - n) * incx } else { 0 };
+    let ky = if incy < 0 { (1 - n) * incy } else { 0 };
+    let ix = |i: usize| (kx + i as isize * incx) as usize;
+    let iy = |i: usize| (ky + i as isize * incy) as usize;
+
+    let (n, lda) = (n as usize, lda as usize);
+
+    for j in 0..n {
+        let rows = if upper { 0..j } else { j + 1..n };
+        let temp1 = alpha * conj(y[[iy(j)]]);
+        let temp2 = conj(alpha * x[[ix(j)]]);
+        for i in rows {
+            a[[i + j * lda]] = a[[i + j * lda]] + x[[ix(i)]] * temp1 + y[[iy(i)]] * temp2;
+        }
+        let ajj = a[[j + j * lda]] + x[[ix(j)]] * temp1 + y[[iy(j)]] * temp2;
+        a[[j + j * lda]] = diag(ajj);
+    }
+}
@@ -0,0 +1,179 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_complex::Complex;
+use num_traits::{Float, Num};
+
+/// Solves a system of linear equations with a triangular band matrix.
+///
+/// op(A) * x = b
+///
+/// No test for singularity or near-singularity is included in this routine.
+///
+/// Input:
+///     uplo: 'U' if A is upper triangular, 'L' if A is lower triangular
+///     trans: 'N' for op(A) = A, 'T' or 'C' for op(A) = Aᵀ
+///     diag: 'U' if A has a unit diagonal, which is then not referenced, 'N' otherwise
+///     n: order of A
+///     k: number of super-diagonals of A if uplo = 'U', of sub-diagonals if uplo = 'L'
+///     a: band matrix in column-major order, lda by n. Column j holds the elements of column j
+///         of A in the band, the diagonal in row k if uplo = 'U' and in row 0 if uplo = 'L'
+///     lda: leading dimension of a, at least k + 1
+///     x: vector holding b
+///     incx: increment for x, must not be zero
+/// Output:
+///     x: the solution x
+#[allow(clippy::too_many_arguments)]
+pub fn tbsv_real<T: Float>(
+    uplo: &char,
+    trans: &char,
+    diag: &char,
+    n: &isize,
+    k: &isize,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    x: &mut NdSliceMut<'_, T, 1>,
+    incx: &isize,
+) {
+    tbsv(uplo, trans, diag, n, k, a, lda, x, incx, |a| a);
+}
+
+/// Solves a system of linear equations with a triangular band matrix.
+///
+/// op(A) * x = b
+///
+/// No test for singularity or near-singularity is included in this routine.
+///
+/// Input:
+///     uplo: 'U' if A is upper triangular, 'L' if A is lower triangular
+///     trans: 'N' for op(A) = A, 'T' for op(A) = Aᵀ, 'C' for op(A) = Aᴴ
+///     diag: 'U' if A has a unit diagonal, which is then not referenced, 'N' otherwise
+///     n: order of A
+///     k: number of super-diagonals of A if uplo = 'U', of sub-diagonals if uplo = 'L'
+///     a: band matrix in column-major order, lda by n. Column j holds the elements of column j
+///         of A in the band, the diagonal in row k if uplo = 'U' and in row 0 if uplo = 'L'
+///     lda: leading dimension of a, at least k + 1
+///     x: vector holding b
+///     incx: increment for x, must not be zero
+/// Output:
+///     x: the solution x
+#[allow(clippy::too_many_arguments)]
+pub fn tbsv_complex<T: Float>(
+    uplo: &char,
+    trans: &char,
+    diag: &char,
+    n: &isize,
+    k: &isize,
+    a: &NdSlice<'_, Complex<T>, 1>,
+    lda: &isize,
+    x: &mut NdSliceMut<'_, Complex<T>, 1>,
+    incx: &isize,
+) {
+    tbsv(uplo, trans, diag, n, k, a, lda, x, incx, |a| a.conj());
+}
+
+/// Both versions of tbsv, conj being applied to the elements of A if trans = 'C'.
+#[allow(clippy::too_many_arguments)]
+fn tbsv<T: Num + Copy>(
+    uplo: &char,
+    trans: &char,
+    diag: &char,
+    n: &isize,
+    k: &isize,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    x: &mut NdSliceMut<'_, T, 1>,
+    incx: &isize,
+    conj: impl Fn(T) -> T,
+) {
+    let (n, k, lda, incx) = (*n, *k, *lda, *incx);
+
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+    let notrans = trans.eq_ignore_ascii_case(&'N');
+    let conjugate = trans.eq_ignore_ascii_case(&'C');
+    let nounit = diag.eq_ignore_ascii_case(&'N');
+
+    if !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return;
+    }
+    if !(notrans || conjugate || trans.eq_ignore_ascii_case(&'T')) {
+        return;
+    }
+    if !(nounit || diag.eq_ignore_ascii_case(&'U')) {
+        return;
+    }
+    if n < 0 || k < 0 || lda < k + 1 || incx == 0 {
+        return;
+    }
+    if n == 0 {
+        return;
+    }
+
+    // the first element of x is the last one in memory if incx is negative
+    let kx = if incx < 0 { (1 - n) * incx } else { 0 };
+    let ix = |i: usize| (kx + i as isize * incx) as usize;
+
+    let (n, k, lda) = (n as usize, k as usize, lda as usize);
+
+    // element (i, j) of A, which must lie in the band
+    let a = |i: usize, j: usize| {
+        let aij = if upper {
+            a[[k + i - j + j * lda]]
+        } else {
+            a[[i - j + j * lda]]
+        };
+
+        if conjugate {
+            conj(aij)
+        } else {
+            aij
+        }
+    };
+
+    // rows of column j of A in the band, apart from the diagonal
+    let band = |j: usize| {
+        if upper {
+            j.saturating_sub(k)..j
+        } else {
+            j + 1..(j + k + 1).min(n)
+        }
+    };
+
+    if notrans {
+        // once x[j] is solved for, it is eliminated from the elements before it (upper) or after
+        // it (lower)
+        let eliminate = |x: &mut NdSliceMut<'_, T, 1>, j: usize| {
+            if x[[ix(j)]] != T::zero() {
+                if nounit {
+                    x[[ix(j)]] = x[[ix(j)]] / a(j, j);
+                }
+                let temp = x[[ix(j)]];
+                for i in band(j) {
+                    x[[ix(i)]] = x[[ix(i)]] - temp * a(i, j);
+                }
+            }
+        };
+
+        if upper {
+            (0..n).rev().for_each(|j| eliminate(x, j));
+        } else {
+            (0..n).for_each(|j| eliminate(x, j));
+        }
+    } else {
+        // x[j] is solved for from column j of A and the elements of x already solved for
+        let solve = |x: &mut NdSliceMut<'_, T, 1>, j: usize| {
+            let mut temp = x[[ix(j)]];
+            for i in band(j) {
+                temp = temp - a(i, j) * x[[ix(i)]];
+            }
+            if nounit {
+                temp = temp / a(j, j);
+            }
+            x[[ix(j)]] = temp;
+        };
+
+        if upper {
+            (0..n).for_each(|j| solve(x, j));
+        } else {
+            (0..n).rev().for_each(|j| solve(x, j));
+        }
+    }
+}
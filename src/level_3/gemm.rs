@@ -0,0 +1,347 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_complex::Complex;
+use num_traits::Float;
+
+/// Computes a matrix-matrix product with general matrices.
+///
+/// C = alpha * op(A) * op(B) + beta * C
+///
+/// The products are accumulated in U, so `gemm::<f16, f32>` multiplies half precision matrices
+/// into a single precision C. `gemm_mixed` is the variant with C in T.
+///
+/// Input:
+///     transa: 'N' for op(A) = A, 'T' or 'C' for op(A) = Aᵀ
+///     transb: 'N' for op(B) = B, 'T' or 'C' for op(B) = Bᵀ
+///     m: number of rows of op(A) and C
+///     n: number of columns of op(B) and C
+///     k: number of columns of op(A) and rows of op(B)
+///     alpha: scalar
+///     a: matrix in column-major order, lda by k if transa = 'N' and lda by m otherwise
+///     lda: leading dimension of a
+///     b: matrix in column-major order, ldb by n if transb = 'N' and ldb by k otherwise
+///     ldb: leading dimension of b
+///     beta: scalar, C need not be set if beta is zero
+///     c: matrix in column-major order, ldc by n
+///     ldc: leading dimension of c
+/// Output:
+///     c: modified c
+#[allow(clippy::too_many_arguments)]
+pub fn gemm<T: Float, U: Float>(
+    transa: &char,
+    transb: &char,
+    m: &isize,
+    n: &isize,
+    k: &isize,
+    alpha: &U,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    b: &NdSlice<'_, T, 1>,
+    ldb: &isize,
+    beta: &U,
+    c: &mut NdSliceMut<'_, U, 1>,
+    ldc: &isize,
+) {
+    let (m, n, k, alpha, beta) = (*m, *n, *k, *alpha, *beta);
+    let (lda, ldb, ldc) = (*lda, *ldb, *ldc);
+
+    let (zero, one): (U, U) = (num_traits::zero(), num_traits::one());
+
+    let nota = transa.eq_ignore_ascii_case(&'N');
+    let notb = transb.eq_ignore_ascii_case(&'N');
+    let (nrowa, nrowb) = (if nota { m } else { k }, if notb { k } else { n });
+
+    if !(nota || "TtCc".contains(*transa)) || !(notb || "TtCc".contains(*transb)) {
+        return;
+    }
+    if m < 0 || n < 0 || k < 0 || lda < nrowa.max(1) || ldb < nrowb.max(1) || ldc < m.max(1) {
+        return;
+    }
+    if m == 0 || n == 0 || ((alpha == zero || k == 0) && beta == one) {
+        return;
+    }
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+
+    let a = |i: usize, j: usize| U::from(a[[i + j * lda]]).unwrap();
+    let b = |i: usize, j: usize| U::from(b[[i + j * ldb]]).unwrap();
+
+    if alpha == zero {
+        for j in 0..n {
+            for i in 0..m {
+                c[[i + j * ldc]] = if beta == zero {
+                    zero
+                } else {
+                    beta * c[[i + j * ldc]]
+                };
+            }
+        }
+
+        return;
+    }
+
+    if nota {
+        // C = alpha * A * op(B) + beta * C, one column of C at a time
+        for j in 0..n {
+            if beta == zero {
+                for i in 0..m {
+                    c[[i + j * ldc]] = zero;
+                }
+            } else if beta != one {
+                for i in 0..m {
+                    c[[i + j * ldc]] = beta * c[[i + j * ldc]];
+                }
+            }
+
+            for l in 0..k {
+                let temp = alpha * if notb { b(l, j) } else { b(j, l) };
+
+                for i in 0..m {
+                    c[[i + j * ldc]] = c[[i + j * ldc]] + temp * a(i, l);
+                }
+            }
+        }
+    } else {
+        // C = alpha * Aᵀ * op(B) + beta * C, one dot product per element of C
+        for j in 0..n {
+            for i in 0..m {
+                let mut temp = zero;
+                for l in 0..k {
+                    temp = temp + a(l, i) * if notb { b(l, j) } else { b(j, l) };
+                }
+
+                c[[i + j * ldc]] = if beta == zero {
+                    alpha * temp
+                } else {
+                    alpha * temp + beta * c[[i + j * ldc]]
+                };
+            }
+        }
+    }
+}
+
+/// Computes a matrix-matrix product with general matrices, in a wider type.
+///
+/// C = alpha * op(A) * op(B) + beta * C
+///
+/// Each element of C is accumulated in U and rounded back to T once, so `gemm_mixed::<f16, f32>`
+/// multiplies half precision matrices into a half precision C the way hardware BLAS libraries
+/// do. `gemm` is the variant with C in U.
+///
+/// Input:
+///     transa: 'N' for op(A) = A, 'T' or 'C' for op(A) = Aᵀ
+///     transb: 'N' for op(B) = B, 'T' or 'C' for op(B) = Bᵀ
+///     m: number of rows of op(A) and C
+///     n: number of columns of op(B) and C
+///     k: number of columns of op(A) and rows of op(B)
+///     alpha: scalar
+///     a: matrix in column-major order, lda by k if transa = 'N' and lda by m otherwise
+///     lda: leading dimension of a
+///     b: matrix in column-major order, ldb by n if transb = 'N' and ldb by k otherwise
+///     ldb: leading dimension of b
+///     beta: scalar, C need not be set if beta is zero
+///     c: matrix in column-major order, ldc by n
+///     ldc: leading dimension of c
+/// Output:
+///     c: modified c
+#[allow(clippy::too_many_arguments)]
+pub fn gemm_mixed<T: Float, U: Float>(
+    transa: &char,
+    transb: &char,
+    m: &isize,
+    n: &isize,
+    k: &isize,
+    alpha: &U,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    b: &NdSlice<'_, T, 1>,
+    ldb: &isize,
+    beta: &U,
+    c: &mut NdSliceMut<'_, T, 1>,
+    ldc: &isize,
+) {
+    let (m, n, k, alpha, beta) = (*m, *n, *k, *alpha, *beta);
+    let (lda, ldb, ldc) = (*lda, *ldb, *ldc);
+
+    let (zero, one): (U, U) = (num_traits::zero(), num_traits::one());
+
+    let nota = transa.eq_ignore_ascii_case(&'N');
+    let notb = transb.eq_ignore_ascii_case(&'N');
+    let (nrowa, nrowb) = (if nota { m } else { k }, if notb { k } else { n });
+
+    if !(nota || "TtCc".contains(*transa)) || !(notb || "TtCc".contains(*transb)) {
+        return;
+    }
+    if m < 0 || n < 0 || k < 0 || lda < nrowa.max(1) || ldb < nrowb.max(1) || ldc < m.max(1) {
+        return;
+    }
+    if m == 0 || n == 0 || ((alpha == zero || k == 0) && beta == one) {
+        return;
+    }
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+
+    let a = |i: usize, l: usize| {
+        U::from(if nota {
+            a[[i + l * lda]]
+        } else {
+            a[[l + i * lda]]
+        })
+        .unwrap()
+    };
+    let b = |l: usize, j: usize| {
+        U::from(if notb {
+            b[[l + j * ldb]]
+        } else {
+            b[[j + l * ldb]]
+        })
+        .unwrap()
+    };
+
+    // one dot product per element of C, rounded to T at the end
+    for j in 0..n {
+        for i in 0..m {
+            let mut temp = zero;
+            if alpha != zero {
+                for l in 0..k {
+                    temp = temp + a(i, l) * b(l, j);
+                }
+            }
+
+            let cij = if beta == zero {
+                alpha * temp
+            } else {
+                alpha * temp + beta * U::from(c[[i + j * ldc]]).unwrap()
+            };
+            c[[i + j * ldc]] = T::from(cij).unwrap();
+        }
+    }
+}
+
+/// Computes a matrix-matrix product with general complex matrices.
+///
+/// C = alpha * op(A) * op(B) + beta * C
+///
+/// Input:
+///     transa: 'N' for op(A) = A, 'T' for op(A) = Aᵀ, 'C' for op(A) = Aᴴ
+///     transb: 'N' for op(B) = B, 'T' for op(B) = Bᵀ, 'C' for op(B) = Bᴴ
+///     m: number of rows of op(A) and C
+///     n: number of columns of op(B) and C
+///     k: number of columns of op(A) and rows of op(B)
+///     alpha: scalar
+///     a: matrix in column-major order, lda by k if transa = 'N' and lda by m otherwise
+///     lda: leading dimension of a
+///     b: matrix in column-major order, ldb by n if transb = 'N' and ldb by k otherwise
+///     ldb: leading dimension of b
+///     beta: scalar, C need not be set if beta is zero
+///     c: matrix in column-major order, ldc by n
+///     ldc: leading dimension of c
+/// Output:
+///     c: modified c
+#[allow(clippy::too_many_arguments)]
+pub fn gemm_complex<T: Float>(
+    transa: &char,
+    transb: &char,
+    m: &isize,
+    n: &isize,
+    k: &isize,
+    alpha: &Complex<T>,
+    a: &NdSlice<'_, Complex<T>, 1>,
+    lda: &isize,
+    b: &NdSlice<'_, Complex<T>, 1>,
+    ldb: &isize,
+    beta: &Complex<T>,
+    c: &mut NdSliceMut<'_, Complex<T>, 1>,
+    ldc: &isize,
+) {
+    let (m, n, k, alpha, beta) = (*m, *n, *k, *alpha, *beta);
+    let (lda, ldb, ldc) = (*lda, *ldb, *ldc);
+
+    let zero = Complex::new(T::zero(), T::zero());
+    let one = Complex::new(T::one(), T::zero());
+
+    let nota = transa.eq_ignore_ascii_case(&'N');
+    let notb = transb.eq_ignore_ascii_case(&'N');
+    let conja = transa.eq_ignore_ascii_case(&'C');
+    let conjb = transb.eq_ignore_ascii_case(&'C');
+    let (nrowa, nrowb) = (if nota { m } else { k }, if notb { k } else { n });
+
+    if !(nota || conja || transa.eq_ignore_ascii_case(&'T')) {
+        return;
+    }
+    if !(notb || conjb || transb.eq_ignore_ascii_case(&'T')) {
+        return;
+    }
+    if m < 0 || n < 0 || k < 0 || lda < nrowa.max(1) || ldb < nrowb.max(1) || ldc < m.max(1) {
+        return;
+    }
+    if m == 0 || n == 0 || ((alpha == zero || k == 0) && beta == one) {
+        return;
+    }
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+
+    // element (i, l) of op(A) and (l, j) of op(B)
+    let a = |i: usize, l: usize| match (nota, conja) {
+        (true, _) => a[[i + l * lda]],
+        (false, false) => a[[l + i * lda]],
+        (false, true) => a[[l + i * lda]].conj(),
+    };
+    let b = |l: usize, j: usize| match (notb, conjb) {
+        (true, _) => b[[l + j * ldb]],
+        (false, false) => b[[j + l * ldb]],
+        (false, true) => b[[j + l * ldb]].conj(),
+    };
+
+    if alpha == zero {
+        for j in 0..n {
+            for i in 0..m {
+                c[[i + j * ldc]] = if beta == zero {
+                    zero
+                } else {
+                    beta * c[[i + j * ldc]]
+                };
+            }
+        }
+
+        return;
+    }
+
+    if nota {
+        // C = alpha * A * op(B) + beta * C, one column of C at a time
+        for j in 0..n {
+            if beta == zero {
+                for i in 0..m {
+                    c[[i + j * ldc]] = zero;
+                }
+            } else if beta != one {
+                for i in 0..m {
+                    c[[i + j * ldc]] = beta * c[[i + j * ldc]];
+                }
+            }
+
+            for l in 0..k {
+                let temp = alpha * b(l, j);
+
+                for i in 0..m {
+                    c[[i + j * ldc]] = c[[i + j * ldc]] + temp * a(i, l);
+                }
+            }
+        }
+    } else {
+        // C = alpha * op(A) * op(B) + beta * C, one dot product per element of C
+        for j in 0..n {
+            for i in 0..m {
+                let mut temp = zero;
+                for l in 0..k {
+                    temp = temp + a(i, l) * b(l, j);
+                }
+
+                c[[i + j * ldc]] = if beta == zero {
+                    alpha * temp
+                } else {
+                    alpha * temp + beta * c[[i + j * ldc]]
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use alloc::vec::Vec;
+
+use nd_slice::{NdSlice, NdSliceMut};
+use num_traits::Float;
+
+use crate::contiguous::{as_mut_slice, as_slice, vector, vector_mut};
+use crate::level_3::gemm::gemm;
+#[cfg(feature = "rayon")]
+use crate::threading;
+
+/// Runs every job, spread across threads when the rayon feature is enabled.
+fn run<I: Send>(work: usize, jobs: Vec<I>, f: impl Fn(I) + Sync) {
+    #[cfg(feature = "rayon")]
+    threading::for_each(work, jobs, f);
+
+    #[cfg(not(feature = "rayon"))]
+    {
+        let _ = work;
+        jobs.into_iter().for_each(f);
+    }
+}
+
+/// Returns the work of n products of m by k and k by n matrices, saturating instead of
+/// overflowing as it is only an estimate.
+fn work(count: usize, m: isize, n: isize, k: isize) -> usize {
+    [m, n, k]
+        .iter()
+        .fold(count, |acc, &dim| acc.saturating_mul(dim.max(0) as usize))
+}
+
+/// Computes groups of matrix-matrix products with general matrices.
+///
+/// C[p] = alpha[g] * op(A[p]) * op(B[p]) + beta[g] * C[p]
+///
+/// The products are split into groups; the p-th product belongs to group g and uses that group's
+/// transa, transb, m, n, k, alpha, lda, ldb, beta and ldc. The products are independent and are
+/// spread across threads rather than splitting each one.
+///
+/// Input:
+///     transa: per group, 'N' for op(A) = A, 'T' or 'C' for op(A) = Aᵀ
+///     transb: per group, 'N' for op(B) = B, 'T' or 'C' for op(B) = Bᵀ
+///     m: per group, number of rows of op(A) and C
+///     n: per group, number of columns of op(B) and C
+///     k: per group, number of columns of op(A) and rows of op(B)
+///     alpha: per group, scalar
+///     a: matrices in column-major order, one per product
+///     lda: per group, leading dimension of a
+///     b: matrices in column-major order, one per product
+///     ldb: per group, leading dimension of b
+///     beta: per group, scalar
+///     c: matrices in column-major order, one per product
+///     ldc: per group, leading dimension of c
+///     group_size: number of products in each group
+/// Output:
+///     c: modified c
+#[allow(clippy::too_many_arguments)]
+pub fn gemm_batch<T: Float + Sync, U: Float + Send + Sync>(
+    transa: &[char],
+    transb: &[char],
+    m: &[isize],
+    n: &[isize],
+    k: &[isize],
+    alpha: &[U],
+    a: &[NdSlice<'_, T, 1>],
+    lda: &[isize],
+    b: &[NdSlice<'_, T, 1>],
+    ldb: &[isize],
+    beta: &[U],
+    c: &mut [NdSliceMut<'_, U, 1>],
+    ldc: &[isize],
+    group_size: &[isize],
+) {
+    let group_count = group_size.len();
+
+    let params = [
+        transa.len(),
+        transb.len(),
+        m.len(),
+        n.len(),
+        k.len(),
+        alpha.len(),
+        lda.len(),
+        ldb.len(),
+        beta.len(),
+        ldc.len(),
+    ];
+    if params.iter().any(|&len| len < group_count) || group_size.iter().any(|&size| size < 0) {
+        return;
+    }
+
+    let batch_size = group_size
+        .iter()
+        .fold(0usize, |acc, &size| acc.saturating_add(size as usize));
+    if a.len() < batch_size || b.len() < batch_size || c.len() < batch_size {
+        return;
+    }
+
+    let work = group_size
+        .iter()
+        .enumerate()
+        .map(|(g, &size)| work(size as usize, m[g], n[g], k[g]))
+        .fold(0usize, usize::saturating_add);
+
+    let groups = group_size
+        .iter()
+        .enumerate()
+        .flat_map(|(g, &size)| core::iter::repeat_n(g, size as usize));
+    let jobs: Vec<_> = groups
+        .zip(a)
+        .zip(b)
+        .zip(c.iter_mut())
+        .map(|(((g, a), b), c)| (g, a, b, c))
+        .collect();
+
+    run(work, jobs, |(g, a, b, c)| {
+        gemm(
+            &transa[g], &transb[g], &m[g], &n[g], &k[g], &alpha[g], a, &lda[g], b, &ldb[g],
+            &beta[g], c, &ldc[g],
+        );
+    });
+}
+
+/// Computes a batch of matrix-matrix products with general matrices stored at a fixed stride.
+///
+/// C[p] = alpha * op(A[p]) * op(B[p]) + beta * C[p]
+///
+/// where A[p] starts at a[p * stridea], B[p] at b[p * strideb] and C[p] at c[p * stridec]. The
+/// products are independent and are spread across threads rather than splitting each one.
+///
+/// Input:
+///     transa: 'N' for op(A) = A, 'T' or 'C' for op(A) = Aᵀ
+///     transb: 'N' for op(B) = B, 'T' or 'C' for op(B) = Bᵀ
+///     m: number of rows of op(A) and C
+///     n: number of columns of op(B) and C
+///     k: number of columns of op(A) and rows of op(B)
+///     alpha: scalar
+///     a: matrices in column-major order
+///     lda: leading dimension of each A[p]
+///     stridea: distance between the starts of A[p] and A[p + 1]
+///     b: matrices in column-major order
+///     ldb: leading dimension of each B[p]
+///     strideb: distance between the starts of B[p] and B[p + 1]
+///     beta: scalar
+///     c: matrices in column-major order
+///     ldc: leading dimension of each C[p]
+///     stridec: distance between the starts of C[p] and C[p + 1], at least ldc * n
+///     batch_size: number of products
+/// Output:
+///     c: modified c
+#[allow(clippy::too_many_arguments)]
+pub fn gemm_batch_strided<T: Float + Sync, U: Float + Send + Sync>(
+    transa: &char,
+    transb: &char,
+    m: &isize,
+    n: &isize,
+    k: &isize,
+    alpha: &U,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    stridea: &isize,
+    b: &NdSlice<'_, T, 1>,
+    ldb: &isize,
+    strideb: &isize,
+    beta: &U,
+    c: &mut NdSliceMut<'_, U, 1>,
+    ldc: &isize,
+    stridec: &isize,
+    batch_size: &isize,
+) {
+    let (m, n, k, batch_size) = (*m, *n, *k, *batch_size);
+    let (lda, ldb, ldc) = (*lda, *ldb, *ldc);
+    let (stridea, strideb, stridec) = (*stridea, *strideb, *stridec);
+
+    let nota = transa.eq_ignore_ascii_case(&'N');
+    let notb = transb.eq_ignore_ascii_case(&'N');
+    let (nrowa, ncola) = if nota { (m, k) } else { (k, m) };
+    let (nrowb, ncolb) = if notb { (k, n) } else { (n, k) };
+
+    if m < 0 || n < 0 || k < 0 || batch_size < 0 {
+        return;
+    }
+    if lda < nrowa.max(1) || ldb < nrowb.max(1) || ldc < m.max(1) {
+        return;
+    }
+    if stridea < 0 || strideb < 0 || stridec < ldc.saturating_mul(n) {
+        return;
+    }
+    if m == 0 || n == 0 || batch_size == 0 {
+        return;
+    }
+
+    // number of elements each matrix spans from its first element
+    let extent = |ld: isize, rows: isize, cols: isize| {
+        if rows == 0 || cols == 0 {
+            0
+        } else {
+            (ld * (cols - 1) + rows) as usize
+        }
+    };
+    let (lena, lenb, lenc) = (
+        extent(lda, nrowa, ncola),
+        extent(ldb, nrowb, ncolb),
+        extent(ldc, m, n),
+    );
+    let (batch_size, stridea, strideb, stridec) = (
+        batch_size as usize,
+        stridea as usize,
+        strideb as usize,
+        stridec as usize,
+    );
+
+    let a = as_slice(a, (batch_size - 1) * stridea + lena);
+    let b = as_slice(b, (batch_size - 1) * strideb + lenb);
+    let c = as_mut_slice(c, (batch_size - 1) * stridec + lenc);
+
+    let jobs: Vec<_> = c
+        .chunks_mut(stridec)
+        .take(batch_size)
+        .enumerate()
+        .map(|(p, c)| {
+            let a = &a[p * stridea..p * stridea + lena];
+            let b = &b[p * strideb..p * strideb + lenb];
+            (a, b, &mut c[..lenc])
+        })
+        .collect();
+
+    run(work(batch_size, m, n, k), jobs, |(a, b, c)| {
+        gemm(
+            transa,
+            transb,
+            &m,
+            &n,
+            &k,
+            alpha,
+            &vector(a),
+            &lda,
+            &vector(b),
+            &ldb,
+            beta,
+            &mut vector_mut(c),
+            &ldc,
+        );
+    });
+}
@@ -0,0 +1,172 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_traits::Float;
+
+/// Computes a matrix-matrix product of 8-bit integer matrices, accumulating in 32-bit integers.
+///
+/// C = alpha * (op(A) - ao) * (op(B) - bo) + beta * C + co
+///
+/// A and B hold `i8` or `u8` elements, e.g. `gemm_s32::<u8, i8>` for unsigned activations and
+/// signed weights. The product is accumulated in `i32` and wraps around on overflow, as MKL's
+/// `gemm_s8u8s32` does, which 8-bit data only reaches for k in the tens of thousands. alpha and
+/// beta are only applied if they are not 1 and 0, in which case the result is rounded to the
+/// nearest integer and saturated. Adding co wraps around too.
+///
+/// Input:
+///     transa: 'N' for op(A) = A, 'T' or 'C' for op(A) = Aᵀ
+///     transb: 'N' for op(B) = B, 'T' or 'C' for op(B) = Bᵀ
+///     offsetc: 'F' to add co[0] to every element of C, 'C' to add co[i] to row i and 'R' to add
+///         co[j] to column j
+///     m: number of rows of op(A) and C
+///     n: number of columns of op(B) and C
+///     k: number of columns of op(A) and rows of op(B)
+///     alpha: scalar
+///     a: matrix in column-major order, lda by k if transa = 'N' and lda by m otherwise
+///     lda: leading dimension of a
+///     ao: zero point of a
+///     b: matrix in column-major order, ldb by n if transb = 'N' and ldb by k otherwise
+///     ldb: leading dimension of b
+///     bo: zero point of b
+///     beta: scalar, C need not be set if beta is zero
+///     c: matrix in column-major order, ldc by n
+///     ldc: leading dimension of c
+///     co: offsets for c, of size 1, m or n depending on offsetc
+/// Output:
+///     c: modified c
+#[allow(clippy::too_many_arguments)]
+pub fn gemm_s32<TA: Copy + Into<i32>, TB: Copy + Into<i32>>(
+    transa: &char,
+    transb: &char,
+    offsetc: &char,
+    m: &isize,
+    n: &isize,
+    k: &isize,
+    alpha: &f32,
+    a: &NdSlice<'_, TA, 1>,
+    lda: &isize,
+    ao: &i32,
+    b: &NdSlice<'_, TB, 1>,
+    ldb: &isize,
+    bo: &i32,
+    beta: &f32,
+    c: &mut NdSliceMut<'_, i32, 1>,
+    ldc: &isize,
+    co: &NdSlice<'_, i32, 1>,
+) {
+    let (m, n, k, ao, bo) = (*m, *n, *k, *ao, *bo);
+    let (alpha, beta) = (*alpha as f64, *beta as f64);
+    let (lda, ldb, ldc) = (*lda, *ldb, *ldc);
+
+    let nota = transa.eq_ignore_ascii_case(&'N');
+    let notb = transb.eq_ignore_ascii_case(&'N');
+    let (nrowa, nrowb) = (if nota { m } else { k }, if notb { k } else { n });
+
+    if !(nota || "TtCc".contains(*transa)) || !(notb || "TtCc".contains(*transb)) {
+        return;
+    }
+    if !"FfCcRr".contains(*offsetc) {
+        return;
+    }
+    if m < 0 || n < 0 || k < 0 || lda < nrowa.max(1) || ldb < nrowb.max(1) || ldc < m.max(1) {
+        return;
+    }
+    if m == 0 || n == 0 {
+        return;
+    }
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+
+    let a = |i: usize, j: usize| {
+        let aij = if nota {
+            a[[i + j * lda]]
+        } else {
+            a[[j + i * lda]]
+        };
+        Into::<i32>::into(aij).wrapping_sub(ao)
+    };
+    let b = |i: usize, j: usize| {
+        let bij = if notb {
+            b[[i + j * ldb]]
+        } else {
+            b[[j + i * ldb]]
+        };
+        Into::<i32>::into(bij).wrapping_sub(bo)
+    };
+    let offset = |i: usize, j: usize| match offsetc {
+        'F' | 'f' => co[[0]],
+        'C' | 'c' => co[[i]],
+        _ => co[[j]],
+    };
+
+    for j in 0..n {
+        for i in 0..m {
+            let mut temp: i32 = 0;
+            for l in 0..k {
+                temp = temp.wrapping_add(a(i, l).wrapping_mul(b(l, j)));
+            }
+
+            let cij = if alpha == 1.0 && beta == 0.0 {
+                temp
+            } else if beta == 0.0 {
+                Float::round(alpha * temp as f64) as i32
+            } else {
+                Float::round(alpha * temp as f64 + beta * c[[i + j * ldc]] as f64) as i32
+            };
+
+            c[[i + j * ldc]] = cij.wrapping_add(offset(i, j));
+        }
+    }
+}
+
+/// Requantizes a 32-bit integer matrix to 8-bit integers.
+///
+/// Q = clamp(round(scale * C) + qo)
+///
+/// Input:
+///     mode: 'F' to scale every element by scale[0], 'C' to scale row i by scale[i] and 'R' to
+///         scale column j by scale[j]
+///     m: number of rows of C and Q
+///     n: number of columns of C and Q
+///     c: matrix in column-major order, ldc by n
+///     ldc: leading dimension of c
+///     scale: scales, of size 1, m or n depending on mode
+///     qo: zero point of q
+///     q: matrix in column-major order, ldq by n
+///     ldq: leading dimension of q
+/// Output:
+///     q: requantized c
+#[allow(clippy::too_many_arguments)]
+pub fn requantize_s8(
+    mode: &char,
+    m: &isize,
+    n: &isize,
+    c: &NdSlice<'_, i32, 1>,
+    ldc: &isize,
+    scale: &NdSlice<'_, f32, 1>,
+    qo: &i32,
+    q: &mut NdSliceMut<'_, i8, 1>,
+    ldq: &isize,
+) {
+    let (m, n, ldc, ldq, qo) = (*m, *n, *ldc, *ldq, *qo);
+
+    if !"FfCcRr".contains(*mode) {
+        return;
+    }
+    if m < 0 || n < 0 || ldc < m.max(1) || ldq < m.max(1) {
+        return;
+    }
+    let (m, n, ldc, ldq) = (m as usize, n as usize, ldc as usize, ldq as usize);
+
+    let scale = |i: usize, j: usize| match mode {
+        'F' | 'f' => scale[[0]],
+        'C' | 'c' => scale[[i]],
+        _ => scale[[j]],
+    };
+
+    for j in 0..n {
+        for i in 0..m {
+            let temp = Float::round(scale(i, j) as f64 * c[[i + j * ldc]] as f64) + qo as f64;
+
+            q[[i + j * ldq]] = temp.clamp(i8::MIN as f64, i8::MAX as f64) as i8;
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_traits::Float;
+
+/// Computes a matrix-matrix product with general matrices, updating only one triangle of C.
+///
+/// C = alpha * op(A) * op(B) + beta * C
+///
+/// Only the upper or lower triangle of C, including the diagonal, is referenced and updated. The
+/// products are accumulated in U, as in `gemm`.
+///
+/// Input:
+///     uplo: 'U' to update the upper triangle of C, 'L' to update the lower triangle
+///     transa: 'N' for op(A) = A, 'T' or 'C' for op(A) = Aᵀ
+///     transb: 'N' for op(B) = B, 'T' or 'C' for op(B) = Bᵀ
+///     n: order of C, number of rows of op(A) and columns of op(B)
+///     k: number of columns of op(A) and rows of op(B)
+///     alpha: scalar
+///     a: matrix in column-major order, lda by k if transa = 'N' and lda by n otherwise
+///     lda: leading dimension of a
+///     b: matrix in column-major order, ldb by n if transb = 'N' and ldb by k otherwise
+///     ldb: leading dimension of b
+///     beta: scalar, C need not be set if beta is zero
+///     c: matrix in column-major order, ldc by n
+///     ldc: leading dimension of c
+/// Output:
+///     c: modified c
+#[allow(clippy::too_many_arguments)]
+pub fn gemmt<T: Float, U: Float>(
+    uplo: &char,
+    transa: &char,
+    transb: &char,
+    n: &isize,
+    k: &isize,
+    alpha: &U,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    b: &NdSlice<'_, T, 1>,
+    ldb: &isize,
+    beta: &U,
+    c: &mut NdSliceMut<'_, U, 1>,
+    ldc: &isize,
+) {
+    let (n, k, alpha, beta) = (*n, *k, *alpha, *beta);
+    let (lda, ldb, ldc) = (*lda, *ldb, *ldc);
+
+    let (zero, one): (U, U) = (num_traits::zero(), num_traits::one());
+
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+    let nota = transa.eq_ignore_ascii_case(&'N');
+    let notb = transb.eq_ignore_ascii_case(&'N');
+    let (nrowa, nrowb) = (if nota { n } else { k }, if notb { k } else { n });
+
+    if !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return;
+    }
+    if !(nota || "TtCc".contains(*transa)) || !(notb || "TtCc".contains(*transb)) {
+        return;
+    }
+    if n < 0 || k < 0 || lda < nrowa.max(1) || ldb < nrowb.max(1) || ldc < n.max(1) {
+        return;
+    }
+    if n == 0 || ((alpha == zero || k == 0) && beta == one) {
+        return;
+    }
+    let (n, k) = (n as usize, k as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+
+    let a = |i: usize, j: usize| U::from(a[[i + j * lda]]).unwrap();
+    let b = |i: usize, j: usize| U::from(b[[i + j * ldb]]).unwrap();
+
+    // rows of column j that lie in the triangle
+    let rows = |j: usize| if upper { 0..j + 1 } else { j..n };
+
+    if alpha == zero {
+        for j in 0..n {
+            for i in rows(j) {
+                c[[i + j * ldc]] = if beta == zero {
+                    zero
+                } else {
+                    beta * c[[i + j * ldc]]
+                };
+            }
+        }
+
+        return;
+    }
+
+    if nota {
+        for j in 0..n {
+            if beta == zero {
+                for i in rows(j) {
+                    c[[i + j * ldc]] = zero;
+                }
+            } else if beta != one {
+                for i in rows(j) {
+                    c[[i + j * ldc]] = beta * c[[i + j * ldc]];
+                }
+            }
+
+            for l in 0..k {
+                let temp = alpha * if notb { b(l, j) } else { b(j, l) };
+
+                for i in rows(j) {
+                    c[[i + j * ldc]] = c[[i + j * ldc]] + temp * a(i, l);
+                }
+            }
+        }
+    } else {
+        for j in 0..n {
+            for i in rows(j) {
+                let mut temp = zero;
+                for l in 0..k {
+                    temp = temp + a(l, i) * if notb { b(l, j) } else { b(j, l) };
+                }
+
+                c[[i + j * ldc]] = if beta == zero {
+                    alpha * temp
+                } else {
+                    alpha * temp + beta * c[[i + j * ldc]]
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_complex::Complex;
+use num_traits::Float;
+
+use super::syrk::rank_k;
+
+/// Performs a rank-k update of a Hermitian matrix.
+///
+/// C = alpha * A * Aᴴ + beta * C or C = alpha * Aᴴ * A + beta * C
+///
+/// Only the upper or lower triangle of C, including the diagonal, is referenced and updated.
+/// The imaginary parts of the diagonal of C are set to zero.
+///
+/// Input:
+///     uplo: 'U' to update the upper triangle of C, 'L' to update the lower triangle
+///     trans: 'N' for C = alpha * A * Aᴴ + beta * C, 'C' for C = alpha * Aᴴ * A + beta * C
+///     n: order of C
+///     k: number of columns of A if trans = 'N', number of rows otherwise
+///     alpha: real scalar
+///     a: matrix in column-major order, lda by k if trans = 'N' and lda by n otherwise
+///     lda: leading dimension of a
+///     beta: real scalar, C need not be set if beta is zero
+///     c: Hermitian matrix in column-major order, ldc by n
+///     ldc: leading dimension of c
+/// Output:
+///     c: modified c
+#[allow(clippy::too_many_arguments)]
+pub fn herk_complex<T: Float>(
+    uplo: &char,
+    trans: &char,
+    n: &isize,
+    k: &isize,
+    alpha: &T,
+    a: &NdSlice<'_, Complex<T>, 1>,
+    lda: &isize,
+    beta: &T,
+    c: &mut NdSliceMut<'_, Complex<T>, 1>,
+    ldc: &isize,
+) {
+    if !"NnCc".contains(*trans) {
+        return;
+    }
+
+    let (alpha, beta) = (Complex::from(*alpha), Complex::from(*beta));
+    rank_k(
+        uplo,
+        trans,
+        n,
+        k,
+        &alpha,
+        a,
+        lda,
+        &beta,
+        c,
+        ldc,
+        |a| a.conj(),
+        |c| Complex::from(c.re),
+    );
+}
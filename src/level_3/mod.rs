@@ -0,0 +1,9 @@
+pub mod gemm;
+#[cfg(feature = "alloc")]
+pub mod gemm_batch;
+pub mod gemm_s32;
+pub mod gemmt;
+pub mod herk;
+pub mod syrk;
+pub mod trmm;
+pub mod trsm;
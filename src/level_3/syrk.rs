@@ -0,0 +1,163 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_complex::Complex;
+use num_traits::{Float, Num};
+
+/// Performs a rank-k update of a symmetric matrix.
+///
+/// C = alpha * A * Aᵀ + beta * C or C = alpha * Aᵀ * A + beta * C
+///
+/// Only the upper or lower triangle of C, including the diagonal, is referenced and updated.
+///
+/// Input:
+///     uplo: 'U' to update the upper triangle of C, 'L' to update the lower triangle
+///     trans: 'N' for C = alpha * A * Aᵀ + beta * C, 'T' or 'C' for C = alpha * Aᵀ * A + beta * C
+///     n: order of C
+///     k: number of columns of A if trans = 'N', number of rows otherwise
+///     alpha: scalar
+///     a: matrix in column-major order, lda by k if trans = 'N' and lda by n otherwise
+///     lda: leading dimension of a
+///     beta: scalar, C need not be set if beta is zero
+///     c: symmetric matrix in column-major order, ldc by n
+///     ldc: leading dimension of c
+/// Output:
+///     c: modified c
+#[allow(clippy::too_many_arguments)]
+pub fn syrk_real<T: Float>(
+    uplo: &char,
+    trans: &char,
+    n: &isize,
+    k: &isize,
+    alpha: &T,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    beta: &T,
+    c: &mut NdSliceMut<'_, T, 1>,
+    ldc: &isize,
+) {
+    if "NnTtCc".contains(*trans) {
+        rank_k(uplo, trans, n, k, alpha, a, lda, beta, c, ldc, |a| a, |c| c);
+    }
+}
+
+/// Performs a rank-k update of a complex symmetric matrix.
+///
+/// C = alpha * A * Aᵀ + beta * C or C = alpha * Aᵀ * A + beta * C
+///
+/// Only the upper or lower triangle of C, including the diagonal, is referenced and updated.
+///
+/// Input:
+///     uplo: 'U' to update the upper triangle of C, 'L' to update the lower triangle
+///     trans: 'N' for C = alpha * A * Aᵀ + beta * C, 'T' for C = alpha * Aᵀ * A + beta * C
+///     n: order of C
+///     k: number of columns of A if trans = 'N', number of rows otherwise
+///     alpha: scalar
+///     a: matrix in column-major order, lda by k if trans = 'N' and lda by n otherwise
+///     lda: leading dimension of a
+///     beta: scalar, C need not be set if beta is zero
+///     c: symmetric matrix in column-major order, ldc by n
+///     ldc: leading dimension of c
+/// Output:
+///     c: modified c
+#[allow(clippy::too_many_arguments)]
+pub fn syrk_complex<T: Float>(
+    uplo: &char,
+    trans: &char,
+    n: &isize,
+    k: &isize,
+    alpha: &Complex<T>,
+    a: &NdSlice<'_, Complex<T>, 1>,
+    lda: &isize,
+    beta: &Complex<T>,
+    c: &mut NdSliceMut<'_, Complex<T>, 1>,
+    ldc: &isize,
+) {
+    if "NnTt".contains(*trans) {
+        rank_k(uplo, trans, n, k, alpha, a, lda, beta, c, ldc, |a| a, |c| c);
+    }
+}
+
+/// The rank-k updates of syrk and herk, for a trans that has already been checked.
+///
+/// conj is applied to the elements of A that are transposed, and diag to the diagonal of C
+/// once it is updated.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn rank_k<T: Num + Copy>(
+    uplo: &char,
+    trans: &char,
+    n: &isize,
+    k: &isize,
+    alpha: &T,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    beta: &T,
+    c: &mut NdSliceMut<'_, T, 1>,
+    ldc: &isize,
+    conj: impl Fn(T) -> T,
+    diag: impl Fn(T) -> T,
+) {
+    let (n, k, alpha, beta, lda, ldc) = (*n, *k, *alpha, *beta, *lda, *ldc);
+
+    let (zero, one) = (T::zero(), T::one());
+
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+    let notrans = trans.eq_ignore_ascii_case(&'N');
+    let nrowa = if notrans { n } else { k };
+
+    if !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return;
+    }
+    if n < 0 || k < 0 || lda < nrowa.max(1) || ldc < n.max(1) {
+        return;
+    }
+    if n == 0 || ((alpha == zero || k == 0) && beta == one) {
+        return;
+    }
+    let (n, k, lda, ldc) = (n as usize, k as usize, lda as usize, ldc as usize);
+
+    let a = |i: usize, j: usize| a[[i + j * lda]];
+
+    // rows of column j that lie in the triangle
+    let rows = |j: usize| if upper { 0..j + 1 } else { j..n };
+
+    for j in 0..n {
+        if notrans {
+            // C = alpha * A * Aᵀ + beta * C, one column of C at a time
+            for i in rows(j) {
+                c[[i + j * ldc]] = if beta == zero {
+                    zero
+                } else {
+                    beta * c[[i + j * ldc]]
+                };
+            }
+
+            if alpha != zero {
+                for l in 0..k {
+                    if a(j, l) != zero {
+                        let temp = alpha * conj(a(j, l));
+                        for i in rows(j) {
+                            c[[i + j * ldc]] = c[[i + j * ldc]] + temp * a(i, l);
+                        }
+                    }
+                }
+            }
+        } else {
+            // C = alpha * Aᵀ * A + beta * C, one dot product per element of C
+            for i in rows(j) {
+                let mut temp = zero;
+                if alpha != zero {
+                    for l in 0..k {
+                        temp = temp + conj(a(l, i)) * a(l, j);
+                    }
+                }
+
+                c[[i + j * ldc]] = if beta == zero {
+                    alpha * temp
+                } else {
+                    alpha * temp + beta * c[[i + j * ldc]]
+                };
+            }
+        }
+
+        c[[j + j * ldc]] = diag(c[[j + j * ldc]]);
+    }
+}
@@ -0,0 +1,241 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_complex::Complex;
+use num_traits::{Float, Num};
+
+/// Multiplies a matrix by a triangular matrix.
+///
+/// B = alpha * op(A) * B or B = alpha * B * op(A)
+///
+/// Input:
+///     side: 'L' for B = alpha * op(A) * B, 'R' for B = alpha * B * op(A)
+///     uplo: 'U' if A is upper triangular, 'L' if A is lower triangular
+///     transa: 'N' for op(A) = A, 'T' or 'C' for op(A) = Aᵀ
+///     diag: 'U' if A has a unit diagonal, which is then not referenced, 'N' otherwise
+///     m: number of rows of B
+///     n: number of columns of B
+///     alpha: scalar, B need not be set if alpha is zero
+///     a: triangular matrix in column-major order, lda by m if side = 'L' and lda by n otherwise
+///     lda: leading dimension of a
+///     b: matrix in column-major order, ldb by n
+///     ldb: leading dimension of b
+/// Output:
+///     b: the product
+#[allow(clippy::too_many_arguments)]
+pub fn trmm_real<T: Float>(
+    side: &char,
+    uplo: &char,
+    transa: &char,
+    diag: &char,
+    m: &isize,
+    n: &isize,
+    alpha: &T,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) {
+    trmm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, |a| a);
+}
+
+/// Multiplies a matrix by a triangular matrix.
+///
+/// B = alpha * op(A) * B or B = alpha * B * op(A)
+///
+/// Input:
+///     side: 'L' for B = alpha * op(A) * B, 'R' for B = alpha * B * op(A)
+///     uplo: 'U' if A is upper triangular, 'L' if A is lower triangular
+///     transa: 'N' for op(A) = A, 'T' for op(A) = Aᵀ, 'C' for op(A) = Aᴴ
+///     diag: 'U' if A has a unit diagonal, which is then not referenced, 'N' otherwise
+///     m: number of rows of B
+///     n: number of columns of B
+///     alpha: scalar, B need not be set if alpha is zero
+///     a: triangular matrix in column-major order, lda by m if side = 'L' and lda by n otherwise
+///     lda: leading dimension of a
+///     b: matrix in column-major order, ldb by n
+///     ldb: leading dimension of b
+/// Output:
+///     b: the product
+#[allow(clippy::too_many_arguments)]
+pub fn trmm_complex<T: Float>(
+    side: &char,
+    uplo: &char,
+    transa: &char,
+    diag: &char,
+    m: &isize,
+    n: &isize,
+    alpha: &Complex<T>,
+    a: &NdSlice<'_, Complex<T>, 1>,
+    lda: &isize,
+    b: &mut NdSliceMut<'_, Complex<T>, 1>,
+    ldb: &isize,
+) {
+    trmm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, |a| {
+        a.conj()
+    });
+}
+
+/// Both versions of trmm, conj being applied to the elements of A if transa = 'C'.
+#[allow(clippy::too_many_arguments)]
+fn trmm<T: Num + Copy>(
+    side: &char,
+    uplo: &char,
+    transa: &char,
+    diag: &char,
+    m: &isize,
+    n: &isize,
+    alpha: &T,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+    conj: impl Fn(T) -> T,
+) {
+    let (m, n, alpha, lda, ldb) = (*m, *n, *alpha, *lda, *ldb);
+
+    let (zero, one) = (T::zero(), T::one());
+
+    let lside = side.eq_ignore_ascii_case(&'L');
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+    let notrans = transa.eq_ignore_ascii_case(&'N');
+    let conjugate = transa.eq_ignore_ascii_case(&'C');
+    let nounit = diag.eq_ignore_ascii_case(&'N');
+    let nrowa = if lside { m } else { n };
+
+    if !(lside || side.eq_ignore_ascii_case(&'R')) || !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return;
+    }
+    if !(notrans || conjugate || transa.eq_ignore_ascii_case(&'T')) {
+        return;
+    }
+    if !(nounit || diag.eq_ignore_ascii_case(&'U')) {
+        return;
+    }
+    if m < 0 || n < 0 || lda < nrowa.max(1) || ldb < m.max(1) {
+        return;
+    }
+    if m == 0 || n == 0 {
+        return;
+    }
+    let (m, n, lda, ldb) = (m as usize, n as usize, lda as usize, ldb as usize);
+
+    if alpha == zero {
+        for j in 0..n {
+            for i in 0..m {
+                b[[i + j * ldb]] = zero;
+            }
+        }
+
+        return;
+    }
+
+    // element (i, j) of A, conjugated if transa = 'C'
+    let a = |i: usize, j: usize| {
+        if conjugate {
+            conj(a[[i + j * lda]])
+        } else {
+            a[[i + j * lda]]
+        }
+    };
+
+    let scale = |b: &mut NdSliceMut<'_, T, 1>, j: usize, s: T| {
+        for i in 0..m {
+            b[[i + j * ldb]] = s * b[[i + j * ldb]];
+        }
+    };
+
+    // B(:, j) = B(:, j) + s * B(:, k)
+    let update = |b: &mut NdSliceMut<'_, T, 1>, j: usize, s: T, k: usize| {
+        for i in 0..m {
+            b[[i + j * ldb]] = b[[i + j * ldb]] + s * b[[i + k * ldb]];
+        }
+    };
+
+    if lside {
+        if notrans {
+            // B = alpha * A * B, each column from the elements not yet overwritten
+            for j in 0..n {
+                let mut multiply = |k: usize| {
+                    if b[[k + j * ldb]] != zero {
+                        let temp = alpha * b[[k + j * ldb]];
+                        let rows = if upper { 0..k } else { k + 1..m };
+                        for i in rows {
+                            b[[i + j * ldb]] = b[[i + j * ldb]] + temp * a(i, k);
+                        }
+                        b[[k + j * ldb]] = if nounit { temp * a(k, k) } else { temp };
+                    }
+                };
+
+                if upper {
+                    (0..m).for_each(&mut multiply);
+                } else {
+                    (0..m).rev().for_each(&mut multiply);
+                }
+            }
+        } else {
+            // B = alpha * Aᵀ * B, each element a dot product with the elements not yet overwritten
+            for j in 0..n {
+                let mut multiply = |i: usize| {
+                    let mut temp = b[[i + j * ldb]];
+                    if nounit {
+                        temp = temp * a(i, i);
+                    }
+                    let rows = if upper { 0..i } else { i + 1..m };
+                    for k in rows {
+                        temp = temp + a(k, i) * b[[k + j * ldb]];
+                    }
+                    b[[i + j * ldb]] = alpha * temp;
+                };
+
+                if upper {
+                    (0..m).rev().for_each(&mut multiply);
+                } else {
+                    (0..m).for_each(&mut multiply);
+                }
+            }
+        }
+    } else if notrans {
+        // B = alpha * B * A, each column of B from the columns not yet overwritten
+        let mut multiply = |j: usize| {
+            let temp = if nounit { alpha * a(j, j) } else { alpha };
+            if temp != one {
+                scale(b, j, temp);
+            }
+
+            let cols = if upper { 0..j } else { j + 1..n };
+            for k in cols {
+                let akj = a(k, j);
+                if akj != zero {
+                    update(b, j, alpha * akj, k);
+                }
+            }
+        };
+
+        if upper {
+            (0..n).rev().for_each(&mut multiply);
+        } else {
+            (0..n).for_each(&mut multiply);
+        }
+    } else {
+        // B = alpha * B * Aᵀ, each column of B added to the columns not yet overwritten
+        let mut multiply = |k: usize| {
+            let cols = if upper { 0..k } else { k + 1..n };
+            for j in cols {
+                let ajk = a(j, k);
+                if ajk != zero {
+                    update(b, j, alpha * ajk, k);
+                }
+            }
+
+            let temp = if nounit { alpha * a(k, k) } else { alpha };
+            if temp != one {
+                scale(b, k, temp);
+            }
+        };
+
+        if upper {
+            (0..n).for_each(&mut multiply);
+        } else {
+            (0..n).rev().for_each(&mut multiply);
+        }
+    }
+}
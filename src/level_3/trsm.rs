@@ -0,0 +1,254 @@
+use nd_slice::{NdSlice, NdSliceMut};
+use num_complex::Complex;
+use num_traits::{Float, Num};
+
+/// Solves a triangular matrix equation with multiple right-hand sides.
+///
+/// op(A) * X = alpha * B or X * op(A) = alpha * B
+///
+/// No test for singularity or near-singularity is included in this routine.
+///
+/// Input:
+///     side: 'L' to solve op(A) * X = alpha * B, 'R' to solve X * op(A) = alpha * B
+///     uplo: 'U' if A is upper triangular, 'L' if A is lower triangular
+///     transa: 'N' for op(A) = A, 'T' or 'C' for op(A) = Aᵀ
+///     diag: 'U' if A has a unit diagonal, which is then not referenced, 'N' otherwise
+///     m: number of rows of B
+///     n: number of columns of B
+///     alpha: scalar, B need not be set if alpha is zero
+///     a: triangular matrix in column-major order, lda by m if side = 'L' and lda by n otherwise
+///     lda: leading dimension of a
+///     b: matrix in column-major order, ldb by n
+///     ldb: leading dimension of b
+/// Output:
+///     b: the solution X
+#[allow(clippy::too_many_arguments)]
+pub fn trsm_real<T: Float>(
+    side: &char,
+    uplo: &char,
+    transa: &char,
+    diag: &char,
+    m: &isize,
+    n: &isize,
+    alpha: &T,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+) {
+    trsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, |a| a);
+}
+
+/// Solves a triangular matrix equation with multiple right-hand sides.
+///
+/// op(A) * X = alpha * B or X * op(A) = alpha * B
+///
+/// No test for singularity or near-singularity is included in this routine.
+///
+/// Input:
+///     side: 'L' to solve op(A) * X = alpha * B, 'R' to solve X * op(A) = alpha * B
+///     uplo: 'U' if A is upper triangular, 'L' if A is lower triangular
+///     transa: 'N' for op(A) = A, 'T' for op(A) = Aᵀ, 'C' for op(A) = Aᴴ
+///     diag: 'U' if A has a unit diagonal, which is then not referenced, 'N' otherwise
+///     m: number of rows of B
+///     n: number of columns of B
+///     alpha: scalar, B need not be set if alpha is zero
+///     a: triangular matrix in column-major order, lda by m if side = 'L' and lda by n otherwise
+///     lda: leading dimension of a
+///     b: matrix in column-major order, ldb by n
+///     ldb: leading dimension of b
+/// Output:
+///     b: the solution X
+#[allow(clippy::too_many_arguments)]
+pub fn trsm_complex<T: Float>(
+    side: &char,
+    uplo: &char,
+    transa: &char,
+    diag: &char,
+    m: &isize,
+    n: &isize,
+    alpha: &Complex<T>,
+    a: &NdSlice<'_, Complex<T>, 1>,
+    lda: &isize,
+    b: &mut NdSliceMut<'_, Complex<T>, 1>,
+    ldb: &isize,
+) {
+    trsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, |a| {
+        a.conj()
+    });
+}
+
+/// Both versions of trsm, conj being applied to the elements of A if transa = 'C'.
+#[allow(clippy::too_many_arguments)]
+fn trsm<T: Num + Copy>(
+    side: &char,
+    uplo: &char,
+    transa: &char,
+    diag: &char,
+    m: &isize,
+    n: &isize,
+    alpha: &T,
+    a: &NdSlice<'_, T, 1>,
+    lda: &isize,
+    b: &mut NdSliceMut<'_, T, 1>,
+    ldb: &isize,
+    conj: impl Fn(T) -> T,
+) {
+    let (m, n, alpha, lda, ldb) = (*m, *n, *alpha, *lda, *ldb);
+
+    let (zero, one) = (T::zero(), T::one());
+
+    let lside = side.eq_ignore_ascii_case(&'L');
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+    let notrans = transa.eq_ignore_ascii_case(&'N');
+    let conjugate = transa.eq_ignore_ascii_case(&'C');
+    let nounit = diag.eq_ignore_ascii_case(&'N');
+    let nrowa = if lside { m } else { n };
+
+    if !(lside || side.eq_ignore_ascii_case(&'R')) || !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return;
+    }
+    if !(notrans || conjugate || transa.eq_ignore_ascii_case(&'T')) {
+        return;
+    }
+    if !(nounit || diag.eq_ignore_ascii_case(&'U')) {
+        return;
+    }
+    if m < 0 || n < 0 || lda < nrowa.max(1) || ldb < m.max(1) {
+        return;
+    }
+    if m == 0 || n == 0 {
+        return;
+    }
+    let (m, n, lda, ldb) = (m as usize, n as usize, lda as usize, ldb as usize);
+
+    if alpha == zero {
+        for j in 0..n {
+            for i in 0..m {
+                b[[i + j * ldb]] = zero;
+            }
+        }
+
+        return;
+    }
+
+    // element (i, j) of A, conjugated if transa = 'C'
+    let a = |i: usize, j: usize| {
+        if conjugate {
+            conj(a[[i + j * lda]])
+        } else {
+            a[[i + j * lda]]
+        }
+    };
+
+    let scale = |b: &mut NdSliceMut<'_, T, 1>, j: usize, s: T| {
+        for i in 0..m {
+            b[[i + j * ldb]] = s * b[[i + j * ldb]];
+        }
+    };
+
+    if lside {
+        if notrans {
+            // B = alpha * A⁻¹ * B, each column by substitution
+            for j in 0..n {
+                if alpha != one {
+                    scale(b, j, alpha);
+                }
+
+                let mut eliminate = |k: usize| {
+                    if b[[k + j * ldb]] != zero {
+                        if nounit {
+                            b[[k + j * ldb]] = b[[k + j * ldb]] / a(k, k);
+                        }
+                        let temp = b[[k + j * ldb]];
+                        let rows = if upper { 0..k } else { k + 1..m };
+                        for i in rows {
+                            b[[i + j * ldb]] = b[[i + j * ldb]] - temp * a(i, k);
+                        }
+                    }
+                };
+
+                if upper {
+                    (0..m).rev().for_each(&mut eliminate);
+                } else {
+                    (0..m).for_each(&mut eliminate);
+                }
+            }
+        } else {
+            // B = alpha * A⁻ᵀ * B, each element from the ones already solved for
+            for j in 0..n {
+                let mut solve = |i: usize| {
+                    let mut temp = alpha * b[[i + j * ldb]];
+                    let rows = if upper { 0..i } else { i + 1..m };
+                    for k in rows {
+                        temp = temp - a(k, i) * b[[k + j * ldb]];
+                    }
+                    if nounit {
+                        temp = temp / a(i, i);
+                    }
+                    b[[i + j * ldb]] = temp;
+                };
+
+                if upper {
+                    (0..m).for_each(&mut solve);
+                } else {
+                    (0..m).rev().for_each(&mut solve);
+                }
+            }
+        }
+    } else if notrans {
+        // B = alpha * B * A⁻¹, each column of B from the columns already solved for
+        let mut solve = |j: usize| {
+            if alpha != one {
+                scale(b, j, alpha);
+            }
+
+            let cols = if upper { 0..j } else { j + 1..n };
+            for k in cols {
+                let akj = a(k, j);
+                if akj != zero {
+                    for i in 0..m {
+                        b[[i + j * ldb]] = b[[i + j * ldb]] - akj * b[[i + k * ldb]];
+                    }
+                }
+            }
+
+            if nounit {
+                scale(b, j, one / a(j, j));
+            }
+        };
+
+        if upper {
+            (0..n).for_each(&mut solve);
+        } else {
+            (0..n).rev().for_each(&mut solve);
+        }
+    } else {
+        // B = alpha * B * A⁻ᵀ, each solved column of B eliminated from the others
+        let mut eliminate = |k: usize| {
+            if nounit {
+                scale(b, k, one / a(k, k));
+            }
+
+            let cols = if upper { 0..k } else { k + 1..n };
+            for j in cols {
+                let ajk = a(j, k);
+                if ajk != zero {
+                    for i in 0..m {
+                        b[[i + j * ldb]] = b[[i + j * ldb]] - ajk * b[[i + k * ldb]];
+                    }
+                }
+            }
+
+            if alpha != one {
+                scale(b, k, alpha);
+            }
+        };
+
+        if upper {
+            (0..n).rev().for_each(&mut eliminate);
+        } else {
+            (0..n).for_each(&mut eliminate);
+        }
+    }
+}
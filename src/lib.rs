@@ -1 +1,27 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod extensions;
+#[cfg(feature = "alloc")]
+pub mod lapack;
 pub mod level_1;
+pub mod level_2;
+pub mod level_3;
+pub mod mat;
+pub mod sparse;
+pub mod strided;
+
+mod contiguous;
+mod simd;
+#[cfg(feature = "rayon")]
+pub mod threading;
+
+#[cfg(feature = "ndarray")]
+pub mod array;
+#[cfg(feature = "nalgebra")]
+pub mod na;
+
+#[cfg(feature = "half")]
+pub use half::{bf16, f16};
@@ -0,0 +1,859 @@
+//! Matrix views over slices.
+//!
+//! `MatRef` and `MatMut` describe a rows by cols matrix stored anywhere in a slice: element
+//! (i, j) is `data[offset + i * row_stride + j * col_stride]`. Transposing, conjugating and
+//! taking submatrices, rows or columns only change these numbers, never the data. Rows and
+//! columns are returned as `strided` vectors, so they can be handed to the `level_1` routines.
+//!
+//! ```
+//! use naive_blas::mat::MatRef;
+//!
+//! // 2 by 3 matrix in column-major order
+//! let a = [1.0, 4.0, 2.0, 5.0, 3.0, 6.0];
+//! let a = MatRef::col_major(&a, 2, 3, 2).unwrap();
+//!
+//! assert_eq!(a.t().get(2, 1), Some(&6.0));
+//! assert_eq!(a.row(1).dot(&a.row(1)), 16.0 + 25.0 + 36.0);
+//! assert_eq!(a.submatrix(0, 1, 2, 2).col(1).nrm2(), 45f64.sqrt());
+//! ```
+
+use core::fmt;
+use core::ops::{Index, Range};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use nd_slice::{NdSlice, NdSliceMut};
+#[cfg(feature = "alloc")]
+use num_traits::Float;
+
+#[cfg(feature = "alloc")]
+use crate::contiguous::{vector, vector_mut};
+#[cfg(feature = "alloc")]
+use crate::level_3::gemm;
+use crate::strided::{span, StridedVec, StridedVecMut};
+
+/// Error returned when a matrix view would reach outside of its slice, or when the elements of a
+/// mutable view would overlap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutError {
+    /// number of rows
+    pub rows: usize,
+    /// number of columns
+    pub cols: usize,
+    /// distance between consecutive elements of a column
+    pub row_stride: isize,
+    /// distance between consecutive elements of a row
+    pub col_stride: isize,
+    /// position of the element (0, 0) in the slice
+    pub offset: usize,
+    /// length of the slice
+    pub slice_len: usize,
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a {} by {} matrix with strides ({}, {}) starting at {} is not a valid view of a slice of length {}",
+            self.rows, self.cols, self.row_stride, self.col_stride, self.offset, self.slice_len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LayoutError {}
+
+/// Shape and position of a matrix inside its slice.
+#[derive(Debug, Clone, Copy)]
+struct Layout {
+    rows: usize,
+    cols: usize,
+    row_stride: isize,
+    col_stride: isize,
+    offset: usize,
+}
+
+impl Layout {
+    /// Returns the part of the slice between the lowest and highest elements, if it fits.
+    fn bounds(&self, slice_len: usize) -> Result<Range<usize>, LayoutError> {
+        let err = LayoutError {
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            offset: self.offset,
+            slice_len,
+        };
+
+        if self.rows == 0 || self.cols == 0 {
+            return if self.offset <= slice_len {
+                Ok(self.offset..self.offset)
+            } else {
+                Err(err)
+            };
+        }
+
+        // distances from the first element to the lowest and highest ones
+        let span = |len: usize, stride: isize| (len as isize - 1).checked_mul(stride);
+        let (rs, cs) = (
+            span(self.rows, self.row_stride).ok_or(err)?,
+            span(self.cols, self.col_stride).ok_or(err)?,
+        );
+        let lo = (self.offset as isize)
+            .checked_add(rs.min(0))
+            .and_then(|lo| lo.checked_add(cs.min(0)))
+            .ok_or(err)?;
+        let hi = (self.offset as isize)
+            .checked_add(rs.max(0))
+            .and_then(|hi| hi.checked_add(cs.max(0)))
+            .ok_or(err)?;
+
+        if lo < 0 || hi as usize >= slice_len {
+            Err(err)
+        } else {
+            Ok(lo as usize..hi as usize + 1)
+        }
+    }
+
+    /// Returns true if no two elements share a position.
+    fn is_disjoint(&self) -> bool {
+        let (rows, cols) = (self.rows, self.cols);
+        let (rs, cs) = (
+            self.row_stride.unsigned_abs(),
+            self.col_stride.unsigned_abs(),
+        );
+
+        if rows <= 1 || cols <= 1 {
+            (rows <= 1 || rs > 0) && (cols <= 1 || cs > 0)
+        } else if rs <= cs {
+            rs > 0 && rs * (rows - 1) < cs
+        } else {
+            cs > 0 && cs * (cols - 1) < rs
+        }
+    }
+
+    fn index(&self, i: usize, j: usize) -> usize {
+        (self.offset as isize + i as isize * self.row_stride + j as isize * self.col_stride)
+            as usize
+    }
+
+    fn t(self) -> Self {
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            offset: self.offset,
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the submatrix does not lie inside the matrix.
+    fn submatrix(self, r: usize, c: usize, m: usize, n: usize) -> Self {
+        assert!(
+            r + m <= self.rows && c + n <= self.cols,
+            "submatrix out of bounds"
+        );
+
+        Self {
+            rows: m,
+            cols: n,
+            offset: if m == 0 || n == 0 {
+                self.offset
+            } else {
+                self.index(r, c)
+            },
+            ..self
+        }
+    }
+
+    /// Returns the arguments of the j-th column as a strided vector.
+    fn col(&self, j: usize) -> (usize, isize, usize) {
+        assert!(j < self.cols, "column out of bounds");
+        (self.rows, self.row_stride, self.index(0, j))
+    }
+
+    /// Returns the column-major description of the matrix, if it has one.
+    fn blas(&self) -> Option<(bool, isize)> {
+        // a stride is irrelevant along a dimension of length 1
+        let unit = |len: usize, stride: isize| len <= 1 || stride == 1;
+        let ld = |len: usize, stride: isize, other: usize| {
+            if len <= 1 {
+                Some(other.max(1) as isize)
+            } else if stride >= other.max(1) as isize {
+                Some(stride)
+            } else {
+                None
+            }
+        };
+
+        if unit(self.rows, self.row_stride) {
+            ld(self.cols, self.col_stride, self.rows).map(|ld| (false, ld))
+        } else if unit(self.cols, self.col_stride) {
+            ld(self.rows, self.row_stride, self.cols).map(|ld| (true, ld))
+        } else {
+            None
+        }
+    }
+}
+
+/// Finds the layout of a 2-dimensional `NdSlice` or `NdSliceMut` of the given shape.
+///
+/// `NdSlice` does not expose its shape or order, so they are recovered from the addresses of
+/// its elements; `x[[rows - 1, cols - 1]]` is its last element for either order.
+///
+/// # Panics
+///
+/// Panics if x does not hold a non-empty matrix of that shape.
+fn nd_layout<T, S: Index<[usize; 2], Output = T> + ?Sized>(
+    x: &S,
+    shape: [usize; 2],
+) -> (*const T, Layout) {
+    let [rows, cols] = shape;
+    let base = &x[[0, 0]] as *const T;
+    let last = &x[[rows - 1, cols - 1]] as *const T;
+
+    // SAFETY: both elements come from the same slice
+    let addr = |i: usize, j: usize| unsafe { (&x[[i, j]] as *const T).offset_from(base) };
+    let row_stride = if rows > 1 { addr(1, 0) } else { cols as isize };
+    let col_stride = if cols > 1 { addr(0, 1) } else { rows as isize };
+
+    let layout = Layout {
+        rows,
+        cols,
+        row_stride,
+        col_stride,
+        offset: 0,
+    };
+    assert!(
+        // SAFETY: both elements come from the same slice
+        unsafe { last.offset_from(base) } == (rows * cols) as isize - 1 && layout.is_disjoint(),
+        "shape does not match the NdSlice"
+    );
+
+    (base, layout)
+}
+
+/// An immutable matrix view: element (i, j) is `data[offset + i * row_stride + j * col_stride]`,
+/// conjugated if the view is marked as conjugated.
+#[derive(Debug)]
+pub struct MatRef<'a, T> {
+    data: &'a [T],
+    layout: Layout,
+    conj: bool,
+}
+
+impl<T> Clone for MatRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatRef<'_, T> {}
+
+impl<'a, T> MatRef<'a, T> {
+    /// Creates a rows by cols view of data, with element (0, 0) at offset. The strides may be
+    /// negative or zero.
+    pub fn new(
+        data: &'a [T],
+        rows: usize,
+        cols: usize,
+        row_stride: isize,
+        col_stride: isize,
+        offset: usize,
+    ) -> Result<Self, LayoutError> {
+        let layout = Layout {
+            rows,
+            cols,
+            row_stride,
+            col_stride,
+            offset,
+        };
+        let range = layout.bounds(data.len())?;
+
+        Ok(Self {
+            data: &data[range.clone()],
+            layout: Layout {
+                offset: offset - range.start,
+                ..layout
+            },
+            conj: false,
+        })
+    }
+
+    /// Creates a rows by cols view with element (0, 0) at ptr. The strides may be negative or
+    /// zero.
+    ///
+    /// # Safety
+    ///
+    /// The elements must lie inside one allocation, and be valid for reads and not be written to
+    /// for 'a. The view holds a slice over the memory from its lowest to its highest element, but
+    /// only reads its own elements.
+    pub unsafe fn from_raw_parts(
+        ptr: *const T,
+        rows: usize,
+        cols: usize,
+        row_stride: isize,
+        col_stride: isize,
+    ) -> Self {
+        let (first, span) = span(&[(rows, row_stride), (cols, col_stride)]);
+        let data = core::slice::from_raw_parts(ptr.sub(first), span);
+
+        Self::new(data, rows, cols, row_stride, col_stride, first)
+            .unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Creates a rows by cols view of a matrix in column-major order with leading dimension ld.
+    pub fn col_major(
+        data: &'a [T],
+        rows: usize,
+        cols: usize,
+        ld: usize,
+    ) -> Result<Self, LayoutError> {
+        Self::new(data, rows, cols, 1, ld as isize, 0)
+    }
+
+    /// Creates a rows by cols view of a matrix in row-major order with leading dimension ld.
+    pub fn row_major(
+        data: &'a [T],
+        rows: usize,
+        cols: usize,
+        ld: usize,
+    ) -> Result<Self, LayoutError> {
+        Self::new(data, rows, cols, ld as isize, 1, 0)
+    }
+
+    /// Creates a view of a 2-dimensional `NdSlice`, whose shape must be given as `NdSlice` does
+    /// not expose it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if x does not hold a matrix of that shape.
+    pub fn from_nd_slice(x: &'a NdSlice<'_, T, 2>, shape: [usize; 2]) -> Self {
+        if shape[0] == 0 || shape[1] == 0 {
+            return Self::col_major(&[], shape[0], shape[1], shape[0])
+                .unwrap_or_else(|_| unreachable!());
+        }
+        let (base, layout) = nd_layout(x, shape);
+
+        // SAFETY: nd_layout checked that the matrix spans the slice from x[[0, 0]] to its last
+        // element
+        let data = unsafe { core::slice::from_raw_parts(base, shape[0] * shape[1]) };
+
+        Self {
+            data,
+            layout,
+            conj: false,
+        }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.layout.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.layout.cols
+    }
+
+    /// Returns the distance between consecutive elements of a column.
+    pub fn row_stride(&self) -> isize {
+        self.layout.row_stride
+    }
+
+    /// Returns the distance between consecutive elements of a row.
+    pub fn col_stride(&self) -> isize {
+        self.layout.col_stride
+    }
+
+    /// Returns true if the elements are to be conjugated.
+    pub fn is_conj(&self) -> bool {
+        self.conj
+    }
+
+    /// Returns element (i, j) as stored, or None if it is out of bounds. The conjugate flag is
+    /// not applied.
+    pub fn get(&self, i: usize, j: usize) -> Option<&'a T> {
+        if i < self.layout.rows && j < self.layout.cols {
+            Some(&self.data[self.layout.index(i, j)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the transpose.
+    pub fn t(self) -> Self {
+        Self {
+            layout: self.layout.t(),
+            ..self
+        }
+    }
+
+    /// Returns the conjugate.
+    pub fn conj(self) -> Self {
+        Self {
+            conj: !self.conj,
+            ..self
+        }
+    }
+
+    /// Returns the conjugate transpose.
+    pub fn h(self) -> Self {
+        self.t().conj()
+    }
+
+    /// Returns the same view without the conjugate flag.
+    #[cfg(feature = "alloc")]
+    fn unconj(self) -> Self {
+        Self {
+            conj: false,
+            ..self
+        }
+    }
+
+    /// Returns the m by n submatrix whose element (0, 0) is element (r, c).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the submatrix does not lie inside the matrix.
+    pub fn submatrix(self, r: usize, c: usize, m: usize, n: usize) -> Self {
+        Self {
+            layout: self.layout.submatrix(r, c, m, n),
+            ..self
+        }
+    }
+
+    /// Returns the j-th column. The vector holds the elements as stored, see `is_conj`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if j is out of bounds.
+    pub fn col(&self, j: usize) -> StridedVec<'a, T> {
+        let (len, stride, offset) = self.layout.col(j);
+        StridedVec::new(self.data, len, stride, offset).unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Returns the i-th row. The vector holds the elements as stored, see `is_conj`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if i is out of bounds.
+    pub fn row(&self, i: usize) -> StridedVec<'a, T> {
+        self.t().col(i)
+    }
+
+    /// Returns the arguments to pass to the Level 2 and 3 routines: trans, the matrix and its
+    /// leading dimension, or None if the view is not a column-major matrix or its transpose.
+    ///
+    /// trans is 'N', 'T' or 'C', the latter when the view is a conjugated transpose. The matrix
+    /// starts at element (0, 0), so negative strides are not supported, and neither are
+    /// conjugated views that are not transposed.
+    pub fn blas(&self) -> Option<(char, NdSlice<'a, T, 1>, isize)> {
+        let (trans, ld) = self.layout.blas()?;
+        let trans = match (trans, self.conj) {
+            (false, false) => 'N',
+            (true, false) => 'T',
+            (true, true) => 'C',
+            (false, true) => return None,
+        };
+
+        let data = &self.data[self.layout.offset..];
+        Some((
+            trans,
+            NdSlice::new_row_ordered(data, [data.len()]).ok()?,
+            ld,
+        ))
+    }
+
+    /// Returns the view as a 2-dimensional `NdSlice`, or None if its elements are not stored
+    /// contiguously in row- or column-major order or it is conjugated.
+    pub fn to_nd_slice(&self) -> Option<NdSlice<'a, T, 2>> {
+        let Layout {
+            rows,
+            cols,
+            row_stride,
+            col_stride,
+            offset,
+        } = self.layout;
+        let data = self.data.get(offset..offset + rows * cols)?;
+
+        if self.conj {
+            None
+        } else if (rows <= 1 || row_stride == 1) && (cols <= 1 || col_stride == rows as isize) {
+            NdSlice::col_ordered(data, [rows, cols]).ok()
+        } else if (cols <= 1 || col_stride == 1) && (rows <= 1 || row_stride == cols as isize) {
+            NdSlice::new_row_ordered(data, [rows, cols]).ok()
+        } else {
+            None
+        }
+    }
+}
+
+/// A mutable matrix view: element (i, j) is `data[offset + i * row_stride + j * col_stride]`,
+/// conjugated if the view is marked as conjugated.
+#[derive(Debug)]
+pub struct MatMut<'a, T> {
+    data: &'a mut [T],
+    layout: Layout,
+    conj: bool,
+}
+
+impl<'a, T> MatMut<'a, T> {
+    /// Creates a rows by cols view of data, with element (0, 0) at offset. The strides may be
+    /// negative, but no two elements may share a position.
+    pub fn new(
+        data: &'a mut [T],
+        rows: usize,
+        cols: usize,
+        row_stride: isize,
+        col_stride: isize,
+        offset: usize,
+    ) -> Result<Self, LayoutError> {
+        let layout = Layout {
+            rows,
+            cols,
+            row_stride,
+            col_stride,
+            offset,
+        };
+        let range = layout.bounds(data.len())?;
+        if !layout.is_disjoint() {
+            return Err(LayoutError {
+                rows,
+                cols,
+                row_stride,
+                col_stride,
+                offset,
+                slice_len: data.len(),
+            });
+        }
+
+        Ok(Self {
+            layout: Layout {
+                offset: offset - range.start,
+                ..layout
+            },
+            data: &mut data[range],
+            conj: false,
+        })
+    }
+
+    /// Creates a rows by cols view with element (0, 0) at ptr. The strides may be negative, but
+    /// no two elements may share a position.
+    ///
+    /// # Safety
+    ///
+    /// The elements must lie inside one allocation, and be valid for reads and writes and not be
+    /// accessed through any other pointer for 'a. The view holds a slice over the memory from its
+    /// lowest to its highest element, but only accesses its own elements.
+    pub unsafe fn from_raw_parts(
+        ptr: *mut T,
+        rows: usize,
+        cols: usize,
+        row_stride: isize,
+        col_stride: isize,
+    ) -> Result<Self, LayoutError> {
+        let (first, span) = span(&[(rows, row_stride), (cols, col_stride)]);
+        let data = core::slice::from_raw_parts_mut(ptr.sub(first), span);
+
+        Self::new(data, rows, cols, row_stride, col_stride, first)
+    }
+
+    /// Creates a rows by cols view of a matrix in column-major order with leading dimension ld.
+    pub fn col_major(
+        data: &'a mut [T],
+        rows: usize,
+        cols: usize,
+        ld: usize,
+    ) -> Result<Self, LayoutError> {
+        Self::new(data, rows, cols, 1, ld as isize, 0)
+    }
+
+    /// Creates a rows by cols view of a matrix in row-major order with leading dimension ld.
+    pub fn row_major(
+        data: &'a mut [T],
+        rows: usize,
+        cols: usize,
+        ld: usize,
+    ) -> Result<Self, LayoutError> {
+        Self::new(data, rows, cols, ld as isize, 1, 0)
+    }
+
+    /// Creates a view of a 2-dimensional `NdSliceMut`, whose shape must be given as `NdSliceMut`
+    /// does not expose it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if x does not hold a matrix of that shape.
+    pub fn from_nd_slice(x: &'a mut NdSliceMut<'_, T, 2>, shape: [usize; 2]) -> Self {
+        if shape[0] == 0 || shape[1] == 0 {
+            return Self::col_major(&mut [], shape[0], shape[1], shape[0])
+                .unwrap_or_else(|_| unreachable!());
+        }
+        let (_, layout) = nd_layout(x, shape);
+        let base = &mut x[[0, 0]] as *mut T;
+
+        // SAFETY: nd_layout checked that the matrix spans the slice from x[[0, 0]] to its last
+        // element, and x is borrowed mutably for 'a
+        let data = unsafe { core::slice::from_raw_parts_mut(base, shape[0] * shape[1]) };
+
+        Self {
+            data,
+            layout,
+            conj: false,
+        }
+    }
+
+    /// Returns an immutable view of the same elements.
+    pub fn rb(&self) -> MatRef<'_, T> {
+        MatRef {
+            data: self.data,
+            layout: self.layout,
+            conj: self.conj,
+        }
+    }
+
+    /// Returns a mutable view of the same elements with a shorter lifetime.
+    pub fn rb_mut(&mut self) -> MatMut<'_, T> {
+        MatMut {
+            data: self.data,
+            layout: self.layout,
+            conj: self.conj,
+        }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.layout.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.layout.cols
+    }
+
+    /// Returns the distance between consecutive elements of a column.
+    pub fn row_stride(&self) -> isize {
+        self.layout.row_stride
+    }
+
+    /// Returns the distance between consecutive elements of a row.
+    pub fn col_stride(&self) -> isize {
+        self.layout.col_stride
+    }
+
+    /// Returns true if the elements are to be conjugated.
+    pub fn is_conj(&self) -> bool {
+        self.conj
+    }
+
+    /// Returns element (i, j) as stored, or None if it is out of bounds. The conjugate flag is
+    /// not applied.
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.rb().get(i, j)
+    }
+
+    /// Returns element (i, j) as stored mutably, or None if it is out of bounds. The conjugate
+    /// flag is not applied.
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        if i < self.layout.rows && j < self.layout.cols {
+            Some(&mut self.data[self.layout.index(i, j)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the transpose.
+    pub fn t(self) -> Self {
+        Self {
+            layout: self.layout.t(),
+            ..self
+        }
+    }
+
+    /// Returns the conjugate.
+    pub fn conj(self) -> Self {
+        Self {
+            conj: !self.conj,
+            ..self
+        }
+    }
+
+    /// Returns the conjugate transpose.
+    pub fn h(self) -> Self {
+        self.t().conj()
+    }
+
+    /// Returns the same view without the conjugate flag.
+    #[cfg(feature = "alloc")]
+    fn unconj(self) -> Self {
+        Self {
+            conj: false,
+            ..self
+        }
+    }
+
+    /// Returns the m by n submatrix whose element (0, 0) is element (r, c).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the submatrix does not lie inside the matrix.
+    pub fn submatrix(self, r: usize, c: usize, m: usize, n: usize) -> Self {
+        Self {
+            layout: self.layout.submatrix(r, c, m, n),
+            ..self
+        }
+    }
+
+    /// Returns the j-th column. The vector holds the elements as stored, see `is_conj`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if j is out of bounds.
+    pub fn col(&self, j: usize) -> StridedVec<'_, T> {
+        self.rb().col(j)
+    }
+
+    /// Returns the i-th row. The vector holds the elements as stored, see `is_conj`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if i is out of bounds.
+    pub fn row(&self, i: usize) -> StridedVec<'_, T> {
+        self.rb().row(i)
+    }
+
+    /// Returns the j-th column mutably. The vector holds the elements as stored, see `is_conj`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if j is out of bounds.
+    pub fn col_mut(&mut self, j: usize) -> StridedVecMut<'_, T> {
+        let (len, stride, offset) = self.layout.col(j);
+        StridedVecMut::new(self.data, len, stride, offset).unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Returns the i-th row mutably. The vector holds the elements as stored, see `is_conj`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if i is out of bounds.
+    pub fn row_mut(&mut self, i: usize) -> StridedVecMut<'_, T> {
+        let (len, stride, offset) = self.layout.t().col(i);
+        StridedVecMut::new(self.data, len, stride, offset).unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Returns the arguments to pass to the Level 2 and 3 routines, see `MatRef::blas`.
+    pub fn blas(&mut self) -> Option<(char, NdSliceMut<'_, T, 1>, isize)> {
+        let (trans, ld) = self.layout.blas()?;
+        let trans = match (trans, self.conj) {
+            (false, false) => 'N',
+            (true, false) => 'T',
+            (true, true) => 'C',
+            (false, true) => return None,
+        };
+
+        let data = &mut self.data[self.layout.offset..];
+        let n = data.len();
+        Some((trans, NdSliceMut::new_row_ordered(data, [n]).ok()?, ld))
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Copies a matrix into a new one in column-major order.
+fn to_col_major<T: Copy>(a: &MatRef<'_, T>) -> Vec<T> {
+    (0..a.cols())
+        .flat_map(|j| a.col(j).iter().copied().collect::<Vec<_>>())
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+/// Computes C = alpha * A * B + beta * C, copying C if it is not in column-major order.
+fn gemm_col_major<T: Float + 'static>(
+    alpha: T,
+    a: MatRef<'_, T>,
+    b: MatRef<'_, T>,
+    beta: T,
+    mut c: MatMut<'_, T>,
+) {
+    let (m, n, k) = (c.rows(), c.cols(), a.cols());
+
+    let (owned_a, owned_b);
+    let (transa, a, lda) = match a.blas() {
+        Some(a) => a,
+        None => {
+            owned_a = to_col_major(&a);
+            ('N', vector(&owned_a), m.max(1) as isize)
+        }
+    };
+    let (transb, b, ldb) = match b.blas() {
+        Some(b) => b,
+        None => {
+            owned_b = to_col_major(&b);
+            ('N', vector(&owned_b), k.max(1) as isize)
+        }
+    };
+
+    let (m, n, k) = (m as isize, n as isize, k as isize);
+    if let Some(('N', mut c, ldc)) = c.blas() {
+        gemm::gemm(
+            &transa, &transb, &m, &n, &k, &alpha, &a, &lda, &b, &ldb, &beta, &mut c, &ldc,
+        );
+        return;
+    }
+
+    let ldc = m.max(1);
+    let mut temp = to_col_major(&c.rb());
+    gemm::gemm(
+        &transa,
+        &transb,
+        &m,
+        &n,
+        &k,
+        &alpha,
+        &a,
+        &lda,
+        &b,
+        &ldb,
+        &beta,
+        &mut vector_mut(&mut temp),
+        &ldc,
+    );
+
+    for (j, col) in temp.chunks(ldc as usize).enumerate() {
+        c.col_mut(j).copy_from(&StridedVec::from(col));
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Computes C = alpha * A * B + beta * C on matrix views.
+///
+/// Transposed views are passed to `level_3::gemm` as transposed operands. If C is in row-major
+/// order, Cᵀ = Bᵀ * Aᵀ is computed instead. A view is only copied if neither of its strides is 1
+/// or it has negative strides. The elements are real, so the conjugate flags are ignored.
+///
+/// # Panics
+///
+/// Panics if A is not m by k, B is not k by n or C is not m by n.
+pub fn gemm<T: Float + 'static>(
+    alpha: T,
+    a: MatRef<'_, T>,
+    b: MatRef<'_, T>,
+    beta: T,
+    c: MatMut<'_, T>,
+) {
+    assert!(
+        a.cols() == b.rows() && c.rows() == a.rows() && c.cols() == b.cols(),
+        "matrices of incompatible shapes"
+    );
+
+    let (a, b, c) = (a.unconj(), b.unconj(), c.unconj());
+    if c.row_stride() != 1 && c.col_stride() == 1 && c.cols() > 1 {
+        gemm_col_major(alpha, b.t(), a.t(), beta, c.t());
+    } else {
+        gemm_col_major(alpha, a, b, beta, c);
+    }
+}
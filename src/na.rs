@@ -0,0 +1,210 @@
+//! Routines for `nalgebra` matrices, enabled by the `nalgebra` feature.
+//!
+//! Column vectors become strided vectors and matrices become matrix views without copying,
+//! nalgebra's row stride being used as the increment and its column stride as the leading
+//! dimension, so a row or a block of a matrix is used in place too. A view holds a slice over the
+//! memory from its lowest to its highest element, which may cover elements of other views, but
+//! only accesses its own.
+//!
+//! ```
+//! use nalgebra::{dmatrix, dvector, DMatrix};
+//! use naive_blas::na;
+//!
+//! let a = dmatrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+//! let mut y = dvector![1.0, 1.0];
+//!
+//! // y = 2 * a[.., 1] + y
+//! na::axpy(2.0, &a.column(1), &mut y);
+//! assert_eq!(y, dvector![5.0, 11.0]);
+//!
+//! let mut c = DMatrix::zeros(2, 2);
+//! na::gemm(1.0, &a, &a.transpose(), 0.0, &mut c);
+//! assert_eq!(c, &a * a.transpose());
+//! ```
+
+use nalgebra::{Dim, Matrix, RawStorage, RawStorageMut, U1};
+use num_traits::Float;
+
+use crate::mat::{self, MatMut, MatRef};
+use crate::strided::{StridedVec, StridedVecMut};
+
+// SAFETY, for all of the conversions below: the elements of a matrix lie inside one allocation
+// and stay valid for 'a, and accessible only through the reference for a mutable one. The
+// elements of a mutable matrix are distinct, so its strides are accepted.
+
+impl<'a, T, R: Dim, S: RawStorage<T, R>> From<&'a Matrix<T, R, U1, S>> for StridedVec<'a, T> {
+    fn from(x: &'a Matrix<T, R, U1, S>) -> Self {
+        let stride = x.strides().0 as isize;
+        unsafe { StridedVec::from_raw_parts(x.as_ptr(), x.nrows(), stride) }
+    }
+}
+
+impl<'a, T, R: Dim, S: RawStorageMut<T, R>> From<&'a mut Matrix<T, R, U1, S>>
+    for StridedVecMut<'a, T>
+{
+    fn from(x: &'a mut Matrix<T, R, U1, S>) -> Self {
+        let (len, stride) = (x.nrows(), x.strides().0 as isize);
+        unsafe { StridedVecMut::from_raw_parts(x.as_mut_ptr(), len, stride) }
+            .unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>> From<&'a Matrix<T, R, C, S>> for MatRef<'a, T> {
+    fn from(a: &'a Matrix<T, R, C, S>) -> Self {
+        let ((rows, cols), (rs, cs)) = (a.shape(), a.strides());
+        unsafe { MatRef::from_raw_parts(a.as_ptr(), rows, cols, rs as isize, cs as isize) }
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> From<&'a mut Matrix<T, R, C, S>>
+    for MatMut<'a, T>
+{
+    fn from(a: &'a mut Matrix<T, R, C, S>) -> Self {
+        let ((rows, cols), (rs, cs)) = (a.shape(), a.strides());
+        unsafe { MatMut::from_raw_parts(a.as_mut_ptr(), rows, cols, rs as isize, cs as isize) }
+            .unwrap_or_else(|_| unreachable!())
+    }
+}
+
+/// Computes the dot product of x and y.
+///
+/// # Panics
+///
+/// Panics if x and y have different lengths.
+pub fn dot<T, R1, R2, S1, S2>(x: &Matrix<T, R1, U1, S1>, y: &Matrix<T, R2, U1, S2>) -> T
+where
+    T: Float + 'static,
+    R1: Dim,
+    R2: Dim,
+    S1: RawStorage<T, R1>,
+    S2: RawStorage<T, R2>,
+{
+    StridedVec::from(x).dot(&y.into())
+}
+
+/// Computes the Euclidean norm of x.
+pub fn nrm2<T: Float + 'static, R: Dim, S: RawStorage<T, R>>(x: &Matrix<T, R, U1, S>) -> T {
+    StridedVec::from(x).nrm2()
+}
+
+/// Computes y = a * x + y.
+///
+/// # Panics
+///
+/// Panics if x and y have different lengths.
+pub fn axpy<T, R1, R2, S1, S2>(a: T, x: &Matrix<T, R1, U1, S1>, y: &mut Matrix<T, R2, U1, S2>)
+where
+    T: Float + 'static,
+    R1: Dim,
+    R2: Dim,
+    S1: RawStorage<T, R1>,
+    S2: RawStorageMut<T, R2>,
+{
+    StridedVecMut::from(y).axpy(a, &x.into());
+}
+
+/// Computes y = a * x + b * y.
+///
+/// # Panics
+///
+/// Panics if x and y have different lengths.
+pub fn axpby<T, R1, R2, S1, S2>(
+    a: T,
+    x: &Matrix<T, R1, U1, S1>,
+    b: T,
+    y: &mut Matrix<T, R2, U1, S2>,
+) where
+    T: Float + 'static,
+    R1: Dim,
+    R2: Dim,
+    S1: RawStorage<T, R1>,
+    S2: RawStorageMut<T, R2>,
+{
+    StridedVecMut::from(y).axpby(a, &x.into(), b);
+}
+
+/// Computes x = a * x.
+pub fn scal<T: Float + 'static, R: Dim, S: RawStorageMut<T, R>>(a: T, x: &mut Matrix<T, R, U1, S>) {
+    StridedVecMut::from(x).scal(a);
+}
+
+/// Copies x into y.
+///
+/// # Panics
+///
+/// Panics if x and y have different lengths.
+pub fn copy<T, R1, R2, S1, S2>(x: &Matrix<T, R1, U1, S1>, y: &mut Matrix<T, R2, U1, S2>)
+where
+    T: Float + 'static,
+    R1: Dim,
+    R2: Dim,
+    S1: RawStorage<T, R1>,
+    S2: RawStorageMut<T, R2>,
+{
+    StridedVecMut::from(y).copy_from(&x.into());
+}
+
+/// Swaps the elements of x and y.
+///
+/// # Panics
+///
+/// Panics if x and y have different lengths.
+pub fn swap<T, R1, R2, S1, S2>(x: &mut Matrix<T, R1, U1, S1>, y: &mut Matrix<T, R2, U1, S2>)
+where
+    T: Float + 'static,
+    R1: Dim,
+    R2: Dim,
+    S1: RawStorageMut<T, R1>,
+    S2: RawStorageMut<T, R2>,
+{
+    StridedVecMut::from(x).swap(&mut y.into());
+}
+
+/// Applies the plane rotation with cosine c and sine s to the points (x[i], y[i]).
+///
+/// # Panics
+///
+/// Panics if x and y have different lengths.
+pub fn rot<T, R1, R2, S1, S2>(
+    x: &mut Matrix<T, R1, U1, S1>,
+    y: &mut Matrix<T, R2, U1, S2>,
+    c: T,
+    s: T,
+) where
+    T: Float + 'static,
+    R1: Dim,
+    R2: Dim,
+    S1: RawStorageMut<T, R1>,
+    S2: RawStorageMut<T, R2>,
+{
+    StridedVecMut::from(x).rot(&mut y.into(), c, s);
+}
+
+/// Computes C = alpha * A * B + beta * C.
+///
+/// To multiply by a transpose without copying it, pass `MatRef::from(&a).t()` to
+/// `mat::gemm`.
+///
+/// # Panics
+///
+/// Panics if A is not m by k, B is not k by n or C is not m by n.
+pub fn gemm<T, R1, C1, R2, C2, R3, C3, S1, S2, S3>(
+    alpha: T,
+    a: &Matrix<T, R1, C1, S1>,
+    b: &Matrix<T, R2, C2, S2>,
+    beta: T,
+    c: &mut Matrix<T, R3, C3, S3>,
+) where
+    T: Float + 'static,
+    R1: Dim,
+    C1: Dim,
+    R2: Dim,
+    C2: Dim,
+    R3: Dim,
+    C3: Dim,
+    S1: RawStorage<T, R1, C1>,
+    S2: RawStorage<T, R2, C2>,
+    S3: RawStorageMut<T, R3, C3>,
+{
+    mat::gemm(alpha, a.into(), b.into(), beta, c.into());
+}
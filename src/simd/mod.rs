@@ -0,0 +1,179 @@
+//! Explicit SIMD kernels for the unit-stride paths of the level 1 routines.
+//!
+//! Each function here returns `None`/`false` when it cannot handle the element type or the
+//! target, in which case the caller falls through to its scalar loop. The scalar loops are the
+//! reference implementation; the kernels only change the order of the floating point operations.
+
+use core::ops::{Index, IndexMut};
+
+#[cfg(target_arch = "x86_64")]
+use crate::contiguous::{as_mut_slice, as_slice, cast, cast_mut, cast_scalar};
+
+#[cfg(target_arch = "x86_64")]
+mod x86_64;
+
+/// y = ax + y for unit-stride x and y
+pub(crate) fn axpy<T, X, Y>(n: usize, a: T, x: &X, y: &mut Y) -> bool
+where
+    T: Copy + 'static,
+    X: Index<[usize; 1], Output = T> + ?Sized,
+    Y: IndexMut<[usize; 1], Output = T> + ?Sized,
+{
+    #[cfg(target_arch = "x86_64")]
+    {
+        let (x, y) = (as_slice(x, n), as_mut_slice(y, n));
+
+        if let (Some(a), Some(x), Some(y)) = (cast_scalar(a), cast(x), cast_mut(y)) {
+            x86_64::single::axpy(a, x, y);
+            return true;
+        }
+        if let (Some(a), Some(x), Some(y)) = (cast_scalar(a), cast(x), cast_mut(y)) {
+            x86_64::double::axpy(a, x, y);
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Σ x[i] * y[i] for unit-stride x and y
+pub(crate) fn dot<T, X, Y>(n: usize, x: &X, y: &Y) -> Option<T>
+where
+    T: Copy + 'static,
+    X: Index<[usize; 1], Output = T> + ?Sized,
+    Y: Index<[usize; 1], Output = T> + ?Sized,
+{
+    #[cfg(target_arch = "x86_64")]
+    {
+        let (x, y) = (as_slice(x, n), as_slice(y, n));
+
+        if let (Some(x), Some(y)) = (cast(x), cast(y)) {
+            return cast_scalar(x86_64::single::dot(x, y));
+        }
+        if let (Some(x), Some(y)) = (cast(x), cast(y)) {
+            return cast_scalar(x86_64::double::dot(x, y));
+        }
+    }
+
+    None
+}
+
+/// x = a * x for unit-stride x
+pub(crate) fn scal<T, X>(n: usize, a: T, x: &mut X) -> bool
+where
+    T: Copy + 'static,
+    X: IndexMut<[usize; 1], Output = T> + ?Sized,
+{
+    #[cfg(target_arch = "x86_64")]
+    {
+        let x = as_mut_slice(x, n);
+
+        if let (Some(a), Some(x)) = (cast_scalar(a), cast_mut(x)) {
+            x86_64::single::scal(a, x);
+            return true;
+        }
+        if let (Some(a), Some(x)) = (cast_scalar(a), cast_mut(x)) {
+            x86_64::double::scal(a, x);
+            return true;
+        }
+    }
+
+    false
+}
+
+/// y = x for unit-stride x and y
+pub(crate) fn copy<T, X, Y>(n: usize, x: &X, y: &mut Y) -> bool
+where
+    T: Copy + 'static,
+    X: Index<[usize; 1], Output = T> + ?Sized,
+    Y: IndexMut<[usize; 1], Output = T> + ?Sized,
+{
+    #[cfg(target_arch = "x86_64")]
+    {
+        let (x, y) = (as_slice(x, n), as_mut_slice(y, n));
+
+        if let (Some(x), Some(y)) = (cast(x), cast_mut(y)) {
+            x86_64::single::copy(x, y);
+            return true;
+        }
+        if let (Some(x), Some(y)) = (cast(x), cast_mut(y)) {
+            x86_64::double::copy(x, y);
+            return true;
+        }
+    }
+
+    false
+}
+
+/// x <-> y for unit-stride x and y
+pub(crate) fn swap<T, X, Y>(n: usize, x: &mut X, y: &mut Y) -> bool
+where
+    T: 'static,
+    X: IndexMut<[usize; 1], Output = T> + ?Sized,
+    Y: IndexMut<[usize; 1], Output = T> + ?Sized,
+{
+    #[cfg(target_arch = "x86_64")]
+    {
+        let (x, y) = (as_mut_slice(x, n), as_mut_slice(y, n));
+
+        if let (Some(x), Some(y)) = (cast_mut(x), cast_mut(y)) {
+            x86_64::single::swap(x, y);
+            return true;
+        }
+        if let (Some(x), Some(y)) = (cast_mut(x), cast_mut(y)) {
+            x86_64::double::swap(x, y);
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Plane rotation of unit-stride x and y
+pub(crate) fn rot<T, X, Y>(n: usize, c: T, s: T, x: &mut X, y: &mut Y) -> bool
+where
+    T: Copy + 'static,
+    X: IndexMut<[usize; 1], Output = T> + ?Sized,
+    Y: IndexMut<[usize; 1], Output = T> + ?Sized,
+{
+    #[cfg(target_arch = "x86_64")]
+    {
+        let (x, y) = (as_mut_slice(x, n), as_mut_slice(y, n));
+
+        if let (Some(c), Some(s), Some(x), Some(y)) =
+            (cast_scalar(c), cast_scalar(s), cast_mut(x), cast_mut(y))
+        {
+            x86_64::single::rot(c, s, x, y);
+            return true;
+        }
+        if let (Some(c), Some(s), Some(x), Some(y)) =
+            (cast_scalar(c), cast_scalar(s), cast_mut(x), cast_mut(y))
+        {
+            x86_64::double::rot(c, s, x, y);
+            return true;
+        }
+    }
+
+    false
+}
+
+/// ‖ x ‖ for unit-stride x
+pub(crate) fn nrm2<T, X>(n: usize, x: &X) -> Option<T>
+where
+    T: Copy + 'static,
+    X: Index<[usize; 1], Output = T> + ?Sized,
+{
+    #[cfg(target_arch = "x86_64")]
+    {
+        let x = as_slice(x, n);
+
+        if let Some(x) = cast(x) {
+            return cast_scalar(x86_64::single::nrm2(x));
+        }
+        if let Some(x) = cast(x) {
+            return cast_scalar(x86_64::double::nrm2(x));
+        }
+    }
+
+    None
+}
@@ -0,0 +1,308 @@
+//! SSE2, AVX2 and AVX-512 kernels, selected at runtime.
+//!
+//! SSE2 is part of the x86_64 baseline so there is always a kernel to fall back to.
+
+/// Generates the kernels for one element type and instruction set.
+///
+/// All kernels expect x and y to have the same length.
+macro_rules! kernels {
+    (
+        $isa:ident, $feature:literal, $t:ident, $lanes:literal,
+        $loadu:ident, $storeu:ident, $set1:ident, $setzero:ident,
+        $add:ident, $sub:ident, $mul:ident, $div:ident, $max:ident
+    ) => {
+        pub(super) mod $isa {
+            use core::arch::x86_64::*;
+
+            const LANES: usize = $lanes;
+
+            #[target_feature(enable = $feature)]
+            pub(in super::super) unsafe fn axpy(a: $t, x: &[$t], y: &mut [$t]) {
+                let n = y.len();
+                let body = n - n % LANES;
+
+                let av = $set1(a);
+                for i in (0..body).step_by(LANES) {
+                    let xv = $loadu(x.as_ptr().add(i));
+                    let yv = $loadu(y.as_ptr().add(i));
+                    $storeu(y.as_mut_ptr().add(i), $add(yv, $mul(av, xv)));
+                }
+
+                for (yi, xi) in y[body..].iter_mut().zip(&x[body..]) {
+                    *yi += a * *xi;
+                }
+            }
+
+            #[target_feature(enable = $feature)]
+            pub(in super::super) unsafe fn dot(x: &[$t], y: &[$t]) -> $t {
+                let n = x.len();
+                let body = n - n % LANES;
+
+                let mut acc = $setzero();
+                for i in (0..body).step_by(LANES) {
+                    let xv = $loadu(x.as_ptr().add(i));
+                    let yv = $loadu(y.as_ptr().add(i));
+                    acc = $add(acc, $mul(xv, yv));
+                }
+
+                let mut lanes = [0.0; LANES];
+                $storeu(lanes.as_mut_ptr(), acc);
+
+                let mut temp: $t = lanes.iter().sum();
+                for (xi, yi) in x[body..].iter().zip(&y[body..]) {
+                    temp += *xi * *yi;
+                }
+
+                temp
+            }
+
+            #[target_feature(enable = $feature)]
+            pub(in super::super) unsafe fn scal(a: $t, x: &mut [$t]) {
+                let n = x.len();
+                let body = n - n % LANES;
+
+                let av = $set1(a);
+                for i in (0..body).step_by(LANES) {
+                    let xv = $loadu(x.as_ptr().add(i));
+                    $storeu(x.as_mut_ptr().add(i), $mul(xv, av));
+                }
+
+                for xi in x[body..].iter_mut() {
+                    *xi *= a;
+                }
+            }
+
+            #[target_feature(enable = $feature)]
+            pub(in super::super) unsafe fn copy(x: &[$t], y: &mut [$t]) {
+                let n = y.len();
+                let body = n - n % LANES;
+
+                for i in (0..body).step_by(LANES) {
+                    $storeu(y.as_mut_ptr().add(i), $loadu(x.as_ptr().add(i)));
+                }
+
+                y[body..].copy_from_slice(&x[body..]);
+            }
+
+            #[target_feature(enable = $feature)]
+            pub(in super::super) unsafe fn swap(x: &mut [$t], y: &mut [$t]) {
+                let n = x.len();
+                let body = n - n % LANES;
+
+                for i in (0..body).step_by(LANES) {
+                    let xv = $loadu(x.as_ptr().add(i));
+                    let yv = $loadu(y.as_ptr().add(i));
+                    $storeu(x.as_mut_ptr().add(i), yv);
+                    $storeu(y.as_mut_ptr().add(i), xv);
+                }
+
+                x[body..].swap_with_slice(&mut y[body..]);
+            }
+
+            #[target_feature(enable = $feature)]
+            pub(in super::super) unsafe fn rot(c: $t, s: $t, x: &mut [$t], y: &mut [$t]) {
+                let n = x.len();
+                let body = n - n % LANES;
+
+                let (cv, sv) = ($set1(c), $set1(s));
+                for i in (0..body).step_by(LANES) {
+                    let xv = $loadu(x.as_ptr().add(i));
+                    let yv = $loadu(y.as_ptr().add(i));
+                    $storeu(x.as_mut_ptr().add(i), $add($mul(cv, xv), $mul(sv, yv)));
+                    $storeu(y.as_mut_ptr().add(i), $sub($mul(cv, yv), $mul(sv, xv)));
+                }
+
+                for (xi, yi) in x[body..].iter_mut().zip(y[body..].iter_mut()) {
+                    let temp = c * *xi + s * *yi;
+                    *yi = c * *yi - s * *xi;
+                    *xi = temp;
+                }
+            }
+
+            /// Two passes instead of the scalar single pass with rescaling: the first finds the
+            /// largest magnitude, the second sums squares scaled by it.
+            #[target_feature(enable = $feature)]
+            pub(in super::super) unsafe fn nrm2(x: &[$t]) -> $t {
+                let n = x.len();
+                let body = n - n % LANES;
+                let zero = $setzero();
+
+                let mut maxv = zero;
+                for i in (0..body).step_by(LANES) {
+                    let xv = $loadu(x.as_ptr().add(i));
+                    maxv = $max(maxv, $max(xv, $sub(zero, xv)));
+                }
+
+                let mut lanes = [0.0; LANES];
+                $storeu(lanes.as_mut_ptr(), maxv);
+
+                let scale = lanes
+                    .iter()
+                    .chain(x[body..].iter())
+                    .fold(0.0, |acc: $t, xi| acc.max(xi.abs()));
+                if scale == 0.0 || !scale.is_finite() {
+                    return scale;
+                }
+
+                let scalev = $set1(scale);
+                let mut ssqv = zero;
+                for i in (0..body).step_by(LANES) {
+                    let xv = $div($loadu(x.as_ptr().add(i)), scalev);
+                    ssqv = $add(ssqv, $mul(xv, xv));
+                }
+
+                $storeu(lanes.as_mut_ptr(), ssqv);
+
+                let mut ssq: $t = lanes.iter().sum();
+                for xi in x[body..].iter() {
+                    let xi = *xi / scale;
+                    ssq += xi * xi;
+                }
+
+                scale * num_traits::Float::sqrt(ssq)
+            }
+        }
+    };
+}
+
+/// Returns true if the CPU supports the target feature. Without std the features can only be
+/// known at compile time.
+macro_rules! detected {
+    ($feature:tt) => {{
+        #[cfg(feature = "std")]
+        let detected = std::is_x86_feature_detected!($feature);
+        #[cfg(not(feature = "std"))]
+        let detected = cfg!(target_feature = $feature);
+        detected
+    }};
+}
+
+/// Generates the runtime dispatch for one kernel, preferring the widest available instructions.
+macro_rules! dispatch {
+    ($name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)?) => {
+        pub(in super::super) fn $name($($arg: $ty),*) $(-> $ret)? {
+            // SAFETY: each kernel is only called after its target feature has been detected
+            unsafe {
+                if detected!("avx512f") {
+                    avx512f::$name($($arg),*)
+                } else if detected!("avx2") {
+                    avx2::$name($($arg),*)
+                } else {
+                    sse2::$name($($arg),*)
+                }
+            }
+        }
+    };
+}
+
+pub(super) mod single {
+    kernels!(
+        sse2,
+        "sse2",
+        f32,
+        4,
+        _mm_loadu_ps,
+        _mm_storeu_ps,
+        _mm_set1_ps,
+        _mm_setzero_ps,
+        _mm_add_ps,
+        _mm_sub_ps,
+        _mm_mul_ps,
+        _mm_div_ps,
+        _mm_max_ps
+    );
+    kernels!(
+        avx2,
+        "avx2",
+        f32,
+        8,
+        _mm256_loadu_ps,
+        _mm256_storeu_ps,
+        _mm256_set1_ps,
+        _mm256_setzero_ps,
+        _mm256_add_ps,
+        _mm256_sub_ps,
+        _mm256_mul_ps,
+        _mm256_div_ps,
+        _mm256_max_ps
+    );
+    kernels!(
+        avx512f,
+        "avx512f",
+        f32,
+        16,
+        _mm512_loadu_ps,
+        _mm512_storeu_ps,
+        _mm512_set1_ps,
+        _mm512_setzero_ps,
+        _mm512_add_ps,
+        _mm512_sub_ps,
+        _mm512_mul_ps,
+        _mm512_div_ps,
+        _mm512_max_ps
+    );
+
+    dispatch!(axpy(a: f32, x: &[f32], y: &mut [f32]));
+    dispatch!(dot(x: &[f32], y: &[f32]) -> f32);
+    dispatch!(scal(a: f32, x: &mut [f32]));
+    dispatch!(copy(x: &[f32], y: &mut [f32]));
+    dispatch!(swap(x: &mut [f32], y: &mut [f32]));
+    dispatch!(rot(c: f32, s: f32, x: &mut [f32], y: &mut [f32]));
+    dispatch!(nrm2(x: &[f32]) -> f32);
+}
+
+pub(super) mod double {
+    kernels!(
+        sse2,
+        "sse2",
+        f64,
+        2,
+        _mm_loadu_pd,
+        _mm_storeu_pd,
+        _mm_set1_pd,
+        _mm_setzero_pd,
+        _mm_add_pd,
+        _mm_sub_pd,
+        _mm_mul_pd,
+        _mm_div_pd,
+        _mm_max_pd
+    );
+    kernels!(
+        avx2,
+        "avx2",
+        f64,
+        4,
+        _mm256_loadu_pd,
+        _mm256_storeu_pd,
+        _mm256_set1_pd,
+        _mm256_setzero_pd,
+        _mm256_add_pd,
+        _mm256_sub_pd,
+        _mm256_mul_pd,
+        _mm256_div_pd,
+        _mm256_max_pd
+    );
+    kernels!(
+        avx512f,
+        "avx512f",
+        f64,
+        8,
+        _mm512_loadu_pd,
+        _mm512_storeu_pd,
+        _mm512_set1_pd,
+        _mm512_setzero_pd,
+        _mm512_add_pd,
+        _mm512_sub_pd,
+        _mm512_mul_pd,
+        _mm512_div_pd,
+        _mm512_max_pd
+    );
+
+    dispatch!(axpy(a: f64, x: &[f64], y: &mut [f64]));
+    dispatch!(dot(x: &[f64], y: &[f64]) -> f64);
+    dispatch!(scal(a: f64, x: &mut [f64]));
+    dispatch!(copy(x: &[f64], y: &mut [f64]));
+    dispatch!(swap(x: &mut [f64], y: &mut [f64]));
+    dispatch!(rot(c: f64, s: f64, x: &mut [f64], y: &mut [f64]));
+    dispatch!(nrm2(x: &[f64]) -> f64);
+}
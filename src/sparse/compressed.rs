@@ -0,0 +1,143 @@
+use crate::sparse::{Op, Scalar, SparseError};
+
+/// Compressed storage of a matrix, by rows: the elements of row i are `values[k]` in column
+/// `indices[k] - base`, for k from `pointers[i] - base` to `pointers[i + 1] - base`.
+///
+/// `Csr` stores its matrix this way and `Csc` stores the transpose of its matrix.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Compressed<'a, T> {
+    pub(crate) rows: usize,
+    pub(crate) cols: usize,
+    pub(crate) pointers: &'a [isize],
+    pub(crate) indices: &'a [isize],
+    pub(crate) values: &'a [T],
+    pub(crate) base: isize,
+}
+
+impl<'a, T: Copy> Compressed<'a, T> {
+    pub(crate) fn new(
+        rows: usize,
+        cols: usize,
+        pointers: &'a [isize],
+        indices: &'a [isize],
+        values: &'a [T],
+        base: isize,
+    ) -> Result<Self, SparseError> {
+        if base != 0 && base != 1 {
+            return Err(SparseError::Base);
+        }
+        if pointers.len() != rows + 1
+            || pointers[0] != base
+            || pointers.windows(2).any(|p| p[0] > p[1])
+        {
+            return Err(SparseError::Pointers);
+        }
+
+        let nnz = (pointers[rows] - base) as usize;
+        if indices.len() != nnz || values.len() != nnz {
+            return Err(SparseError::Lengths);
+        }
+        if let Some(k) = indices
+            .iter()
+            .position(|&j| j < base || j - base >= cols as isize)
+        {
+            return Err(SparseError::Index(k));
+        }
+
+        Ok(Self {
+            rows,
+            cols,
+            pointers,
+            indices,
+            values,
+            base,
+        })
+    }
+
+    /// Returns the positions of the elements of row i in indices and values.
+    pub(crate) fn row(&self, i: usize) -> core::ops::Range<usize> {
+        (self.pointers[i] - self.base) as usize..(self.pointers[i + 1] - self.base) as usize
+    }
+
+    /// Returns the columns and values of the elements of row i.
+    fn entries(&self, i: usize) -> impl Iterator<Item = (usize, T)> + '_ {
+        let row = self.row(i);
+        let base = self.base;
+        self.indices[row.clone()]
+            .iter()
+            .zip(&self.values[row])
+            .map(move |(&j, &v)| ((j - base) as usize, v))
+    }
+}
+
+impl<T: Scalar> Compressed<'_, T> {
+    /// Computes y = alpha * op(A) * x + y.
+    pub(crate) fn update(&self, op: Op, alpha: T, x: &[T], y: &mut [T]) {
+        for i in 0..self.rows {
+            let row = self.row(i);
+            let (indices, values) = (&self.indices[row.clone()], &self.values[row]);
+
+            match op {
+                Op::None => y[i] = y[i] + alpha * T::doti(values, indices, self.base, x),
+                Op::Conj => y[i] = y[i] + alpha * T::dotci(values, indices, self.base, x),
+                Op::Trans => T::axpyi(alpha * x[i], values, indices, self.base, y),
+                Op::ConjTrans => {
+                    let temp = alpha * x[i];
+                    for (j, v) in self.entries(i) {
+                        y[j] = y[j] + temp * v.conj();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Solves op(A) * x = b in place, using only the upper or lower triangle of A.
+    ///
+    /// A must be square. The diagonal is taken as 1 if unit is true, and is otherwise divided by
+    /// without checking it is stored or nonzero.
+    pub(crate) fn solve(&self, upper: bool, op: Op, unit: bool, x: &mut [T]) {
+        let n = self.rows;
+        let f = |v: T| if op.conjugates() { v.conj() } else { v };
+
+        // the diagonal of row i
+        let diagonal = |i: usize| {
+            self.entries(i)
+                .filter(|&(j, _)| j == i)
+                .fold(T::zero(), |d, (_, v)| d + f(v))
+        };
+        // rows in the order of forward or backward substitution
+        let at = |forward: bool, step: usize| if forward { step } else { n - 1 - step };
+
+        if !op.transposes() {
+            // row i holds the coefficients of equation i, so solve for x[i] once the other
+            // unknowns of the row are known
+            for step in 0..n {
+                let i = at(!upper, step);
+                let mut temp = x[i];
+                for (j, v) in self.entries(i) {
+                    if (upper && j > i) || (!upper && j < i) {
+                        temp = temp - f(v) * x[j];
+                    }
+                }
+
+                x[i] = if unit { temp } else { temp / diagonal(i) };
+            }
+        } else {
+            // row i holds the coefficients of x[i] in the other equations, so remove x[i] from
+            // them once it is known
+            for step in 0..n {
+                let i = at(upper, step);
+                if !unit {
+                    x[i] = x[i] / diagonal(i);
+                }
+
+                let temp = x[i];
+                for (j, v) in self.entries(i) {
+                    if (upper && j > i) || (!upper && j < i) {
+                        x[j] = x[j] - f(v) * temp;
+                    }
+                }
+            }
+        }
+    }
+}
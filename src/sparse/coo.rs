@@ -0,0 +1,105 @@
+use crate::sparse::{private, Op, Scalar, SparseError, SparseMatrix};
+
+/// A sparse matrix in coordinate format.
+///
+/// The k-th element is `values[k]` in row `rows[k] - base` and column `cols[k] - base`.
+/// Elements with the same row and column are added together.
+#[derive(Debug, Clone, Copy)]
+pub struct Coo<'a, T> {
+    nrows: usize,
+    ncols: usize,
+    rows: &'a [isize],
+    cols: &'a [isize],
+    values: &'a [T],
+    base: isize,
+}
+
+impl<'a, T: Copy> Coo<'a, T> {
+    /// Creates an nrows by ncols matrix from its row index, column index and value arrays.
+    ///
+    /// The three arrays must have the same length and every index must be valid.
+    pub fn new(
+        nrows: usize,
+        ncols: usize,
+        rows: &'a [isize],
+        cols: &'a [isize],
+        values: &'a [T],
+        base: isize,
+    ) -> Result<Self, SparseError> {
+        if base != 0 && base != 1 {
+            return Err(SparseError::Base);
+        }
+        if rows.len() != values.len() || cols.len() != values.len() {
+            return Err(SparseError::Lengths);
+        }
+
+        let valid = |i: isize, n: usize| i >= base && i - base < n as isize;
+        if let Some(k) =
+            (0..values.len()).find(|&k| !valid(rows[k], nrows) || !valid(cols[k], ncols))
+        {
+            return Err(SparseError::Index(k));
+        }
+
+        Ok(Self {
+            nrows,
+            ncols,
+            rows,
+            cols,
+            values,
+            base,
+        })
+    }
+
+    /// Returns the row indices.
+    pub fn row_indices(&self) -> &'a [isize] {
+        self.rows
+    }
+
+    /// Returns the column indices.
+    pub fn col_indices(&self) -> &'a [isize] {
+        self.cols
+    }
+
+    /// Returns the values.
+    pub fn values(&self) -> &'a [T] {
+        self.values
+    }
+
+    /// Returns the index base.
+    pub fn base(&self) -> isize {
+        self.base
+    }
+}
+
+impl<T> private::Sealed for Coo<'_, T> {}
+
+impl<T: Scalar> SparseMatrix<T> for Coo<'_, T> {
+    fn rows(&self) -> usize {
+        self.nrows
+    }
+
+    fn cols(&self) -> usize {
+        self.ncols
+    }
+
+    fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    fn update(&self, op: Op, alpha: T, x: &[T], y: &mut [T]) {
+        for k in 0..self.values.len() {
+            let (i, j) = (
+                (self.rows[k] - self.base) as usize,
+                (self.cols[k] - self.base) as usize,
+            );
+            let (i, j) = if op.transposes() { (j, i) } else { (i, j) };
+            let v = if op.conjugates() {
+                self.values[k].conj()
+            } else {
+                self.values[k]
+            };
+
+            y[i] = y[i] + alpha * v * x[j];
+        }
+    }
+}
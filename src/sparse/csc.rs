@@ -0,0 +1,89 @@
+use crate::sparse::compressed::Compressed;
+use crate::sparse::{private, Op, Scalar, SparseError, SparseMatrix, Triangular};
+
+/// A sparse matrix in compressed sparse column format.
+///
+/// The elements of column j are `values[k]` in row `indices[k] - base`, for k from
+/// `pointers[j] - base` to `pointers[j + 1] - base`.
+#[derive(Debug, Clone, Copy)]
+pub struct Csc<'a, T> {
+    /// the transpose of the matrix, stored by rows
+    transpose: Compressed<'a, T>,
+}
+
+impl<'a, T: Copy> Csc<'a, T> {
+    /// Creates a rows by cols matrix from its pointer, index and value arrays.
+    ///
+    /// pointers must have cols + 1 elements, start at base and not decrease, indices and values
+    /// must have `pointers[cols] - base` elements, and every index must be a valid row.
+    pub fn new(
+        rows: usize,
+        cols: usize,
+        pointers: &'a [isize],
+        indices: &'a [isize],
+        values: &'a [T],
+        base: isize,
+    ) -> Result<Self, SparseError> {
+        Compressed::new(cols, rows, pointers, indices, values, base)
+            .map(|transpose| Self { transpose })
+    }
+
+    /// Returns the column pointers.
+    pub fn pointers(&self) -> &'a [isize] {
+        self.transpose.pointers
+    }
+
+    /// Returns the row indices.
+    pub fn indices(&self) -> &'a [isize] {
+        self.transpose.indices
+    }
+
+    /// Returns the values.
+    pub fn values(&self) -> &'a [T] {
+        self.transpose.values
+    }
+
+    /// Returns the index base.
+    pub fn base(&self) -> isize {
+        self.transpose.base
+    }
+}
+
+/// Returns the operation to apply to the stored transpose to get op applied to the matrix.
+fn transposed(op: Op) -> Op {
+    match op {
+        Op::None => Op::Trans,
+        Op::Trans => Op::None,
+        Op::ConjTrans => Op::Conj,
+        Op::Conj => Op::ConjTrans,
+    }
+}
+
+impl<T> private::Sealed for Csc<'_, T> {}
+
+impl<T: Scalar> SparseMatrix<T> for Csc<'_, T> {
+    fn rows(&self) -> usize {
+        let Compressed { cols, .. } = self.transpose;
+        cols
+    }
+
+    fn cols(&self) -> usize {
+        let Compressed { rows, .. } = self.transpose;
+        rows
+    }
+
+    fn nnz(&self) -> usize {
+        self.transpose.values.len()
+    }
+
+    fn update(&self, op: Op, alpha: T, x: &[T], y: &mut [T]) {
+        self.transpose.update(transposed(op), alpha, x, y);
+    }
+}
+
+impl<T: Scalar> Triangular<T> for Csc<'_, T> {
+    fn solve(&self, upper: bool, op: Op, unit: bool, x: &mut [T]) {
+        // the upper triangle of the matrix is the lower triangle of its transpose
+        self.transpose.solve(!upper, transposed(op), unit, x);
+    }
+}
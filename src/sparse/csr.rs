@@ -0,0 +1,74 @@
+use crate::sparse::compressed::Compressed;
+use crate::sparse::{private, Op, Scalar, SparseError, SparseMatrix, Triangular};
+
+/// A sparse matrix in compressed sparse row format.
+///
+/// The elements of row i are `values[k]` in column `indices[k] - base`, for k from
+/// `pointers[i] - base` to `pointers[i + 1] - base`.
+#[derive(Debug, Clone, Copy)]
+pub struct Csr<'a, T> {
+    storage: Compressed<'a, T>,
+}
+
+impl<'a, T: Copy> Csr<'a, T> {
+    /// Creates a rows by cols matrix from its pointer, index and value arrays.
+    ///
+    /// pointers must have rows + 1 elements, start at base and not decrease, indices and values
+    /// must have `pointers[rows] - base` elements, and every index must be a valid column.
+    pub fn new(
+        rows: usize,
+        cols: usize,
+        pointers: &'a [isize],
+        indices: &'a [isize],
+        values: &'a [T],
+        base: isize,
+    ) -> Result<Self, SparseError> {
+        Compressed::new(rows, cols, pointers, indices, values, base).map(|storage| Self { storage })
+    }
+
+    /// Returns the row pointers.
+    pub fn pointers(&self) -> &'a [isize] {
+        self.storage.pointers
+    }
+
+    /// Returns the column indices.
+    pub fn indices(&self) -> &'a [isize] {
+        self.storage.indices
+    }
+
+    /// Returns the values.
+    pub fn values(&self) -> &'a [T] {
+        self.storage.values
+    }
+
+    /// Returns the index base.
+    pub fn base(&self) -> isize {
+        self.storage.base
+    }
+}
+
+impl<T> private::Sealed for Csr<'_, T> {}
+
+impl<T: Scalar> SparseMatrix<T> for Csr<'_, T> {
+    fn rows(&self) -> usize {
+        self.storage.rows
+    }
+
+    fn cols(&self) -> usize {
+        self.storage.cols
+    }
+
+    fn nnz(&self) -> usize {
+        self.storage.values.len()
+    }
+
+    fn update(&self, op: Op, alpha: T, x: &[T], y: &mut [T]) {
+        self.storage.update(op, alpha, x, y);
+    }
+}
+
+impl<T: Scalar> Triangular<T> for Csr<'_, T> {
+    fn solve(&self, upper: bool, op: Op, unit: bool, x: &mut [T]) {
+        self.storage.solve(upper, op, unit, x);
+    }
+}
@@ -0,0 +1,240 @@
+//! Sparse matrices and the routines that multiply them with dense vectors and matrices.
+//!
+//! A matrix in compressed sparse row (`csr::Csr`), compressed sparse column (`csc::Csc`) or
+//! coordinate (`coo::Coo`) format borrows its index and value arrays, which are validated once
+//! when it is created. Indices start at 0 or 1, as chosen by the base of the matrix. The dense
+//! vectors are updated with the sparse `level_1` routines.
+//!
+//! ```
+//! use naive_blas::sparse::{csr::Csr, spmv::spmv};
+//! use nd_slice::{NdSlice, NdSliceMut};
+//!
+//! // [1 0 2]
+//! // [0 3 0]
+//! let a = Csr::new(2, 3, &[0, 2, 3], &[0, 2, 1], &[1.0, 2.0, 3.0], 0).unwrap();
+//!
+//! let x = [1.0, 1.0, 1.0];
+//! let mut y = [0.0; 2];
+//! spmv(
+//!     &'N',
+//!     &1.0,
+//!     &a,
+//!     &NdSlice::new_row_ordered(&x, [3]).unwrap(),
+//!     &0.0,
+//!     &mut NdSliceMut::new_row_ordered(&mut y, [2]).unwrap(),
+//! );
+//! assert_eq!(y, [3.0, 3.0]);
+//! ```
+
+use core::fmt;
+
+use num_complex::Complex;
+use num_traits::Num;
+
+use crate::contiguous::{vector, vector_mut};
+use crate::level_1::{axpyi, doti, scal};
+
+mod compressed;
+pub mod coo;
+pub mod csc;
+pub mod csr;
+pub mod spmm;
+pub mod spmv;
+pub mod trsv;
+
+/// Error returned when the arrays of a sparse matrix do not describe a valid matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparseError {
+    /// the index base is neither 0 nor 1
+    Base,
+    /// the pointer array has the wrong length, does not start at the base or decreases
+    Pointers,
+    /// the index and value arrays do not have the number of elements the pointers give
+    Lengths,
+    /// the index at this position of the index arrays is out of bounds
+    Index(usize),
+}
+
+impl fmt::Display for SparseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SparseError::Base => write!(f, "the index base must be 0 or 1"),
+            SparseError::Pointers => write!(f, "invalid pointer array"),
+            SparseError::Lengths => write!(f, "index and value arrays of different lengths"),
+            SparseError::Index(k) => write!(f, "index {} is out of bounds", k),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SparseError {}
+
+/// Element types of sparse matrices: f32, f64 and their complex counterparts.
+///
+/// The methods other than `conj` forward to the matching real or complex `level_1` routine.
+pub trait Scalar: Copy + Num + 'static {
+    /// Returns the complex conjugate, or the value itself for real types.
+    fn conj(self) -> Self;
+
+    #[doc(hidden)]
+    fn doti(x: &[Self], indx: &[isize], base: isize, y: &[Self]) -> Self;
+
+    #[doc(hidden)]
+    fn dotci(x: &[Self], indx: &[isize], base: isize, y: &[Self]) -> Self;
+
+    #[doc(hidden)]
+    fn axpyi(a: Self, x: &[Self], indx: &[isize], base: isize, y: &mut [Self]);
+
+    #[doc(hidden)]
+    fn scal(a: Self, x: &mut [Self]);
+}
+
+macro_rules! real_scalar {
+    ($t:ty) => {
+        impl Scalar for $t {
+            fn conj(self) -> Self {
+                self
+            }
+
+            fn doti(x: &[Self], indx: &[isize], base: isize, y: &[Self]) -> Self {
+                let nz = x.len() as isize;
+                doti::doti(&nz, &vector(x), &vector(indx), &base, &vector(y))
+            }
+
+            fn dotci(x: &[Self], indx: &[isize], base: isize, y: &[Self]) -> Self {
+                Self::doti(x, indx, base, y)
+            }
+
+            fn axpyi(a: Self, x: &[Self], indx: &[isize], base: isize, y: &mut [Self]) {
+                let nz = x.len() as isize;
+                axpyi::axpyi_real(
+                    &nz,
+                    &a,
+                    &vector(x),
+                    &vector(indx),
+                    &base,
+                    &mut vector_mut(y),
+                );
+            }
+
+            fn scal(a: Self, x: &mut [Self]) {
+                let n = x.len() as isize;
+                scal::scal_real(&n, &a, &mut vector_mut(x), &1);
+            }
+        }
+    };
+}
+
+macro_rules! complex_scalar {
+    ($t:ty) => {
+        impl Scalar for Complex<$t> {
+            fn conj(self) -> Self {
+                Complex::conj(&self)
+            }
+
+            fn doti(x: &[Self], indx: &[isize], base: isize, y: &[Self]) -> Self {
+                let nz = x.len() as isize;
+                doti::dotui(&nz, &vector(x), &vector(indx), &base, &vector(y))
+            }
+
+            fn dotci(x: &[Self], indx: &[isize], base: isize, y: &[Self]) -> Self {
+                let nz = x.len() as isize;
+                doti::dotci(&nz, &vector(x), &vector(indx), &base, &vector(y))
+            }
+
+            fn axpyi(a: Self, x: &[Self], indx: &[isize], base: isize, y: &mut [Self]) {
+                let nz = x.len() as isize;
+                axpyi::axpyi_complex(
+                    &nz,
+                    &a,
+                    &vector(x),
+                    &vector(indx),
+                    &base,
+                    &mut vector_mut(y),
+                );
+            }
+
+            fn scal(a: Self, x: &mut [Self]) {
+                scal::scal_complex(x.len() as isize, a, vector_mut(x), 1);
+            }
+        }
+    };
+}
+
+real_scalar!(f32);
+real_scalar!(f64);
+complex_scalar!(f32);
+complex_scalar!(f64);
+
+mod private {
+    pub trait Sealed {}
+
+    /// What a routine applies to the matrix.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Op {
+        /// A
+        None,
+        /// Aᵀ
+        Trans,
+        /// Aᴴ
+        ConjTrans,
+        /// conjugate(A), needed for the conjugate transpose of a matrix stored as its transpose
+        Conj,
+    }
+
+    impl Op {
+        /// Parses a trans argument: 'N', 'T' or 'C'.
+        pub fn new(trans: &char) -> Option<Self> {
+            match trans {
+                'N' | 'n' => Some(Op::None),
+                'T' | 't' => Some(Op::Trans),
+                'C' | 'c' => Some(Op::ConjTrans),
+                _ => None,
+            }
+        }
+
+        /// Returns true if the operation transposes the matrix.
+        pub fn transposes(self) -> bool {
+            matches!(self, Op::Trans | Op::ConjTrans)
+        }
+
+        /// Returns true if the operation conjugates the elements.
+        pub fn conjugates(self) -> bool {
+            matches!(self, Op::ConjTrans | Op::Conj)
+        }
+    }
+}
+
+pub(crate) use private::Op;
+
+/// A sparse matrix in one of the formats of this module.
+pub trait SparseMatrix<T: Scalar>: private::Sealed {
+    /// Returns the number of rows.
+    fn rows(&self) -> usize;
+
+    /// Returns the number of columns.
+    fn cols(&self) -> usize;
+
+    /// Returns the number of stored elements.
+    fn nnz(&self) -> usize;
+
+    /// Computes y = alpha * op(A) * x + y.
+    #[doc(hidden)]
+    fn update(&self, op: Op, alpha: T, x: &[T], y: &mut [T]);
+}
+
+/// A sparse matrix whose triangles can be solved with, see `trsv`.
+pub trait Triangular<T: Scalar>: SparseMatrix<T> {
+    /// Solves op(A) * x = b in place for the upper or lower triangle of A.
+    #[doc(hidden)]
+    fn solve(&self, upper: bool, op: Op, unit: bool, x: &mut [T]);
+}
+
+/// Computes y = beta * y, setting y to zero without reading it if beta is zero.
+pub(crate) fn scale<T: Scalar>(beta: T, y: &mut [T]) {
+    if beta == T::zero() {
+        y.iter_mut().for_each(|y| *y = T::zero());
+    } else if beta != T::one() {
+        T::scal(beta, y);
+    }
+}
@@ -0,0 +1,65 @@
+use nd_slice::{NdSlice, NdSliceMut};
+
+use crate::contiguous::{as_mut_slice, as_slice};
+use crate::sparse::{scale, Op, Scalar, SparseMatrix};
+
+/// Computes the product of a sparse matrix with a dense matrix.
+///
+/// C = alpha * op(A) * B + beta * C
+///
+/// Input:
+///     trans: 'N' for op(A) = A, 'T' for op(A) = Aᵀ and 'C' for op(A) = Aᴴ
+///     n: number of columns of B and C
+///     alpha: scalar
+///     a: sparse matrix
+///     b: matrix in column-major order, ldb by n, with as many rows as op(A) has columns
+///     ldb: leading dimension of b
+///     beta: scalar, C need not be set if beta is zero
+///     c: matrix in column-major order, ldc by n, with as many rows as op(A)
+///     ldc: leading dimension of c
+/// Output:
+///     c: modified c
+#[allow(clippy::too_many_arguments)]
+pub fn spmm<T: Scalar, A: SparseMatrix<T> + ?Sized>(
+    trans: &char,
+    n: &isize,
+    alpha: &T,
+    a: &A,
+    b: &NdSlice<'_, T, 1>,
+    ldb: &isize,
+    beta: &T,
+    c: &mut NdSliceMut<'_, T, 1>,
+    ldc: &isize,
+) {
+    let (n, ldb, ldc, alpha, beta) = (*n, *ldb, *ldc, *alpha, *beta);
+
+    let op = match Op::new(trans) {
+        Some(op) => op,
+        None => return,
+    };
+    let (m, k) = if op.transposes() {
+        (a.cols(), a.rows())
+    } else {
+        (a.rows(), a.cols())
+    };
+
+    if n < 0 || ldb < k.max(1) as isize || ldc < m.max(1) as isize {
+        return;
+    }
+    if n == 0 || m == 0 {
+        return;
+    }
+    let (n, ldb, ldc) = (n as usize, ldb as usize, ldc as usize);
+
+    let b = as_slice(b, ldb * (n - 1) + k);
+    let c = as_mut_slice(c, ldc * (n - 1) + m);
+
+    for (j, c) in c.chunks_mut(ldc).enumerate() {
+        let (b, c) = (&b[j * ldb..j * ldb + k], &mut c[..m]);
+
+        scale(beta, c);
+        if alpha != T::zero() {
+            a.update(op, alpha, b, c);
+        }
+    }
+}
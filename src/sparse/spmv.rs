@@ -0,0 +1,45 @@
+use nd_slice::{NdSlice, NdSliceMut};
+
+use crate::contiguous::{as_mut_slice, as_slice};
+use crate::sparse::{scale, Op, Scalar, SparseMatrix};
+
+/// Computes a sparse matrix-vector product.
+///
+/// y = alpha * op(A) * x + beta * y
+///
+/// Input:
+///     trans: 'N' for op(A) = A, 'T' for op(A) = Aᵀ and 'C' for op(A) = Aᴴ
+///     alpha: scalar
+///     a: sparse matrix
+///     x: vector with as many elements as op(A) has columns
+///     beta: scalar, y need not be set if beta is zero
+///     y: vector with as many elements as op(A) has rows
+/// Output:
+///     y: modified y
+pub fn spmv<T: Scalar, A: SparseMatrix<T> + ?Sized>(
+    trans: &char,
+    alpha: &T,
+    a: &A,
+    x: &NdSlice<'_, T, 1>,
+    beta: &T,
+    y: &mut NdSliceMut<'_, T, 1>,
+) {
+    let (alpha, beta) = (*alpha, *beta);
+
+    let op = match Op::new(trans) {
+        Some(op) => op,
+        None => return,
+    };
+    let (m, n) = if op.transposes() {
+        (a.cols(), a.rows())
+    } else {
+        (a.rows(), a.cols())
+    };
+
+    let (x, y) = (as_slice(x, n), as_mut_slice(y, m));
+
+    scale(beta, y);
+    if alpha != T::zero() {
+        a.update(op, alpha, x, y);
+    }
+}
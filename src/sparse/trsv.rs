@@ -0,0 +1,47 @@
+use nd_slice::NdSliceMut;
+
+use crate::contiguous::as_mut_slice;
+use crate::sparse::{Op, Scalar, Triangular};
+
+/// Solves a system of linear equations with a sparse triangular matrix.
+///
+/// op(A) * x = b
+///
+/// Only the elements of A in the chosen triangle, including the diagonal, are used; the others
+/// are ignored. A non-unit diagonal must be stored and nonzero.
+///
+/// Input:
+///     uplo: 'U' to use the upper triangle of A, 'L' to use the lower triangle
+///     trans: 'N' for op(A) = A, 'T' for op(A) = Aᵀ and 'C' for op(A) = Aᴴ
+///     diag: 'U' if A has a unit diagonal, 'N' otherwise
+///     a: square sparse matrix in compressed row or column format
+///     x: right-hand side b, with as many elements as A has rows
+/// Output:
+///     x: solution x
+pub fn trsv<T: Scalar, A: Triangular<T> + ?Sized>(
+    uplo: &char,
+    trans: &char,
+    diag: &char,
+    a: &A,
+    x: &mut NdSliceMut<'_, T, 1>,
+) {
+    let upper = uplo.eq_ignore_ascii_case(&'U');
+    let unit = diag.eq_ignore_ascii_case(&'U');
+
+    if !(upper || uplo.eq_ignore_ascii_case(&'L')) {
+        return;
+    }
+    if !(unit || diag.eq_ignore_ascii_case(&'N')) {
+        return;
+    }
+    let op = match Op::new(trans) {
+        Some(op) => op,
+        None => return,
+    };
+    if a.rows() != a.cols() || a.rows() == 0 {
+        return;
+    }
+
+    let x = as_mut_slice(x, a.rows());
+    a.solve(upper, op, unit, x);
+}
@@ -0,0 +1,452 @@
+//! Safe strided vector views over slices.
+//!
+//! `StridedVec` and `StridedVecMut` bundle a slice with the length, stride and offset of the
+//! vector it holds, check once that every element lies inside the slice, and then call the
+//! `level_1` routines with the matching `n` and increments.
+//!
+//! ```
+//! use naive_blas::strided::{StridedVec, StridedVecMut};
+//!
+//! let x = [1.0, 2.0, 3.0];
+//! let mut y = [1.0, 0.0, 1.0, 0.0, 1.0];
+//!
+//! // every other element of y, last to first
+//! let mut y = StridedVecMut::new(&mut y, 3, -2, 4).unwrap();
+//! y.axpy(2.0, &StridedVec::from(&x[..]));
+//!
+//! assert_eq!(y.as_vec().iter().copied().collect::<Vec<_>>(), vec![3.0, 5.0, 7.0]);
+//! ```
+
+use core::fmt;
+use core::ops::Range;
+
+use nd_slice::{NdSlice, NdSliceMut};
+use num_traits::Float;
+
+use crate::contiguous::{vector, vector_mut};
+use crate::level_1::{axpby, axpy, copy, dot, nrm2, rot, rotm, scal, swap};
+
+/// Error returned when a strided vector would reach outside of its slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrideError {
+    /// number of elements in the vector
+    pub len: usize,
+    /// distance between consecutive elements
+    pub stride: isize,
+    /// position of the first element in the slice
+    pub offset: usize,
+    /// length of the slice
+    pub slice_len: usize,
+}
+
+impl fmt::Display for StrideError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a vector of {} elements with stride {} starting at {} is not a valid view of a slice of length {}",
+            self.len, self.stride, self.offset, self.slice_len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StrideError {}
+
+/// Returns the part of the slice between the lowest and highest elements of the vector, if it
+/// fits.
+fn bounds(
+    slice_len: usize,
+    len: usize,
+    stride: isize,
+    offset: usize,
+) -> Result<Range<usize>, StrideError> {
+    let err = StrideError {
+        len,
+        stride,
+        offset,
+        slice_len,
+    };
+
+    if len == 0 {
+        return if offset <= slice_len {
+            Ok(offset..offset)
+        } else {
+            Err(err)
+        };
+    }
+
+    let last = (len as isize - 1)
+        .checked_mul(stride)
+        .and_then(|span| span.checked_add(offset as isize))
+        .ok_or(err)?;
+    let (lo, hi) = (last.min(offset as isize), last.max(offset as isize));
+
+    if lo < 0 || hi as usize >= slice_len {
+        Err(err)
+    } else {
+        Ok(lo as usize..hi as usize + 1)
+    }
+}
+
+/// Returns the position of the first element of a view with the given lengths and strides in
+/// the span from its lowest to its highest element, and the length of that span.
+pub(crate) fn span(dims: &[(usize, isize)]) -> (usize, usize) {
+    if dims.iter().any(|&(len, _)| len == 0) {
+        return (0, 0);
+    }
+
+    let reach = |sign: isize| -> usize {
+        dims.iter()
+            .map(|&(len, stride)| (len - 1) * (sign * stride).max(0) as usize)
+            .sum()
+    };
+    let first = reach(-1);
+    (first, first + reach(1) + 1)
+}
+
+/// An immutable strided vector: the i-th element is `data[offset + i * stride]`.
+#[derive(Debug)]
+pub struct StridedVec<'a, T> {
+    data: &'a [T],
+    len: usize,
+    stride: isize,
+    offset: usize,
+}
+
+impl<T> Clone for StridedVec<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for StridedVec<'_, T> {}
+
+impl<'a, T> StridedVec<'a, T> {
+    /// Creates a vector of len elements of data, the first at offset and each following one
+    /// stride elements further. The stride may be negative or zero, and is taken as 1 if len is at
+    /// most 1.
+    pub fn new(
+        data: &'a [T],
+        len: usize,
+        stride: isize,
+        offset: usize,
+    ) -> Result<Self, StrideError> {
+        let range = bounds(data.len(), len, stride, offset)?;
+
+        Ok(Self {
+            offset: offset - range.start,
+            data: &data[range],
+            len,
+            // the stride of a vector of at most one element does not matter
+            stride: if len <= 1 { 1 } else { stride },
+        })
+    }
+
+    /// Creates a vector of len elements, the first at ptr and each following one stride elements
+    /// further. The stride may be negative or zero.
+    ///
+    /// # Safety
+    ///
+    /// The elements must lie inside one allocation, and be valid for reads and not be written to
+    /// for 'a. The vector holds a slice over the memory from its lowest to its highest element,
+    /// but only reads its own elements.
+    pub unsafe fn from_raw_parts(ptr: *const T, len: usize, stride: isize) -> Self {
+        let (first, span) = span(&[(len, stride)]);
+        let data = core::slice::from_raw_parts(ptr.sub(first), span);
+
+        Self::new(data, len, stride, first).unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the vector has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the distance between consecutive elements.
+    pub fn stride(&self) -> isize {
+        self.stride
+    }
+
+    /// Returns the i-th element, or None if i is out of bounds.
+    pub fn get(&self, i: usize) -> Option<&'a T> {
+        if i < self.len {
+            Some(&self.data[(self.offset as isize + i as isize * self.stride) as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over the elements in order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        (0..self.len).map(move |i| self.get(i).unwrap())
+    }
+
+    /// Returns the arguments to pass to the `level_1` routines: n, the vector and its increment.
+    pub fn blas(&self) -> (isize, NdSlice<'a, T, 1>, isize) {
+        (self.len as isize, vector(self.data), self.stride)
+    }
+}
+
+impl<'a, T> From<&'a [T]> for StridedVec<'a, T> {
+    fn from(data: &'a [T]) -> Self {
+        Self {
+            data,
+            len: data.len(),
+            stride: 1,
+            offset: 0,
+        }
+    }
+}
+
+impl<T: Float + 'static> StridedVec<'_, T> {
+    /// Computes the dot product with y.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors have different lengths.
+    pub fn dot(&self, y: &StridedVec<'_, T>) -> T {
+        assert_eq!(self.len, y.len, "vectors of different lengths");
+
+        let (n, x, incx) = self.blas();
+        let (_, y, incy) = y.blas();
+        dot::dot(&n, &x, &incx, &y, &incy)
+    }
+
+    /// Computes the Euclidean norm.
+    pub fn nrm2(&self) -> T {
+        let (n, mut x, incx) = self.blas();
+        if incx == 0 && n > 1 {
+            return T::from(n).unwrap().sqrt() * x[[0]].abs();
+        }
+
+        // the norm does not depend on the order of the elements
+        nrm2::nrm2(&n, &mut x, &incx.abs())
+    }
+}
+
+/// A mutable strided vector: the i-th element is `data[offset + i * stride]`.
+#[derive(Debug)]
+pub struct StridedVecMut<'a, T> {
+    data: &'a mut [T],
+    len: usize,
+    stride: isize,
+    offset: usize,
+}
+
+impl<'a, T> StridedVecMut<'a, T> {
+    /// Creates a vector of len elements of data, the first at offset and each following one
+    /// stride elements further. The stride may be negative, but not zero unless len is at most 1,
+    /// in which case it is taken as 1.
+    pub fn new(
+        data: &'a mut [T],
+        len: usize,
+        stride: isize,
+        offset: usize,
+    ) -> Result<Self, StrideError> {
+        let range = bounds(data.len(), len, stride, offset)?;
+        if stride == 0 && len > 1 {
+            return Err(StrideError {
+                len,
+                stride,
+                offset,
+                slice_len: data.len(),
+            });
+        }
+
+        Ok(Self {
+            offset: offset - range.start,
+            data: &mut data[range],
+            len,
+            // the stride of a vector of at most one element does not matter
+            stride: if len <= 1 { 1 } else { stride },
+        })
+    }
+
+    /// Creates a vector of len elements, the first at ptr and each following one stride elements
+    /// further. The stride may be negative, but not zero unless len is at most 1.
+    ///
+    /// # Safety
+    ///
+    /// The elements must lie inside one allocation, and be valid for reads and writes and not be
+    /// accessed through any other pointer for 'a. The vector holds a slice over the memory from
+    /// its lowest to its highest element, but only accesses its own elements.
+    pub unsafe fn from_raw_parts(
+        ptr: *mut T,
+        len: usize,
+        stride: isize,
+    ) -> Result<Self, StrideError> {
+        let (first, span) = span(&[(len, stride)]);
+        let data = core::slice::from_raw_parts_mut(ptr.sub(first), span);
+
+        Self::new(data, len, stride, first)
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the vector has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the distance between consecutive elements.
+    pub fn stride(&self) -> isize {
+        self.stride
+    }
+
+    /// Returns an immutable view of the same elements.
+    pub fn as_vec(&self) -> StridedVec<'_, T> {
+        StridedVec {
+            data: self.data,
+            len: self.len,
+            stride: self.stride,
+            offset: self.offset,
+        }
+    }
+
+    /// Returns the i-th element, or None if i is out of bounds.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        self.as_vec().get(i)
+    }
+
+    /// Returns the i-th element mutably, or None if i is out of bounds.
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i < self.len {
+            Some(&mut self.data[(self.offset as isize + i as isize * self.stride) as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the arguments to pass to the `level_1` routines: n, the vector and its increment.
+    pub fn blas(&mut self) -> (isize, NdSliceMut<'_, T, 1>, isize) {
+        (self.len as isize, vector_mut(self.data), self.stride)
+    }
+}
+
+impl<'a, T> From<&'a mut [T]> for StridedVecMut<'a, T> {
+    fn from(data: &'a mut [T]) -> Self {
+        Self {
+            len: data.len(),
+            data,
+            stride: 1,
+            offset: 0,
+        }
+    }
+}
+
+impl<T: Float + 'static> StridedVecMut<'_, T> {
+    /// Computes the dot product with y.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors have different lengths.
+    pub fn dot(&self, y: &StridedVec<'_, T>) -> T {
+        self.as_vec().dot(y)
+    }
+
+    /// Computes the Euclidean norm.
+    pub fn nrm2(&self) -> T {
+        self.as_vec().nrm2()
+    }
+
+    /// Computes self = a * x + self.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors have different lengths.
+    pub fn axpy(&mut self, a: T, x: &StridedVec<'_, T>) {
+        assert_eq!(self.len, x.len, "vectors of different lengths");
+
+        let (_, x, incx) = x.blas();
+        let (n, mut y, incy) = self.blas();
+        axpy::axpy_real(&n, &a, &x, &incx, &mut y, &incy);
+    }
+
+    /// Computes self = a * x + b * self.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors have different lengths.
+    pub fn axpby(&mut self, a: T, x: &StridedVec<'_, T>, b: T) {
+        assert_eq!(self.len, x.len, "vectors of different lengths");
+
+        let (_, x, incx) = x.blas();
+        let (n, mut y, incy) = self.blas();
+        axpby::axpby_real(&n, &a, &x, &incx, &b, &mut y, &incy);
+    }
+
+    /// Computes self = a * self.
+    pub fn scal(&mut self, a: T) {
+        // scaling does not depend on the order of the elements
+        let (n, mut x, incx) = self.blas();
+        scal::scal_real(&n, &a, &mut x, &incx.abs());
+    }
+
+    /// Copies the elements of x into self.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors have different lengths.
+    pub fn copy_from(&mut self, x: &StridedVec<'_, T>) {
+        assert_eq!(self.len, x.len, "vectors of different lengths");
+
+        let (_, x, incx) = x.blas();
+        let (n, mut y, incy) = self.blas();
+        copy::copy(&n, &x, &incx, &mut y, &incy);
+    }
+
+    /// Swaps the elements of self and y.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors have different lengths.
+    pub fn swap(&mut self, y: &mut StridedVecMut<'_, T>) {
+        assert_eq!(self.len, y.len, "vectors of different lengths");
+
+        let (n, mut x, incx) = self.blas();
+        let (_, mut y, incy) = y.blas();
+        swap::swap(&n, &mut x, &incx, &mut y, &incy);
+    }
+
+    /// Applies the plane rotation with cosine c and sine s to the points (self[i], y[i]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors have different lengths.
+    pub fn rot(&mut self, y: &mut StridedVecMut<'_, T>, c: T, s: T) {
+        assert_eq!(self.len, y.len, "vectors of different lengths");
+
+        let (n, mut x, incx) = self.blas();
+        let (_, mut y, incy) = y.blas();
+        rot::rot(&n, &mut x, &incx, &mut y, &incy, &c, &s);
+    }
+
+    /// Applies the modified Givens rotation described by param, as returned by `rotmg`, to the
+    /// points (self[i], y[i]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors have different lengths.
+    pub fn rotm(&mut self, y: &mut StridedVecMut<'_, T>, mut param: [T; 5]) {
+        assert_eq!(self.len, y.len, "vectors of different lengths");
+
+        let (n, mut x, incx) = self.blas();
+        let (_, mut y, incy) = y.blas();
+        rotm::rotm(
+            &n,
+            &mut x,
+            &incx,
+            &mut y,
+            &incy,
+            &mut vector_mut(&mut param),
+        );
+    }
+}
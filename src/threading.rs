@@ -0,0 +1,497 @@
+//! Multi-threaded execution of the level 1 routines.
+//!
+//! Unit-stride calls on `f32` and `f64` vectors of at least `threshold()` elements are split
+//! into one contiguous chunk per thread, and each chunk is handed back to the single-threaded
+//! routine. Reductions combine the per-chunk results in chunk order, so for a given number of
+//! threads the result does not depend on scheduling.
+//!
+//! ```no_run
+//! naive_blas::threading::set_num_threads(8);
+//!
+//! // everything inside the closure runs on the calling thread only
+//! naive_blas::threading::with_num_threads(1, || {
+//!     // ...
+//! });
+//! ```
+
+use std::any::TypeId;
+use std::cell::Cell;
+use std::ops::{Index, IndexMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use num_traits::Float;
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use crate::contiguous::{as_mut_slice, as_slice, cast, cast_mut, cast_scalar, vector, vector_mut};
+use crate::level_1::{axpy, copy, dot, nrm2, rot, rotm, scal, swap};
+
+/// 0 means one thread per available core.
+static NUM_THREADS: AtomicUsize = AtomicUsize::new(0);
+static THRESHOLD: AtomicUsize = AtomicUsize::new(1 << 16);
+
+thread_local! {
+    static OVERRIDE: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Sets the number of threads used by the routines. 0 uses one thread per available core.
+pub fn set_num_threads(n: usize) {
+    NUM_THREADS.store(n, Ordering::Relaxed);
+}
+
+/// Returns the number of threads a call made from this thread would use.
+pub fn num_threads() -> usize {
+    match OVERRIDE
+        .with(Cell::get)
+        .unwrap_or(NUM_THREADS.load(Ordering::Relaxed))
+    {
+        0 => {
+            static CORES: OnceLock<usize> = OnceLock::new();
+            *CORES.get_or_init(|| std::thread::available_parallelism().map_or(1, |n| n.get()))
+        }
+        n => n,
+    }
+}
+
+/// Sets the number of elements below which the routines stay single-threaded.
+pub fn set_threshold(n: usize) {
+    THRESHOLD.store(n, Ordering::Relaxed);
+}
+
+/// Returns the number of elements below which the routines stay single-threaded.
+pub fn threshold() -> usize {
+    THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Runs f with the number of threads overridden to n for calls made from this thread.
+pub fn with_num_threads<R>(n: usize, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<usize>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            OVERRIDE.with(|o| o.set(self.0));
+        }
+    }
+
+    let _restore = Restore(OVERRIDE.with(|o| o.replace(Some(n))));
+    f()
+}
+
+/// Returns the pool to split a call of length n across, if it is worth splitting.
+///
+/// A single pool is kept, and rebuilt when a call asks for a different number of threads.
+fn pool_for(n: usize) -> Option<Arc<ThreadPool>> {
+    if n < threshold() {
+        return None;
+    }
+    let threads = num_threads();
+    if threads < 2 || n < threads {
+        return None;
+    }
+
+    static POOL: Mutex<Option<Arc<ThreadPool>>> = Mutex::new(None);
+
+    let mut pool = POOL.lock().unwrap_or_else(|e| e.into_inner());
+    match &*pool {
+        Some(pool) if pool.current_num_threads() == threads => {}
+        _ => {
+            *pool = Some(Arc::new(
+                ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .expect("failed to build the thread pool"),
+            ))
+        }
+    }
+
+    pool.clone()
+}
+
+/// Runs f on every item, spread across the pool if the total work is large enough.
+pub(crate) fn for_each<I: Send>(work: usize, items: Vec<I>, f: impl Fn(I) + Sync) {
+    match pool_for(work) {
+        Some(pool) => pool.install(|| {
+            items
+                .into_par_iter()
+                .for_each(|item| with_num_threads(1, || f(item)))
+        }),
+        None => items.into_iter().for_each(f),
+    }
+}
+
+fn chunk_len(pool: &ThreadPool, n: usize) -> usize {
+    n.div_ceil(pool.current_num_threads())
+}
+
+/// Runs f on matching chunks of x and y, each chunk single-threaded.
+fn for_each_chunk<T: Send + Sync>(
+    pool: &ThreadPool,
+    x: &[T],
+    y: &mut [T],
+    f: impl Fn(&[T], &mut [T]) + Sync,
+) {
+    let len = chunk_len(pool, x.len());
+
+    pool.install(|| {
+        x.par_chunks(len)
+            .zip(y.par_chunks_mut(len))
+            .for_each(|(x, y)| with_num_threads(1, || f(x, y)))
+    });
+}
+
+/// Runs f on matching chunks of x and y, each chunk single-threaded.
+fn for_each_chunk_mut<T: Send>(
+    pool: &ThreadPool,
+    x: &mut [T],
+    y: &mut [T],
+    f: impl Fn(&mut [T], &mut [T]) + Sync,
+) {
+    let len = chunk_len(pool, x.len());
+
+    pool.install(|| {
+        x.par_chunks_mut(len)
+            .zip(y.par_chunks_mut(len))
+            .for_each(|(x, y)| with_num_threads(1, || f(x, y)))
+    });
+}
+
+/// Runs f on matching chunks of x and y, each chunk single-threaded, and returns the results in
+/// chunk order.
+fn map_chunks<T: Sync, R: Send>(
+    pool: &ThreadPool,
+    x: &[T],
+    y: &[T],
+    f: impl Fn(&[T], &[T]) -> R + Sync,
+) -> Vec<R> {
+    let len = chunk_len(pool, x.len());
+
+    pool.install(|| {
+        x.par_chunks(len)
+            .zip(y.par_chunks(len))
+            .map(|(x, y)| with_num_threads(1, || f(x, y)))
+            .collect()
+    })
+}
+
+fn par_axpy<T: Float + Send + Sync + 'static>(pool: &ThreadPool, a: T, x: &[T], y: &mut [T]) {
+    for_each_chunk(pool, x, y, |x, y| {
+        let n = x.len() as isize;
+        axpy::axpy_real(&n, &a, &vector(x), &1, &mut vector_mut(y), &1);
+    });
+}
+
+/// y = ax + y for unit-stride x and y
+pub(crate) fn axpy<T, X, Y>(n: usize, a: T, x: &X, y: &mut Y) -> bool
+where
+    T: Copy + 'static,
+    X: Index<[usize; 1], Output = T> + ?Sized,
+    Y: IndexMut<[usize; 1], Output = T> + ?Sized,
+{
+    let pool = match pool_for(n) {
+        Some(pool) => pool,
+        None => return false,
+    };
+    let (x, y) = (as_slice(x, n), as_mut_slice(y, n));
+
+    if let (Some(a), Some(x), Some(y)) = (cast_scalar::<T, f32>(a), cast(x), cast_mut(y)) {
+        par_axpy(&pool, a, x, y);
+        return true;
+    }
+    if let (Some(a), Some(x), Some(y)) = (cast_scalar::<T, f64>(a), cast(x), cast_mut(y)) {
+        par_axpy(&pool, a, x, y);
+        return true;
+    }
+
+    false
+}
+
+fn par_dot<T: Float + Send + Sync + 'static, U: Float + Send + 'static>(
+    pool: &ThreadPool,
+    x: &[T],
+    y: &[T],
+) -> U {
+    map_chunks(pool, x, y, |x, y| {
+        let n = x.len() as isize;
+        dot::dot::<T, U>(&n, &vector(x), &1, &vector(y), &1)
+    })
+    .into_iter()
+    .fold(U::zero(), |acc, part| acc + part)
+}
+
+/// par_dot for an accumulator type that is only known to be one of f32 or f64
+fn par_dot_as<T: Float + Send + Sync + 'static, U: Copy + 'static>(
+    pool: &ThreadPool,
+    x: &[T],
+    y: &[T],
+) -> Option<U> {
+    if TypeId::of::<U>() == TypeId::of::<f32>() {
+        cast_scalar(par_dot::<T, f32>(pool, x, y))
+    } else if TypeId::of::<U>() == TypeId::of::<f64>() {
+        cast_scalar(par_dot::<T, f64>(pool, x, y))
+    } else {
+        None
+    }
+}
+
+/// Σ x[i] * y[i] for unit-stride x and y, accumulated in U
+pub(crate) fn dot<T, U, X, Y>(n: usize, x: &X, y: &Y) -> Option<U>
+where
+    T: Copy + 'static,
+    U: Copy + 'static,
+    X: Index<[usize; 1], Output = T> + ?Sized,
+    Y: Index<[usize; 1], Output = T> + ?Sized,
+{
+    let pool = pool_for(n)?;
+    let (x, y) = (as_slice(x, n), as_slice(y, n));
+
+    if let (Some(x), Some(y)) = (cast::<T, f32>(x), cast(y)) {
+        return par_dot_as(&pool, x, y);
+    }
+    if let (Some(x), Some(y)) = (cast::<T, f64>(x), cast(y)) {
+        return par_dot_as(&pool, x, y);
+    }
+
+    None
+}
+
+fn par_scal<T: Float + Send + Sync + 'static>(pool: &ThreadPool, a: T, x: &mut [T]) {
+    let len = chunk_len(pool, x.len());
+
+    pool.install(|| {
+        x.par_chunks_mut(len).for_each(|x| {
+            with_num_threads(1, || {
+                let n = x.len() as isize;
+                scal::scal_real(&n, &a, &mut vector_mut(x), &1);
+            })
+        })
+    });
+}
+
+/// x = a * x for unit-stride x
+pub(crate) fn scal<T, X>(n: usize, a: T, x: &mut X) -> bool
+where
+    T: Copy + 'static,
+    X: IndexMut<[usize; 1], Output = T> + ?Sized,
+{
+    let pool = match pool_for(n) {
+        Some(pool) => pool,
+        None => return false,
+    };
+    let x = as_mut_slice(x, n);
+
+    if let (Some(a), Some(x)) = (cast_scalar::<T, f32>(a), cast_mut(x)) {
+        par_scal(&pool, a, x);
+        return true;
+    }
+    if let (Some(a), Some(x)) = (cast_scalar::<T, f64>(a), cast_mut(x)) {
+        par_scal(&pool, a, x);
+        return true;
+    }
+
+    false
+}
+
+fn par_copy<T: Float + Send + Sync + 'static>(pool: &ThreadPool, x: &[T], y: &mut [T]) {
+    for_each_chunk(pool, x, y, |x, y| {
+        let n = x.len() as isize;
+        copy::copy(&n, &vector(x), &1, &mut vector_mut(y), &1);
+    });
+}
+
+/// y = x for unit-stride x and y
+pub(crate) fn copy<T, X, Y>(n: usize, x: &X, y: &mut Y) -> bool
+where
+    T: Copy + 'static,
+    X: Index<[usize; 1], Output = T> + ?Sized,
+    Y: IndexMut<[usize; 1], Output = T> + ?Sized,
+{
+    let pool = match pool_for(n) {
+        Some(pool) => pool,
+        None => return false,
+    };
+    let (x, y) = (as_slice(x, n), as_mut_slice(y, n));
+
+    if let (Some(x), Some(y)) = (cast::<T, f32>(x), cast_mut(y)) {
+        par_copy(&pool, x, y);
+        return true;
+    }
+    if let (Some(x), Some(y)) = (cast::<T, f64>(x), cast_mut(y)) {
+        par_copy(&pool, x, y);
+        return true;
+    }
+
+    false
+}
+
+fn par_swap<T: Float + Send + Sync + 'static>(pool: &ThreadPool, x: &mut [T], y: &mut [T]) {
+    for_each_chunk_mut(pool, x, y, |x, y| {
+        let n = x.len() as isize;
+        swap::swap(&n, &mut vector_mut(x), &1, &mut vector_mut(y), &1);
+    });
+}
+
+/// x <-> y for unit-stride x and y
+pub(crate) fn swap<T, X, Y>(n: usize, x: &mut X, y: &mut Y) -> bool
+where
+    T: 'static,
+    X: IndexMut<[usize; 1], Output = T> + ?Sized,
+    Y: IndexMut<[usize; 1], Output = T> + ?Sized,
+{
+    let pool = match pool_for(n) {
+        Some(pool) => pool,
+        None => return false,
+    };
+    let (x, y) = (as_mut_slice(x, n), as_mut_slice(y, n));
+
+    if let (Some(x), Some(y)) = (cast_mut::<T, f32>(x), cast_mut(y)) {
+        par_swap(&pool, x, y);
+        return true;
+    }
+    if let (Some(x), Some(y)) = (cast_mut::<T, f64>(x), cast_mut(y)) {
+        par_swap(&pool, x, y);
+        return true;
+    }
+
+    false
+}
+
+fn par_rot<T: Float + Send + Sync + 'static>(
+    pool: &ThreadPool,
+    c: T,
+    s: T,
+    x: &mut [T],
+    y: &mut [T],
+) {
+    for_each_chunk_mut(pool, x, y, |x, y| {
+        let n = x.len() as isize;
+        rot::rot(&n, &mut vector_mut(x), &1, &mut vector_mut(y), &1, &c, &s);
+    });
+}
+
+/// Plane rotation of unit-stride x and y
+pub(crate) fn rot<T, X, Y>(n: usize, c: T, s: T, x: &mut X, y: &mut Y) -> bool
+where
+    T: Copy + 'static,
+    X: IndexMut<[usize; 1], Output = T> + ?Sized,
+    Y: IndexMut<[usize; 1], Output = T> + ?Sized,
+{
+    let pool = match pool_for(n) {
+        Some(pool) => pool,
+        None => return false,
+    };
+    let (x, y) = (as_mut_slice(x, n), as_mut_slice(y, n));
+
+    if let (Some(c), Some(s), Some(x), Some(y)) = (
+        cast_scalar::<T, f32>(c),
+        cast_scalar(s),
+        cast_mut(x),
+        cast_mut(y),
+    ) {
+        par_rot(&pool, c, s, x, y);
+        return true;
+    }
+    if let (Some(c), Some(s), Some(x), Some(y)) = (
+        cast_scalar::<T, f64>(c),
+        cast_scalar(s),
+        cast_mut(x),
+        cast_mut(y),
+    ) {
+        par_rot(&pool, c, s, x, y);
+        return true;
+    }
+
+    false
+}
+
+fn par_rotm<T: Float + Send + Sync + 'static>(
+    pool: &ThreadPool,
+    param: [T; 5],
+    x: &mut [T],
+    y: &mut [T],
+) {
+    for_each_chunk_mut(pool, x, y, |x, y| {
+        let n = x.len() as isize;
+        let mut param = param;
+        rotm::rotm(
+            &n,
+            &mut vector_mut(x),
+            &1,
+            &mut vector_mut(y),
+            &1,
+            &mut vector_mut(&mut param),
+        );
+    });
+}
+
+/// Modified Givens rotation of unit-stride x and y
+pub(crate) fn rotm<T, X, Y>(n: usize, x: &mut X, y: &mut Y, param: [T; 5]) -> bool
+where
+    T: Copy + 'static,
+    X: IndexMut<[usize; 1], Output = T> + ?Sized,
+    Y: IndexMut<[usize; 1], Output = T> + ?Sized,
+{
+    let pool = match pool_for(n) {
+        Some(pool) => pool,
+        None => return false,
+    };
+    let (x, y) = (as_mut_slice(x, n), as_mut_slice(y, n));
+
+    if let (Some(param), Some(x), Some(y)) = (cast::<T, f32>(&param), cast_mut(x), cast_mut(y)) {
+        par_rotm(
+            &pool,
+            [param[0], param[1], param[2], param[3], param[4]],
+            x,
+            y,
+        );
+        return true;
+    }
+    if let (Some(param), Some(x), Some(y)) = (cast::<T, f64>(&param), cast_mut(x), cast_mut(y)) {
+        par_rotm(
+            &pool,
+            [param[0], param[1], param[2], param[3], param[4]],
+            x,
+            y,
+        );
+        return true;
+    }
+
+    false
+}
+
+fn par_nrm2<T: Float + Send + Sync + 'static>(pool: &ThreadPool, x: &[T]) -> T {
+    let norms = map_chunks(pool, x, x, |x, _| {
+        let n = x.len() as isize;
+        nrm2::nrm2(&n, &mut vector(x), &1)
+    });
+
+    // ‖ x ‖ = √(Σ ‖ x_k ‖²), scaled by the largest chunk norm to avoid overflow
+    let scale = norms.iter().fold(T::zero(), |acc, &norm| acc.max(norm));
+    if scale == T::zero() || !scale.is_finite() {
+        return scale;
+    }
+
+    let ssq = norms
+        .iter()
+        .fold(T::zero(), |acc, &norm| acc + (norm / scale).powi(2));
+    scale * ssq.sqrt()
+}
+
+/// ‖ x ‖ for unit-stride x
+pub(crate) fn nrm2<T, X>(n: usize, x: &X) -> Option<T>
+where
+    T: Copy + 'static,
+    X: Index<[usize; 1], Output = T> + ?Sized,
+{
+    let pool = pool_for(n)?;
+    let x = as_slice(x, n);
+
+    if let Some(x) = cast::<T, f32>(x) {
+        return cast_scalar(par_nrm2(&pool, x));
+    }
+    if let Some(x) = cast::<T, f64>(x) {
+        return cast_scalar(par_nrm2(&pool, x));
+    }
+
+    None
+}
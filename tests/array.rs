@@ -0,0 +1,127 @@
+#![cfg(feature = "ndarray")]
+
+use naive_blas::array;
+use naive_blas::mat::{MatMut, MatRef};
+use naive_blas::strided::{StridedVec, StridedVecMut};
+use ndarray::{array, s, Array1, Array2, ShapeBuilder};
+
+#[test]
+fn level_1_follows_ndarray_strides() {
+    let x = array![1.0f64, 2.0, 3.0, 4.0];
+    let mut y = array![1.0, 1.0];
+    array::axpy(2.0, &x.slice(s![..;-2]), &mut y);
+    assert_eq!(y, array![9.0, 5.0]);
+
+    assert_eq!(
+        array::dot(&x.slice(s![1..;2]), &x.slice(s![..;-2])),
+        2.0 * 4.0 + 4.0 * 2.0
+    );
+    assert_eq!(array::nrm2(&x.slice(s![..;3])), 17f64.sqrt());
+
+    let mut z = x.clone();
+    array::scal(2.0, &mut z.slice_mut(s![..;-3]));
+    assert_eq!(z, array![2.0, 2.0, 3.0, 8.0]);
+
+    let mut z = Array1::zeros(4);
+    array::copy(&x.slice(s![..;-1]), &mut z);
+    assert_eq!(z, array![4.0, 3.0, 2.0, 1.0]);
+
+    let mut z = array![1.0, 1.0];
+    array::axpby(1.0, &x.slice(s![..2]), -1.0, &mut z);
+    assert_eq!(z, array![0.0, 1.0]);
+}
+
+#[test]
+fn interleaved_mutable_views_stay_disjoint() {
+    let mut x = Array1::from_iter((0..8).map(f64::from));
+    let (mut even, mut odd) = x.multi_slice_mut((s![..;2], s![1..;2]));
+
+    array::swap(&mut even, &mut odd);
+    assert_eq!(x, array![1.0, 0.0, 3.0, 2.0, 5.0, 4.0, 7.0, 6.0]);
+
+    let (mut even, mut odd) = x.multi_slice_mut((s![..;2], s![1..;2]));
+    array::rot(&mut even, &mut odd, 0.0, 1.0);
+    assert_eq!(x, array![0.0, -1.0, 2.0, -3.0, 4.0, -5.0, 6.0, -7.0]);
+
+    let (mut even, odd) = x.multi_slice_mut((s![..;2], s![1..;2]));
+    array::axpy(1.0, &odd, &mut even);
+    assert_eq!(x, array![-1.0, -1.0, -1.0, -3.0, -1.0, -5.0, -1.0, -7.0]);
+
+    // the columns of a row-major matrix are interleaved too
+    let mut a = Array2::from_shape_fn((3, 4), |(i, j)| (4 * i + j) as f64);
+    let (left, mut right) = a.multi_slice_mut((s![.., ..2], s![.., 2..]));
+    let b = array![[1.0, 0.0], [0.0, 2.0]];
+    array::gemm(1.0, &left, &b, 1.0, &mut right);
+    let expected = Array2::from_shape_fn((3, 4), |(i, j)| {
+        let x = (4 * i + j) as f64;
+        match j {
+            2 => x + (4 * i) as f64,
+            3 => x + 2.0 * (4 * i + 1) as f64,
+            _ => x,
+        }
+    });
+    assert_eq!(a, expected);
+}
+
+#[test]
+fn gemm_handles_every_layout() {
+    let a = array![[1.0f64, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let b = array![[1.0f64, 0.0], [0.0, 1.0], [1.0, 1.0]];
+    let expected = a.dot(&b);
+
+    let mut c = Array2::zeros((2, 2));
+    array::gemm(1.0, &a, &b, 0.0, &mut c);
+    assert_eq!(c, expected);
+
+    let mut c = Array2::zeros((2, 2).f());
+    array::gemm(1.0, &a, &b, 0.0, &mut c);
+    assert_eq!(c, expected);
+
+    let mut c = Array2::zeros((2, 4));
+    let mut view = c.slice_mut(s![..;-1, ..;2]);
+    array::gemm(1.0, &a, &b, 0.0, &mut view);
+    assert_eq!(view, expected);
+    assert_eq!(c.slice(s![.., 1..;2]), Array2::<f64>::zeros((2, 2)));
+
+    let mut c = Array2::zeros((3, 3));
+    array::gemm(1.0, &a.t(), &a.slice(s![..;-1, ..]), 0.0, &mut c);
+    assert_eq!(c, a.t().dot(&a.slice(s![..;-1, ..])));
+
+    let big = Array2::from_shape_fn((4, 6), |(i, j)| (i * 6 + j) as f64);
+    let sub = big.slice(s![..;2, ..;2]);
+    let mut c = Array2::zeros((2, 2));
+    array::gemm(2.0, &sub, &sub.t(), 0.0, &mut c);
+    assert_eq!(c, sub.dot(&sub.t()) * 2.0);
+}
+
+#[test]
+fn strided_views_are_borrowed() {
+    let x = array![1.0f64, 2.0, 3.0, 4.0];
+
+    // the vector points into x, with ndarray's stride
+    let v = StridedVec::from(x.slice(s![..;-2]));
+    assert_eq!(v.stride(), -2);
+    assert!(std::ptr::eq(v.get(0).unwrap(), &x[3]));
+    assert_eq!(v.iter().copied().collect::<Vec<_>>(), [4.0, 2.0]);
+
+    let mut y = x.clone();
+    let mut v = StridedVecMut::from(y.slice_mut(s![1..;2]));
+    assert_eq!(v.stride(), 2);
+    v.scal(0.0);
+    assert_eq!(y, array![1.0, 0.0, 3.0, 0.0]);
+
+    // a block of a column-major matrix, and the matrix with its rows reversed
+    let a = Array2::from_shape_fn((4, 3).f(), |(i, j)| (3 * i + j) as f64);
+    let m = MatRef::from(a.slice(s![1..3, 1..3]));
+    assert!(std::ptr::eq(m.get(0, 0).unwrap(), &a[[1, 1]]));
+    let m = MatRef::from(a.slice(s![..;-1, ..]));
+    assert!(std::ptr::eq(m.get(0, 1).unwrap(), &a[[3, 1]]));
+    // even one with no unit stride, which only gemm copies
+    let m = MatRef::from(a.slice(s![..;2, ..;2]));
+    assert!(std::ptr::eq(m.get(1, 1).unwrap(), &a[[2, 2]]));
+
+    let mut a = a;
+    let mut m = MatMut::from(a.slice_mut(s![1..3, ..;2]).reversed_axes());
+    *m.get_mut(1, 0).unwrap() = -1.0;
+    assert_eq!(a[[1, 2]], -1.0);
+}
@@ -0,0 +1,246 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use naive_blas::lapack::{
+    gbsv::gbsv, gbtrf::gbtrf, gbtrs::gbtrs, gtsv::gtsv, ptsv::ptsv, LapackError,
+};
+use num_complex::Complex;
+
+use common::{assert_matrix_close, mul, vector, vector_mut, Rng};
+
+type C = Complex<f64>;
+
+const ZERO: C = C::new(0.0, 0.0);
+
+/// Returns op(A) * X for the n by n matrix A given as a function, X being n by nrhs with
+/// leading dimension ldx, with B in the same layout.
+fn rhs(n: usize, a: impl Fn(usize, usize) -> C, trans: char, x: &[C], ldx: usize) -> Vec<C> {
+    let nrhs = x.len() / ldx;
+    let op = |i: usize, j: usize| match trans {
+        'N' => a(i, j),
+        'T' => a(j, i),
+        _ => a(j, i).conj(),
+    };
+    let ax = mul(n, nrhs, n, op, |l, j| x[l + j * ldx]);
+    let mut b = vec![ZERO; ldx * nrhs];
+    for j in 0..nrhs {
+        b[j * ldx..j * ldx + n].copy_from_slice(&ax[j * n..(j + 1) * n]);
+    }
+    b
+}
+
+#[test]
+fn gtsv_solves_tridiagonal_systems() {
+    let mut rng = Rng::new(46);
+
+    for n in [1, 2, 3, 10, 50] {
+        let (dl, d, du) = (
+            rng.complex_vec(n - 1),
+            rng.complex_vec(n),
+            rng.complex_vec(n - 1),
+        );
+        let a = |i: usize, j: usize| match i as isize - j as isize {
+            0 => d[i],
+            1 => dl[j],
+            -1 => du[i],
+            _ => ZERO,
+        };
+        let (ldb, nrhs) = (n + 2, 3);
+        let x = rng.complex_vec(ldb * nrhs);
+        let mut b = rhs(n, a, 'N', &x, ldb);
+
+        let (mut dl, mut d, mut du) = (dl.clone(), d.clone(), du.clone());
+        gtsv(
+            &(n as isize),
+            &(nrhs as isize),
+            &mut vector_mut(&mut dl),
+            &mut vector_mut(&mut d),
+            &mut vector_mut(&mut du),
+            &mut vector_mut(&mut b),
+            &(ldb as isize),
+        )
+        .unwrap();
+        assert_matrix_close(n, nrhs, &b, ldb, &x, ldb, 1e-9);
+    }
+
+    // a zero pivot even with the interchanges
+    let mut b = [1.0f64, 1.0];
+    assert_eq!(
+        gtsv(
+            &2,
+            &1,
+            &mut vector_mut(&mut [0.0]),
+            &mut vector_mut(&mut [1.0, 0.0]),
+            &mut vector_mut(&mut [1.0]),
+            &mut vector_mut(&mut b),
+            &2
+        ),
+        Err(LapackError::Singular(1))
+    );
+    assert_eq!(
+        gtsv(
+            &2,
+            &1,
+            &mut vector_mut(&mut [0.0]),
+            &mut vector_mut(&mut [1.0, 1.0]),
+            &mut vector_mut(&mut [0.0]),
+            &mut vector_mut(&mut b),
+            &1
+        ),
+        Err(LapackError::Argument(7))
+    );
+}
+
+#[test]
+fn ptsv_solves_positive_definite_tridiagonal_systems() {
+    let mut rng = Rng::new(47);
+
+    for n in [1, 2, 3, 10, 50] {
+        // diagonally dominant, so positive definite
+        let d: Vec<f64> = (0..n).map(|_| 3.0 + rng.next()).collect();
+        let e = rng.complex_vec(n - 1);
+        let a = |i: usize, j: usize| match i as isize - j as isize {
+            0 => C::from(d[i]),
+            1 => e[j],
+            -1 => e[i].conj(),
+            _ => ZERO,
+        };
+        let (ldb, nrhs) = (n + 1, 2);
+        let x = rng.complex_vec(ldb * nrhs);
+        let mut b = rhs(n, a, 'N', &x, ldb);
+
+        let (mut d, mut e) = (d.clone(), e.clone());
+        ptsv(
+            &(n as isize),
+            &(nrhs as isize),
+            &mut vector_mut(&mut d),
+            &mut vector_mut(&mut e),
+            &mut vector_mut(&mut b),
+            &(ldb as isize),
+        )
+        .unwrap();
+        assert_matrix_close(n, nrhs, &b, ldb, &x, ldb, 1e-10);
+    }
+
+    // the leading minor of order 2 is zero, and b is left alone
+    let mut b = [1.0f64; 3];
+    assert_eq!(
+        ptsv::<f64>(
+            &3,
+            &1,
+            &mut vector_mut(&mut [1.0, 1.0, 1.0]),
+            &mut vector_mut(&mut [1.0, 0.0]),
+            &mut vector_mut(&mut b),
+            &3
+        ),
+        Err(LapackError::NotPositiveDefinite(1))
+    );
+    assert_eq!(b, [1.0; 3]);
+}
+
+#[test]
+fn gbtrs_and_gbsv_solve_with_every_op() {
+    let mut rng = Rng::new(48);
+
+    for n in [1, 2, 5, 12, 30] {
+        for (kl, ku) in [(0, 0), (1, 0), (0, 1), (2, 1), (1, 3), (6, 4)] {
+            let (kv, ldab) = (kl + ku, 2 * kl + ku + 3);
+            let full = rng.complex_vec(n * n);
+            let in_band = |i: usize, j: usize| i + ku >= j && j + kl >= i;
+            let a = |i: usize, j: usize| if in_band(i, j) { full[i + j * n] } else { ZERO };
+
+            // the first kl rows are workspace and the two last padding, neither of which
+            // should be read
+            let mut ab = vec![C::new(f64::NAN, f64::NAN); ldab * n];
+            for j in 0..n {
+                for i in (0..n).filter(|&i| in_band(i, j)) {
+                    ab[kv + i - j + j * ldab] = a(i, j);
+                }
+            }
+
+            let (ldb, nrhs) = (n + 1, 3);
+            let x = rng.complex_vec(ldb * nrhs);
+
+            let mut lu = ab.clone();
+            let mut ipiv = vec![0; n];
+            gbtrf(
+                &(n as isize),
+                &(n as isize),
+                &(kl as isize),
+                &(ku as isize),
+                &mut vector_mut(&mut lu),
+                &(ldab as isize),
+                &mut vector_mut(&mut ipiv),
+            )
+            .unwrap();
+
+            for trans in ['N', 'T', 'C'] {
+                let mut b = rhs(n, a, trans, &x, ldb);
+                gbtrs(
+                    &trans,
+                    &(n as isize),
+                    &(kl as isize),
+                    &(ku as isize),
+                    &(nrhs as isize),
+                    &vector(&lu),
+                    &(ldab as isize),
+                    &vector(&ipiv),
+                    &mut vector_mut(&mut b),
+                    &(ldb as isize),
+                )
+                .unwrap();
+                assert_matrix_close(n, nrhs, &b, ldb, &x, ldb, 1e-8);
+            }
+
+            let mut b = rhs(n, a, 'N', &x, ldb);
+            gbsv(
+                &(n as isize),
+                &(kl as isize),
+                &(ku as isize),
+                &(nrhs as isize),
+                &mut vector_mut(&mut ab),
+                &(ldab as isize),
+                &mut vector_mut(&mut ipiv),
+                &mut vector_mut(&mut b),
+                &(ldb as isize),
+            )
+            .unwrap();
+            assert_matrix_close(n, nrhs, &b, ldb, &x, ldb, 1e-8);
+        }
+    }
+}
+
+#[test]
+fn singular_band_matrices_are_reported() {
+    // a tridiagonal matrix with a zero third column
+    let (n, ldab) = (4, 4);
+    let mut ab = vec![1.0f64; ldab * n];
+    ab[2 * ldab..3 * ldab].fill(0.0);
+    let mut ipiv = [0; 4];
+    assert_eq!(
+        gbtrf(
+            &4,
+            &4,
+            &1,
+            &1,
+            &mut vector_mut(&mut ab),
+            &(ldab as isize),
+            &mut vector_mut(&mut ipiv)
+        ),
+        Err(LapackError::Singular(2))
+    );
+
+    assert_eq!(
+        gbtrf(
+            &4,
+            &4,
+            &1,
+            &1,
+            &mut vector_mut(&mut [0.0f64; 12]),
+            &3,
+            &mut vector_mut(&mut ipiv)
+        ),
+        Err(LapackError::Argument(6))
+    );
+}
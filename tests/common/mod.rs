@@ -0,0 +1,106 @@
+//! Helpers shared by the integration tests.
+
+#![allow(dead_code)]
+
+use nd_slice::{NdSlice, NdSliceMut};
+use num_complex::Complex;
+
+/// Wraps a slice in a 1-dimensional `NdSlice`.
+pub fn vector<T>(x: &[T]) -> NdSlice<'_, T, 1> {
+    NdSlice::new_row_ordered(x, [x.len()]).unwrap_or_else(|_| unreachable!())
+}
+
+/// Wraps a slice in a 1-dimensional `NdSliceMut`.
+pub fn vector_mut<T>(x: &mut [T]) -> NdSliceMut<'_, T, 1> {
+    let n = x.len();
+    NdSliceMut::new_row_ordered(x, [n]).unwrap_or_else(|_| unreachable!())
+}
+
+/// A linear congruential generator, so that the tests are reproducible.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    /// Returns a number uniformly distributed in [-1, 1).
+    pub fn next(&mut self) -> f64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((self.0 >> 11) as f64 / (1u64 << 53) as f64) * 2.0 - 1.0
+    }
+
+    pub fn complex(&mut self) -> Complex<f64> {
+        Complex::new(self.next(), self.next())
+    }
+
+    pub fn vec(&mut self, n: usize) -> Vec<f64> {
+        (0..n).map(|_| self.next()).collect()
+    }
+
+    pub fn complex_vec(&mut self, n: usize) -> Vec<Complex<f64>> {
+        (0..n).map(|_| self.complex()).collect()
+    }
+}
+
+/// Asserts that |a - b| <= tol * max(1, |b|).
+#[track_caller]
+pub fn assert_close(a: f64, b: f64, tol: f64) {
+    assert!(
+        (a - b).abs() <= tol * b.abs().max(1.0),
+        "{} and {} differ by more than {}",
+        a,
+        b,
+        tol
+    );
+}
+
+/// Returns the column-major m by n product of op(A) and op(B), given as functions of the row
+/// and column indices, k being the inner dimension.
+pub fn mul<T, F, G>(m: usize, n: usize, k: usize, a: F, b: G) -> Vec<T>
+where
+    T: Copy + num_traits::Zero + std::ops::Mul<Output = T>,
+    F: Fn(usize, usize) -> T,
+    G: Fn(usize, usize) -> T,
+{
+    let mut c = Vec::with_capacity(m * n);
+    for j in 0..n {
+        for i in 0..m {
+            c.push((0..k).fold(T::zero(), |s, l| s + a(i, l) * b(l, j)));
+        }
+    }
+    c
+}
+
+/// Asserts that the m by n column-major matrices a and b, with leading dimensions lda and ldb,
+/// differ by at most tol in every element, relative to the largest element of b.
+#[track_caller]
+pub fn assert_matrix_close(
+    m: usize,
+    n: usize,
+    a: &[Complex<f64>],
+    lda: usize,
+    b: &[Complex<f64>],
+    ldb: usize,
+    tol: f64,
+) {
+    let scale = (0..n)
+        .flat_map(|j| (0..m).map(move |i| b[i + j * ldb].norm()))
+        .fold(1.0, f64::max);
+    for j in 0..n {
+        for i in 0..m {
+            let (x, y) = (a[i + j * lda], b[i + j * ldb]);
+            assert!(
+                (x - y).norm() <= tol * scale,
+                "{} and {} differ at ({}, {})",
+                x,
+                y,
+                i,
+                j
+            );
+        }
+    }
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use naive_blas::extensions::omatcopy::omatcopy;
+
+use common::{vector, vector_mut};
+
+/// Returns alpha times the transpose of the column-major rows by cols matrix a.
+fn transpose(rows: usize, cols: usize, alpha: f64, a: &[f64], lda: usize) -> Vec<f64> {
+    let mut b = vec![0.0; rows * cols];
+    for j in 0..cols {
+        for i in 0..rows {
+            b[j + i * cols] = alpha * a[i + j * lda];
+        }
+    }
+    b
+}
+
+#[test]
+fn omatcopy_copies_and_transposes() {
+    // 2 by 3 column-major with lda = 3, the padding being -1
+    let a = [1.0, 2.0, -1.0, 3.0, 4.0, -1.0, 5.0, 6.0, -1.0];
+
+    let mut b = [0.0; 6];
+    omatcopy(
+        &'C',
+        &'N',
+        &2,
+        &3,
+        &2.0,
+        &vector(&a),
+        &3,
+        &mut vector_mut(&mut b),
+        &2,
+    );
+    assert_eq!(b, [2.0, 4.0, 6.0, 8.0, 10.0, 12.0]);
+
+    let mut b = [0.0; 6];
+    omatcopy(
+        &'C',
+        &'T',
+        &2,
+        &3,
+        &1.0,
+        &vector(&a),
+        &3,
+        &mut vector_mut(&mut b),
+        &3,
+    );
+    assert_eq!(b.to_vec(), transpose(2, 3, 1.0, &a, 3));
+
+    // the same memory read as a row-major 3 by 2 matrix
+    let mut b = [0.0; 6];
+    omatcopy(
+        &'R',
+        &'T',
+        &3,
+        &2,
+        &1.0,
+        &vector(&a),
+        &3,
+        &mut vector_mut(&mut b),
+        &3,
+    );
+    assert_eq!(b.to_vec(), transpose(2, 3, 1.0, &a, 3));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn imatcopy_matches_omatcopy() {
+    use naive_blas::extensions::imatcopy::imatcopy;
+
+    let a: Vec<f64> = (1..=20).map(f64::from).collect();
+
+    // (trans, rows, cols, lda, ldb): square in place, changing leading dimensions either way and
+    // a rectangular transpose through the temporary
+    let cases = [
+        ('T', 4, 4, 4, 4),
+        ('T', 3, 3, 5, 3),
+        ('N', 3, 4, 5, 3),
+        ('N', 3, 4, 3, 5),
+        ('T', 2, 5, 4, 5),
+        ('T', 4, 3, 4, 3),
+    ];
+    for &(trans, rows, cols, lda, ldb) in &cases {
+        let mut expected = a.clone();
+        omatcopy(
+            &'C',
+            &trans,
+            &rows,
+            &cols,
+            &-0.5,
+            &vector(&a),
+            &lda,
+            &mut vector_mut(&mut expected),
+            &ldb,
+        );
+
+        let mut ab = a.clone();
+        imatcopy(
+            &'C',
+            &trans,
+            &rows,
+            &cols,
+            &-0.5,
+            &mut vector_mut(&mut ab),
+            &lda,
+            &ldb,
+        );
+
+        let (brows, bcols) = if trans == 'N' {
+            (rows, cols)
+        } else {
+            (cols, rows)
+        };
+        for j in 0..bcols as usize {
+            for i in 0..brows as usize {
+                let k = i + j * ldb as usize;
+                assert_eq!(ab[k], expected[k], "{:?}", (trans, rows, cols, lda, ldb));
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use naive_blas::level_3::gemm::gemm;
+use naive_blas::level_3::gemm_batch::{gemm_batch, gemm_batch_strided};
+
+use common::{vector, vector_mut, Rng};
+
+#[test]
+fn gemm_batch_matches_gemm() {
+    let mut rng = Rng::new(30);
+
+    // two groups of different shapes, plus an empty group whose dimensions would overflow a
+    // work estimate computed without saturating
+    let transa = ['N', 'T', 'N'];
+    let transb = ['T', 'N', 'N'];
+    let (m, n, k) = ([3, 2, isize::MAX], [4, 5, isize::MAX], [2, 3, isize::MAX]);
+    let (alpha, beta) = ([1.5, -0.5, 1.0], [0.0, 2.0, 0.0]);
+    let (lda, ldb, ldc) = ([4, 3, 1], [5, 3, 1], [3, 2, 1]);
+    let group_size = [2, 3, 0];
+
+    let mut a = Vec::new();
+    let mut b = Vec::new();
+    let mut c = Vec::new();
+    for (g, &size) in group_size.iter().enumerate() {
+        for _ in 0..size {
+            let cols_a = if transa[g] == 'N' { k[g] } else { m[g] };
+            let cols_b = if transb[g] == 'N' { n[g] } else { k[g] };
+            a.push(rng.vec((lda[g] * cols_a) as usize));
+            b.push(rng.vec((ldb[g] * cols_b) as usize));
+            c.push(rng.vec((ldc[g] * n[g]) as usize));
+        }
+    }
+
+    let mut expected = c.clone();
+    let mut p = 0;
+    for (g, &size) in group_size.iter().enumerate() {
+        for _ in 0..size {
+            gemm(
+                &transa[g],
+                &transb[g],
+                &m[g],
+                &n[g],
+                &k[g],
+                &alpha[g],
+                &vector(&a[p]),
+                &lda[g],
+                &vector(&b[p]),
+                &ldb[g],
+                &beta[g],
+                &mut vector_mut(&mut expected[p]),
+                &ldc[g],
+            );
+            p += 1;
+        }
+    }
+
+    let a_views: Vec<_> = a.iter().map(|a| vector(a)).collect();
+    let b_views: Vec<_> = b.iter().map(|b| vector(b)).collect();
+    let mut c_views: Vec<_> = c.iter_mut().map(|c| vector_mut(c)).collect();
+    gemm_batch(
+        &transa,
+        &transb,
+        &m,
+        &n,
+        &k,
+        &alpha,
+        &a_views,
+        &lda,
+        &b_views,
+        &ldb,
+        &beta,
+        &mut c_views,
+        &ldc,
+        &group_size,
+    );
+    drop(c_views);
+
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn gemm_batch_strided_matches_gemm() {
+    let mut rng = Rng::new(31);
+
+    let (m, n, k, batch_size) = (3, 2, 4, 5);
+    let (lda, ldb, ldc) = (4, 4, 3);
+    let (stridea, strideb, stridec) = (lda * m + 1, ldb * n, ldc * n + 2);
+
+    let a = rng.vec((stridea * batch_size) as usize);
+    let b = rng.vec((strideb * batch_size) as usize);
+    let mut c = rng.vec((stridec * batch_size) as usize);
+
+    let mut expected = c.clone();
+    for p in 0..batch_size as usize {
+        gemm(
+            &'T',
+            &'N',
+            &m,
+            &n,
+            &k,
+            &2.0,
+            &vector(&a[p * stridea as usize..]),
+            &lda,
+            &vector(&b[p * strideb as usize..]),
+            &ldb,
+            &-1.0,
+            &mut vector_mut(&mut expected[p * stridec as usize..]),
+            &ldc,
+        );
+    }
+
+    gemm_batch_strided(
+        &'T',
+        &'N',
+        &m,
+        &n,
+        &k,
+        &2.0,
+        &vector(&a),
+        &lda,
+        &stridea,
+        &vector(&b),
+        &ldb,
+        &strideb,
+        &-1.0,
+        &mut vector_mut(&mut c),
+        &ldc,
+        &stridec,
+        &batch_size,
+    );
+
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn gemm_batch_strided_rejects_overlapping_c() {
+    let a = [1.0; 4];
+    let b = [1.0; 4];
+    let mut c = [0.0; 8];
+
+    // stridec below ldc * n, which would have overflowed before being compared
+    gemm_batch_strided(
+        &'N',
+        &'N',
+        &1,
+        &isize::MAX,
+        &1,
+        &1.0,
+        &vector(&a),
+        &1,
+        &1,
+        &vector(&b),
+        &1,
+        &1,
+        &0.0,
+        &mut vector_mut(&mut c),
+        &2,
+        &2,
+        &2,
+    );
+
+    assert_eq!(c, [0.0; 8]);
+}
@@ -0,0 +1,182 @@
+mod common;
+
+use naive_blas::level_3::gemm_s32::{gemm_s32, requantize_s8};
+
+use common::{vector, vector_mut, Rng};
+
+#[test]
+fn matches_the_exact_product() {
+    let mut rng = Rng::new(1);
+    let (m, n, k) = (3, 4, 5);
+    let a: Vec<u8> = (0..m * k)
+        .map(|_| (rng.next() * 127.0 + 128.0) as u8)
+        .collect();
+    let b: Vec<i8> = (0..k * n).map(|_| (rng.next() * 127.0) as i8).collect();
+    let (ao, bo) = (128, -3);
+
+    // Aᵀ and Bᵀ
+    let at: Vec<u8> = (0..m * k).map(|p| a[p / k + (p % k) * m]).collect();
+    let bt: Vec<i8> = (0..k * n).map(|p| b[p / n + (p % n) * k]).collect();
+
+    let product = |i: usize, j: usize| {
+        (0..k)
+            .map(|l| (a[i + l * m] as i32 - ao) * (b[l + j * k] as i32 - bo))
+            .sum::<i32>()
+    };
+    let co = [5, -6, 7, -8];
+
+    for &(transa, transb) in &[('N', 'N'), ('T', 'N'), ('N', 'T'), ('T', 'T')] {
+        for &offsetc in &['F', 'C', 'R'] {
+            let (a, lda) = if transa == 'N' { (&a, m) } else { (&at, k) };
+            let (b, ldb) = if transb == 'N' { (&b, k) } else { (&bt, n) };
+
+            let mut c = vec![i32::MIN; m * n];
+            gemm_s32(
+                &transa,
+                &transb,
+                &offsetc,
+                &(m as isize),
+                &(n as isize),
+                &(k as isize),
+                &1.0,
+                &vector(a),
+                &(lda as isize),
+                &ao,
+                &vector(b),
+                &(ldb as isize),
+                &bo,
+                &0.0,
+                &mut vector_mut(&mut c),
+                &(m as isize),
+                &vector(&co),
+            );
+
+            for j in 0..n {
+                for i in 0..m {
+                    let offset = match offsetc {
+                        'F' => co[0],
+                        'C' => co[i],
+                        _ => co[j],
+                    };
+                    assert_eq!(c[i + j * m], product(i, j) + offset);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn scales_rounds_and_saturates() {
+    let a = [10i8, 20];
+    let b = [3i8, 1];
+    let mut c = [7, 9];
+
+    // C = 0.25 * A * B + 0.5 * C, A 2 by 1 and B 1 by 2
+    gemm_s32(
+        &'N',
+        &'N',
+        &'F',
+        &2,
+        &1,
+        &1,
+        &0.25,
+        &vector(&a),
+        &2,
+        &0,
+        &vector(&b),
+        &1,
+        &0,
+        &0.5,
+        &mut vector_mut(&mut c),
+        &2,
+        &vector(&[0]),
+    );
+    assert_eq!(c, [11, 20]);
+
+    let a = [1i8];
+    let mut c = [i32::MAX];
+    gemm_s32(
+        &'N',
+        &'N',
+        &'F',
+        &1,
+        &1,
+        &1,
+        &1.0,
+        &vector(&a),
+        &1,
+        &0,
+        &vector(&a),
+        &1,
+        &0,
+        &1.0,
+        &mut vector_mut(&mut c),
+        &1,
+        &vector(&[0]),
+    );
+    assert_eq!(c, [i32::MAX]);
+}
+
+#[test]
+fn wraps_around_on_overflow() {
+    // 70000 * 255 * 127 does not fit in an i32
+    let k = 70_000;
+    let a = vec![u8::MAX; k];
+    let b = vec![i8::MAX; k];
+    let mut c = [0];
+
+    gemm_s32(
+        &'N',
+        &'N',
+        &'F',
+        &1,
+        &1,
+        &(k as isize),
+        &1.0,
+        &vector(&a),
+        &1,
+        &0,
+        &vector(&b),
+        &(k as isize),
+        &0,
+        &0.0,
+        &mut vector_mut(&mut c),
+        &1,
+        &vector(&[i32::MAX]),
+    );
+
+    let exact = k as i64 * 255 * 127 + i32::MAX as i64;
+    assert_eq!(c[0], exact as i32);
+}
+
+#[test]
+fn requantizes_with_rounding_and_clamping() {
+    let c = [100, -100, 1000, 3];
+    let mut q = [0i8; 4];
+
+    requantize_s8(
+        &'R',
+        &2,
+        &2,
+        &vector(&c),
+        &2,
+        &vector(&[0.5, 0.1]),
+        &-1,
+        &mut vector_mut(&mut q),
+        &2,
+    );
+    assert_eq!(q, [49, -51, 99, -1]);
+
+    requantize_s8(
+        &'F',
+        &4,
+        &1,
+        &vector(&c),
+        &4,
+        &vector(&[1.0]),
+        &0,
+        &mut vector_mut(&mut q),
+        &4,
+    );
+    assert_eq!(q, [100, -100, 127, 3]);
+}
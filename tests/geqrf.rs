@@ -0,0 +1,273 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use naive_blas::lapack::{gels::gels, geqrf::geqrf, orgqr::ungqr, ormqr::unmqr, LapackError};
+use num_complex::Complex;
+
+use common::{assert_matrix_close, mul, vector, vector_mut, Rng};
+
+type C = Complex<f64>;
+
+const ZERO: C = C::new(0.0, 0.0);
+const ONE: C = C::new(1.0, 0.0);
+
+/// Factors the m by n matrix a0, returning the factorization and tau.
+fn qr(m: usize, n: usize, a0: &[C], lda: usize) -> (Vec<C>, Vec<C>) {
+    let mut a = a0.to_vec();
+    let mut tau = vec![ZERO; m.min(n)];
+    geqrf(
+        &(m as isize),
+        &(n as isize),
+        &mut vector_mut(&mut a),
+        &(lda as isize),
+        &mut vector_mut(&mut tau),
+    )
+    .unwrap();
+    (a, tau)
+}
+
+/// Returns the first q columns of the m by m matrix Q of a QR factorization with k reflectors,
+/// with leading dimension m.
+fn form_q(m: usize, q: usize, k: usize, a: &[C], lda: usize, tau: &[C]) -> Vec<C> {
+    let mut qm = vec![ZERO; m * q];
+    for j in 0..k {
+        qm[j * m..(j + 1) * m].copy_from_slice(&a[j * lda..j * lda + m]);
+    }
+    ungqr(
+        &(m as isize),
+        &(q as isize),
+        &(k as isize),
+        &mut vector_mut(&mut qm),
+        &(m as isize),
+        &vector(tau),
+    )
+    .unwrap();
+    qm
+}
+
+#[test]
+fn geqrf_and_ungqr_reproduce_a() {
+    let mut rng = Rng::new(44);
+
+    // the unblocked and blocked paths, with the blocked one ending on a partial block
+    for (m, n) in [(1, 1), (5, 3), (3, 5), (70, 50), (40, 60), (100, 100)] {
+        let lda = m + 2;
+        let a0 = rng.complex_vec(lda * n);
+        let (a, tau) = qr(m, n, &a0, lda);
+        let k = m.min(n);
+
+        let q = form_q(m, k, k, &a, lda, &tau);
+        let r = |i: usize, j: usize| if i <= j { a[i + j * lda] } else { ZERO };
+        let qr = mul(m, n, k, |i, l| q[i + l * m], r);
+        assert_matrix_close(m, n, &qr, m, &a0, lda, 1e-12);
+
+        let qhq = mul(k, k, m, |i, l| q[l + i * m].conj(), |l, j| q[l + j * m]);
+        let eye: Vec<C> = (0..k * k)
+            .map(|p| if p % (k + 1) == 0 { ONE } else { ZERO })
+            .collect();
+        assert_matrix_close(k, k, &qhq, k, &eye, k, 1e-12);
+    }
+}
+
+#[test]
+fn unmqr_matches_the_formed_q() {
+    let mut rng = Rng::new(45);
+
+    for (m, n, k) in [(6, 4, 3), (70, 45, 40)] {
+        let (lda, ldc) = (m + 1, m + 3);
+        let a0 = rng.complex_vec(lda * k);
+        let (a, tau) = qr(m, k, &a0, lda);
+        let q = form_q(m, m, k, &a, lda, &tau);
+
+        for side in ['L', 'R'] {
+            // C is m by n for side = 'L' and n by m otherwise
+            let (cm, cn) = if side == 'L' { (m, n) } else { (n, m) };
+            let c0 = rng.complex_vec(ldc * cn);
+
+            for trans in ['N', 'C'] {
+                let op = |i: usize, j: usize| {
+                    if trans == 'N' {
+                        q[i + j * m]
+                    } else {
+                        q[j + i * m].conj()
+                    }
+                };
+                let cf = |i: usize, j: usize| c0[i + j * ldc];
+                let expected = if side == 'L' {
+                    mul(cm, cn, m, op, cf)
+                } else {
+                    mul(cm, cn, m, cf, op)
+                };
+
+                let mut c = c0.clone();
+                unmqr(
+                    &side,
+                    &trans,
+                    &(cm as isize),
+                    &(cn as isize),
+                    &(k as isize),
+                    &vector(&a),
+                    &(lda as isize),
+                    &vector(&tau),
+                    &mut vector_mut(&mut c),
+                    &(ldc as isize),
+                )
+                .unwrap();
+                assert_matrix_close(cm, cn, &c, ldc, &expected, cm, 1e-12);
+            }
+        }
+    }
+}
+
+#[test]
+fn gels_solves_least_squares_and_minimum_norm_problems() {
+    let mut rng = Rng::new(46);
+
+    for (m, n) in [(6, 4), (4, 6), (60, 40), (40, 60)] {
+        let lda = m + 1;
+        let a0 = rng.complex_vec(lda * n);
+        let ldb = m.max(n) + 2;
+        let nrhs = 2;
+
+        for trans in ['N', 'C'] {
+            let op = |i: usize, j: usize| {
+                if trans == 'N' {
+                    a0[i + j * lda]
+                } else {
+                    a0[j + i * lda].conj()
+                }
+            };
+            let (rows, cols) = if trans == 'N' { (m, n) } else { (n, m) };
+
+            let mut a = a0.clone();
+            let mut b = rng.complex_vec(ldb * nrhs);
+            let b0 = b.clone();
+            if rows < cols {
+                // b = op(A) * op(A)ᴴ * y, whose solution of minimum norm is op(A)ᴴ * y
+                let y = rng.complex_vec(rows * nrhs);
+                let x = mul(
+                    cols,
+                    nrhs,
+                    rows,
+                    |i, l| op(l, i).conj(),
+                    |l, j| y[l + j * rows],
+                );
+                let ax = mul(rows, nrhs, cols, op, |l, j| x[l + j * cols]);
+                for j in 0..nrhs {
+                    b[j * ldb..j * ldb + rows].copy_from_slice(&ax[j * rows..(j + 1) * rows]);
+                }
+                gels(
+                    &trans,
+                    &(m as isize),
+                    &(n as isize),
+                    &(nrhs as isize),
+                    &mut vector_mut(&mut a),
+                    &(lda as isize),
+                    &mut vector_mut(&mut b),
+                    &(ldb as isize),
+                )
+                .unwrap();
+                assert_matrix_close(cols, nrhs, &b, ldb, &x, cols, 1e-10);
+            } else {
+                gels(
+                    &trans,
+                    &(m as isize),
+                    &(n as isize),
+                    &(nrhs as isize),
+                    &mut vector_mut(&mut a),
+                    &(lda as isize),
+                    &mut vector_mut(&mut b),
+                    &(ldb as isize),
+                )
+                .unwrap();
+
+                // the residual is orthogonal to the columns of op(A)
+                let x = |i: usize, j: usize| b[i + j * ldb];
+                let ax = mul(rows, nrhs, cols, op, x);
+                let r: Vec<C> = (0..rows * nrhs)
+                    .map(|p| ax[p] - b0[p % rows + p / rows * ldb])
+                    .collect();
+                let normal = mul(
+                    cols,
+                    nrhs,
+                    rows,
+                    |i, l| op(l, i).conj(),
+                    |l, j| r[l + j * rows],
+                );
+                assert_matrix_close(
+                    cols,
+                    nrhs,
+                    &normal,
+                    cols,
+                    &vec![ZERO; cols * nrhs],
+                    cols,
+                    1e-10,
+                );
+
+                // the squared norms of the rows below X sum to the squared residual
+                for j in 0..nrhs {
+                    let tail: f64 = (cols..rows).map(|i| b[i + j * ldb].norm_sqr()).sum();
+                    let res: f64 = (0..rows).map(|i| r[i + j * rows].norm_sqr()).sum();
+                    common::assert_close(tail, res, 1e-10);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn gels_reports_rank_deficiency() {
+    // only an exact zero on the diagonal of R is detected, as with a zero column
+    let mut a = [1.0f64, 2.0, 3.0, 0.0, 0.0, 0.0];
+    let mut b = [1.0, 1.0, 1.0];
+    assert_eq!(
+        gels(
+            &'N',
+            &3,
+            &2,
+            &1,
+            &mut vector_mut(&mut a),
+            &3,
+            &mut vector_mut(&mut b),
+            &3
+        ),
+        Err(LapackError::Singular(1))
+    );
+    assert_eq!(b, [1.0; 3]);
+
+    // real matrices go through the same code, with 'T' for the transpose
+    let mut a = [1.0f64, 1.0, 1.0, 0.0, 1.0, 2.0];
+    let mut b = [1.0, 2.0, 2.0];
+    gels(
+        &'N',
+        &3,
+        &2,
+        &1,
+        &mut vector_mut(&mut a),
+        &3,
+        &mut vector_mut(&mut b),
+        &3,
+    )
+    .unwrap();
+    common::assert_close(b[0], 7.0 / 6.0, 1e-12);
+    common::assert_close(b[1], 0.5, 1e-12);
+
+    let mut a = [1.0f64, 1.0, 1.0, 0.0, 1.0, 2.0];
+    let mut b = [3.0, 3.0, 0.0];
+    gels(
+        &'T',
+        &3,
+        &2,
+        &1,
+        &mut vector_mut(&mut a),
+        &3,
+        &mut vector_mut(&mut b),
+        &3,
+    )
+    .unwrap();
+    // the solution of minimum norm of x0 + x1 + x2 = 3, x1 + 2 x2 = 3
+    for (b, x) in b.iter().zip([1.0, 1.0, 1.0]) {
+        common::assert_close(*b, x, 1e-12);
+    }
+}
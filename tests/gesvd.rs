@@ -0,0 +1,363 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use naive_blas::lapack::{bdsqr::bdsqr, gebrd::gebrd, gesvd::gesvd, LapackError};
+use num_complex::Complex;
+
+use common::{mul, vector_mut, Rng};
+
+type C = Complex<f64>;
+
+/// Returns an m by n matrix of the given kind: random, zero, diagonal with graded singular
+/// values, random and scaled close to overflow or underflow, or real of rank 2.
+fn matrix(rng: &mut Rng, m: usize, n: usize, kind: usize) -> Vec<C> {
+    let mut a = Vec::with_capacity(m * n);
+    for j in 0..n {
+        for i in 0..m {
+            a.push(match kind {
+                0 => rng.complex(),
+                1 => C::new(0.0, 0.0),
+                2 if i == j => C::from(graded(i)),
+                2 => C::new(0.0, 0.0),
+                3 => rng.complex() * 1e300,
+                4 => rng.complex() * 1e-300,
+                _ => C::from(((i + 1) * (j % 2 + 1)) as f64 + j as f64 * 0.5),
+            });
+        }
+    }
+    a
+}
+
+/// The singular value i of the graded matrices, 10⁻³ⁱ.
+fn graded(i: usize) -> f64 {
+    10f64.powi(-3 * i as i32)
+}
+
+/// Returns the 2-norm of x, scaled so that it neither overflows nor underflows.
+fn norm(x: &[C]) -> f64 {
+    let scale = x.iter().map(|x| x.norm()).fold(0.0, f64::max);
+    if scale == 0.0 {
+        return 0.0;
+    }
+    scale * x.iter().map(|x| (x / scale).norm_sqr()).sum::<f64>().sqrt()
+}
+
+/// Asserts that the columns of the r by q matrix x are orthonormal.
+#[track_caller]
+fn assert_orthonormal(r: usize, q: usize, x: impl Fn(usize, usize) -> C, tol: f64) {
+    let xhx = mul(q, q, r, |i, l| x(l, i).conj(), &x);
+    for j in 0..q {
+        for i in 0..q {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!(
+                (xhx[i + j * q] - expected).norm() <= tol,
+                "{}",
+                xhx[i + j * q]
+            );
+        }
+    }
+}
+
+/// Computes the singular value decomposition of the m by n matrix a0 with the given jobs and
+/// checks it against A.
+fn check(m: usize, n: usize, a0: &[C], jobu: char, jobvt: char) {
+    let k = m.min(n);
+    let scale = a0
+        .iter()
+        .map(|x| x.norm())
+        .fold(f64::MIN_POSITIVE, f64::max);
+    let tol = 1e-13 * (m + n) as f64;
+
+    let lda = m + 1;
+    let mut a = vec![C::new(f64::NAN, f64::NAN); lda * n];
+    for j in 0..n {
+        a[j * lda..j * lda + m].copy_from_slice(&a0[j * m..(j + 1) * m]);
+    }
+
+    let ucols = match jobu {
+        'A' => m,
+        'S' => k,
+        _ => 0,
+    };
+    let vtrows = match jobvt {
+        'A' => n,
+        'S' => k,
+        _ => 0,
+    };
+    let (ldu, ldvt) = (m + 2, vtrows.max(1) + 1);
+    let mut u = vec![C::new(f64::NAN, f64::NAN); ldu * ucols.max(1)];
+    let mut vt = vec![C::new(f64::NAN, f64::NAN); ldvt * n.max(1)];
+    let mut s = vec![0.0; k];
+    gesvd(
+        &jobu,
+        &jobvt,
+        &(m as isize),
+        &(n as isize),
+        &mut vector_mut(&mut a),
+        &(lda as isize),
+        &mut vector_mut(&mut s),
+        &mut vector_mut(&mut u),
+        &(ldu as isize),
+        &mut vector_mut(&mut vt),
+        &(ldvt as isize),
+    )
+    .unwrap();
+
+    // as in LAPACK, neither U nor Vᴴ is set if A is empty
+    if k == 0 {
+        return;
+    }
+    assert!(s.iter().all(|&s| s >= 0.0));
+    assert!(s.windows(2).all(|s| s[0] >= s[1]), "{:?}", s);
+
+    let (u, vt) = (|i, j| u[i + j * ldu], |i, j| vt[i + j * ldvt]);
+    if ucols > 0 {
+        assert_orthonormal(m, ucols, u, tol);
+    }
+    if vtrows > 0 {
+        assert_orthonormal(n, vtrows, |i, j| vt(j, i).conj(), tol);
+    }
+
+    if ucols > 0 && vtrows > 0 {
+        // A = U * Σ * Vᴴ
+        let usv = mul(m, n, k, |i, l| u(i, l) * s[l], vt);
+        for (x, y) in usv.iter().zip(a0) {
+            assert!((x - y).norm() <= tol * scale, "{} {}", x, y);
+        }
+    } else {
+        // ‖Aᴴ * uᵢ‖ = ‖A * vᵢ‖ = sᵢ
+        for (i, s) in s.iter().enumerate().take(ucols.min(k)) {
+            let ahu = mul(n, 1, m, |j, l| a0[l + j * m].conj(), |l, _| u(l, i));
+            assert!(
+                (norm(&ahu) - s).abs() <= tol * scale,
+                "{} {}",
+                norm(&ahu),
+                s
+            );
+        }
+        for (i, s) in s.iter().enumerate().take(vtrows.min(k)) {
+            let av = mul(m, 1, n, |l, j| a0[l + j * m], |j, _| vt(i, j).conj());
+            assert!((norm(&av) - s).abs() <= tol * scale, "{} {}", norm(&av), s);
+        }
+    }
+
+    // the singular values alone agree with those computed along with the vectors
+    let mut a = a0.to_vec();
+    let mut s2 = vec![0.0; k];
+    gesvd(
+        &'N',
+        &'N',
+        &(m as isize),
+        &(n as isize),
+        &mut vector_mut(&mut a),
+        &(m.max(1) as isize),
+        &mut vector_mut(&mut s2),
+        &mut vector_mut(&mut []),
+        &1,
+        &mut vector_mut(&mut []),
+        &1,
+    )
+    .unwrap();
+    for (s, s2) in s.iter().zip(&s2) {
+        assert!((s - s2).abs() <= tol * scale, "{} {}", s, s2);
+    }
+}
+
+#[test]
+fn gesvd_reproduces_a() {
+    let mut rng = Rng::new(50);
+
+    for (m, n) in [
+        (0, 3),
+        (3, 0),
+        (1, 1),
+        (1, 5),
+        (5, 1),
+        (2, 2),
+        (6, 4),
+        (4, 6),
+        (10, 10),
+        (37, 20),
+        (20, 37),
+    ] {
+        for kind in 0..6 {
+            let a = matrix(&mut rng, m, n, kind);
+            for (jobu, jobvt) in [('A', 'A'), ('S', 'S'), ('A', 'N'), ('N', 'S'), ('S', 'A')] {
+                check(m, n, &a, jobu, jobvt);
+            }
+        }
+    }
+}
+
+#[test]
+fn graded_singular_values_keep_their_relative_accuracy() {
+    let mut rng = Rng::new(51);
+
+    for (m, n) in [(6, 4), (4, 6), (5, 5)] {
+        let mut a = matrix(&mut rng, m, n, 2);
+        let mut s = vec![0.0; m.min(n)];
+        gesvd(
+            &'N',
+            &'N',
+            &(m as isize),
+            &(n as isize),
+            &mut vector_mut(&mut a),
+            &(m as isize),
+            &mut vector_mut(&mut s),
+            &mut vector_mut(&mut []),
+            &1,
+            &mut vector_mut(&mut []),
+            &1,
+        )
+        .unwrap();
+        for (i, s) in s.iter().enumerate() {
+            assert!(
+                (s - graded(i)).abs() <= 1e-13 * graded(i),
+                "{} {}",
+                s,
+                graded(i)
+            );
+        }
+    }
+
+    // real matrices go through the same code: [3 0; 4 5] has singular values √45 and √5
+    let mut a = [3.0f64, 4.0, 0.0, 5.0];
+    let mut s = [0.0; 2];
+    gesvd(
+        &'N',
+        &'N',
+        &2,
+        &2,
+        &mut vector_mut(&mut a),
+        &2,
+        &mut vector_mut(&mut s),
+        &mut vector_mut(&mut []),
+        &1,
+        &mut vector_mut(&mut []),
+        &1,
+    )
+    .unwrap();
+    common::assert_close(s[0], 45f64.sqrt(), 1e-14);
+    common::assert_close(s[1], 5f64.sqrt(), 1e-14);
+}
+
+#[test]
+fn gebrd_and_bdsqr_give_the_singular_values() {
+    let mut rng = Rng::new(52);
+    let (m, n) = (5, 8);
+    let a0 = matrix(&mut rng, m, n, 0);
+
+    // m < n, so the bidiagonal form is lower
+    let mut a = a0.clone();
+    let (mut d, mut e) = (vec![0.0; m], vec![0.0; m - 1]);
+    let (mut tauq, mut taup) = (vec![C::new(0.0, 0.0); m], vec![C::new(0.0, 0.0); m]);
+    gebrd(
+        &(m as isize),
+        &(n as isize),
+        &mut vector_mut(&mut a),
+        &(m as isize),
+        &mut vector_mut(&mut d),
+        &mut vector_mut(&mut e),
+        &mut vector_mut(&mut tauq),
+        &mut vector_mut(&mut taup),
+    )
+    .unwrap();
+    bdsqr::<C>(
+        &'L',
+        &(m as isize),
+        &0,
+        &0,
+        &0,
+        &mut vector_mut(&mut d),
+        &mut vector_mut(&mut e),
+        &mut vector_mut(&mut []),
+        &1,
+        &mut vector_mut(&mut []),
+        &1,
+        &mut vector_mut(&mut []),
+        &1,
+    )
+    .unwrap();
+
+    let mut a = a0;
+    let mut s = vec![0.0; m];
+    gesvd(
+        &'N',
+        &'N',
+        &(m as isize),
+        &(n as isize),
+        &mut vector_mut(&mut a),
+        &(m as isize),
+        &mut vector_mut(&mut s),
+        &mut vector_mut(&mut []),
+        &1,
+        &mut vector_mut(&mut []),
+        &1,
+    )
+    .unwrap();
+    for (d, s) in d.iter().zip(&s) {
+        assert!((d - s).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn bdsqr_updates_u_vt_and_c() {
+    let mut rng = Rng::new(53);
+    let n = 6;
+    let (d0, e0) = (rng.vec(n), rng.vec(n - 1));
+    let eye: Vec<f64> = (0..n * n)
+        .map(|p| if p % (n + 1) == 0 { 1.0 } else { 0.0 })
+        .collect();
+
+    // starting from the identity, B = U * Σ * Vᵀ and C = Uᵀ
+    let (mut d, mut e) = (d0.clone(), e0.clone());
+    let (mut u, mut vt, mut c) = (eye.clone(), eye.clone(), eye);
+    let nn = n as isize;
+    bdsqr::<f64>(
+        &'U',
+        &nn,
+        &nn,
+        &nn,
+        &nn,
+        &mut vector_mut(&mut d),
+        &mut vector_mut(&mut e),
+        &mut vector_mut(&mut vt),
+        &nn,
+        &mut vector_mut(&mut u),
+        &nn,
+        &mut vector_mut(&mut c),
+        &nn,
+    )
+    .unwrap();
+
+    let b = |i: usize, j: usize| match j as isize - i as isize {
+        0 => d0[i],
+        1 => e0[i],
+        _ => 0.0,
+    };
+    let usv = mul(n, n, n, |i, l| u[i + l * n] * d[l], |l, j| vt[l + j * n]);
+    for j in 0..n {
+        for i in 0..n {
+            assert!((usv[i + j * n] - b(i, j)).abs() < 1e-13);
+            assert!((c[i + j * n] - u[j + i * n]).abs() < 1e-13);
+        }
+    }
+
+    assert_eq!(
+        gesvd::<f64>(
+            &'O',
+            &'N',
+            &1,
+            &1,
+            &mut vector_mut(&mut [1.0]),
+            &1,
+            &mut vector_mut(&mut [0.0]),
+            &mut vector_mut(&mut []),
+            &1,
+            &mut vector_mut(&mut []),
+            &1
+        ),
+        Err(LapackError::Argument(1))
+    );
+}
@@ -0,0 +1,231 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use naive_blas::lapack::{gesv::gesv, getrf::getrf, getrs::getrs, laswp::laswp, LapackError};
+use num_complex::Complex;
+
+use common::{assert_matrix_close, mul, vector, vector_mut, Rng};
+
+type C = Complex<f64>;
+
+/// Factors the m by n matrix a0 with getrf and checks that P * L * U is a0.
+fn check_factorization(m: usize, n: usize, a0: &[C], lda: usize) {
+    let mut a = a0.to_vec();
+    let k = m.min(n);
+    let mut ipiv = vec![0; k];
+    getrf(
+        &(m as isize),
+        &(n as isize),
+        &mut vector_mut(&mut a),
+        &(lda as isize),
+        &mut vector_mut(&mut ipiv),
+    )
+    .unwrap();
+
+    let l = |i: usize, j: usize| {
+        if i > j {
+            a[i + j * lda]
+        } else if i == j {
+            C::new(1.0, 0.0)
+        } else {
+            C::new(0.0, 0.0)
+        }
+    };
+    let u = |i: usize, j: usize| {
+        if i <= j {
+            a[i + j * lda]
+        } else {
+            C::new(0.0, 0.0)
+        }
+    };
+    let mut lu = mul(m, n, k, l, u);
+
+    // undo the interchanges, last one first
+    laswp(
+        &(n as isize),
+        &mut vector_mut(&mut lu),
+        &(m as isize),
+        &0,
+        &(k as isize),
+        &vector(&ipiv),
+        &-1,
+    );
+    assert_matrix_close(m, n, &lu, m, a0, lda, 1e-12);
+
+    // the pivots are the largest elements of their columns by |re| + |im|, as iamax measures
+    // them, which bounds the moduli of the elements of L by √2
+    for j in 0..k {
+        let bound = std::f64::consts::SQRT_2 + 1e-12;
+        assert!((j + 1..m).all(|i| a[i + j * lda].norm() <= bound));
+        assert!((j..m).contains(&(ipiv[j] as usize)));
+    }
+}
+
+#[test]
+fn getrf_factors_every_shape() {
+    let mut rng = Rng::new(42);
+
+    // the unblocked and blocked paths, with the blocked one ending on a partial block
+    for (m, n) in [
+        (1, 1),
+        (5, 5),
+        (33, 1),
+        (1, 33),
+        (70, 50),
+        (50, 70),
+        (100, 100),
+    ] {
+        let lda = m + 3;
+        let a0 = rng.complex_vec(lda * n);
+        check_factorization(m, n, &a0, lda);
+    }
+}
+
+#[test]
+fn getrs_solves_with_every_op() {
+    let mut rng = Rng::new(43);
+
+    for n in [4, 40, 90] {
+        let (lda, ldb, nrhs) = (n + 1, n + 2, 3);
+        let a0 = rng.complex_vec(lda * n);
+        let x = rng.complex_vec(ldb * nrhs);
+
+        let mut a = a0.clone();
+        let mut ipiv = vec![0; n];
+        getrf(
+            &(n as isize),
+            &(n as isize),
+            &mut vector_mut(&mut a),
+            &(lda as isize),
+            &mut vector_mut(&mut ipiv),
+        )
+        .unwrap();
+
+        for trans in ['N', 'T', 'C'] {
+            let op = |i: usize, j: usize| match trans {
+                'N' => a0[i + j * lda],
+                'T' => a0[j + i * lda],
+                _ => a0[j + i * lda].conj(),
+            };
+            let mut b = vec![C::new(0.0, 0.0); ldb * nrhs];
+            let ax = mul(n, nrhs, n, op, |i, j| x[i + j * ldb]);
+            for j in 0..nrhs {
+                b[j * ldb..j * ldb + n].copy_from_slice(&ax[j * n..(j + 1) * n]);
+            }
+
+            getrs(
+                &trans,
+                &(n as isize),
+                &(nrhs as isize),
+                &vector(&a),
+                &(lda as isize),
+                &vector(&ipiv),
+                &mut vector_mut(&mut b),
+                &(ldb as isize),
+            )
+            .unwrap();
+            assert_matrix_close(n, nrhs, &b, ldb, &x, ldb, 1e-9);
+        }
+    }
+}
+
+#[test]
+fn gesv_solves_real_systems() {
+    let mut rng = Rng::new(44);
+
+    for n in [3, 50] {
+        let a0 = rng.vec(n * n);
+        let x: Vec<f64> = (0..n).map(|i| i as f64).collect();
+        let mut b: Vec<f64> = (0..n)
+            .map(|i| (0..n).map(|l| a0[i + l * n] * x[l]).sum())
+            .collect();
+
+        let mut a = a0.clone();
+        let mut ipiv = vec![0; n];
+        gesv(
+            &(n as isize),
+            &1,
+            &mut vector_mut(&mut a),
+            &(n as isize),
+            &mut vector_mut(&mut ipiv),
+            &mut vector_mut(&mut b),
+            &(n as isize),
+        )
+        .unwrap();
+        for (b, x) in b.iter().zip(&x) {
+            common::assert_close(*b, *x, 1e-9);
+        }
+
+        // single precision goes through the same code
+        let mut a: Vec<f32> = a0.iter().map(|&a| a as f32).collect();
+        let mut b: Vec<f32> = (0..n)
+            .map(|i| (0..n).map(|l| a[i + l * n] * x[l] as f32).sum())
+            .collect();
+        gesv(
+            &(n as isize),
+            &1,
+            &mut vector_mut(&mut a),
+            &(n as isize),
+            &mut vector_mut(&mut ipiv),
+            &mut vector_mut(&mut b),
+            &(n as isize),
+        )
+        .unwrap();
+        for (b, x) in b.iter().zip(&x) {
+            common::assert_close(*b as f64, *x, 1e-2);
+        }
+    }
+}
+
+#[test]
+fn singular_matrices_are_reported() {
+    let mut rng = Rng::new(45);
+
+    // a zero column, in the unblocked and the blocked path
+    for n in [3, 40] {
+        let mut a = rng.vec(n * n);
+        a[2 * n..3 * n].iter_mut().for_each(|a| *a = 0.0);
+        let mut ipiv = vec![0; n];
+        assert_eq!(
+            getrf(
+                &(n as isize),
+                &(n as isize),
+                &mut vector_mut(&mut a),
+                &(n as isize),
+                &mut vector_mut(&mut ipiv)
+            ),
+            Err(LapackError::Singular(2))
+        );
+
+        // gesv leaves b alone if A is singular
+        let mut a = vec![0.0; n * n];
+        let mut b = vec![1.0; n];
+        assert_eq!(
+            gesv(
+                &(n as isize),
+                &1,
+                &mut vector_mut(&mut a),
+                &(n as isize),
+                &mut vector_mut(&mut ipiv),
+                &mut vector_mut(&mut b),
+                &(n as isize)
+            ),
+            Err(LapackError::Singular(0))
+        );
+        assert_eq!(b, vec![1.0; n]);
+    }
+
+    let mut a = [0.0f64; 4];
+    let mut ipiv = [0; 2];
+    assert_eq!(
+        getrf(
+            &2,
+            &2,
+            &mut vector_mut(&mut a),
+            &1,
+            &mut vector_mut(&mut ipiv)
+        ),
+        Err(LapackError::Argument(4))
+    );
+}
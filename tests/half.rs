@@ -0,0 +1,129 @@
+#![cfg(feature = "half")]
+
+mod common;
+
+use naive_blas::level_1::{axpy, dot, nrm2, scal};
+use naive_blas::level_3::gemm;
+use naive_blas::{bf16, f16};
+
+use common::{vector, vector_mut, Rng};
+
+fn halves(rng: &mut Rng, n: usize) -> Vec<f16> {
+    (0..n).map(|_| f16::from_f64(rng.next())).collect()
+}
+
+#[test]
+fn level_1_accumulates_in_f32() {
+    // 1 + 2⁻¹² is not representable in f16, so adding the small terms one at a time in f16 would
+    // lose all of them
+    let n = 4096;
+    let x = vec![f16::from_f32(1.0); n];
+    let mut y = vec![f16::from_f32(2f32.powi(-12)); n];
+    y[0] = f16::from_f32(1.0);
+
+    let res: f32 = dot::dot(&(n as isize), &vector(&x), &1, &vector(&y), &1);
+    assert_eq!(res, 1.0 + (n - 1) as f32 * 2f32.powi(-12));
+
+    let norm: f32 = nrm2::nrm2_mixed(&(n as isize), &vector(&x), &1);
+    assert_eq!(norm, 64.0);
+
+    let b = vec![bf16::from_f32(0.5); 3];
+    let res: f32 = dot::dot(&3, &vector(&b), &1, &vector(&b), &1);
+    assert_eq!(res, 0.75);
+}
+
+#[test]
+fn axpy_and_scal_round_once() {
+    let x = [f16::from_f32(3.0), f16::from_f32(-1.0)];
+    let mut y = [f16::from_f32(1.0), f16::from_f32(2.0)];
+
+    axpy::axpy_mixed(
+        &2,
+        &(1.0f32 / 3.0),
+        &vector(&x),
+        &1,
+        &mut vector_mut(&mut y),
+        &1,
+    );
+    assert_eq!(y, [f16::from_f32(2.0), f16::from_f32(2.0 - 1.0 / 3.0)]);
+
+    scal::scal_mixed(&2, &0.1f32, &mut vector_mut(&mut y), &1);
+    assert_eq!(y[0], f16::from_f32(0.2));
+}
+
+#[test]
+fn gemm_into_f32_and_f16() {
+    let mut rng = Rng::new(7);
+    let (m, n, k) = (5, 4, 300);
+    let a = halves(&mut rng, m * k);
+    let b = halves(&mut rng, k * n);
+    let c0 = halves(&mut rng, m * n);
+
+    // the exact result, rounded once
+    let exact = |i: usize, j: usize| {
+        let ab: f64 = (0..k)
+            .map(|l| a[i + l * m].to_f64() * b[l + j * k].to_f64())
+            .sum();
+        0.5 * ab - 2.0 * c0[i + j * m].to_f64()
+    };
+
+    let mut c: Vec<f32> = c0.iter().map(|cij| cij.to_f32()).collect();
+    gemm::gemm(
+        &'N',
+        &'N',
+        &(m as isize),
+        &(n as isize),
+        &(k as isize),
+        &0.5f32,
+        &vector(&a),
+        &(m as isize),
+        &vector(&b),
+        &(k as isize),
+        &-2.0,
+        &mut vector_mut(&mut c),
+        &(m as isize),
+    );
+    for j in 0..n {
+        for i in 0..m {
+            assert!((f64::from(c[i + j * m]) - exact(i, j)).abs() < 1e-5);
+        }
+    }
+
+    // Aᵀ and Bᵀ
+    let at: Vec<f16> = (0..m * k).map(|p| a[p / k + (p % k) * m]).collect();
+    let bt: Vec<f16> = (0..k * n).map(|p| b[p / n + (p % n) * k]).collect();
+
+    for &(transa, transb) in &[('N', 'N'), ('T', 'N'), ('N', 'T'), ('T', 'T')] {
+        let (a, lda) = if transa == 'N' { (&a, m) } else { (&at, k) };
+        let (b, ldb) = if transb == 'N' { (&b, k) } else { (&bt, n) };
+
+        let mut c = c0.clone();
+        gemm::gemm_mixed(
+            &transa,
+            &transb,
+            &(m as isize),
+            &(n as isize),
+            &(k as isize),
+            &0.5f32,
+            &vector(a),
+            &(lda as isize),
+            &vector(b),
+            &(ldb as isize),
+            &-2.0,
+            &mut vector_mut(&mut c),
+            &(m as isize),
+        );
+        for j in 0..n {
+            for i in 0..m {
+                // within half a unit in the last place of f16, give or take the f32 rounding
+                let (cij, exact) = (c[i + j * m].to_f64(), exact(i, j));
+                assert!(
+                    (cij - exact).abs() <= exact.abs() * 2f64.powi(-11) * 1.01 + 1e-6,
+                    "{}{}",
+                    transa,
+                    transb
+                );
+            }
+        }
+    }
+}
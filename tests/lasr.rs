@@ -0,0 +1,209 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use naive_blas::lapack::{
+    ch1dn::ch1dn, ch1up::ch1up, geqrf::geqrf, lasr::lasr, orgqr::orgqr, qr1up::qr1up, LapackError,
+};
+use num_complex::Complex;
+
+use common::{assert_matrix_close, mul, vector, vector_mut, Rng};
+
+type C = Complex<f64>;
+
+/// Returns the z by z identity matrix.
+fn eye(z: usize) -> Vec<f64> {
+    (0..z * z)
+        .map(|p| if p % (z + 1) == 0 { 1.0 } else { 0.0 })
+        .collect()
+}
+
+/// Asserts that the m by n real matrices a and b agree to tol.
+#[track_caller]
+fn assert_real_close(m: usize, n: usize, a: &[f64], lda: usize, b: &[f64], ldb: usize, tol: f64) {
+    for j in 0..n {
+        for i in 0..m {
+            common::assert_close(a[i + j * lda], b[i + j * ldb], tol);
+        }
+    }
+}
+
+#[test]
+fn lasr_applies_the_rotations_in_order() {
+    let mut rng = Rng::new(45);
+    let (m, n) = (5, 4);
+    let lda = m + 1;
+    let a0 = rng.complex_vec(lda * n);
+
+    for side in ['L', 'R'] {
+        for pivot in ['V', 'T', 'B'] {
+            for direct in ['F', 'B'] {
+                let z = if side == 'L' { m } else { n };
+                let angles = rng.vec(z - 1);
+                let c: Vec<f64> = angles.iter().map(|t| (3.0 * t).cos()).collect();
+                let s: Vec<f64> = angles.iter().map(|t| (3.0 * t).sin()).collect();
+
+                // P as a product of the z by z rotations
+                let mut p = eye(z);
+                let order: Vec<usize> = match direct {
+                    'F' => (0..z - 1).collect(),
+                    _ => (0..z - 1).rev().collect(),
+                };
+                for k in order {
+                    let (i, j) = match pivot {
+                        'V' => (k, k + 1),
+                        'T' => (0, k + 1),
+                        _ => (k, z - 1),
+                    };
+                    let mut g = eye(z);
+                    g[i + i * z] = c[k];
+                    g[i + j * z] = s[k];
+                    g[j + i * z] = -s[k];
+                    g[j + j * z] = c[k];
+                    p = mul(z, z, z, |i, l| g[i + l * z], |l, j| p[l + j * z]);
+                }
+                let p = |i: usize, j: usize| C::from(p[i + j * z]);
+                let a = |i: usize, j: usize| a0[i + j * lda];
+                let expected = if side == 'L' {
+                    mul(m, n, m, p, a)
+                } else {
+                    mul(m, n, n, a, |l, j| p(j, l))
+                };
+
+                let mut a = a0.clone();
+                lasr(
+                    &side,
+                    &pivot,
+                    &direct,
+                    &(m as isize),
+                    &(n as isize),
+                    &vector(&c),
+                    &vector(&s),
+                    &mut vector_mut(&mut a),
+                    &(lda as isize),
+                );
+                assert_matrix_close(m, n, &a, lda, &expected, m, 1e-13);
+            }
+        }
+    }
+}
+
+#[test]
+fn qr1up_updates_q_and_r() {
+    let mut rng = Rng::new(46);
+
+    for (m, n) in [(6, 4), (4, 6), (5, 5), (1, 3)] {
+        let a0 = rng.vec(m * n);
+        let mut a = a0.clone();
+        let k = m.min(n);
+        let mut tau = vec![0.0; k];
+        geqrf(
+            &(m as isize),
+            &(n as isize),
+            &mut vector_mut(&mut a),
+            &(m as isize),
+            &mut vector_mut(&mut tau),
+        )
+        .unwrap();
+
+        let mut r = vec![0.0; m * n];
+        for j in 0..n {
+            for i in 0..=j.min(m - 1) {
+                r[i + j * m] = a[i + j * m];
+            }
+        }
+        let mut q = vec![0.0; m * m];
+        q[..m * k].copy_from_slice(&a[..m * k]);
+        orgqr(
+            &(m as isize),
+            &(m as isize),
+            &(k as isize),
+            &mut vector_mut(&mut q),
+            &(m as isize),
+            &vector(&tau),
+        )
+        .unwrap();
+
+        let (u, v) = (rng.vec(m), rng.vec(n));
+        qr1up(
+            &(m as isize),
+            &(n as isize),
+            &mut vector_mut(&mut q),
+            &(m as isize),
+            &mut vector_mut(&mut r),
+            &(m as isize),
+            &vector(&u),
+            &vector(&v),
+        )
+        .unwrap();
+
+        let qr = mul(m, n, m, |i, l| q[i + l * m], |l, j| r[l + j * m]);
+        let expected: Vec<f64> = (0..m * n).map(|p| a0[p] + u[p % m] * v[p / m]).collect();
+        assert_real_close(m, n, &qr, m, &expected, m, 1e-13);
+
+        let qtq = mul(m, m, m, |i, l| q[l + i * m], |l, j| q[l + j * m]);
+        assert_real_close(m, m, &qtq, m, &eye(m), m, 1e-13);
+        for j in 0..n {
+            assert!((j + 1..m).all(|i| r[i + j * m] == 0.0));
+        }
+    }
+}
+
+#[test]
+fn ch1up_and_ch1dn_add_and_remove_a_row() {
+    let mut rng = Rng::new(47);
+    let (n, ldr) = (6, 8);
+
+    // the strict lower triangle is not referenced
+    let mut r = vec![f64::NAN; ldr * n];
+    for j in 0..n {
+        for i in 0..=j {
+            r[i + j * ldr] = rng.next() + if i == j { 2.0 } else { 0.0 };
+        }
+    }
+    let gram = |r: &[f64]| {
+        let u = |i: usize, j: usize| if i <= j { r[i + j * ldr] } else { 0.0 };
+        mul(n, n, n, |i, l| u(l, i), u)
+    };
+    let g0 = gram(&r);
+    let x = rng.vec(n);
+
+    ch1up(
+        &(n as isize),
+        &mut vector_mut(&mut r),
+        &(ldr as isize),
+        &vector(&x),
+    )
+    .unwrap();
+    let expected: Vec<f64> = (0..n * n).map(|p| g0[p] + x[p % n] * x[p / n]).collect();
+    assert_real_close(n, n, &gram(&r), n, &expected, n, 1e-13);
+
+    ch1dn(
+        &(n as isize),
+        &mut vector_mut(&mut r),
+        &(ldr as isize),
+        &vector(&x),
+    )
+    .unwrap();
+    assert_real_close(n, n, &gram(&r), n, &g0, n, 1e-12);
+}
+
+#[test]
+fn ch1dn_reports_an_indefinite_result() {
+    // I - x * xᵀ with x = [0.6, 0.6, 0.6, 0, ...] has a leading minor of order 3 below zero
+    let n = 6;
+    let mut r: Vec<f64> = eye(n);
+    let mut x = vec![0.0; n];
+    x[..3].fill(0.6);
+
+    assert_eq!(
+        ch1dn(
+            &(n as isize),
+            &mut vector_mut(&mut r),
+            &(n as isize),
+            &vector(&x)
+        ),
+        Err(LapackError::NotPositiveDefinite(2))
+    );
+    assert_eq!(r, eye(n));
+}
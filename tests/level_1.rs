@@ -0,0 +1,348 @@
+mod common;
+
+use naive_blas::level_1::nrm2::{nrm2, real_complex_nrm2};
+use num_complex::Complex;
+
+use common::{assert_close, vector, vector_mut};
+
+#[test]
+fn nrm2_includes_the_last_element() {
+    let x = [3.0f64, 0.0, 4.0];
+    assert_eq!(nrm2(&3, &mut vector(&x), &1), 5.0);
+
+    let x = [0.0f64, -1.0, 0.0, 0.0, 12.0];
+    assert_eq!(nrm2(&2, &mut vector(&x), &4), 12.0);
+
+    let x = [Complex::new(0.0f64, 0.0), Complex::new(3.0, 4.0)];
+    assert_eq!(real_complex_nrm2(&2, vector(&x), &1), 5.0);
+}
+
+#[test]
+fn nrm2_of_one_element_is_its_absolute_value() {
+    let x = [-2.5f64];
+    assert_eq!(nrm2(&1, &mut vector(&x), &1), 2.5);
+
+    let x = [Complex::new(-3.0f64, 4.0)];
+    assert_eq!(real_complex_nrm2(&1, vector(&x), &1), 5.0);
+}
+
+#[test]
+fn nrm2_matches_the_naive_norm() {
+    let mut rng = common::Rng::new(1);
+
+    for &n in &[2, 7, 64, 1001] {
+        for &incx in &[1, 3] {
+            let x = rng.vec(n * incx);
+            let expected = (0..n).map(|i| x[i * incx].powi(2)).sum::<f64>().sqrt();
+
+            let res = nrm2(&(n as isize), &mut vector(&x), &(incx as isize));
+            assert_close(res, expected, 1e-14);
+        }
+    }
+}
+
+#[test]
+fn nrm2_avoids_overflow_and_underflow() {
+    let x = [3e300f64, 4e300];
+    assert_close(nrm2(&2, &mut vector(&x), &1), 5e300, 1e-15);
+
+    let x = [3e-300f64, 4e-300];
+    assert_close(nrm2(&2, &mut vector(&x), &1) * 1e300, 5.0, 1e-15);
+}
+
+#[test]
+fn scal_ignores_non_positive_increments() {
+    use naive_blas::level_1::scal::{scal_complex, scal_complex_real, scal_mixed, scal_real};
+
+    for &incx in &[0, -1] {
+        let mut x = [1.0f64, 2.0];
+        scal_real(&2, &3.0, &mut vector_mut(&mut x), &incx);
+        scal_mixed::<f64, f64>(&2, &3.0, &mut vector_mut(&mut x), &incx);
+        assert_eq!(x, [1.0, 2.0]);
+
+        let mut z = [Complex::new(1.0f64, 2.0)];
+        scal_complex(1, Complex::new(0.0, 1.0), vector_mut(&mut z), incx);
+        scal_complex_real(1, 3.0, vector_mut(&mut z), incx);
+        assert_eq!(z, [Complex::new(1.0, 2.0)]);
+    }
+}
+
+#[test]
+fn axpby_does_not_read_y_when_b_is_zero() {
+    use naive_blas::level_1::axpby::{axpby_complex, axpby_real};
+
+    let x = [1.0f64, 2.0, 3.0];
+    let mut y = [f64::NAN; 3];
+    axpby_real(&3, &2.0, &vector(&x), &1, &0.0, &mut vector_mut(&mut y), &1);
+    assert_eq!(y, [2.0, 4.0, 6.0]);
+
+    let mut y = [f64::NAN; 6];
+    axpby_real(
+        &3,
+        &2.0,
+        &vector(&x),
+        &-1,
+        &0.0,
+        &mut vector_mut(&mut y),
+        &2,
+    );
+    assert_eq!([y[0], y[2], y[4]], [6.0, 4.0, 2.0]);
+    assert!(y[1].is_nan() && y[3].is_nan());
+
+    let z = [Complex::new(1.0f64, 1.0)];
+    let mut w = [Complex::new(f64::NAN, f64::NAN)];
+    let zero = Complex::new(0.0, 0.0);
+    axpby_complex(
+        &1,
+        &Complex::new(0.0, 1.0),
+        &vector(&z),
+        &1,
+        &zero,
+        &mut vector_mut(&mut w),
+        &1,
+    );
+    assert_eq!(w, [Complex::new(-1.0, 1.0)]);
+}
+
+#[test]
+fn axpby_matches_the_naive_sum() {
+    use naive_blas::level_1::axpby::axpby_real;
+
+    let x = [1.0f64, -2.0, 0.5];
+    let mut y = [4.0f64, 1.0, -3.0];
+    axpby_real(
+        &3,
+        &2.0,
+        &vector(&x),
+        &1,
+        &-0.5,
+        &mut vector_mut(&mut y),
+        &1,
+    );
+    assert_eq!(y, [0.0, -4.5, 2.5]);
+}
+
+#[test]
+fn copy_indexes_x_and_y_with_their_own_increments() {
+    use naive_blas::level_1::copy::copy;
+
+    let x = [1.0f64, 2.0, 3.0];
+    let mut y = [0.0f64; 5];
+    copy(&3, &vector(&x), &1, &mut vector_mut(&mut y), &2);
+    assert_eq!(y, [1.0, 0.0, 2.0, 0.0, 3.0]);
+
+    let x = [1.0f64, -1.0, 2.0, -1.0, 3.0];
+    let mut y = [0.0f64; 3];
+    copy(&3, &vector(&x), &2, &mut vector_mut(&mut y), &-1);
+    assert_eq!(y, [3.0, 2.0, 1.0]);
+}
+
+#[test]
+fn sparse_routines_follow_the_index_base() {
+    use naive_blas::level_1::{axpyi::axpyi_real, doti, gthr, roti::roti, sctr::sctr};
+
+    let x = [1.0f64, 2.0];
+    for (base, indx) in [(0, [2, 0]), (1, [3, 1])] {
+        let mut y = [1.0f64, 1.0, 1.0];
+        axpyi_real(
+            &2,
+            &3.0,
+            &vector(&x),
+            &vector(&indx),
+            &base,
+            &mut vector_mut(&mut y),
+        );
+        assert_eq!(y, [7.0, 1.0, 4.0]);
+        assert_eq!(
+            doti::doti(&2, &vector(&x), &vector(&indx), &base, &vector(&y)),
+            18.0
+        );
+
+        let mut z = [0.0f64; 3];
+        sctr(
+            &2,
+            &vector(&x),
+            &vector(&indx),
+            &base,
+            &mut vector_mut(&mut z),
+        );
+        assert_eq!(z, [2.0, 0.0, 1.0]);
+
+        let mut w = [0.0f64; 2];
+        gthr::gthr(
+            &2,
+            &vector(&y),
+            &mut vector_mut(&mut w),
+            &vector(&indx),
+            &base,
+        );
+        assert_eq!(w, [4.0, 7.0]);
+        gthr::gthrz(
+            &2,
+            &mut vector_mut(&mut y),
+            &mut vector_mut(&mut w),
+            &vector(&indx),
+            &base,
+        );
+        assert_eq!((w, y), ([4.0, 7.0], [0.0, 1.0, 0.0]));
+
+        let (mut w, mut y) = ([1.0f64, 2.0], [3.0f64, 5.0, 4.0]);
+        roti(
+            &2,
+            &mut vector_mut(&mut w),
+            &vector(&indx),
+            &base,
+            &mut vector_mut(&mut y),
+            &0.0,
+            &1.0,
+        );
+        assert_eq!((w, y), ([4.0, 3.0], [-2.0, 5.0, -1.0]));
+    }
+}
+
+/// Asserts that f panics and leaves y as it was.
+#[track_caller]
+fn assert_panics_leaving<T: Copy + PartialEq + std::fmt::Debug>(
+    y: &mut [T],
+    f: impl FnOnce(&mut [T]),
+) {
+    let y0 = y.to_vec();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(&mut *y)));
+    assert!(result.is_err());
+    assert_eq!(y, &y0[..]);
+}
+
+#[test]
+fn sparse_routines_panic_on_invalid_indices() {
+    use naive_blas::level_1::{axpyi, doti, gthr, roti::roti, sctr::sctr};
+
+    let x = [1.0f64, 2.0];
+    let c = [Complex::new(1.0f64, 1.0), Complex::new(2.0, -1.0)];
+    // the invalid index comes last, so a routine that checked it only when it got there would
+    // already have written the first element
+    let cases: [(isize, [isize; 2]); 4] = [(0, [0, 3]), (1, [1, 4]), (1, [1, 0]), (2, [2, 3])];
+    for (base, indx) in cases {
+        let indx = vector(&indx);
+        let mut y = [5.0f64, 6.0, 7.0];
+        let mut yc = [Complex::new(5.0f64, 0.0); 3];
+
+        assert_panics_leaving(&mut y, |y| {
+            axpyi::axpyi_real(&2, &3.0, &vector(&x), &indx, &base, &mut vector_mut(y))
+        });
+        assert_panics_leaving(&mut yc, |y| {
+            axpyi::axpyi_complex(&2, &c[0], &vector(&c), &indx, &base, &mut vector_mut(y))
+        });
+        assert_panics_leaving(&mut y, |y| {
+            doti::doti(&2, &vector(&x), &indx, &base, &vector(y));
+        });
+        assert_panics_leaving(&mut yc, |y| {
+            doti::dotci(&2, &vector(&c), &indx, &base, &vector(y));
+        });
+        assert_panics_leaving(&mut yc, |y| {
+            doti::dotui(&2, &vector(&c), &indx, &base, &vector(y));
+        });
+        assert_panics_leaving(&mut y, |y| {
+            sctr(&2, &vector(&x), &indx, &base, &mut vector_mut(y))
+        });
+
+        // x is the output of the gathers and is left alone too
+        let mut w = x;
+        assert_panics_leaving(&mut w, |w| {
+            gthr::gthr(&2, &vector(&[5.0; 3]), &mut vector_mut(w), &indx, &base)
+        });
+        assert_panics_leaving(&mut y, |y| {
+            gthr::gthrz(
+                &2,
+                &mut vector_mut(y),
+                &mut vector_mut(&mut w),
+                &indx,
+                &base,
+            )
+        });
+        assert_panics_leaving(&mut y, |y| {
+            roti(
+                &2,
+                &mut vector_mut(&mut w),
+                &indx,
+                &base,
+                &mut vector_mut(y),
+                &0.0,
+                &1.0,
+            )
+        });
+        assert_eq!(w, x);
+    }
+
+    // so does an x shorter than nz
+    let mut y = [1.0f64; 3];
+    assert_panics_leaving(&mut y, |y| {
+        sctr(
+            &2,
+            &vector(&[1.0]),
+            &vector(&[0, 1]),
+            &0,
+            &mut vector_mut(y),
+        )
+    });
+}
+
+#[test]
+#[should_panic(expected = "index base 2 is not 0 or 1")]
+fn sparse_routines_name_an_invalid_base() {
+    use naive_blas::level_1::doti::doti;
+
+    doti(
+        &1,
+        &vector(&[1.0f64]),
+        &vector(&[2]),
+        &2,
+        &vector(&[1.0; 3]),
+    );
+}
+
+#[test]
+fn dsdot_accumulates_in_double_precision() {
+    use naive_blas::level_1::dsdot::dsdot;
+
+    // 1e8 + 1 - 1e8 is 0 in f32 but exact in f64
+    let x = [1e8f32, 1.0, 1e8];
+    let y = [1.0f32, 1.0, -1.0];
+    assert_eq!(dsdot(&3, &vector(&x), &1, &vector(&y), &1), 1.0);
+
+    // the products are formed in f64 too
+    let third = 1.0f32 / 3.0;
+    assert_eq!(
+        dsdot(&1, &vector(&[3.0f32]), &1, &vector(&[third]), &1),
+        3.0 * third as f64
+    );
+
+    assert_eq!(dsdot(&0, &vector(&x), &1, &vector(&y), &1), 0.0);
+    assert_eq!(dsdot(&-1, &vector(&x), &1, &vector(&y), &1), 0.0);
+}
+
+#[test]
+fn dsdot_follows_the_increments() {
+    use naive_blas::level_1::dsdot::dsdot;
+
+    let x = [1.0f32, 2.0, 3.0, 4.0, 5.0];
+    let y = [1e8f32, 1.0, -1e8, 1.0, 0.5];
+    // x[0], x[2], x[4] with y[4], y[2], y[0]
+    assert_eq!(
+        dsdot(&3, &vector(&x), &2, &vector(&y), &-2),
+        0.5 - 3e8 + 5e8
+    );
+    // x[1], x[0] with y[0], y[1]
+    assert_eq!(dsdot(&2, &vector(&x), &-1, &vector(&y), &1), 2e8 + 1.0);
+
+    let mut rng = common::Rng::new(40);
+    let x: Vec<f32> = rng.vec(30).iter().map(|&x| x as f32).collect();
+    let y: Vec<f32> = rng.vec(20).iter().map(|&y| y as f32).collect();
+    let expected: f64 = (0..10)
+        .map(|i| x[3 * i] as f64 * y[2 * (9 - i)] as f64)
+        .sum();
+    assert_close(
+        dsdot(&10, &vector(&x), &3, &vector(&y), &-2),
+        expected,
+        1e-12,
+    );
+}
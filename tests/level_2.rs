@@ -0,0 +1,385 @@
+mod common;
+
+use naive_blas::level_2::{
+    symv::{hemv_complex, symv_real},
+    syr2::{her2_complex, syr2_real},
+    tbmv::{tbmv_complex, tbmv_real},
+    tbsv::{tbsv_complex, tbsv_real},
+};
+use num_complex::Complex;
+
+use common::{vector, vector_mut, Rng};
+
+type C = Complex<f64>;
+
+/// A random n by n triangular band matrix with k off-diagonals, in band storage with
+/// lda = k + 2, and as a dense row-major matrix.
+///
+/// Every element of the band storage outside the band is NaN, as is the diagonal if unit is
+/// set, so that reading any of them shows in the result.
+fn band(rng: &mut Rng, uplo: char, unit: bool, n: usize, k: usize) -> (Vec<C>, Vec<Vec<C>>) {
+    let lda = k + 2;
+    let nan = C::new(f64::NAN, f64::NAN);
+    let mut a = vec![nan; lda * n];
+    let mut dense = vec![vec![C::new(0.0, 0.0); n]; n];
+
+    for j in 0..n {
+        let rows = match uplo {
+            'U' => j.saturating_sub(k)..j + 1,
+            _ => j..n.min(j + k + 1),
+        };
+        for i in rows {
+            // a dominant diagonal keeps the solves well conditioned
+            let aij = if i != j {
+                rng.complex()
+            } else if unit {
+                C::new(1.0, 0.0)
+            } else {
+                rng.complex() + 4.0
+            };
+            dense[i][j] = aij;
+            if i != j || !unit {
+                let row = if uplo == 'U' { k + i - j } else { i - j };
+                a[row + j * lda] = aij;
+            }
+        }
+    }
+
+    (a, dense)
+}
+
+/// Returns op(A) * x for a dense A.
+fn matvec(a: &[Vec<C>], trans: char, x: &[C]) -> Vec<C> {
+    let n = a.len();
+    (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| match trans {
+                    'N' => a[i][j] * x[j],
+                    'T' => a[j][i] * x[j],
+                    _ => a[j][i].conj() * x[j],
+                })
+                .sum()
+        })
+        .collect()
+}
+
+/// Spreads x over a vector with increment inc, the other elements being NaN.
+fn strided(x: &[C], inc: isize) -> Vec<C> {
+    let (n, step) = (x.len(), inc.unsigned_abs());
+    let mut v = vec![C::new(f64::NAN, 0.0); (n - 1) * step + 1];
+    for (i, &x) in x.iter().enumerate() {
+        let i = if inc > 0 { i } else { n - 1 - i };
+        v[i * step] = x;
+    }
+    v
+}
+
+/// Reads the elements of a vector with increment inc.
+fn unstrided(v: &[C], n: usize, inc: isize) -> Vec<C> {
+    let step = inc.unsigned_abs();
+    (0..n)
+        .map(|i| {
+            let i = if inc > 0 { i } else { n - 1 - i };
+            v[i * step]
+        })
+        .collect()
+}
+
+#[track_caller]
+fn assert_all_close(a: &[C], b: &[C]) {
+    for (i, (a, b)) in a.iter().zip(b).enumerate() {
+        assert!((a - b).norm() < 1e-10, "{} and {} differ at {}", a, b, i);
+    }
+}
+
+#[test]
+fn complex_tbmv_and_tbsv_match_the_dense_matrix() {
+    let mut rng = Rng::new(41);
+
+    for (n, k) in [(1, 0), (5, 0), (6, 2), (4, 5)] {
+        for uplo in ['U', 'L'] {
+            for diag in ['N', 'U'] {
+                let (a, dense) = band(&mut rng, uplo, diag == 'U', n, k);
+                let lda = (k + 2) as isize;
+
+                for trans in ['N', 'T', 'C'] {
+                    for inc in [1, -2] {
+                        let x0 = rng.complex_vec(n);
+                        let expected = matvec(&dense, trans, &x0);
+
+                        let mut x = strided(&x0, inc);
+                        tbmv_complex(
+                            &uplo,
+                            &trans,
+                            &diag,
+                            &(n as isize),
+                            &(k as isize),
+                            &vector(&a),
+                            &lda,
+                            &mut vector_mut(&mut x),
+                            &inc,
+                        );
+                        assert_all_close(&unstrided(&x, n, inc), &expected);
+
+                        tbsv_complex(
+                            &uplo,
+                            &trans,
+                            &diag,
+                            &(n as isize),
+                            &(k as isize),
+                            &vector(&a),
+                            &lda,
+                            &mut vector_mut(&mut x),
+                            &inc,
+                        );
+                        assert_all_close(&unstrided(&x, n, inc), &x0);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn real_tbmv_and_tbsv_match_the_dense_matrix() {
+    // upper triangular with one super-diagonal
+    // [2 1 0]
+    // [0 3 4]
+    // [0 0 5]
+    let nan = f64::NAN;
+    let a = [nan, 2.0, 1.0, 3.0, 4.0, 5.0];
+    let x0 = [1.0, 1.0, 2.0];
+
+    let mut x = x0;
+    tbmv_real(
+        &'U',
+        &'N',
+        &'N',
+        &3,
+        &1,
+        &vector(&a),
+        &2,
+        &mut vector_mut(&mut x),
+        &1,
+    );
+    assert_eq!(x, [3.0, 11.0, 10.0]);
+    tbsv_real(
+        &'U',
+        &'N',
+        &'N',
+        &3,
+        &1,
+        &vector(&a),
+        &2,
+        &mut vector_mut(&mut x),
+        &1,
+    );
+    assert_eq!(x, x0);
+
+    let mut x = x0;
+    tbmv_real(
+        &'U',
+        &'T',
+        &'N',
+        &3,
+        &1,
+        &vector(&a),
+        &2,
+        &mut vector_mut(&mut x),
+        &1,
+    );
+    assert_eq!(x, [2.0, 4.0, 14.0]);
+    tbsv_real(
+        &'U',
+        &'T',
+        &'N',
+        &3,
+        &1,
+        &vector(&a),
+        &2,
+        &mut vector_mut(&mut x),
+        &1,
+    );
+    assert_eq!(x, x0);
+
+    // n = 0 leaves x alone
+    let mut x = x0;
+    tbmv_real(
+        &'L',
+        &'N',
+        &'U',
+        &0,
+        &1,
+        &vector(&a),
+        &2,
+        &mut vector_mut(&mut x),
+        &1,
+    );
+    tbsv_real(
+        &'L',
+        &'N',
+        &'U',
+        &0,
+        &1,
+        &vector(&a),
+        &2,
+        &mut vector_mut(&mut x),
+        &1,
+    );
+    assert_eq!(x, x0);
+}
+
+/// A random n by n Hermitian matrix as a dense row-major matrix, and stored in the triangle
+/// uplo with lda = n + 1.
+///
+/// The other triangle is NaN, and the diagonal has a nonzero imaginary part which is not
+/// referenced, so that reading either shows in the result.
+fn hermitian(rng: &mut Rng, uplo: char, n: usize) -> (Vec<C>, Vec<Vec<C>>) {
+    let lda = n + 1;
+    let mut a = vec![C::new(f64::NAN, f64::NAN); lda * n];
+    let mut dense = vec![vec![C::new(0.0, 0.0); n]; n];
+
+    for j in 0..n {
+        for i in 0..=j {
+            let aij = if i == j {
+                C::new(rng.next(), 0.0)
+            } else {
+                rng.complex()
+            };
+            dense[i][j] = aij;
+            dense[j][i] = aij.conj();
+            if uplo == 'U' {
+                a[i + j * lda] = aij;
+            } else {
+                a[j + i * lda] = aij.conj();
+            }
+        }
+        a[j + j * lda].im = f64::NAN;
+    }
+
+    (a, dense)
+}
+
+#[test]
+fn hemv_and_her2_match_the_dense_matrix() {
+    let mut rng = Rng::new(49);
+    let (alpha, beta) = (C::new(0.3, -0.7), C::new(1.1, 0.2));
+
+    for n in [1, 2, 6] {
+        let lda = (n + 1) as isize;
+        for uplo in ['U', 'L'] {
+            let (a, dense) = hermitian(&mut rng, uplo, n);
+
+            for (incx, incy) in [(1, 1), (2, -3)] {
+                let (x0, y0) = (rng.complex_vec(n), rng.complex_vec(n));
+                let ax = matvec(&dense, 'N', &x0);
+                let expected: Vec<C> = (0..n).map(|i| alpha * ax[i] + beta * y0[i]).collect();
+
+                let mut y = strided(&y0, incy);
+                hemv_complex(
+                    &uplo,
+                    &(n as isize),
+                    &alpha,
+                    &vector(&a),
+                    &lda,
+                    &vector(&strided(&x0, incx)),
+                    &incx,
+                    &beta,
+                    &mut vector_mut(&mut y),
+                    &incy,
+                );
+                assert_all_close(&unstrided(&y, n, incy), &expected);
+
+                // y need not be set if beta is zero
+                let mut y = vec![C::new(f64::NAN, f64::NAN); y.len()];
+                hemv_complex(
+                    &uplo,
+                    &(n as isize),
+                    &alpha,
+                    &vector(&a),
+                    &lda,
+                    &vector(&strided(&x0, incx)),
+                    &incx,
+                    &C::new(0.0, 0.0),
+                    &mut vector_mut(&mut y),
+                    &incy,
+                );
+                let expected: Vec<C> = ax.iter().map(|ax| alpha * ax).collect();
+                assert_all_close(&unstrided(&y, n, incy), &expected);
+
+                // A = alpha * x * yᴴ + conj(alpha) * y * xᴴ + A, in the stored triangle only
+                let mut updated = a.clone();
+                her2_complex(
+                    &uplo,
+                    &(n as isize),
+                    &alpha,
+                    &vector(&strided(&x0, incx)),
+                    &incx,
+                    &vector(&strided(&y0, incy)),
+                    &incy,
+                    &mut vector_mut(&mut updated),
+                    &lda,
+                );
+                let lda = n + 1;
+                for j in 0..n {
+                    for i in 0..n {
+                        let aij = updated[i + j * lda];
+                        if (uplo == 'U') == (i <= j) || i == j {
+                            let expected = dense[i][j]
+                                + alpha * x0[i] * y0[j].conj()
+                                + alpha.conj() * y0[i] * x0[j].conj();
+                            assert!((aij - expected).norm() < 1e-12, "{} {}", aij, expected);
+                            // the diagonal is set to real
+                            assert!(i != j || aij.im == 0.0);
+                        } else {
+                            assert!(aij.re.is_nan());
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn real_symv_and_syr2_match_the_dense_matrix() {
+    // [2 1]
+    // [1 3], with the unstored element NaN
+    let nan = f64::NAN;
+    let (upper, lower) = ([2.0, nan, 1.0, 3.0], [2.0, 1.0, nan, 3.0]);
+
+    for (uplo, a) in [('U', upper), ('L', lower)] {
+        let mut y = [1.0, 1.0];
+        symv_real(
+            &uplo,
+            &2,
+            &2.0,
+            &vector(&a),
+            &2,
+            &vector(&[1.0, -1.0]),
+            &1,
+            &0.5,
+            &mut vector_mut(&mut y),
+            &1,
+        );
+        assert_eq!(y, [2.5, -3.5]);
+
+        // A + 0.5 * (x * yᵀ + y * xᵀ) with x = [1, 0], y = [2, 4]
+        let mut a = a;
+        syr2_real(
+            &uplo,
+            &2,
+            &0.5,
+            &vector(&[1.0, 0.0]),
+            &1,
+            &vector(&[2.0, 4.0]),
+            &1,
+            &mut vector_mut(&mut a),
+            &2,
+        );
+        let off = if uplo == 'U' { 2 } else { 1 };
+        assert_eq!([a[0], a[off], a[3]], [4.0, 3.0, 3.0]);
+        assert!(a[3 - off].is_nan());
+    }
+}
@@ -0,0 +1,398 @@
+mod common;
+
+use naive_blas::level_3::gemm::gemm;
+use naive_blas::level_3::gemmt::gemmt;
+use naive_blas::level_3::herk::herk_complex;
+use naive_blas::level_3::syrk::{syrk_complex, syrk_real};
+use naive_blas::level_3::trmm::trmm_complex;
+use naive_blas::level_3::trsm::trsm_complex;
+use num_complex::Complex;
+
+use common::{assert_matrix_close, mul, vector, vector_mut, Rng};
+
+type C = Complex<f64>;
+
+const ZERO: C = C::new(0.0, 0.0);
+const ONE: C = C::new(1.0, 0.0);
+
+/// Returns an n by n triangular matrix with leading dimension n + 1 and a dominant diagonal,
+/// NaN where uplo and diag say it is not referenced.
+fn triangular(rng: &mut Rng, uplo: char, diag: char, n: usize) -> Vec<C> {
+    let lda = n + 1;
+    let mut a = vec![C::new(f64::NAN, f64::NAN); lda * n];
+    for j in 0..n {
+        for i in 0..n {
+            if i == j && diag == 'N' {
+                a[i + j * lda] = rng.complex() + 4.0;
+            } else if i != j && (uplo == 'U') == (i < j) {
+                a[i + j * lda] = rng.complex() * 0.3;
+            }
+        }
+    }
+    a
+}
+
+/// Returns element (i, j) of op(A), A being the triangle of a given by uplo and diag.
+fn op_triangle(uplo: char, transa: char, diag: char, a: &[C], lda: usize, i: usize, j: usize) -> C {
+    let (i, j) = if transa == 'N' { (i, j) } else { (j, i) };
+    let x = if i == j && diag == 'U' {
+        ONE
+    } else if i == j || (uplo == 'U') == (i < j) {
+        a[i + j * lda]
+    } else {
+        ZERO
+    };
+    if transa == 'C' {
+        x.conj()
+    } else {
+        x
+    }
+}
+
+#[test]
+fn gemmt_updates_one_triangle_of_gemm() {
+    let mut rng = Rng::new(31);
+    let (n, k, lda, ldb, ldc) = (4, 3, 5, 5, 6);
+    let a = rng.vec(lda * 5);
+    let b = rng.vec(ldb * 5);
+    let c = rng.vec(ldc * n);
+
+    for &uplo in &['U', 'L'] {
+        for &transa in &['N', 'T'] {
+            for &transb in &['N', 'T'] {
+                for &beta in &[0.0, 0.5] {
+                    let mut full = c.clone();
+                    gemm(
+                        &transa,
+                        &transb,
+                        &(n as isize),
+                        &(n as isize),
+                        &(k as isize),
+                        &1.5,
+                        &vector(&a),
+                        &(lda as isize),
+                        &vector(&b),
+                        &(ldb as isize),
+                        &beta,
+                        &mut vector_mut(&mut full),
+                        &(ldc as isize),
+                    );
+
+                    let mut tri = c.clone();
+                    gemmt(
+                        &uplo,
+                        &transa,
+                        &transb,
+                        &(n as isize),
+                        &(k as isize),
+                        &1.5,
+                        &vector(&a),
+                        &(lda as isize),
+                        &vector(&b),
+                        &(ldb as isize),
+                        &beta,
+                        &mut vector_mut(&mut tri),
+                        &(ldc as isize),
+                    );
+
+                    for j in 0..n {
+                        for i in 0..ldc {
+                            let updated = i < n && if uplo == 'U' { i <= j } else { i >= j };
+                            let expected = if updated {
+                                full[i + j * ldc]
+                            } else {
+                                c[i + j * ldc]
+                            };
+                            assert_eq!(
+                                tri[i + j * ldc],
+                                expected,
+                                "{} {} {}",
+                                uplo,
+                                transa,
+                                transb
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn trsm_solves_every_triangular_system() {
+    let mut rng = Rng::new(32);
+    let alpha = C::new(1.5, -0.5);
+
+    for (m, n) in [(1, 1), (5, 3), (3, 6)] {
+        let ldb = m + 2;
+        let b0 = rng.complex_vec(ldb * n);
+
+        for side in ['L', 'R'] {
+            let k = if side == 'L' { m } else { n };
+            for uplo in ['U', 'L'] {
+                for transa in ['N', 'T', 'C'] {
+                    for diag in ['N', 'U'] {
+                        let a = triangular(&mut rng, uplo, diag, k);
+                        let lda = k + 1;
+                        let mut x = b0.clone();
+                        trsm_complex(
+                            &side,
+                            &uplo,
+                            &transa,
+                            &diag,
+                            &(m as isize),
+                            &(n as isize),
+                            &alpha,
+                            &vector(&a),
+                            &(lda as isize),
+                            &mut vector_mut(&mut x),
+                            &(ldb as isize),
+                        );
+
+                        // op(A) * X = alpha * B or X * op(A) = alpha * B
+                        let op = |i, j| op_triangle(uplo, transa, diag, &a, lda, i, j);
+                        let solution = |i: usize, j: usize| x[i + j * ldb];
+                        let product = if side == 'L' {
+                            mul(m, n, m, op, solution)
+                        } else {
+                            mul(m, n, n, solution, op)
+                        };
+                        let expected: Vec<_> = b0.iter().map(|b| alpha * b).collect();
+                        assert_matrix_close(m, n, &product, m, &expected, ldb, 1e-13);
+
+                        // the rows past m are left alone
+                        for j in 0..n {
+                            assert_eq!(
+                                x[m + j * ldb..(j + 1) * ldb],
+                                b0[m + j * ldb..(j + 1) * ldb]
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns C = alpha * op(A) * op(A)ᴴ + beta * C, or op(A) * op(A)ᵀ if conjugate is false, in
+/// the triangle given by uplo, op(A) being n by k and the other triangle of C left as it is.
+#[allow(clippy::too_many_arguments)]
+fn rank_k(
+    uplo: char,
+    trans: char,
+    conjugate: bool,
+    n: usize,
+    k: usize,
+    alpha: C,
+    a: &[C],
+    lda: usize,
+    beta: C,
+    c: &[C],
+    ldc: usize,
+) -> Vec<C> {
+    let op = |i: usize, l: usize| match trans {
+        'N' => a[i + l * lda],
+        'T' => a[l + i * lda],
+        _ => a[l + i * lda].conj(),
+    };
+    let aat = mul(n, n, k, op, |l, j| {
+        if conjugate {
+            op(j, l).conj()
+        } else {
+            op(j, l)
+        }
+    });
+
+    let mut expected = c.to_vec();
+    for j in 0..n {
+        for i in 0..n {
+            if i == j || (uplo == 'U') == (i < j) {
+                let c = if beta == ZERO {
+                    ZERO
+                } else {
+                    beta * c[i + j * ldc]
+                };
+                expected[i + j * ldc] = alpha * aat[i + j * n] + c;
+            }
+        }
+    }
+    expected
+}
+
+#[test]
+fn syrk_and_herk_update_one_triangle() {
+    let mut rng = Rng::new(33);
+    let outside = C::new(7.0, -7.0);
+
+    for (n, k) in [(1, 1), (4, 3), (3, 6), (3, 0)] {
+        for trans in ['N', 'T', 'C'] {
+            let nrowa = if trans == 'N' { n } else { k };
+            let (lda, ldc) = (nrowa + 1, n + 2);
+            let cols = if trans == 'N' { k } else { n };
+            let a = rng.complex_vec(lda * cols);
+            let a_re: Vec<_> = a.iter().map(|a| a.re).collect();
+            let re: Vec<_> = a_re.iter().map(|&a| C::from(a)).collect();
+
+            for uplo in ['U', 'L'] {
+                for beta in [0.0, 0.5] {
+                    // C is NaN in its triangle if beta is zero, where it need not be set
+                    let mut c = vec![outside; ldc * n];
+                    for j in 0..n {
+                        for i in 0..n {
+                            if i == j || (uplo == 'U') == (i < j) {
+                                c[i + j * ldc] = if beta == 0.0 {
+                                    C::new(f64::NAN, f64::NAN)
+                                } else {
+                                    rng.complex()
+                                };
+                            }
+                        }
+                    }
+
+                    let (n_, k_) = (n as isize, k as isize);
+                    let (lda_, ldc_) = (lda as isize, ldc as isize);
+
+                    // syrk for real types, 'C' meaning 'T'
+                    let mut real: Vec<_> = c.iter().map(|c| c.re).collect();
+                    syrk_real(
+                        &uplo,
+                        &trans,
+                        &n_,
+                        &k_,
+                        &1.5,
+                        &vector(&a_re),
+                        &lda_,
+                        &beta,
+                        &mut vector_mut(&mut real),
+                        &ldc_,
+                    );
+                    let c_re: Vec<_> = c.iter().map(|c| C::from(c.re)).collect();
+                    let expected = rank_k(
+                        uplo,
+                        trans,
+                        false,
+                        n,
+                        k,
+                        C::from(1.5),
+                        &re,
+                        lda,
+                        C::from(beta),
+                        &c_re,
+                        ldc,
+                    );
+                    let real: Vec<_> = real.into_iter().map(C::from).collect();
+                    assert_matrix_close(ldc, n, &real, ldc, &expected, ldc, 1e-14);
+
+                    // syrk for complex types, which has no 'C'
+                    if trans != 'C' {
+                        let (alpha, beta) = (C::new(1.5, -0.5), C::new(beta, 0.25 * beta));
+                        let mut sy = c.clone();
+                        syrk_complex(
+                            &uplo,
+                            &trans,
+                            &n_,
+                            &k_,
+                            &alpha,
+                            &vector(&a),
+                            &lda_,
+                            &beta,
+                            &mut vector_mut(&mut sy),
+                            &ldc_,
+                        );
+                        let expected =
+                            rank_k(uplo, trans, false, n, k, alpha, &a, lda, beta, &c, ldc);
+                        assert_matrix_close(ldc, n, &sy, ldc, &expected, ldc, 1e-14);
+                    }
+
+                    // herk, which has no 'T' and leaves the diagonal real
+                    if trans != 'T' {
+                        let mut he = c.clone();
+                        herk_complex(
+                            &uplo,
+                            &trans,
+                            &n_,
+                            &k_,
+                            &1.5,
+                            &vector(&a),
+                            &lda_,
+                            &beta,
+                            &mut vector_mut(&mut he),
+                            &ldc_,
+                        );
+                        let mut expected = rank_k(
+                            uplo,
+                            trans,
+                            true,
+                            n,
+                            k,
+                            C::from(1.5),
+                            &a,
+                            lda,
+                            C::from(beta),
+                            &c,
+                            ldc,
+                        );
+                        for j in 0..n {
+                            expected[j + j * ldc].im = 0.0;
+                        }
+                        assert_matrix_close(ldc, n, &he, ldc, &expected, ldc, 1e-14);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn trmm_multiplies_by_every_triangle() {
+    let mut rng = Rng::new(34);
+    let alpha = C::new(1.5, -0.5);
+
+    for (m, n) in [(1, 1), (5, 3), (3, 6)] {
+        let ldb = m + 2;
+        let b0 = rng.complex_vec(ldb * n);
+
+        for side in ['L', 'R'] {
+            let k = if side == 'L' { m } else { n };
+            for uplo in ['U', 'L'] {
+                for transa in ['N', 'T', 'C'] {
+                    for diag in ['N', 'U'] {
+                        let a = triangular(&mut rng, uplo, diag, k);
+                        let lda = k + 1;
+                        let mut b = b0.clone();
+                        trmm_complex(
+                            &side,
+                            &uplo,
+                            &transa,
+                            &diag,
+                            &(m as isize),
+                            &(n as isize),
+                            &alpha,
+                            &vector(&a),
+                            &(lda as isize),
+                            &mut vector_mut(&mut b),
+                            &(ldb as isize),
+                        );
+
+                        // alpha * op(A) * B or alpha * B * op(A)
+                        let op = |i, j| op_triangle(uplo, transa, diag, &a, lda, i, j);
+                        let scaled = |i: usize, j: usize| alpha * b0[i + j * ldb];
+                        let expected = if side == 'L' {
+                            mul(m, n, m, op, scaled)
+                        } else {
+                            mul(m, n, n, scaled, op)
+                        };
+                        assert_matrix_close(m, n, &b, ldb, &expected, m, 1e-14);
+
+                        // the rows past m are left alone
+                        for j in 0..n {
+                            let rows = m + j * ldb..(j + 1) * ldb;
+                            assert_eq!(b[rows.clone()], b0[rows]);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use naive_blas::mat::{MatMut, MatRef};
+use nd_slice::{NdSlice, NdSliceMut};
+
+#[test]
+fn views_of_nd_slices_follow_their_order() {
+    // 2 by 3 matrix [[1, 2, 3], [4, 5, 6]]
+    let row = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let col = [1.0, 4.0, 2.0, 5.0, 3.0, 6.0];
+
+    let row = NdSlice::new_row_ordered(&row, [2, 3]).unwrap();
+    let col = NdSlice::col_ordered(&col, [2, 3]).unwrap();
+    for a in [
+        MatRef::from_nd_slice(&row, [2, 3]),
+        MatRef::from_nd_slice(&col, [2, 3]),
+    ] {
+        assert_eq!((a.rows(), a.cols()), (2, 3));
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(a.get(i, j), Some(&((3 * i + j + 1) as f64)));
+            }
+        }
+        assert_eq!(a.get(2, 0), None);
+    }
+
+    let mut data = [0.0; 6];
+    let mut nd = NdSliceMut::new_row_ordered(&mut data, [2, 3]).unwrap();
+    let mut a = MatMut::from_nd_slice(&mut nd, [2, 3]);
+    *a.get_mut(1, 0).unwrap() = 7.0;
+    assert_eq!(data, [0.0, 0.0, 0.0, 7.0, 0.0, 0.0]);
+}
+
+#[test]
+fn transposes_and_submatrices_only_change_the_layout() {
+    let data: Vec<f64> = (0..12).map(f64::from).collect();
+    // 3 by 4 column-major
+    let a = MatRef::col_major(&data, 3, 4, 3).unwrap();
+
+    let t = a.t();
+    assert_eq!((t.rows(), t.cols()), (4, 3));
+    assert_eq!((t.row_stride(), t.col_stride()), (3, 1));
+    assert_eq!(t.get(3, 1), a.get(1, 3));
+
+    assert!(!a.is_conj());
+    assert!(a.h().is_conj() && !a.h().h().is_conj());
+    assert_eq!(a.h().get(2, 0), a.get(0, 2));
+
+    let s = a.submatrix(1, 1, 2, 2);
+    assert_eq!(s.get(0, 0), Some(&4.0));
+    assert_eq!(s.get(1, 1), Some(&8.0));
+    assert_eq!(s.col(1).iter().copied().collect::<Vec<_>>(), [7.0, 8.0]);
+    assert_eq!(s.row(0).iter().copied().collect::<Vec<_>>(), [4.0, 7.0]);
+}
+
+#[test]
+#[should_panic]
+fn submatrices_must_lie_inside_the_matrix() {
+    let data = [0.0; 6];
+    MatRef::col_major(&data, 2, 3, 2)
+        .unwrap()
+        .submatrix(1, 1, 2, 2);
+}
+
+#[test]
+fn blas_arguments_describe_the_view() {
+    let data: Vec<f64> = (0..12).map(f64::from).collect();
+    let a = MatRef::col_major(&data, 3, 3, 4).unwrap();
+
+    let (trans, x, ld) = a.blas().unwrap();
+    assert_eq!((trans, ld, x[[0]]), ('N', 4, 0.0));
+
+    let (trans, x, ld) = a.submatrix(1, 1, 2, 2).t().blas().unwrap();
+    assert_eq!((trans, ld, x[[0]]), ('T', 4, 5.0));
+
+    assert_eq!(a.h().blas().unwrap().0, 'C');
+    assert!(a.conj().blas().is_none());
+
+    // neither stride is 1
+    let b = MatRef::new(&data, 2, 2, 2, 6, 0).unwrap();
+    assert!(b.blas().is_none());
+}
+
+#[test]
+fn contiguous_views_convert_back_to_nd_slices() {
+    let data: Vec<f64> = (0..6).map(f64::from).collect();
+
+    let a = MatRef::col_major(&data, 2, 3, 2).unwrap();
+    let nd = a.to_nd_slice().unwrap();
+    assert_eq!(nd[[1, 2]], 5.0);
+
+    let nd = a.t().to_nd_slice().unwrap();
+    assert_eq!(nd[[2, 1]], 5.0);
+
+    assert!(MatRef::col_major(&data, 2, 2, 3)
+        .unwrap()
+        .to_nd_slice()
+        .is_none());
+}
+
+#[test]
+fn mutable_views_must_not_overlap() {
+    let mut data = [0.0; 6];
+    assert!(MatMut::new(&mut data, 2, 3, 1, 2, 0).is_ok());
+    assert!(MatMut::new(&mut data, 2, 3, 1, 1, 0).is_err());
+    assert!(MatMut::new(&mut data, 2, 3, 1, 2, 1).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn gemm_handles_every_layout() {
+    use naive_blas::mat::gemm;
+
+    // A is 2 by 3, B is 3 by 2
+    let a_col = [1.0, 4.0, 2.0, 5.0, 3.0, 6.0];
+    let a_row = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let b_col = [1.0, 0.0, -1.0, 2.0, 1.0, 0.0];
+    let b_row = [1.0, 2.0, 0.0, 1.0, -1.0, 0.0];
+    // strided views with neither stride 1
+    let a_str: Vec<f64> = a_row.iter().flat_map(|&x| [x, f64::NAN]).collect();
+    let expected = [[-2.0, 4.0], [-2.0, 13.0]];
+
+    let a_views = [
+        MatRef::col_major(&a_col, 2, 3, 2).unwrap(),
+        MatRef::row_major(&a_row, 2, 3, 3).unwrap(),
+        MatRef::new(&a_str, 2, 3, 6, 2, 0).unwrap(),
+    ];
+    let b_views = [
+        MatRef::col_major(&b_col, 3, 2, 3).unwrap(),
+        MatRef::row_major(&b_row, 3, 2, 2).unwrap(),
+    ];
+
+    for a in a_views.iter() {
+        for b in b_views.iter() {
+            for &row_major in &[false, true] {
+                let mut data = [1.0; 4];
+                let c = if row_major {
+                    MatMut::row_major(&mut data, 2, 2, 2).unwrap()
+                } else {
+                    MatMut::col_major(&mut data, 2, 2, 2).unwrap()
+                };
+                gemm(1.0, *a, *b, 0.5, c);
+
+                let c = if row_major {
+                    MatRef::row_major(&data, 2, 2, 2).unwrap()
+                } else {
+                    MatRef::col_major(&data, 2, 2, 2).unwrap()
+                };
+                for (i, row) in expected.iter().enumerate() {
+                    for (j, &cij) in row.iter().enumerate() {
+                        assert_eq!(c.get(i, j), Some(&(cij + 0.5)));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "nalgebra")]
+
+use naive_blas::mat::{self, MatMut, MatRef};
+use naive_blas::na;
+use naive_blas::strided::{StridedVec, StridedVecMut};
+use nalgebra::{dmatrix, dvector, DMatrix, DVector};
+
+#[test]
+fn level_1_follows_nalgebra_strides() {
+    let a = DMatrix::from_fn(5, 6, |i, j| (i * 6 + j) as f64);
+
+    let col = a.column(2);
+    let mut y = DVector::from_element(5, 1.0);
+    na::axpy(2.0, &col, &mut y);
+    assert_eq!(y, col * 2.0 + DVector::from_element(5, 1.0));
+    assert_eq!(na::dot(&col, &col), col.dot(&col));
+
+    // a column of every other row has stride 2
+    let rows = a.view_with_steps((0, 0), (3, 6), (1, 0));
+    let col = rows.column(3);
+    assert!((na::nrm2(&col) - col.norm()).abs() < 1e-12);
+    assert_eq!(na::nrm2(&DVector::<f64>::zeros(0)), 0.0);
+
+    // every other element of a column
+    let mut b = a.clone();
+    let mut every_other = b.view_with_steps_mut((0, 1), (3, 1), (1, 0));
+    na::scal(2.0, &mut every_other.column_mut(0));
+    let mut expected = a.clone();
+    for i in [0, 2, 4] {
+        expected[(i, 1)] *= 2.0;
+    }
+    assert_eq!(b, expected);
+
+    let mut y = DVector::zeros(6);
+    na::copy(&a.row(4).transpose(), &mut y);
+    assert_eq!(y, a.row(4).transpose());
+
+    let mut y = dvector![1.0, 1.0];
+    na::axpby(1.0, &a.column(0).rows(0, 2), -1.0, &mut y);
+    assert_eq!(y, dvector![-1.0, 5.0]);
+}
+
+#[test]
+fn interleaved_mutable_views_stay_disjoint() {
+    // the rows of a column-major matrix are interleaved
+    let mut a = DMatrix::from_fn(4, 3, |i, j| (3 * i + j) as f64);
+    let expected = {
+        let mut e = a.clone();
+        let top = a.rows(0, 2).clone_owned();
+        let b = dmatrix![1.0, 0.0, 1.0; 0.0, 2.0, 0.0; 0.0, 0.0, 1.0];
+        e.rows_mut(2, 2).gemm(1.0, &top, &b, 1.0);
+        e
+    };
+    {
+        let (top, mut bottom) = a.rows_range_pair_mut(0..2, 2..4);
+        let b = dmatrix![1.0, 0.0, 1.0; 0.0, 2.0, 0.0; 0.0, 0.0, 1.0];
+        na::gemm(1.0, &top, &b, 1.0, &mut bottom);
+    }
+    assert_eq!(a, expected);
+
+    let mut a = DMatrix::from_fn(4, 2, |i, j| (2 * i + j) as f64);
+    {
+        let (mut top, mut bottom) = a.rows_range_pair_mut(0..2, 2..4);
+        na::axpy(1.0, &bottom.column(1), &mut top.column_mut(0));
+        na::swap(&mut top.column_mut(1), &mut bottom.column_mut(0));
+    }
+    assert_eq!(a, dmatrix![5.0, 4.0; 9.0, 6.0; 1.0, 5.0; 3.0, 7.0]);
+}
+
+#[test]
+fn gemm_handles_views() {
+    let a = DMatrix::from_fn(5, 6, |i, j| (i * 6 + j) as f64);
+    let v = a.view_with_steps((0, 0), (3, 3), (1, 1));
+    let vo = v.clone_owned();
+
+    let mut c = DMatrix::zeros(3, 3);
+    na::gemm(1.0, &v, &v.transpose(), 0.0, &mut c);
+    assert_eq!(c, &vo * vo.transpose());
+
+    let mut big = DMatrix::zeros(6, 6);
+    let mut sub = big.view_with_steps_mut((1, 1), (3, 3), (1, 1));
+    na::gemm(2.0, &vo.transpose(), &vo, 0.0, &mut sub);
+    let expected = vo.transpose() * &vo * 2.0;
+    assert_eq!(big.view_with_steps((1, 1), (3, 3), (1, 1)), expected);
+    big.view_with_steps_mut((1, 1), (3, 3), (1, 1)).fill(0.0);
+    assert_eq!(big, DMatrix::zeros(6, 6));
+}
+
+#[test]
+fn strided_views_are_borrowed() {
+    let a = DMatrix::from_fn(4, 3, |i, j| (3 * i + j) as f64);
+
+    // a block keeps the column stride of a as its leading dimension
+    let block = a.view((1, 1), (2, 2));
+    let m = MatRef::from(&block);
+    assert_eq!((m.row_stride(), m.col_stride()), (1, 4));
+    assert!(std::ptr::eq(m.get(1, 0).unwrap(), &a[(2, 1)]));
+
+    // every other element of a column has stride 2
+    let rows = a.view_with_steps((0, 1), (2, 1), (1, 0));
+    let col = rows.column(0);
+    let x = StridedVec::from(&col);
+    assert!(std::ptr::eq(x.get(1).unwrap(), &a[(2, 1)]));
+    assert_eq!(x.iter().copied().collect::<Vec<_>>(), [1.0, 7.0]);
+
+    let mut b = a.clone();
+    let mut block = b.view_mut((1, 1), (2, 2));
+    *MatMut::from(&mut block).get_mut(1, 0).unwrap() = -1.0;
+    let mut every_other = b.view_with_steps_mut((0, 2), (2, 1), (1, 0));
+    *StridedVecMut::from(&mut every_other.column_mut(0))
+        .get_mut(1)
+        .unwrap() = -2.0;
+    assert_eq!((b[(2, 1)], b[(2, 2)]), (-1.0, -2.0));
+
+    let a = a.columns(0, 2);
+    let mut c = DMatrix::zeros(2, 2);
+    mat::gemm(
+        1.0,
+        MatRef::from(&a).t(),
+        MatRef::from(&a),
+        0.0,
+        MatMut::from(&mut c),
+    );
+    assert_eq!(c, a.transpose() * a);
+}
@@ -0,0 +1,178 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use naive_blas::lapack::{posv::posv, potrf::potrf, potrs::potrs, LapackError};
+use num_complex::Complex;
+
+use common::{assert_matrix_close, mul, vector, vector_mut, Rng};
+
+type C = Complex<f64>;
+
+/// Returns a random Hermitian positive definite matrix Bᴴ * B + n * I, with leading dimension
+/// lda. Only the triangle uplo is set, the rest being NaN so that reading it shows.
+fn hpd(rng: &mut Rng, uplo: char, n: usize, lda: usize) -> Vec<C> {
+    let b = rng.complex_vec(n * n);
+    let full = mul(n, n, n, |i, l| b[l + i * n].conj(), |l, j| b[l + j * n]);
+
+    let mut a = vec![C::new(f64::NAN, f64::NAN); lda * n];
+    for j in 0..n {
+        for i in 0..n {
+            if (uplo == 'U' && i <= j) || (uplo == 'L' && i >= j) {
+                a[i + j * lda] = full[i + j * n];
+            }
+        }
+        a[j + j * lda] = C::new(full[j + j * n].re + n as f64, 0.0);
+    }
+    a
+}
+
+/// Returns the full matrix of a Hermitian matrix stored in the triangle uplo.
+fn hermitian(uplo: char, a: &[C], lda: usize) -> impl Fn(usize, usize) -> C + '_ {
+    move |i, j| {
+        if (uplo == 'U') == (i <= j) || i == j {
+            a[i + j * lda]
+        } else {
+            a[j + i * lda].conj()
+        }
+    }
+}
+
+#[test]
+fn potrf_factors_either_triangle() {
+    let mut rng = Rng::new(43);
+
+    // the unblocked and blocked paths, with the blocked one ending on a partial block
+    for n in [1, 2, 5, 32, 33, 70, 100] {
+        for uplo in ['U', 'L'] {
+            let lda = n + 3;
+            let a0 = hpd(&mut rng, uplo, n, lda);
+            let mut a = a0.clone();
+            potrf(
+                &uplo,
+                &(n as isize),
+                &mut vector_mut(&mut a),
+                &(lda as isize),
+            )
+            .unwrap();
+
+            let zero = C::new(0.0, 0.0);
+            let (u, l) = (
+                |i: usize, j: usize| if i <= j { a[i + j * lda] } else { zero },
+                |i: usize, j: usize| if i >= j { a[i + j * lda] } else { zero },
+            );
+            let product = if uplo == 'U' {
+                mul(n, n, n, |i, k| u(k, i).conj(), u)
+            } else {
+                mul(n, n, n, l, |k, j| l(j, k).conj())
+            };
+            let h = hermitian(uplo, &a0, lda);
+            let full: Vec<C> = (0..n * n).map(|k| h(k % n, k / n)).collect();
+            assert_matrix_close(n, n, &product, n, &full, n, 1e-12);
+
+            // the diagonal of the factor is real and positive
+            assert!((0..n).all(|i| a[i + i * lda].im == 0.0 && a[i + i * lda].re > 0.0));
+        }
+    }
+}
+
+#[test]
+fn potrs_and_posv_solve_either_triangle() {
+    let mut rng = Rng::new(44);
+
+    for n in [4, 50] {
+        for uplo in ['U', 'L'] {
+            let (lda, ldb, nrhs) = (n + 1, n + 2, 3);
+            let a0 = hpd(&mut rng, uplo, n, lda);
+            let x = rng.complex_vec(ldb * nrhs);
+            let ax = mul(n, nrhs, n, hermitian(uplo, &a0, lda), |i, j| x[i + j * ldb]);
+            let mut b = vec![C::new(0.0, 0.0); ldb * nrhs];
+            for j in 0..nrhs {
+                b[j * ldb..j * ldb + n].copy_from_slice(&ax[j * n..(j + 1) * n]);
+            }
+
+            let mut a = a0.clone();
+            let mut b1 = b.clone();
+            posv(
+                &uplo,
+                &(n as isize),
+                &(nrhs as isize),
+                &mut vector_mut(&mut a),
+                &(lda as isize),
+                &mut vector_mut(&mut b1),
+                &(ldb as isize),
+            )
+            .unwrap();
+            assert_matrix_close(n, nrhs, &b1, ldb, &x, ldb, 1e-10);
+
+            // a holds the factor now
+            potrs(
+                &uplo,
+                &(n as isize),
+                &(nrhs as isize),
+                &vector(&a),
+                &(lda as isize),
+                &mut vector_mut(&mut b),
+                &(ldb as isize),
+            )
+            .unwrap();
+            assert_matrix_close(n, nrhs, &b, ldb, &x, ldb, 1e-10);
+        }
+    }
+
+    // real matrices go through the same code
+    let mut a = [4.0f64, 2.0, 2.0, 5.0];
+    let mut b = [8.0, 9.0];
+    posv(
+        &'L',
+        &2,
+        &1,
+        &mut vector_mut(&mut a),
+        &2,
+        &mut vector_mut(&mut b),
+        &2,
+    )
+    .unwrap();
+    assert_eq!(a[..2], [2.0, 1.0]);
+    assert_eq!(a[3], 2.0);
+    for (b, x) in b.iter().zip([1.375, 1.25]) {
+        common::assert_close(*b, x, 1e-12);
+    }
+}
+
+#[test]
+fn indefinite_matrices_are_reported() {
+    let mut rng = Rng::new(45);
+
+    for n in [5, 80] {
+        for uplo in ['U', 'L'] {
+            let k = n - 2;
+            let mut a = hpd(&mut rng, uplo, n, n);
+            a[k + k * n] = C::new(-1e6, 0.0);
+            let mut b = vec![C::new(1.0, 0.0); n];
+            assert_eq!(
+                posv(
+                    &uplo,
+                    &(n as isize),
+                    &1,
+                    &mut vector_mut(&mut a),
+                    &(n as isize),
+                    &mut vector_mut(&mut b),
+                    &(n as isize)
+                ),
+                Err(LapackError::NotPositiveDefinite(k))
+            );
+            assert_eq!(b, vec![C::new(1.0, 0.0); n]);
+        }
+    }
+
+    let mut a = [1.0f64; 4];
+    assert_eq!(
+        potrf(&'X', &1, &mut vector_mut(&mut a), &1),
+        Err(LapackError::Argument(1))
+    );
+    assert_eq!(
+        potrf(&'U', &2, &mut vector_mut(&mut a), &1),
+        Err(LapackError::Argument(4))
+    );
+}
@@ -0,0 +1,145 @@
+//! The unit-stride calls below take the SIMD kernels where the CPU has them, while the same
+//! calls on a stride of 2 take the scalar loops, which are the reference.
+
+mod common;
+
+use std::fmt::Debug;
+
+use naive_blas::level_1::{axpy, copy, dot, nrm2, rot, scal, swap};
+use num_traits::Float;
+
+use common::{vector, vector_mut, Rng};
+
+/// Lengths around every multiple of the 2, 4, 8 and 16 lanes of the kernels.
+fn lengths() -> impl Iterator<Item = usize> {
+    (0..=70).chain([255, 256, 257, 1000])
+}
+
+fn data<T: Float>(rng: &mut Rng, n: usize) -> Vec<T> {
+    (0..n).map(|_| T::from(rng.next()).unwrap()).collect()
+}
+
+/// Spreads x over a vector of stride 2, padding with NaN so that a stray access shows.
+fn spread<T: Float>(x: &[T]) -> Vec<T> {
+    x.iter().flat_map(|&xi| [xi, T::nan()]).collect()
+}
+
+fn gather<T: Float>(x: &[T]) -> Vec<T> {
+    x.iter().step_by(2).copied().collect()
+}
+
+fn elementwise<T: Float + Debug + 'static>(seed: u64) {
+    let mut rng = Rng::new(seed);
+
+    for n in lengths() {
+        let (x, y) = (data::<T>(&mut rng, n), data::<T>(&mut rng, n));
+        let (a, c, s) = (
+            T::from(0.7).unwrap(),
+            T::from(0.6).unwrap(),
+            T::from(0.8).unwrap(),
+        );
+        let len = n as isize;
+
+        let mut unit = y.clone();
+        axpy::axpy_real(&len, &a, &vector(&x), &1, &mut vector_mut(&mut unit), &1);
+        let mut strided = spread(&y);
+        axpy::axpy_real(
+            &len,
+            &a,
+            &vector(&spread(&x)),
+            &2,
+            &mut vector_mut(&mut strided),
+            &2,
+        );
+        assert_eq!(unit, gather(&strided), "axpy, n = {}", n);
+
+        let mut unit = x.clone();
+        scal::scal_real(&len, &a, &mut vector_mut(&mut unit), &1);
+        let mut strided = spread(&x);
+        scal::scal_real(&len, &a, &mut vector_mut(&mut strided), &2);
+        assert_eq!(unit, gather(&strided), "scal, n = {}", n);
+
+        let mut unit = y.clone();
+        copy::copy(&len, &vector(&x), &1, &mut vector_mut(&mut unit), &1);
+        assert_eq!(unit, x, "copy, n = {}", n);
+
+        let (mut ux, mut uy) = (x.clone(), y.clone());
+        swap::swap(
+            &len,
+            &mut vector_mut(&mut ux),
+            &1,
+            &mut vector_mut(&mut uy),
+            &1,
+        );
+        assert_eq!((&ux, &uy), (&y, &x), "swap, n = {}", n);
+
+        let (mut ux, mut uy) = (x.clone(), y.clone());
+        rot::rot(
+            &len,
+            &mut vector_mut(&mut ux),
+            &1,
+            &mut vector_mut(&mut uy),
+            &1,
+            &c,
+            &s,
+        );
+        let (mut sx, mut sy) = (spread(&x), spread(&y));
+        rot::rot(
+            &len,
+            &mut vector_mut(&mut sx),
+            &2,
+            &mut vector_mut(&mut sy),
+            &2,
+            &c,
+            &s,
+        );
+        assert_eq!((ux, uy), (gather(&sx), gather(&sy)), "rot, n = {}", n);
+    }
+}
+
+fn reductions<T: Float + Debug + 'static>(seed: u64, tol: T) {
+    let mut rng = Rng::new(seed);
+
+    for n in lengths() {
+        let (x, y) = (data::<T>(&mut rng, n), data::<T>(&mut rng, n));
+        let len = n as isize;
+
+        let unit: T = dot::dot(&len, &vector(&x), &1, &vector(&y), &1);
+        let strided: T = dot::dot(&len, &vector(&spread(&x)), &2, &vector(&spread(&y)), &2);
+        let scale = x
+            .iter()
+            .zip(&y)
+            .fold(T::one(), |acc, (&xi, &yi)| acc + (xi * yi).abs());
+        assert!((unit - strided).abs() <= tol * scale, "dot, n = {}", n);
+
+        let unit = nrm2::nrm2(&len, &mut vector(&x), &1);
+        let strided = nrm2::nrm2(&len, &mut vector(&spread(&x)), &2);
+        assert!((unit - strided).abs() <= tol * strided, "nrm2, n = {}", n);
+    }
+}
+
+#[test]
+fn kernels_match_the_scalar_loops_f32() {
+    elementwise::<f32>(1);
+    reductions::<f32>(2, 1e-5);
+}
+
+#[test]
+fn kernels_match_the_scalar_loops_f64() {
+    elementwise::<f64>(3);
+    reductions::<f64>(4, 1e-13);
+}
+
+#[test]
+fn nrm2_kernel_avoids_overflow_and_underflow() {
+    for &big in &[1e300f64, 1e-300] {
+        let x: Vec<f64> = (1..=100).map(|i| i as f64 * big).collect();
+        let expected = (1..=100).map(|i| (i * i) as f64).sum::<f64>().sqrt() * big;
+
+        let res = nrm2::nrm2(&100, &mut vector(&x), &1);
+        assert!((res / expected - 1.0).abs() < 1e-14);
+    }
+
+    let x = vec![1e30f32; 64];
+    assert!((nrm2::nrm2(&64, &mut vector(&x), &1) / 8e30 - 1.0).abs() < 1e-6);
+}
@@ -0,0 +1,277 @@
+mod common;
+
+use naive_blas::sparse::{
+    coo::Coo, csc::Csc, csr::Csr, spmm::spmm, spmv::spmv, trsv::trsv, SparseError, SparseMatrix,
+    Triangular,
+};
+use num_complex::Complex;
+
+use common::{vector, vector_mut};
+
+type C = Complex<f64>;
+
+const ZERO: C = C::new(0.0, 0.0);
+
+/// An m by n matrix with about a third of its elements set, the diagonal included.
+fn dense(m: usize, n: usize) -> Vec<Vec<C>> {
+    (0..m)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    if (i + j) % 3 == 0 || i == j {
+                        C::new((i + 2 * j + 1) as f64, i as f64 - j as f64)
+                    } else {
+                        ZERO
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns op(A) for trans 'N', 'T' or 'C'.
+fn op(a: &[Vec<C>], trans: char) -> Vec<Vec<C>> {
+    if trans == 'N' {
+        return a.to_vec();
+    }
+
+    (0..a[0].len())
+        .map(|j| {
+            a.iter()
+                .map(|row| if trans == 'C' { row[j].conj() } else { row[j] })
+                .collect()
+        })
+        .collect()
+}
+
+fn matvec(a: &[Vec<C>], x: &[C]) -> Vec<C> {
+    a.iter()
+        .map(|row| row.iter().zip(x).map(|(a, x)| a * x).sum())
+        .collect()
+}
+
+#[track_caller]
+fn assert_all_close(a: &[C], b: &[C]) {
+    for (i, (a, b)) in a.iter().zip(b).enumerate() {
+        assert!((a - b).norm() < 1e-9, "{} and {} differ at {}", a, b, i);
+    }
+}
+
+/// The arrays of a sparse matrix: pointers or row indices, column or row indices, and values.
+type Arrays = (Vec<isize>, Vec<isize>, Vec<C>);
+
+fn to_csr(d: &[Vec<C>], base: isize) -> Arrays {
+    let (mut ptr, mut ind, mut val) = (vec![base], vec![], vec![]);
+    for row in d {
+        for (j, &a) in row.iter().enumerate() {
+            if a != ZERO {
+                ind.push(j as isize + base);
+                val.push(a);
+            }
+        }
+        ptr.push(ind.len() as isize + base);
+    }
+    (ptr, ind, val)
+}
+
+/// Stores the rows of each column in decreasing order, as the format does not require them to
+/// be sorted.
+fn to_csc(d: &[Vec<C>], base: isize) -> Arrays {
+    let (mut ptr, mut ind, mut val) = (vec![base], vec![], vec![]);
+    for j in 0..d[0].len() {
+        for (i, row) in d.iter().enumerate().rev() {
+            if row[j] != ZERO {
+                ind.push(i as isize + base);
+                val.push(row[j]);
+            }
+        }
+        ptr.push(ind.len() as isize + base);
+    }
+    (ptr, ind, val)
+}
+
+/// Stores the elements column by column, and splits the (0, 0) element into two entries, which
+/// are summed.
+fn to_coo(d: &[Vec<C>], base: isize) -> Arrays {
+    let (mut rows, mut cols, mut val) = (vec![], vec![], vec![]);
+    for j in 0..d[0].len() {
+        for (i, row) in d.iter().enumerate() {
+            if row[j] != ZERO {
+                rows.push(i as isize + base);
+                cols.push(j as isize + base);
+                val.push(row[j]);
+            }
+        }
+    }
+    val[0] -= 1.0;
+    rows.push(base);
+    cols.push(base);
+    val.push(C::new(1.0, 0.0));
+    (rows, cols, val)
+}
+
+/// Checks spmv and spmm with every op against dense products.
+fn check_products<A: SparseMatrix<C>>(a: &A, d: &[Vec<C>]) {
+    let (alpha, beta) = (C::new(2.0, 0.5), C::new(0.5, -1.0));
+
+    for trans in ['N', 'T', 'C'] {
+        let o = op(d, trans);
+        let (m, k) = (o.len(), o[0].len());
+
+        let x: Vec<C> = (0..k).map(|i| C::new(i as f64, 1.0)).collect();
+        let mut y: Vec<C> = (0..m).map(|i| C::new(1.0, i as f64)).collect();
+        let expected: Vec<C> = matvec(&o, &x)
+            .iter()
+            .zip(&y)
+            .map(|(ax, y)| alpha * ax + beta * y)
+            .collect();
+        spmv(
+            &trans,
+            &alpha,
+            a,
+            &vector(&x),
+            &beta,
+            &mut vector_mut(&mut y),
+        );
+        assert_all_close(&y, &expected);
+
+        // two columns with padded leading dimensions, the padding of C must stay untouched
+        let (ldb, ldc) = (k + 1, m + 2);
+        let mut b = vec![ZERO; 2 * ldb];
+        for (j, col) in b.chunks_mut(ldb).enumerate() {
+            for (b, x) in col.iter_mut().zip(&x) {
+                *b = x * (j as f64 + 1.0);
+            }
+        }
+        let mut c = vec![C::new(9.0, 9.0); 2 * ldc];
+        spmm(
+            &trans,
+            &2,
+            &alpha,
+            a,
+            &vector(&b),
+            &(ldb as isize),
+            &ZERO,
+            &mut vector_mut(&mut c),
+            &(ldc as isize),
+        );
+        for (j, col) in c.chunks(ldc).enumerate() {
+            let expected: Vec<C> = matvec(&o, &x)
+                .iter()
+                .map(|ax| alpha * ax * (j as f64 + 1.0))
+                .collect();
+            assert_all_close(&col[..m], &expected);
+            assert!(col[m..].iter().all(|&c| c == C::new(9.0, 9.0)));
+        }
+    }
+}
+
+/// Checks trsv for every triangle, diagonal and op by solving for a known x.
+fn check_trsv<A: Triangular<C>>(a: &A, d: &[Vec<C>]) {
+    let n = d.len();
+    let expected: Vec<C> = (0..n).map(|i| C::new(i as f64 + 1.0, -1.0)).collect();
+
+    for uplo in ['U', 'L'] {
+        for diag in ['N', 'U'] {
+            let mut tri = d.to_vec();
+            for (i, row) in tri.iter_mut().enumerate() {
+                for (j, a) in row.iter_mut().enumerate() {
+                    if (uplo == 'U' && j < i) || (uplo == 'L' && j > i) {
+                        *a = ZERO;
+                    } else if diag == 'U' && i == j {
+                        *a = C::new(1.0, 0.0);
+                    }
+                }
+            }
+
+            for trans in ['N', 'T', 'C'] {
+                let mut x = matvec(&op(&tri, trans), &expected);
+                trsv(&uplo, &trans, &diag, a, &mut vector_mut(&mut x));
+                assert_all_close(&x, &expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn products_match_the_dense_products() {
+    for (m, n) in [(4, 6), (6, 4), (5, 5)] {
+        let d = dense(m, n);
+        for base in [0, 1] {
+            let (ptr, ind, val) = to_csr(&d, base);
+            check_products(&Csr::new(m, n, &ptr, &ind, &val, base).unwrap(), &d);
+
+            let (ptr, ind, val) = to_csc(&d, base);
+            check_products(&Csc::new(m, n, &ptr, &ind, &val, base).unwrap(), &d);
+
+            let (rows, cols, val) = to_coo(&d, base);
+            check_products(&Coo::new(m, n, &rows, &cols, &val, base).unwrap(), &d);
+        }
+    }
+}
+
+#[test]
+fn trsv_solves_with_either_triangle() {
+    let d = dense(5, 5);
+    for base in [0, 1] {
+        let (ptr, ind, val) = to_csr(&d, base);
+        check_trsv(&Csr::new(5, 5, &ptr, &ind, &val, base).unwrap(), &d);
+
+        let (ptr, ind, val) = to_csc(&d, base);
+        check_trsv(&Csc::new(5, 5, &ptr, &ind, &val, base).unwrap(), &d);
+    }
+}
+
+#[test]
+fn real_matrices_work_too() {
+    // [1 0 2]
+    // [0 3 0]
+    let a = Csr::new(2, 3, &[1, 3, 4], &[1, 3, 2], &[1.0f64, 2.0, 3.0], 1).unwrap();
+    let x = [1.0, 2.0, 3.0];
+    let mut y = [1.0, 1.0];
+    spmv(&'N', &2.0, &a, &vector(&x), &-1.0, &mut vector_mut(&mut y));
+    assert_eq!(y, [13.0, 11.0]);
+
+    let mut y = [0.0; 3];
+    spmv(
+        &'T',
+        &1.0,
+        &a,
+        &vector(&[1.0, 1.0]),
+        &0.0,
+        &mut vector_mut(&mut y),
+    );
+    assert_eq!(y, [1.0, 3.0, 2.0]);
+}
+
+#[test]
+fn invalid_arrays_are_rejected() {
+    let d = dense(4, 4);
+    let (ptr, ind, val) = to_csr(&d, 0);
+    assert!(Csr::new(4, 4, &ptr, &ind, &val, 0).is_ok());
+    assert_eq!(
+        Csr::new(4, 4, &ptr, &ind, &val, 2).unwrap_err(),
+        SparseError::Base
+    );
+    assert_eq!(
+        Csr::new(4, 4, &ptr[1..], &ind, &val, 0).unwrap_err(),
+        SparseError::Pointers
+    );
+    assert_eq!(
+        Csr::new(4, 4, &ptr, &ind, &val[1..], 0).unwrap_err(),
+        SparseError::Lengths
+    );
+
+    let mut bad = ind.clone();
+    bad[2] = 4;
+    assert_eq!(
+        Csr::new(4, 4, &ptr, &bad, &val, 0).unwrap_err(),
+        SparseError::Index(2)
+    );
+    let (rows, mut cols, val) = to_coo(&d, 1);
+    cols[1] = 0;
+    assert_eq!(
+        Coo::new(4, 4, &rows, &cols, &val, 1).unwrap_err(),
+        SparseError::Index(1)
+    );
+}
@@ -0,0 +1,72 @@
+use naive_blas::strided::{StrideError, StridedVec, StridedVecMut};
+
+fn elements(x: &StridedVecMut<'_, f64>) -> Vec<f64> {
+    x.as_vec().iter().copied().collect()
+}
+
+#[test]
+fn zero_stride_is_normalised_for_a_single_element() {
+    let mut data = [1.0, 2.0, 3.0];
+
+    let mut x = StridedVecMut::new(&mut data, 1, 0, 2).unwrap();
+    assert_eq!(x.stride(), 1);
+    x.scal(2.0);
+    assert_eq!(elements(&x), [6.0]);
+    assert_eq!(x.nrm2(), 6.0);
+
+    let mut x = StridedVecMut::new(&mut data, 0, 0, 3).unwrap();
+    assert_eq!(x.stride(), 1);
+    x.scal(2.0);
+    assert!(x.is_empty());
+    assert_eq!(data, [1.0, 2.0, 6.0]);
+
+    let x = StridedVec::new(&data, 1, 0, 0).unwrap();
+    assert_eq!(x.stride(), 1);
+    assert_eq!(x.nrm2(), 1.0);
+}
+
+#[test]
+fn zero_stride_is_rejected_for_mutable_vectors() {
+    let mut data = [1.0, 2.0, 3.0];
+    assert_eq!(
+        StridedVecMut::new(&mut data, 2, 0, 0).unwrap_err(),
+        StrideError {
+            len: 2,
+            stride: 0,
+            offset: 0,
+            slice_len: 3,
+        }
+    );
+
+    // an immutable vector may repeat one element
+    let x = StridedVec::new(&data, 3, 0, 1).unwrap();
+    assert_eq!(x.iter().copied().collect::<Vec<_>>(), [2.0; 3]);
+    assert_eq!(x.dot(&StridedVec::from(&data[..])), 12.0);
+}
+
+#[test]
+fn vectors_must_fit_in_their_slice() {
+    let data = [0.0; 5];
+    assert!(StridedVec::new(&data, 3, 2, 0).is_ok());
+    assert!(StridedVec::new(&data, 3, 2, 1).is_err());
+    assert!(StridedVec::new(&data, 3, -2, 4).is_ok());
+    assert!(StridedVec::new(&data, 3, -2, 3).is_err());
+    assert!(StridedVec::new(&data, 0, 1, 5).is_ok());
+    assert!(StridedVec::new(&data, 0, 1, 6).is_err());
+    assert!(StridedVec::new(&data, 2, isize::MAX, 0).is_err());
+}
+
+#[test]
+fn negative_strides_run_last_to_first() {
+    let x = [1.0, 2.0, 3.0];
+    let mut data = [0.0; 5];
+
+    let mut y = StridedVecMut::new(&mut data, 3, -2, 4).unwrap();
+    y.copy_from(&StridedVec::from(&x[..]));
+    assert_eq!(elements(&y), [1.0, 2.0, 3.0]);
+    assert_eq!(data, [3.0, 0.0, 2.0, 0.0, 1.0]);
+
+    let mut y = StridedVecMut::new(&mut data, 3, 2, 0).unwrap();
+    y.axpby(1.0, &StridedVec::from(&x[..]), 0.5);
+    assert_eq!(elements(&y), [2.5, 3.0, 3.5]);
+}
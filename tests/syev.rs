@@ -0,0 +1,237 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use naive_blas::lapack::{
+    steqr::steqr,
+    syev::{heev, syev},
+    sytrd::sytrd,
+    LapackError,
+};
+use num_complex::Complex;
+
+use common::{mul, vector_mut, Rng};
+
+type C = Complex<f64>;
+
+/// Returns an n by n Hermitian matrix of the given kind: random, zero, diagonal, nearly
+/// diagonal with repeated eigenvalues, or random and scaled close to overflow or underflow.
+fn hermitian(rng: &mut Rng, n: usize, kind: usize) -> Vec<C> {
+    let mut a = vec![C::new(0.0, 0.0); n * n];
+    for j in 0..n {
+        for i in 0..=j {
+            let x = match kind {
+                0 => rng.complex(),
+                1 => C::new(0.0, 0.0),
+                2 if i == j => C::new(2.0, 0.0),
+                3 if i == j => C::new((j % 3) as f64, 0.0),
+                3 if i + 1 == j => rng.complex() * 1e-20,
+                4 => rng.complex() * 1e300,
+                5 => rng.complex() * 1e-300,
+                _ => C::new(0.0, 0.0),
+            };
+            let x = if i == j { C::new(x.re, 0.0) } else { x };
+            a[i + j * n] = x;
+            a[j + i * n] = x.conj();
+        }
+    }
+    a
+}
+
+#[test]
+fn heev_satisfies_the_eigenvalue_equation() {
+    let mut rng = Rng::new(49);
+
+    // from the trivial sizes to ones needing many sweeps of steqr
+    for n in [0, 1, 2, 3, 4, 7, 16, 40, 75] {
+        for kind in 0..6 {
+            let full = hermitian(&mut rng, n, kind);
+            let scale = full
+                .iter()
+                .map(|x| x.norm())
+                .fold(f64::MIN_POSITIVE, f64::max);
+            let tol = 1e-13 * n.max(1) as f64;
+
+            for uplo in ['U', 'L'] {
+                // the other triangle is not referenced
+                let lda = n + 3;
+                let mut a = vec![C::new(f64::NAN, f64::NAN); lda * n];
+                for j in 0..n {
+                    for i in 0..n {
+                        if (uplo == 'U') == (i <= j) || i == j {
+                            a[i + j * lda] = full[i + j * n];
+                        }
+                    }
+                }
+                let mut values_only = a.clone();
+
+                let mut w = vec![0.0; n];
+                heev(
+                    &'V',
+                    &uplo,
+                    &(n as isize),
+                    &mut vector_mut(&mut a),
+                    &(lda as isize),
+                    &mut vector_mut(&mut w),
+                )
+                .unwrap();
+                assert!(w.windows(2).all(|w| w[0] <= w[1]), "{:?}", w);
+
+                // A * Z = Z * W, relative to the norm of A
+                let az = mul(n, n, n, |i, l| full[i + l * n], |l, j| a[l + j * lda]);
+                for j in 0..n {
+                    for i in 0..n {
+                        let residual = (az[i + j * n] - a[i + j * lda] * w[j]).norm();
+                        assert!(residual <= tol * scale, "{} {} {}", n, kind, residual);
+                    }
+                }
+
+                // Zᴴ * Z = I
+                let zhz = mul(n, n, n, |i, l| a[l + i * lda].conj(), |l, j| a[l + j * lda]);
+                for j in 0..n {
+                    for i in 0..n {
+                        let expected = if i == j { 1.0 } else { 0.0 };
+                        assert!((zhz[i + j * n] - expected).norm() <= tol);
+                    }
+                }
+
+                // the eigenvalues alone agree with those computed along with the vectors
+                let mut w2 = vec![0.0; n];
+                heev(
+                    &'N',
+                    &uplo,
+                    &(n as isize),
+                    &mut vector_mut(&mut values_only),
+                    &(lda as isize),
+                    &mut vector_mut(&mut w2),
+                )
+                .unwrap();
+                for (w, w2) in w.iter().zip(&w2) {
+                    assert!((w - w2).abs() <= tol * scale, "{} {}", w, w2);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn syev_solves_real_matrices() {
+    // [2 1 0]
+    // [1 2 1]
+    // [0 1 2], with eigenvalues 2 - √2, 2 and 2 + √2
+    let sqrt2 = std::f64::consts::SQRT_2;
+    for uplo in ['U', 'L'] {
+        let full = [2.0, 1.0, 0.0, 1.0, 2.0, 1.0, 0.0, 1.0, 2.0];
+        let mut a = full;
+        let mut w = [0.0; 3];
+        syev(
+            &'V',
+            &uplo,
+            &3,
+            &mut vector_mut(&mut a),
+            &3,
+            &mut vector_mut(&mut w),
+        )
+        .unwrap();
+        for (w, expected) in w.iter().zip([2.0 - sqrt2, 2.0, 2.0 + sqrt2]) {
+            common::assert_close(*w, expected, 1e-14);
+        }
+
+        // each column is a unit eigenvector
+        for j in 0..3 {
+            let z = &a[j * 3..j * 3 + 3];
+            for i in 0..3 {
+                let az: f64 = (0..3).map(|l| full[i + l * 3] * z[l]).sum();
+                common::assert_close(az, w[j] * z[i], 1e-14);
+            }
+            common::assert_close(z.iter().map(|z| z * z).sum(), 1.0, 1e-14);
+        }
+    }
+}
+
+#[test]
+fn sytrd_and_steqr_give_the_eigenvalues() {
+    let mut rng = Rng::new(50);
+    let n = 9;
+    let full = hermitian(&mut rng, n, 0);
+
+    let mut a = full.clone();
+    let (mut d, mut e) = (vec![0.0; n], vec![0.0; n - 1]);
+    let mut tau = vec![C::new(0.0, 0.0); n - 1];
+    sytrd(
+        &'L',
+        &(n as isize),
+        &mut vector_mut(&mut a),
+        &(n as isize),
+        &mut vector_mut(&mut d),
+        &mut vector_mut(&mut e),
+        &mut vector_mut(&mut tau),
+    )
+    .unwrap();
+
+    // the eigenvectors of the tridiagonal matrix, which steqr sorts along with d
+    let (d0, e0) = (d.clone(), e.clone());
+    let mut z = vec![0.0; n * n];
+    steqr::<f64>(
+        &'I',
+        &(n as isize),
+        &mut vector_mut(&mut d),
+        &mut vector_mut(&mut e),
+        &mut vector_mut(&mut z),
+        &(n as isize),
+    )
+    .unwrap();
+    let t = |i: usize, j: usize| match i as isize - j as isize {
+        0 => d0[i],
+        1 => e0[j],
+        -1 => e0[i],
+        _ => 0.0,
+    };
+    let tz = mul(n, n, n, t, |l, j| z[l + j * n]);
+    for j in 0..n {
+        for i in 0..n {
+            assert!((tz[i + j * n] - z[i + j * n] * d[j]).abs() < 1e-13);
+        }
+    }
+
+    let mut a = full;
+    let mut w = vec![0.0; n];
+    heev(
+        &'N',
+        &'U',
+        &(n as isize),
+        &mut vector_mut(&mut a),
+        &(n as isize),
+        &mut vector_mut(&mut w),
+    )
+    .unwrap();
+    for (d, w) in d.iter().zip(&w) {
+        assert!((d - w).abs() < 1e-13);
+    }
+}
+
+#[test]
+fn invalid_arguments_are_rejected() {
+    assert_eq!(
+        syev::<f64>(
+            &'X',
+            &'U',
+            &1,
+            &mut vector_mut(&mut [0.0]),
+            &1,
+            &mut vector_mut(&mut [0.0])
+        ),
+        Err(LapackError::Argument(1))
+    );
+    assert_eq!(
+        steqr::<f64>(
+            &'V',
+            &3,
+            &mut vector_mut(&mut [0.0; 3]),
+            &mut vector_mut(&mut [0.0; 2]),
+            &mut vector_mut(&mut [0.0; 9]),
+            &2
+        ),
+        Err(LapackError::Argument(6))
+    );
+}
@@ -0,0 +1,202 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use naive_blas::lapack::{
+    hetrf::hetrf, hetrs::hetrs, sysv::sysv, sytrf::sytrf, sytrs::sytrs, LapackError,
+};
+use num_complex::Complex;
+
+use common::{assert_matrix_close, mul, vector, vector_mut, Rng};
+
+type C = Complex<f64>;
+
+/// Returns a random n by n matrix, Hermitian if hermitian is set and symmetric otherwise.
+///
+/// A zero diagonal makes sytrf choose 2 by 2 pivots.
+fn random(rng: &mut Rng, n: usize, hermitian: bool, zero_diagonal: bool) -> Vec<C> {
+    let mut a = vec![C::new(0.0, 0.0); n * n];
+    for j in 0..n {
+        for i in 0..j {
+            let x = rng.complex();
+            a[i + j * n] = x;
+            a[j + i * n] = if hermitian { x.conj() } else { x };
+        }
+        if !zero_diagonal {
+            let x = rng.complex();
+            a[j + j * n] = if hermitian { C::from(x.re) } else { x };
+        }
+    }
+    a
+}
+
+/// Factors the stored triangle of a and solves A * X = B, checking the residual A * X - B.
+fn check_solve(hermitian: bool, uplo: char, n: usize, full: &[C], a: &[C], lda: usize, x: &[C]) {
+    let (ldb, nrhs) = (n + 1, x.len() / n);
+    let ax = mul(n, nrhs, n, |i, l| full[i + l * n], |l, j| x[l + j * n]);
+    let mut b = vec![C::new(0.0, 0.0); ldb * nrhs];
+    for j in 0..nrhs {
+        b[j * ldb..j * ldb + n].copy_from_slice(&ax[j * n..(j + 1) * n]);
+    }
+
+    let mut af = a.to_vec();
+    let mut ipiv = vec![0; n];
+    let factor = if hermitian { hetrf } else { sytrf };
+    factor(
+        &uplo,
+        &(n as isize),
+        &mut vector_mut(&mut af),
+        &(lda as isize),
+        &mut vector_mut(&mut ipiv),
+    )
+    .unwrap();
+
+    let mut solution = b.clone();
+    let solve = if hermitian { hetrs } else { sytrs };
+    solve(
+        &uplo,
+        &(n as isize),
+        &(nrhs as isize),
+        &vector(&af),
+        &(lda as isize),
+        &vector(&ipiv),
+        &mut vector_mut(&mut solution),
+        &(ldb as isize),
+    )
+    .unwrap();
+
+    let residual = mul(
+        n,
+        nrhs,
+        n,
+        |i, l| full[i + l * n],
+        |l, j| solution[l + j * ldb],
+    );
+    assert_matrix_close(n, nrhs, &residual, n, &b, ldb, 1e-10);
+    assert_matrix_close(n, nrhs, &solution, ldb, x, n, 1e-7);
+
+    if !hermitian {
+        // sysv is sytrf followed by sytrs
+        let (mut a2, mut b2) = (a.to_vec(), b.clone());
+        sysv(
+            &uplo,
+            &(n as isize),
+            &(nrhs as isize),
+            &mut vector_mut(&mut a2),
+            &(lda as isize),
+            &mut vector_mut(&mut ipiv),
+            &mut vector_mut(&mut b2),
+            &(ldb as isize),
+        )
+        .unwrap();
+        // the NaN outside the stored triangle compares unequal to itself
+        assert!(a2
+            .iter()
+            .zip(&af)
+            .all(|(x, y)| x == y || (x.is_nan() && y.is_nan())));
+        assert_eq!(b2, solution);
+    }
+}
+
+#[test]
+fn sytrf_and_hetrf_solve_with_either_triangle() {
+    let mut rng = Rng::new(47);
+
+    for n in [1, 2, 3, 4, 7, 20, 45, 70] {
+        for hermitian in [false, true] {
+            for zero_diagonal in [false, n > 1] {
+                let full = random(&mut rng, n, hermitian, zero_diagonal);
+                let x = rng.complex_vec(n * 3);
+
+                for uplo in ['U', 'L'] {
+                    // the other triangle is not referenced
+                    let lda = n + 2;
+                    let mut a = vec![C::new(f64::NAN, f64::NAN); lda * n];
+                    for j in 0..n {
+                        for i in 0..n {
+                            if (uplo == 'U') == (i <= j) || i == j {
+                                a[i + j * lda] = full[i + j * n];
+                            }
+                        }
+                    }
+                    check_solve(hermitian, uplo, n, &full, &a, lda, &x);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn real_matrices_work_too() {
+    // [0 1 2]
+    // [1 0 3]
+    // [2 3 0], whose zero diagonal needs a 2 by 2 pivot
+    for uplo in ['U', 'L'] {
+        let mut a = [0.0f64, 1.0, 2.0, 1.0, 0.0, 3.0, 2.0, 3.0, 0.0];
+        let mut b = [3.0, 4.0, 5.0];
+        let mut ipiv = [0; 3];
+        sysv(
+            &uplo,
+            &3,
+            &1,
+            &mut vector_mut(&mut a),
+            &3,
+            &mut vector_mut(&mut ipiv),
+            &mut vector_mut(&mut b),
+            &3,
+        )
+        .unwrap();
+        assert!(ipiv.iter().any(|&p| p < 0));
+        for (b, x) in b.iter().zip([1.0, 1.0, 1.0]) {
+            common::assert_close(*b, x, 1e-12);
+        }
+    }
+}
+
+#[test]
+fn singular_matrices_are_reported() {
+    for uplo in ['U', 'L'] {
+        // diag(1, 0, 2), b being left alone
+        let mut a = [1.0f64, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 2.0];
+        let mut ipiv = [0; 3];
+        let mut b = [1.0; 3];
+        assert_eq!(
+            sysv(
+                &uplo,
+                &3,
+                &1,
+                &mut vector_mut(&mut a),
+                &3,
+                &mut vector_mut(&mut ipiv),
+                &mut vector_mut(&mut b),
+                &3
+            ),
+            Err(LapackError::Singular(1))
+        );
+        assert_eq!(b, [1.0; 3]);
+    }
+
+    assert_eq!(
+        sytrf(
+            &'X',
+            &1,
+            &mut vector_mut(&mut [1.0f64]),
+            &1,
+            &mut vector_mut(&mut [0])
+        ),
+        Err(LapackError::Argument(1))
+    );
+    assert_eq!(
+        hetrs(
+            &'U',
+            &2,
+            &1,
+            &vector(&[1.0f64; 4]),
+            &2,
+            &vector(&[0, 1]),
+            &mut vector_mut(&mut [0.0; 2]),
+            &1
+        ),
+        Err(LapackError::Argument(8))
+    );
+}
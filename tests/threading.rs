@@ -0,0 +1,144 @@
+#![cfg(feature = "rayon")]
+
+mod common;
+
+use naive_blas::level_1::{axpy, copy, dot, nrm2, rot, rotm, scal, swap};
+use naive_blas::threading::{num_threads, set_num_threads, set_threshold, with_num_threads};
+
+use common::{assert_close, vector, vector_mut, Rng};
+
+const N: usize = 10_007;
+
+/// Runs f split across 4 threads and on the calling thread only, and returns both results.
+fn both<R>(f: impl Fn() -> R) -> (R, R) {
+    set_num_threads(4);
+    set_threshold(1000);
+
+    (f(), with_num_threads(1, f))
+}
+
+#[test]
+fn override_is_scoped() {
+    assert_eq!(with_num_threads(1, num_threads), 1);
+    assert_eq!(with_num_threads(2, || with_num_threads(5, num_threads)), 5);
+
+    let restored = with_num_threads(2, || {
+        with_num_threads(5, || ());
+        num_threads()
+    });
+    assert_eq!(restored, 2);
+}
+
+#[test]
+fn elementwise_routines_match_the_single_threaded_ones() {
+    let mut rng = Rng::new(1);
+    let (x, y) = (rng.vec(N), rng.vec(N));
+    let n = N as isize;
+
+    let (par, seq) = both(|| {
+        let mut y = y.clone();
+        axpy::axpy_real(&n, &0.5, &vector(&x), &1, &mut vector_mut(&mut y), &1);
+        y
+    });
+    assert_eq!(par, seq);
+
+    let (par, seq) = both(|| {
+        let mut x = x.clone();
+        scal::scal_real(&n, &-3.0, &mut vector_mut(&mut x), &1);
+        x
+    });
+    assert_eq!(par, seq);
+
+    let (par, _) = both(|| {
+        let mut y = y.clone();
+        copy::copy(&n, &vector(&x), &1, &mut vector_mut(&mut y), &1);
+        y
+    });
+    assert_eq!(par, x);
+
+    let (par, _) = both(|| {
+        let (mut xs, mut ys) = (x.clone(), y.clone());
+        swap::swap(
+            &n,
+            &mut vector_mut(&mut xs),
+            &1,
+            &mut vector_mut(&mut ys),
+            &1,
+        );
+        (xs, ys)
+    });
+    assert_eq!(par, (y.clone(), x.clone()));
+
+    let (par, seq) = both(|| {
+        let (mut xs, mut ys) = (x.clone(), y.clone());
+        rot::rot(
+            &n,
+            &mut vector_mut(&mut xs),
+            &1,
+            &mut vector_mut(&mut ys),
+            &1,
+            &0.6,
+            &0.8,
+        );
+        (xs, ys)
+    });
+    assert_eq!(par, seq);
+
+    for &flag in &[-2.0, -1.0, 0.0, 1.0] {
+        let (par, seq) = both(|| {
+            let (mut xs, mut ys) = (x.clone(), y.clone());
+            let mut param = [flag, 0.5, -0.25, 0.75, 2.0];
+            rotm::rotm(
+                &n,
+                &mut vector_mut(&mut xs),
+                &1,
+                &mut vector_mut(&mut ys),
+                &1,
+                &mut vector_mut(&mut param),
+            );
+            (xs, ys)
+        });
+        assert_eq!(par, seq, "flag = {}", flag);
+    }
+}
+
+#[test]
+fn reductions_are_deterministic() {
+    let mut rng = Rng::new(2);
+    let (x, y) = (rng.vec(N), rng.vec(N));
+    let n = N as isize;
+
+    let dot = || dot::dot::<f64, f64>(&n, &vector(&x), &1, &vector(&y), &1);
+    let (par, seq) = both(dot);
+    assert_eq!(par, both(dot).0);
+    assert_close(par, seq, 1e-13);
+
+    let nrm2 = || nrm2::nrm2(&n, &mut vector(&x), &1);
+    let (par, seq) = both(nrm2);
+    assert_eq!(par, both(nrm2).0);
+    assert_close(par, seq, 1e-13);
+
+    // the per-thread norms are combined without overflowing
+    let big: Vec<f64> = x.iter().map(|xi| xi * 1e300).collect();
+    let (par, seq) = both(|| nrm2::nrm2(&n, &mut vector(&big), &1));
+    assert_close(par / 1e300, seq / 1e300, 1e-13);
+}
+
+#[test]
+fn changing_the_thread_count_keeps_reductions_deterministic() {
+    let mut rng = Rng::new(3);
+    let (x, y) = (rng.vec(N), rng.vec(N));
+    let n = N as isize;
+    set_threshold(1000);
+
+    // each count gets a pool of its own size, so the chunks and their sums repeat
+    let dot = |threads| {
+        with_num_threads(threads, || {
+            dot::dot::<f64, f64>(&n, &vector(&x), &1, &vector(&y), &1)
+        })
+    };
+    let (two, three) = (dot(2), dot(3));
+    assert_eq!(dot(2), two);
+    assert_eq!(dot(3), three);
+    assert_close(two, three, 1e-13);
+}
@@ -0,0 +1,291 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use naive_blas::lapack::{
+    gecon::gecon, getrf::getrf, getri::getri, lacn2::lacn2, pocon::pocon, potrf::potrf,
+    trtri::trtri, LapackError,
+};
+use num_complex::Complex;
+
+use common::{assert_matrix_close, mul, vector, vector_mut, Rng};
+
+type C = Complex<f64>;
+
+const ZERO: C = C::new(0.0, 0.0);
+const ONE: C = C::new(1.0, 0.0);
+
+fn eye(n: usize) -> Vec<C> {
+    (0..n * n)
+        .map(|p| if p % (n + 1) == 0 { ONE } else { ZERO })
+        .collect()
+}
+
+/// Returns the 1-norm of the n by n matrix a, the largest sum of the moduli of a column.
+fn norm1(n: usize, a: &[C], lda: usize) -> f64 {
+    (0..n)
+        .map(|j| (0..n).map(|i| a[i + j * lda].norm()).sum::<f64>())
+        .fold(0.0, f64::max)
+}
+
+/// Returns the ∞-norm of the n by n matrix a, the largest sum of the moduli of a row.
+fn norm_inf(n: usize, a: &[C], lda: usize) -> f64 {
+    (0..n)
+        .map(|i| (0..n).map(|j| a[i + j * lda].norm()).sum::<f64>())
+        .fold(0.0, f64::max)
+}
+
+/// Inverts the n by n matrix a with getrf and getri, returning the factors and the inverse.
+fn invert(n: usize, a: &[C], lda: usize) -> (Vec<C>, Vec<C>) {
+    let mut lu = a.to_vec();
+    let mut ipiv = vec![0; n];
+    getrf(
+        &(n as isize),
+        &(n as isize),
+        &mut vector_mut(&mut lu),
+        &(lda as isize),
+        &mut vector_mut(&mut ipiv),
+    )
+    .unwrap();
+
+    let mut inv = lu.clone();
+    getri(
+        &(n as isize),
+        &mut vector_mut(&mut inv),
+        &(lda as isize),
+        &vector(&ipiv),
+    )
+    .unwrap();
+    (lu, inv)
+}
+
+#[test]
+fn trtri_inverts_either_triangle() {
+    let mut rng = Rng::new(48);
+
+    // the unblocked and blocked paths, with the blocked one ending on a partial block
+    for n in [1, 2, 3, 10, 31, 32, 33, 70, 100] {
+        for uplo in ['U', 'L'] {
+            for diag in ['N', 'U'] {
+                // the other triangle, and the diagonal if it is a unit one, are NaN so that
+                // reading or writing them shows
+                let lda = n + 1;
+                let nan = C::new(f64::NAN, f64::NAN);
+                let stored = |i: usize, j: usize| {
+                    if i == j {
+                        diag == 'N'
+                    } else {
+                        (uplo == 'U') == (i < j)
+                    }
+                };
+                let mut a0 = vec![nan; lda * n];
+                for j in 0..n {
+                    for i in (0..n).filter(|&i| stored(i, j)) {
+                        // a dominant diagonal keeps A well conditioned
+                        a0[i + j * lda] = if i == j {
+                            rng.complex() + 4.0
+                        } else {
+                            rng.complex() * 0.3
+                        };
+                    }
+                }
+                let triangle = |a: &[C], i: usize, j: usize| {
+                    if i == j && diag == 'U' {
+                        ONE
+                    } else if stored(i, j) {
+                        a[i + j * lda]
+                    } else {
+                        ZERO
+                    }
+                };
+
+                let mut a = a0.clone();
+                trtri(
+                    &uplo,
+                    &diag,
+                    &(n as isize),
+                    &mut vector_mut(&mut a),
+                    &(lda as isize),
+                )
+                .unwrap();
+
+                let product = mul(
+                    n,
+                    n,
+                    n,
+                    |i, l| triangle(&a0, i, l),
+                    |l, j| triangle(&a, l, j),
+                );
+                assert_matrix_close(n, n, &product, n, &eye(n), n, 1e-12);
+                for j in 0..n {
+                    assert!((0..n)
+                        .filter(|&i| !stored(i, j))
+                        .all(|i| a[i + j * lda].is_nan()));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn getri_inverts_a() {
+    let mut rng = Rng::new(49);
+
+    for n in [1, 2, 3, 10, 33, 70] {
+        let lda = n + 2;
+        let a = rng.complex_vec(lda * n);
+        let (_, inv) = invert(n, &a, lda);
+
+        let product = mul(n, n, n, |i, l| a[i + l * lda], |l, j| inv[l + j * lda]);
+        assert_matrix_close(n, n, &product, n, &eye(n), n, 1e-9);
+    }
+
+    // U(1, 1) is zero and A is left alone
+    let mut a = [1.0f64, 0.0, 2.0, 0.0];
+    assert_eq!(
+        getri(&2, &mut vector_mut(&mut a), &2, &vector(&[0, 1])),
+        Err(LapackError::Singular(1))
+    );
+    assert_eq!(a, [1.0, 0.0, 2.0, 0.0]);
+}
+
+#[test]
+fn gecon_and_pocon_estimate_the_condition_number() {
+    let mut rng = Rng::new(50);
+
+    // the estimates are lower bounds in real arithmetic, measuring the moduli by |re| + |im|
+    // can overestimate them by up to √2
+    let within = |est: f64, exact: f64| est <= exact * 1.5 && est >= exact / 10.0;
+
+    for n in [1, 2, 5, 20, 50] {
+        let lda = n + 1;
+        let a = rng.complex_vec(lda * n);
+        let (lu, inv) = invert(n, &a, lda);
+
+        for norm in ['1', 'I'] {
+            let (anorm, exact) = if norm == '1' {
+                let anorm = norm1(n, &a, lda);
+                (anorm, anorm * norm1(n, &inv, lda))
+            } else {
+                let anorm = norm_inf(n, &a, lda);
+                (anorm, anorm * norm_inf(n, &inv, lda))
+            };
+            let mut rcond = 0.0;
+            gecon(
+                &norm,
+                &(n as isize),
+                &vector(&lu),
+                &(lda as isize),
+                &anorm,
+                &mut rcond,
+            )
+            .unwrap();
+            assert!(within(1.0 / rcond, exact), "{} {}", 1.0 / rcond, exact);
+        }
+
+        // Bᴴ * B + I / 10, whose condition number grows with n
+        let b = rng.complex_vec(n * n);
+        let mut h = mul(n, n, n, |i, l| b[l + i * n].conj(), |l, j| b[l + j * n]);
+        for i in 0..n {
+            h[i + i * n] += 0.1;
+        }
+        let (_, inv) = invert(n, &h, n);
+        let anorm = norm1(n, &h, n);
+        let exact = anorm * norm1(n, &inv, n);
+
+        for uplo in ['U', 'L'] {
+            let mut factor = h.clone();
+            potrf(
+                &uplo,
+                &(n as isize),
+                &mut vector_mut(&mut factor),
+                &(n as isize),
+            )
+            .unwrap();
+            let mut rcond = 0.0;
+            pocon(
+                &uplo,
+                &(n as isize),
+                &vector(&factor),
+                &(n as isize),
+                &anorm,
+                &mut rcond,
+            )
+            .unwrap();
+            assert!(within(1.0 / rcond, exact), "{} {}", 1.0 / rcond, exact);
+        }
+    }
+
+    let mut rcond = 5.0;
+    gecon::<f64>(&'1', &0, &vector(&[]), &1, &1.0, &mut rcond).unwrap();
+    assert_eq!(rcond, 1.0);
+    assert_eq!(
+        gecon::<f64>(&'X', &1, &vector(&[1.0]), &1, &1.0, &mut rcond),
+        Err(LapackError::Argument(1))
+    );
+    assert_eq!(
+        gecon::<f64>(&'1', &1, &vector(&[1.0]), &1, &-1.0, &mut rcond),
+        Err(LapackError::Argument(5))
+    );
+}
+
+#[test]
+fn lacn2_estimates_the_1_norm_through_products() {
+    let mut rng = Rng::new(51);
+    let n = 6;
+    let a = rng.vec(n * n);
+
+    let (mut v, mut x, mut isave) = (vec![0.0; n], vec![0.0; n], [0; 3]);
+    let (mut est, mut kase) = (0.0, 0);
+    loop {
+        lacn2(
+            &(n as isize),
+            &mut vector_mut(&mut v),
+            &mut vector_mut(&mut x),
+            &mut est,
+            &mut kase,
+            &mut vector_mut(&mut isave),
+        );
+        if kase == 0 {
+            break;
+        }
+        // x = A * x for kase = 1 and Aᵀ * x for kase = 2
+        x = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|l| if kase == 1 { a[i + l * n] } else { a[l + i * n] } * x[l])
+                    .sum()
+            })
+            .collect();
+    }
+
+    let exact = (0..n)
+        .map(|j| (0..n).map(|i| a[i + j * n].abs()).sum::<f64>())
+        .fold(0.0, f64::max);
+    assert!(
+        est <= exact + 1e-12 && est >= exact / 3.0,
+        "{} {}",
+        est,
+        exact
+    );
+}
+
+#[test]
+fn singular_triangles_are_reported() {
+    // A(1, 1) is zero and A is left alone
+    let mut a = [1.0f64, 0.0, 2.0, 0.0];
+    assert_eq!(
+        trtri(&'U', &'N', &2, &mut vector_mut(&mut a), &2),
+        Err(LapackError::Singular(1))
+    );
+    assert_eq!(a, [1.0, 0.0, 2.0, 0.0]);
+
+    // which does not matter for a unit diagonal
+    trtri(&'U', &'U', &2, &mut vector_mut(&mut a), &2).unwrap();
+    assert_eq!(a, [1.0, 0.0, -2.0, 0.0]);
+
+    assert_eq!(
+        trtri(&'U', &'X', &2, &mut vector_mut(&mut a), &2),
+        Err(LapackError::Argument(2))
+    );
+}